@@ -22,6 +22,7 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
         &self,
         existing_data_model: &Datamodel,
         natives_types: bool,
+        referential_actions: bool,
     ) -> ConnectorResult<IntrospectionResult>;
 }
 
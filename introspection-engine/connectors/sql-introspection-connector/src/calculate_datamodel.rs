@@ -18,6 +18,7 @@ pub fn calculate_datamodel(
     family: &SqlFamily,
     previous_data_model: &Datamodel,
     native_types: bool,
+    referential_actions: bool,
 ) -> SqlIntrospectionResult<IntrospectionResult> {
     debug!("Calculating data model.");
 
@@ -25,7 +26,14 @@ pub fn calculate_datamodel(
     let mut data_model = Datamodel::new();
 
     // 1to1 translation of the sql schema
-    introspect(schema, &mut version_check, &mut data_model, *family, native_types)?;
+    introspect(
+        schema,
+        &mut version_check,
+        &mut data_model,
+        *family,
+        native_types,
+        referential_actions,
+    )?;
 
     // our opinionation about valid names
     sanitize_datamodel_names(&mut data_model, family);
@@ -60,7 +68,7 @@ mod tests {
     use super::*;
     use datamodel::{
         dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, IndexDefinition, Model,
-        OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
+        RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
     };
     use native_types::{NativeType, PostgresType};
     use pretty_assertions::assert_eq;
@@ -97,10 +105,13 @@ mod tests {
                         .to_string(),
                 ),
                 is_embedded: false,
+                is_view: false,
                 is_generated: false,
                 is_commented_out: true,
                 indices: vec![],
                 id_fields: vec![],
+                soft_delete_field: None,
+                check_constraints: vec![],
                 fields: col_types
                     .iter()
                     .map(|col_type| {
@@ -126,6 +137,7 @@ mod tests {
                             documentation,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
                             is_commented_out,
                         })
                     })
@@ -153,12 +165,14 @@ mod tests {
                 indices: vec![],
                 primary_key: None,
                 foreign_keys: vec![],
+                check_constraints: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -171,6 +185,7 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -189,6 +204,7 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField::new(
@@ -200,6 +216,8 @@ mod tests {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                soft_delete_field: None,
+                check_constraints: vec![],
             }],
             enums: vec![],
         };
@@ -234,12 +252,14 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -252,6 +272,7 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -270,6 +291,7 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -283,6 +305,7 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -296,6 +319,7 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField {
@@ -309,6 +333,7 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     }),
                 ],
@@ -317,8 +342,14 @@ mod tests {
                     name: Some("unique".into()),
                     fields: vec!["no_default".into(), "int_default".into()],
                     tpe: dml::IndexType::Unique,
+                    algorithm: dml::IndexAlgorithm::BTree,
+                    descending_fields: vec![],
+                    predicate: None,
+                    included_fields: vec![],
                 }],
                 id_fields: vec![],
+                soft_delete_field: None,
+                check_constraints: vec![],
             }],
             enums: vec![],
         };
@@ -362,15 +393,21 @@ mod tests {
                     name: "unique".to_string(),
                     columns: vec!["no_default".into(), "int_default".into()],
                     tpe: IndexType::Unique,
+                    algorithm: IndexAlgorithm::BTree,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                check_constraints: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -385,6 +422,7 @@ mod tests {
                     name: "Table1".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -397,11 +435,14 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
+                    check_constraints: vec![],
                 },
                 // Model with non-auto-incrementing primary key
                 Model {
@@ -409,6 +450,7 @@ mod tests {
                     name: "Table2".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -421,11 +463,14 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
+                    check_constraints: vec![],
                 },
                 // Model with primary key seeded by sequence
                 Model {
@@ -433,6 +478,7 @@ mod tests {
                     name: "Table3".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
                     is_commented_out: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
@@ -445,11 +491,14 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
@@ -479,6 +528,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
                 },
                 Table {
                     name: "Table2".to_string(),
@@ -502,6 +552,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
                 },
                 Table {
                     name: "Table3".to_string(),
@@ -527,13 +578,15 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -546,6 +599,7 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
@@ -564,12 +618,15 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     }),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                soft_delete_field: None,
+                check_constraints: vec![],
             }],
             enums: vec![],
         };
@@ -595,15 +652,21 @@ mod tests {
                     name: "unique".to_string(),
                     columns: vec!["unique".to_string()],
                     tpe: IndexType::Unique,
+                    algorithm: IndexAlgorithm::BTree,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                check_constraints: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -617,6 +680,7 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -630,6 +694,7 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -645,19 +710,23 @@ mod tests {
                                 fields: vec![],
                                 references: vec![],
                                 name: "CityToUser".to_string(),
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: None,
+                                on_update: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
+                    check_constraints: vec![],
                 },
                 Model {
                     database_name: None,
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -671,6 +740,7 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField {
@@ -684,6 +754,7 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField {
@@ -697,6 +768,7 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
                             is_commented_out: false,
                         }),
                         Field::RelationField(RelationField::new(
@@ -707,13 +779,16 @@ mod tests {
                                 to: "City".to_string(),
                                 fields: vec!["city_id".to_string(), "city_name".to_string()],
                                 references: vec!["id".to_string(), "name".to_string()],
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: None,
+                                on_update: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
@@ -760,6 +835,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
                 },
                 Table {
                     name: "User".to_string(),
@@ -822,13 +898,15 @@ mod tests {
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                     }],
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, expected_data_model);
     }
@@ -841,6 +919,7 @@ mod tests {
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
                 is_commented_out: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
@@ -854,6 +933,7 @@ mod tests {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
                         is_commented_out: false,
                     }),
                     Field::ScalarField(ScalarField::new(
@@ -872,8 +952,14 @@ mod tests {
                     name: Some("name_last_name_unique".to_string()),
                     fields: vec!["name".to_string(), "lastname".to_string()],
                     tpe: datamodel::dml::IndexType::Unique,
+                    algorithm: datamodel::dml::IndexAlgorithm::BTree,
+                    descending_fields: vec![],
+                    predicate: None,
+                    included_fields: vec![],
                 }],
                 id_fields: vec![],
+                soft_delete_field: None,
+                check_constraints: vec![],
             }],
             enums: vec![],
         };
@@ -929,6 +1015,10 @@ mod tests {
                     name: "name_last_name_unique".to_string(),
                     columns: vec!["name".to_string(), "lastname".to_string()],
                     tpe: IndexType::Unique,
+                    algorithm: IndexAlgorithm::BTree,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
@@ -936,12 +1026,14 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -955,6 +1047,7 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -968,6 +1061,7 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -983,19 +1077,23 @@ mod tests {
                                 fields: vec![],
                                 references: vec![],
                                 name: "CityToUser".to_string(),
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: None,
+                                on_update: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
+                    check_constraints: vec![],
                 },
                 Model {
                     database_name: None,
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
                     is_commented_out: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
@@ -1009,6 +1107,7 @@ mod tests {
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
                             is_commented_out: false,
                         }),
                         Field::ScalarField(ScalarField::new(
@@ -1024,13 +1123,16 @@ mod tests {
                                 to: "City".to_string(),
                                 fields: vec!["city_id".to_string()],
                                 references: vec!["id".to_string()],
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: None,
+                                on_update: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    soft_delete_field: None,
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
@@ -1077,6 +1179,7 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
                 },
                 Table {
                     name: "User".to_string(),
@@ -1124,13 +1227,15 @@ mod tests {
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string()],
                     }],
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -1169,9 +1274,10 @@ mod tests {
                 values: enum_values,
             }],
             sequences: vec![],
+            views: vec![],
         };
-        let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
+        let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false, false)
+            .expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
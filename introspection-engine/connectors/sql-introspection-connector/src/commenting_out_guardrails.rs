@@ -70,7 +70,9 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, family: &SqlFamily)
     // use unsupported types to drop @@id / @@unique /@@index
     for mf in &unsupported_types {
         let model = datamodel.find_model_mut(&mf.model);
-        model.indices.retain(|i| !i.fields.contains(&mf.field));
+        model
+            .indices
+            .retain(|i| !i.fields.contains(&mf.field) && !i.included_fields.contains(&mf.field));
         if model.id_fields.contains(&mf.field) {
             model.id_fields = vec![]
         };
@@ -104,10 +106,11 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, family: &SqlFamily)
     {
         if model.strict_unique_criterias().is_empty() {
             model.is_commented_out = true;
-            model.documentation = Some(
-                "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
-                    .to_string(),
-            );
+            let relation_kind = if model.is_view { "view" } else { "table" };
+            model.documentation = Some(format!(
+                "The underlying {} does not contain a valid unique identifier and can therefore currently not be handled.",
+                relation_kind
+            ));
             models_without_identifiers.push(Model {
                 model: model.name.clone(),
             })
@@ -1,7 +1,7 @@
 use crate::introspection_helpers::{
     calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-    calculate_scalar_field, is_new_migration_table, is_old_migration_table, is_prisma_1_point_0_join_table,
-    is_prisma_1_point_1_or_2_join_table, is_relay_table,
+    calculate_scalar_field, is_migration_lock_table, is_new_migration_table, is_old_migration_table,
+    is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table, is_relay_table,
 };
 use crate::version_checker::VersionChecker;
 use crate::Dedup;
@@ -17,12 +17,14 @@ pub fn introspect(
     data_model: &mut Datamodel,
     sql_family: SqlFamily,
     native_types: bool,
+    referential_actions: bool,
 ) -> Result<(), SqlError> {
     for table in schema
         .tables
         .iter()
         .filter(|table| !is_old_migration_table(&table))
         .filter(|table| !is_new_migration_table(&table))
+        .filter(|table| !is_migration_lock_table(&table))
         .filter(|table| !is_prisma_1_point_1_or_2_join_table(&table))
         .filter(|table| !is_prisma_1_point_0_join_table(&table))
         .filter(|table| !is_relay_table(&table))
@@ -50,7 +52,7 @@ pub fn introspect(
         }) {
             version_check.has_inline_relations(table);
             version_check.uses_on_delete(foreign_key, table);
-            let relation_field = calculate_relation_field(schema, table, foreign_key)?;
+            let relation_field = calculate_relation_field(schema, table, foreign_key, referential_actions)?;
             model.add_field(Field::RelationField(relation_field));
         }
 
@@ -66,12 +68,44 @@ pub fn introspect(
             model.id_fields = table.primary_key_columns();
         }
 
+        model.check_constraints = table
+            .check_constraints
+            .iter()
+            .map(|check| dml::CheckConstraint {
+                name: check.name.clone(),
+                expression: check.expression.clone(),
+            })
+            .collect();
+
         version_check.always_has_created_at_updated_at(table, &model);
         version_check.has_p1_compatible_primary_key_column(table);
 
         data_model.add_model(model);
     }
 
+    for view in schema.views.iter() {
+        debug!("Calculating view: {}", view.name);
+        let mut model = Model::new(view.name.clone(), None);
+        model.is_view = true;
+
+        // Views have no constraints of their own, their columns are described like the columns of a bare table.
+        let table = Table {
+            name: view.name.clone(),
+            columns: view.columns.clone(),
+            indices: Vec::new(),
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            check_constraints: Vec::new(),
+        };
+
+        for column in &table.columns {
+            let field = calculate_scalar_field(&table, &column, &sql_family, native_types);
+            model.add_field(Field::ScalarField(field));
+        }
+
+        data_model.add_model(model);
+    }
+
     for e in schema.enums.iter() {
         let values = e.values.iter().map(|v| dml::EnumValue::new(v)).collect();
         data_model.add_enum(dml::Enum::new(&e.name, values));
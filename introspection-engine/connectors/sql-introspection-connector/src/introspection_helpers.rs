@@ -2,13 +2,16 @@ use crate::Dedup;
 use crate::SqlError;
 use datamodel::{
     common::RelationNames, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition, Model,
-    OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
+    ReferentialAction, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
 };
 use datamodel_connector::Connector;
 use quaint::connector::SqlFamily;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::DefaultKind;
-use sql_schema_describer::{Column, ColumnArity, ColumnTypeFamily, ForeignKey, Index, IndexType, SqlSchema, Table};
+use sql_schema_describer::{
+    Column, ColumnArity, ColumnTypeFamily, ForeignKey, ForeignKeyAction, Index, IndexAlgorithm, IndexType, SqlSchema,
+    Table,
+};
 use tracing::debug;
 
 //checks
@@ -39,6 +42,14 @@ pub fn is_new_migration_table(table: &Table) -> bool {
         && table.columns.iter().any(|c| c.name == "applied_steps_count")
 }
 
+/// The table holding the migration lock on SQLite, which has no named locks.
+pub fn is_migration_lock_table(table: &Table) -> bool {
+    table.name == "_prisma_migrations_lock"
+        && table.columns.iter().any(|c| c.name == "id")
+        && table.columns.iter().any(|c| c.name == "owner")
+        && table.columns.iter().any(|c| c.name == "acquired_at")
+}
+
 pub(crate) fn is_relay_table(table: &Table) -> bool {
     table.name == "_RelayId"
         && table.columns[0].name == "id"
@@ -107,7 +118,8 @@ pub fn calculate_many_to_many_field(
         fields: vec![],
         to: opposite_foreign_key.referenced_table.clone(),
         references: opposite_foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
     };
 
     let basename = opposite_foreign_key.referenced_table.clone();
@@ -125,12 +137,27 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     let tpe = match index.tpe {
         IndexType::Unique => datamodel::dml::IndexType::Unique,
         IndexType::Normal => datamodel::dml::IndexType::Normal,
+        IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
+    };
+
+    let algorithm = match index.algorithm {
+        // The access method of fulltext indexes is implied by the index type.
+        _ if index.tpe == IndexType::Fulltext => datamodel::dml::IndexAlgorithm::BTree,
+        IndexAlgorithm::BTree => datamodel::dml::IndexAlgorithm::BTree,
+        IndexAlgorithm::Hash => datamodel::dml::IndexAlgorithm::Hash,
+        IndexAlgorithm::Gin => datamodel::dml::IndexAlgorithm::Gin,
+        IndexAlgorithm::Gist => datamodel::dml::IndexAlgorithm::Gist,
+        IndexAlgorithm::Brin => datamodel::dml::IndexAlgorithm::Brin,
     };
 
     IndexDefinition {
         name: Some(index.name.clone()),
         fields: index.columns.clone(),
         tpe,
+        algorithm,
+        descending_fields: index.descending_columns.clone(),
+        predicate: index.predicate.clone(),
+        included_fields: index.included_columns.clone(),
     }
 }
 
@@ -189,6 +216,7 @@ pub(crate) fn calculate_scalar_field(
         documentation,
         is_generated: false,
         is_updated_at: false,
+        is_version: false,
         is_commented_out,
     }
 }
@@ -197,6 +225,7 @@ pub(crate) fn calculate_relation_field(
     schema: &SqlSchema,
     table: &Table,
     foreign_key: &ForeignKey,
+    referential_actions: bool,
 ) -> Result<RelationField, SqlError> {
     debug!("Handling foreign key  {:?}", foreign_key);
 
@@ -205,7 +234,8 @@ pub(crate) fn calculate_relation_field(
         fields: foreign_key.columns.clone(),
         to: foreign_key.referenced_table.clone(),
         references: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
     };
 
     let columns: Vec<&Column> = foreign_key
@@ -219,7 +249,33 @@ pub(crate) fn calculate_relation_field(
         false => FieldArity::Required,
     };
 
-    Ok(RelationField::new(&foreign_key.referenced_table, arity, relation_info))
+    let mut relation_field = RelationField::new(&foreign_key.referenced_table, arity, relation_info);
+
+    if referential_actions {
+        // Only actions that differ from the defaults of the relation are rendered.
+        let on_delete = calculate_referential_action(foreign_key.on_delete_action);
+        let on_update = calculate_referential_action(foreign_key.on_update_action);
+
+        if on_delete != relation_field.resolved_on_delete() {
+            relation_field.relation_info.on_delete = Some(on_delete);
+        }
+
+        if on_update != relation_field.resolved_on_update() {
+            relation_field.relation_info.on_update = Some(on_update);
+        }
+    }
+
+    Ok(relation_field)
+}
+
+fn calculate_referential_action(action: ForeignKeyAction) -> ReferentialAction {
+    match action {
+        ForeignKeyAction::Cascade => ReferentialAction::Cascade,
+        ForeignKeyAction::Restrict => ReferentialAction::Restrict,
+        ForeignKeyAction::NoAction => ReferentialAction::NoAction,
+        ForeignKeyAction::SetNull => ReferentialAction::SetNull,
+        ForeignKeyAction::SetDefault => ReferentialAction::SetDefault,
+    }
 }
 
 pub(crate) fn calculate_backrelation_field(
@@ -239,7 +295,8 @@ pub(crate) fn calculate_backrelation_field(
                 to: model.name.clone(),
                 fields: vec![],
                 references: vec![],
-                on_delete: OnDeleteStrategy::None,
+                on_delete: None,
+                on_update: None,
             };
 
             // unique or id
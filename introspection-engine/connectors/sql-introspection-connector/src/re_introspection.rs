@@ -1,6 +1,6 @@
 use crate::introspection_helpers::replace_field_names;
 use crate::warnings::*;
-use datamodel::{Datamodel, DefaultValue, FieldType, ScalarType, ValueGenerator};
+use datamodel::{Datamodel, DefaultValue, FieldArity, FieldType, IndexDefinition, ScalarType, ValueGenerator};
 use introspection_connector::Warning;
 use prisma_value::PrismaValue;
 use quaint::connector::SqlFamily;
@@ -77,6 +77,16 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
             replace_field_names(&mut model.id_fields, &changed_field_name.0.field, &changed_field_name.1);
             for index in &mut model.indices {
                 replace_field_names(&mut index.fields, &changed_field_name.0.field, &changed_field_name.1);
+                replace_field_names(
+                    &mut index.descending_fields,
+                    &changed_field_name.0.field,
+                    &changed_field_name.1,
+                );
+                replace_field_names(
+                    &mut index.included_fields,
+                    &changed_field_name.0.field,
+                    &changed_field_name.1,
+                );
             }
             for field in model.relation_fields_mut() {
                 replace_field_names(
@@ -336,6 +346,78 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
         }
     }
 
+    // identifiers of views
+    // Views have no primary keys or unique constraints in the database, so the identifiers declared on them
+    // in the previous Prisma schema are kept. Identifier fields are required, whatever the view column says.
+    let mut re_introspected_view_identifiers = vec![];
+    {
+        for model in new_data_model.models().filter(|model| model.is_view) {
+            if let Some(old_model) = old_data_model.find_model(&model.name).filter(|model| model.is_view) {
+                let field_exists = |name: &String| model.find_scalar_field(name).is_some();
+
+                let id_fields: Vec<String> = if old_model.id_fields.iter().all(field_exists) {
+                    old_model.id_fields.clone()
+                } else {
+                    Vec::new()
+                };
+
+                let unique_indices: Vec<IndexDefinition> = old_model
+                    .indices
+                    .iter()
+                    .filter(|index| index.is_unique() && index.fields.iter().all(field_exists))
+                    .cloned()
+                    .collect();
+
+                let id_field = old_model
+                    .scalar_fields()
+                    .find(|field| field.is_id && field_exists(&field.name))
+                    .map(|field| field.name.clone());
+
+                let unique_fields: Vec<String> = old_model
+                    .scalar_fields()
+                    .filter(|field| field.is_unique && field_exists(&field.name))
+                    .map(|field| field.name.clone())
+                    .collect();
+
+                if id_fields.is_empty() && unique_indices.is_empty() && id_field.is_none() && unique_fields.is_empty() {
+                    continue;
+                }
+
+                re_introspected_view_identifiers.push((
+                    Model::new(&model.name),
+                    id_fields,
+                    unique_indices,
+                    id_field,
+                    unique_fields,
+                ));
+            }
+        }
+
+        for (view, id_fields, unique_indices, id_field, unique_fields) in &re_introspected_view_identifiers {
+            let model = new_data_model.find_model_mut(&view.model);
+
+            let identifier_fields: Vec<String> = id_fields
+                .iter()
+                .chain(unique_indices.iter().flat_map(|index| index.fields.iter()))
+                .chain(id_field.iter())
+                .chain(unique_fields.iter())
+                .cloned()
+                .collect();
+
+            model.id_fields = id_fields.clone();
+            model.indices.extend(unique_indices.iter().cloned());
+
+            for field in model.scalar_fields_mut() {
+                field.is_id = id_field.as_ref() == Some(&field.name);
+                field.is_unique = unique_fields.contains(&field.name);
+
+                if identifier_fields.contains(&field.name) {
+                    field.arity = FieldArity::Required;
+                }
+            }
+        }
+    }
+
     // comments - we do NOT generate warnings for comments
     {
         let mut re_introspected_model_comments = vec![];
@@ -461,6 +543,15 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
         warnings.push(warning_enriched_with_updated_at(&re_introspected_updated_at));
     }
 
+    if !re_introspected_view_identifiers.is_empty() {
+        let views: Vec<_> = re_introspected_view_identifiers
+            .iter()
+            .map(|identifiers| identifiers.0.clone())
+            .collect();
+
+        warnings.push(warning_enriched_views_with_identifiers(&views));
+    }
+
     warnings
 }
 
@@ -96,6 +96,8 @@ fn sanitize_models(datamodel: &mut Datamodel, family: &SqlFamily) -> HashMap<Str
 
         for index in &mut model.indices {
             index.fields = sanitize_strings(&index.fields);
+            index.descending_fields = sanitize_strings(&index.descending_fields);
+            index.included_fields = sanitize_strings(&index.included_fields);
         }
     }
 
@@ -186,3 +186,11 @@ pub fn warning_models_without_columns(affected: &[Model]) -> Warning {
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
+
+pub fn warning_enriched_views_with_identifiers(affected: &[Model]) -> Warning {
+    Warning {
+        code: 15,
+        message: "These views were enriched with the identifiers declared on them in the previous Prisma schema, as views have no identifiers of their own in the database.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
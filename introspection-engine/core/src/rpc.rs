@@ -101,16 +101,22 @@ impl RpcImpl {
             Datamodel::new()
         };
 
-        let native_types = match datamodel::parse_configuration(&schema) {
-            Ok(config) => config
-                .subject
-                .generators
-                .iter()
-                .any(|g| g.has_preview_feature("nativeTypes")),
-            Err(_) => false,
+        let (native_types, referential_actions) = match datamodel::parse_configuration(&schema) {
+            Ok(config) => {
+                let generators = &config.subject.generators;
+
+                (
+                    generators.iter().any(|g| g.has_preview_feature("nativeTypes")),
+                    generators.iter().any(|g| g.has_preview_feature("referentialActions")),
+                )
+            }
+            Err(_) => (false, false),
         };
 
-        let result = match connector.introspect(&input_data_model, native_types).await {
+        let result = match connector
+            .introspect(&input_data_model, native_types, referential_actions)
+            .await
+        {
             Ok(introspection_result) => {
                 if introspection_result.data_model.is_empty() {
                     Err(Error::from(CommandError::IntrospectionResultEmpty(url.to_string())))
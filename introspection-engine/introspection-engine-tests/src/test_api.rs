@@ -72,7 +72,7 @@ impl TestApi {
     pub async fn introspect(&self) -> Result<String> {
         let introspection_result = self
             .introspection_connector
-            .introspect(&Datamodel::new(), false)
+            .introspect(&Datamodel::new(), false, false)
             .await?;
 
         Ok(datamodel::render_datamodel_to_string(&introspection_result.data_model))
@@ -82,10 +82,14 @@ impl TestApi {
         let config = parse_configuration(data_model_string)?;
         let data_model = parse_datamodel(data_model_string, &config)?;
         let native_types = config.generators.iter().any(|g| g.has_preview_feature("nativeTypes"));
+        let referential_actions = config
+            .generators
+            .iter()
+            .any(|g| g.has_preview_feature("referentialActions"));
 
         let introspection_result = self
             .introspection_connector
-            .introspect(&data_model, native_types)
+            .introspect(&data_model, native_types, referential_actions)
             .await?;
 
         let dm = datamodel::render_datamodel_and_config_to_string(&introspection_result.data_model, &config);
@@ -96,7 +100,10 @@ impl TestApi {
     pub async fn re_introspect_warnings(&self, data_model_string: &str) -> Result<String> {
         let config = parse_configuration(data_model_string)?;
         let data_model = parse_datamodel(data_model_string, &config)?;
-        let introspection_result = self.introspection_connector.introspect(&data_model, false).await?;
+        let introspection_result = self
+            .introspection_connector
+            .introspect(&data_model, false, false)
+            .await?;
 
         Ok(serde_json::to_string(&introspection_result.warnings)?)
     }
@@ -104,7 +111,7 @@ impl TestApi {
     pub async fn introspect_version(&self) -> Result<Version> {
         let introspection_result = self
             .introspection_connector
-            .introspect(&Datamodel::new(), false)
+            .introspect(&Datamodel::new(), false, false)
             .await?;
 
         Ok(introspection_result.version)
@@ -113,7 +120,7 @@ impl TestApi {
     pub async fn introspection_warnings(&self) -> Result<String> {
         let introspection_result = self
             .introspection_connector
-            .introspect(&Datamodel::new(), false)
+            .introspect(&Datamodel::new(), false, false)
             .await?;
 
         Ok(serde_json::to_string(&introspection_result.warnings)?)
@@ -953,3 +953,93 @@ async fn many_to_many_relation_field_names_do_not_conflict_with_themselves(api:
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn referential_actions_are_introspected_with_the_preview_feature(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("user_id", types::integer().nullable(false));
+                t.inject_custom(
+                    r#"CONSTRAINT "Post_user_id_fkey" FOREIGN KEY ("user_id") REFERENCES "User"("id") ON DELETE RESTRICT ON UPDATE NO ACTION"#,
+                );
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        datasource postgres {
+            provider = "postgres"
+            url      = "postgres://localhost/test"
+        }
+
+        generator client {
+            provider        = "prisma-client-js"
+            previewFeatures = ["referentialActions"]
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        model Post {
+            id      Int  @id @default(autoincrement())
+            user_id Int
+            User    User @relation(fields: [user_id], references: [id], onDelete: Restrict, onUpdate: NoAction)
+        }
+    "#};
+
+    let result = api.re_introspect(dm).await?;
+
+    assert!(result.replace(" ", "").contains(&expected.replace(" ", "")));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn default_referential_actions_are_not_rendered(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("user_id", types::integer().nullable(false));
+                t.inject_custom(
+                    r#"CONSTRAINT "Post_user_id_fkey" FOREIGN KEY ("user_id") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE"#,
+                );
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        datasource postgres {
+            provider = "postgres"
+            url      = "postgres://localhost/test"
+        }
+
+        generator client {
+            provider        = "prisma-client-js"
+            previewFeatures = ["referentialActions"]
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        model Post {
+            id      Int  @id @default(autoincrement())
+            user_id Int
+            User    User @relation(fields: [user_id], references: [id])
+        }
+    "#};
+
+    let result = api.re_introspect(dm).await?;
+
+    assert!(result.replace(" ", "").contains(&expected.replace(" ", "")));
+
+    Ok(())
+}
@@ -73,10 +73,12 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> crate::Tes
                 },
             ),
             foreign_keys: [],
+            check_constraints: [],
         },
     ],
     enums: [],
     sequences: [],
+    views: [],
 }"#;
 
     assert_eq_schema!(expected, api.get_database_description().await?);
@@ -142,10 +144,12 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> crate::T
                 },
             ),
             foreign_keys: [],
+            check_constraints: [],
         },
     ],
     enums: [],
     sequences: [],
+    views: [],
 }"#;
 
     assert_eq_schema!(expected, api.get_database_description().await?);
@@ -222,6 +226,7 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                 },
             ),
             foreign_keys: [],
+            check_constraints: [],
         },
     ],
     enums: [],
@@ -230,6 +235,7 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                 name: "Blog_id_seq",
         },
     ],
+    views: [],
 }"#;
 
     assert_eq_schema!(expected, api.get_database_description().await?);
@@ -285,10 +291,12 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> crate::Te
                 },
             ),
             foreign_keys: [],
+            check_constraints: [],
         },
     ],
     enums: [],
     sequences: [],
+    views: [],
 }"#;
 
     assert_eq_schema!(expected, api.get_database_description().await?);
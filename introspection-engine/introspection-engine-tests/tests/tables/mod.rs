@@ -1,7 +1,8 @@
 use barrel::{functions, types};
 use indoc::indoc;
-use introspection_engine_tests::{assert_eq_datamodels, test_api::*};
+use introspection_engine_tests::{assert_eq_datamodels, assert_eq_json, test_api::*};
 use quaint::prelude::Queryable;
+use serde_json::json;
 use test_macros::test_each_connector;
 
 #[test_each_connector]
@@ -76,6 +77,12 @@ async fn should_ignore_prisma_helper_tables(api: &TestApi) -> crate::TestResult
                     t.add_column("started_at", types::text());
                     t.add_column("applied_steps_count", types::text());
                 });
+
+                migration.create_table("_prisma_migrations_lock", move |t| {
+                    t.add_column("id", types::primary());
+                    t.add_column("owner", types::text());
+                    t.add_column("acquired_at", types::integer());
+                });
             },
             api.schema_name(),
         )
@@ -658,3 +665,206 @@ async fn negative_default_values_should_work(api: &TestApi) -> crate::TestResult
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn views_are_introspected_as_commented_out_views_without_identifier(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("User", move |t| {
+                    t.add_column("id", types::primary());
+                    t.add_column("name", types::text());
+                });
+            },
+            api.schema_name(),
+        )
+        .await?;
+
+    let create_view = format!(
+        r#"CREATE VIEW "{0}"."UserName" AS SELECT id, name FROM "{0}"."User""#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_view).await?;
+
+    let dm = indoc! {r##"
+        model User {
+          id   Int    @id @default(autoincrement())
+          name String
+        }
+
+        // The underlying view does not contain a valid unique identifier and can therefore currently not be handled.
+        // view UserName {
+          // id   Int?
+          // name String?
+        // }
+    "##};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn a_table_with_check_constraints(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Product", |t| {
+                    t.add_column("id", types::primary());
+                    t.add_column("price", types::integer());
+                    t.inject_custom("CONSTRAINT \"positivePrice\" CHECK (price > 0)");
+                });
+            },
+            api.schema_name(),
+        )
+        .await?;
+
+    let dm = indoc! {r#"
+        model Product {
+          id    Int @id @default(autoincrement())
+          price Int
+
+          @@check(name: "positivePrice", expression: "(price > 0)")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn a_table_with_check_constraints_on_mysql(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Product", |t| {
+                    t.add_column("id", types::primary());
+                    t.add_column("price", types::integer());
+                    t.inject_custom("CONSTRAINT `positivePrice` CHECK (price > 0)");
+                });
+            },
+            api.schema_name(),
+        )
+        .await?;
+
+    let dm = indoc! {r#"
+        model Product {
+          id    Int @id @default(autoincrement())
+          price Int
+
+          @@check(name: "positivePrice", expression: "(`price` > 0)")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn a_table_with_check_constraints_on_sql_server(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Product", |t| {
+                    t.add_column("id", types::primary());
+                    t.add_column("price", types::integer());
+                    t.inject_custom("CONSTRAINT [positivePrice] CHECK (price > 0)");
+                });
+            },
+            api.schema_name(),
+        )
+        .await?;
+
+    let dm = indoc! {r#"
+        model Product {
+          id    Int @id @default(autoincrement())
+          price Int
+
+          @@check(name: "positivePrice", expression: "([price]>(0))")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn a_table_with_check_constraints_on_sqlite(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Product", |t| {
+                    t.add_column("id", types::primary());
+                    t.add_column("price", types::integer());
+                    t.inject_custom(r#"CONSTRAINT "positivePrice" CHECK (price > 0)"#);
+                });
+            },
+            api.schema_name(),
+        )
+        .await?;
+
+    let dm = indoc! {r#"
+        model Product {
+          id    Int @id @default(autoincrement())
+          price Int
+
+          @@check(name: "positivePrice", expression: "price > 0")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn re_introspected_views_keep_their_identifiers(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("User", move |t| {
+                    t.add_column("id", types::primary());
+                    t.add_column("name", types::text());
+                });
+            },
+            api.schema_name(),
+        )
+        .await?;
+
+    let create_view = format!(
+        r#"CREATE VIEW "{0}"."UserName" AS SELECT id, name FROM "{0}"."User""#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_view).await?;
+
+    let input_dm = indoc! {r##"
+        model User {
+          id   Int    @id @default(autoincrement())
+          name String
+        }
+
+        view UserName {
+          id   Int     @id
+          name String?
+        }
+    "##};
+
+    assert_eq_datamodels!(input_dm, &api.re_introspect(input_dm).await?);
+
+    let expected = json!([{
+        "code": 15,
+        "message": "These views were enriched with the identifiers declared on them in the previous Prisma schema, as views have no identifiers of their own in the database.",
+        "affected": [{
+            "model": "UserName"
+        }]
+    }]);
+
+    assert_eq_json!(expected, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
@@ -3,9 +3,11 @@ use crate::connector_error::ConnectorError;
 use crate::{ConnectorCapability, NativeTypeConstructor, NativeTypeInstance};
 use dml::field::Field;
 use dml::model::Model;
+use dml::relation_info::ReferentialAction;
 
 pub struct CombinedConnector {
     capabilities: Vec<ConnectorCapability>,
+    referential_actions: Vec<ReferentialAction>,
 }
 
 impl CombinedConnector {
@@ -23,8 +25,14 @@ impl CombinedConnector {
             }
         }
 
+        let referential_actions = ReferentialAction::all()
+            .into_iter()
+            .filter(|action| connectors.iter().all(|c| c.supports_referential_action(*action)))
+            .collect();
+
         CombinedConnector {
             capabilities: combined_capabilities,
+            referential_actions,
         }
     }
 }
@@ -34,6 +42,10 @@ impl Connector for CombinedConnector {
         &self.capabilities
     }
 
+    fn referential_actions(&self) -> Vec<ReferentialAction> {
+        self.referential_actions.clone()
+    }
+
     fn validate_field(&self, _field: &Field) -> Result<(), ConnectorError> {
         Ok(())
     }
@@ -9,6 +9,7 @@ use dml::field::Field;
 use dml::model::Model;
 use dml::native_type_constructor::NativeTypeConstructor;
 use dml::native_type_instance::NativeTypeInstance;
+use dml::relation_info::ReferentialAction;
 
 pub trait Connector: Send + Sync {
     fn capabilities(&self) -> &Vec<ConnectorCapability>;
@@ -75,6 +76,35 @@ pub trait Connector: Send + Sync {
         self.has_capability(ConnectorCapability::RelationFieldsInArbitraryOrder)
     }
 
+    fn supports_fulltext_index(&self) -> bool {
+        self.has_capability(ConnectorCapability::FullTextIndex)
+    }
+
+    fn supports_index_algorithms(&self) -> bool {
+        self.has_capability(ConnectorCapability::IndexAlgorithms)
+    }
+
+    fn supports_descending_index_fields(&self) -> bool {
+        self.has_capability(ConnectorCapability::DescendingIndexFields)
+    }
+
+    fn supports_partial_indexes(&self) -> bool {
+        self.has_capability(ConnectorCapability::PartialIndexes)
+    }
+
+    fn supports_included_index_fields(&self) -> bool {
+        self.has_capability(ConnectorCapability::IncludedIndexFields)
+    }
+
+    /// The referential actions the connector can enforce in `onDelete` and `onUpdate` of relations.
+    fn referential_actions(&self) -> Vec<ReferentialAction> {
+        ReferentialAction::all()
+    }
+
+    fn supports_referential_action(&self, action: ReferentialAction) -> bool {
+        self.referential_actions().contains(&action)
+    }
+
     fn wrap_in_argument_count_mismatch_error(
         &self,
         native_type: &str,
@@ -108,8 +138,18 @@ pub enum ConnectorCapability {
     AutoIncrementMultipleAllowed,
     AutoIncrementNonIndexedAllowed,
     RelationFieldsInArbitraryOrder,
+    FullTextIndex,
+    IndexAlgorithms,
+    DescendingIndexFields,
+    PartialIndexes,
+    IncludedIndexFields,
     // start of Query Engine Capabilities
     InsensitiveFilters,
+    CreateSkipDuplicates,
+    OrderByNullsFirstLast,
+    JsonFilteringJsonPath,
+    JsonFilteringArrayPath,
+    FullTextSearch,
 }
 
 /// Contains all capabilities that the connector is able to serve.
@@ -1,6 +1,7 @@
 use super::*;
 use crate::default_value::{DefaultValue, ValueGenerator};
 use crate::native_type_instance::NativeTypeInstance;
+use crate::relation_info::ReferentialAction;
 use crate::scalars::ScalarType;
 use crate::traits::{WithDatabaseName, WithName};
 use std::hash::Hash;
@@ -138,6 +139,13 @@ impl Field {
         }
     }
 
+    pub fn is_version(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.is_version,
+            Field::RelationField(_) => false,
+        }
+    }
+
     pub fn is_unique(&self) -> bool {
         match &self {
             Field::ScalarField(sf) => sf.is_unique,
@@ -253,6 +261,21 @@ impl RelationField {
         self.arity.is_optional()
     }
 
+    /// The action on deletion of the related record. Without an explicit `onDelete`,
+    /// required relations cascade and optional relations set the referencing fields to null.
+    pub fn resolved_on_delete(&self) -> ReferentialAction {
+        self.relation_info.on_delete.unwrap_or(if self.is_required() {
+            ReferentialAction::Cascade
+        } else {
+            ReferentialAction::SetNull
+        })
+    }
+
+    /// The action on updates of the referenced fields. Cascades without an explicit `onUpdate`.
+    pub fn resolved_on_update(&self) -> ReferentialAction {
+        self.relation_info.on_update.unwrap_or(ReferentialAction::Cascade)
+    }
+
     /// A relation field is virtual if there's no reference to the related model stored in this model.
     /// example: In SQL this means that this will return true if the foreign key is stored on the other side.
     pub fn is_virtual(&self) -> bool {
@@ -294,6 +317,10 @@ pub struct ScalarField {
     /// automatically.
     pub is_updated_at: bool,
 
+    /// If set, signals that this field holds the version of the record, which is checked
+    /// and incremented on every update.
+    pub is_version: bool,
+
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
 }
@@ -312,6 +339,7 @@ impl ScalarField {
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            is_version: false,
             is_commented_out: false,
         }
     }
@@ -15,10 +15,16 @@ pub struct Model {
     pub database_name: Option<String>,
     /// Indicates if this model is embedded or not.
     pub is_embedded: bool,
+    /// Indicates if this model is backed by a database view, and therefore read-only.
+    pub is_view: bool,
     /// Describes Composite Indexes
     pub indices: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// The timestamp field marking records as deleted, if the model is soft deleted.
+    pub soft_delete_field: Option<String>,
+    /// Describes `@@check` constraints.
+    pub check_constraints: Vec<CheckConstraint>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
@@ -31,18 +37,54 @@ pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// The index access method, set with `type:`.
+    pub algorithm: IndexAlgorithm,
+    /// The fields declared with `sort: Desc`.
+    pub descending_fields: Vec<String>,
+    /// The `where:` predicate of a partial index, as raw SQL.
+    pub predicate: Option<String>,
+    /// The covering fields, set with `include:`.
+    pub included_fields: Vec<String>,
 }
 
 impl IndexDefinition {
     pub fn is_unique(&self) -> bool {
         matches!(self.tpe, IndexType::Unique)
     }
+
+    pub fn field_is_descending(&self, field_name: &str) -> bool {
+        self.descending_fields.iter().any(|field| field == field_name)
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum IndexType {
     Unique,
     Normal,
+    Fulltext,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IndexAlgorithm {
+    BTree,
+    Hash,
+    Gin,
+    Gist,
+    Brin,
+}
+
+impl Default for IndexAlgorithm {
+    fn default() -> Self {
+        IndexAlgorithm::BTree
+    }
+}
+
+/// Represents a check constraint defined via `@@check`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    pub name: String,
+    /// The constraint expression, as raw SQL.
+    pub expression: String,
 }
 
 /// A unique criteria is a set of fields through which a record can be uniquely identified.
@@ -65,9 +107,12 @@ impl Model {
             fields: vec![],
             indices: vec![],
             id_fields: vec![],
+            soft_delete_field: None,
+            check_constraints: vec![],
             documentation: None,
             database_name,
             is_embedded: false,
+            is_view: false,
             is_generated: false,
             is_commented_out: false,
         }
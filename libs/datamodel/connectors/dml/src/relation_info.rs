@@ -11,7 +11,10 @@ pub struct RelationInfo {
     pub name: String,
     /// A strategy indicating what happens when
     /// a related node is deleted.
-    pub on_delete: OnDeleteStrategy,
+    pub on_delete: Option<ReferentialAction>,
+    /// A strategy indicating what happens when
+    /// the referenced fields of a related node are updated.
+    pub on_update: Option<ReferentialAction>,
 }
 
 impl PartialEq for RelationInfo {
@@ -21,6 +24,7 @@ impl PartialEq for RelationInfo {
             && self.fields == other.fields
             && self.references == other.references
             && self.on_delete == other.on_delete
+            && self.on_update == other.on_update
     }
 }
 
@@ -33,23 +37,65 @@ impl RelationInfo {
             fields: Vec::new(),
             references: Vec::new(),
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: None,
+            on_update: None,
         }
     }
 }
 
-/// Describes what happens when related nodes are deleted.
-#[derive(Debug, Copy, PartialEq, Clone)]
-pub enum OnDeleteStrategy {
+/// Describes what happens to the referencing records when related
+/// records are deleted or their referenced fields are updated.
+#[derive(Debug, Copy, PartialEq, Eq, Clone)]
+pub enum ReferentialAction {
+    /// Delete or update the referencing records as well.
     Cascade,
-    None,
+    /// Prevent the change if referencing records exist.
+    Restrict,
+    /// Prevent the change if referencing records exist. Unlike
+    /// `Restrict`, the check can be deferred by the database.
+    NoAction,
+    /// Set the referencing fields to null.
+    SetNull,
+    /// Set the referencing fields to their default values.
+    SetDefault,
+}
+
+impl ReferentialAction {
+    /// All referential actions, in the order they are documented.
+    pub fn all() -> Vec<ReferentialAction> {
+        vec![
+            ReferentialAction::Cascade,
+            ReferentialAction::Restrict,
+            ReferentialAction::NoAction,
+            ReferentialAction::SetNull,
+            ReferentialAction::SetDefault,
+        ]
+    }
+}
+
+impl std::str::FromStr for ReferentialAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Cascade" => Ok(ReferentialAction::Cascade),
+            "Restrict" => Ok(ReferentialAction::Restrict),
+            "NoAction" => Ok(ReferentialAction::NoAction),
+            "SetNull" => Ok(ReferentialAction::SetNull),
+            "SetDefault" => Ok(ReferentialAction::SetDefault),
+            _ => Err(format!("Invalid referential action: `{}`", s)),
+        }
+    }
 }
 
-impl ToString for OnDeleteStrategy {
+impl ToString for ReferentialAction {
     fn to_string(&self) -> String {
         match self {
-            OnDeleteStrategy::Cascade => String::from("CASCADE"),
-            OnDeleteStrategy::None => String::from("NONE"),
+            ReferentialAction::Cascade => String::from("Cascade"),
+            ReferentialAction::Restrict => String::from("Restrict"),
+            ReferentialAction::NoAction => String::from("NoAction"),
+            ReferentialAction::SetNull => String::from("SetNull"),
+            ReferentialAction::SetDefault => String::from("SetDefault"),
         }
     }
 }
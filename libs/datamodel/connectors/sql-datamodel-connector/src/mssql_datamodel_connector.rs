@@ -5,6 +5,7 @@ use dml::field::{Field, FieldType};
 use dml::model::{IndexType, Model};
 use dml::native_type_constructor::NativeTypeConstructor;
 use dml::native_type_instance::NativeTypeInstance;
+use dml::relation_info::ReferentialAction;
 use dml::scalars::ScalarType;
 use native_types::{MsSqlType, MsSqlTypeParameter};
 use once_cell::sync::Lazy;
@@ -51,6 +52,10 @@ impl MsSqlDatamodelConnector {
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::AutoIncrementMultipleAllowed,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
+            ConnectorCapability::DescendingIndexFields,
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::IncludedIndexFields,
+            ConnectorCapability::OrderByNullsFirstLast,
         ];
 
         let constructors: Vec<NativeTypeConstructor> = vec![
@@ -96,6 +101,14 @@ impl Connector for MsSqlDatamodelConnector {
         &self.capabilities
     }
 
+    // SQL Server has no `RESTRICT`, `NO ACTION` is the equivalent.
+    fn referential_actions(&self) -> Vec<ReferentialAction> {
+        ReferentialAction::all()
+            .into_iter()
+            .filter(|action| *action != ReferentialAction::Restrict)
+            .collect()
+    }
+
     fn validate_field(&self, field: &Field) -> Result<(), ConnectorError> {
         match field.field_type() {
             FieldType::NativeType(_, native_type) => {
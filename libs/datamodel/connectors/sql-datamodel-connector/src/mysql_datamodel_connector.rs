@@ -5,6 +5,7 @@ use dml::field::{Field, FieldType};
 use dml::model::{IndexType, Model};
 use dml::native_type_constructor::NativeTypeConstructor;
 use dml::native_type_instance::NativeTypeInstance;
+use dml::relation_info::ReferentialAction;
 use dml::scalars::ScalarType;
 use native_types::MySqlType;
 
@@ -67,6 +68,12 @@ impl MySqlDatamodelConnector {
             ConnectorCapability::MultipleIndexesWithSameName,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::FullTextIndex,
+            ConnectorCapability::DescendingIndexFields,
+            ConnectorCapability::CreateSkipDuplicates,
+            ConnectorCapability::OrderByNullsFirstLast,
+            ConnectorCapability::JsonFilteringJsonPath,
+            ConnectorCapability::FullTextSearch,
         ];
 
         let int = NativeTypeConstructor::without_args(INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -155,6 +162,14 @@ impl Connector for MySqlDatamodelConnector {
         &self.capabilities
     }
 
+    // InnoDB rejects `SET DEFAULT` foreign keys.
+    fn referential_actions(&self) -> Vec<ReferentialAction> {
+        ReferentialAction::all()
+            .into_iter()
+            .filter(|action| *action != ReferentialAction::SetDefault)
+            .collect()
+    }
+
     fn validate_field(&self, field: &Field) -> Result<(), ConnectorError> {
         if let FieldType::NativeType(_, native_type_instance) = field.field_type() {
             let native_type_name = native_type_instance.name.as_str();
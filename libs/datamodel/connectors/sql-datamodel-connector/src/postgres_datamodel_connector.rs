@@ -52,6 +52,15 @@ impl PostgresDatamodelConnector {
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
             ConnectorCapability::InsensitiveFilters,
             ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::IndexAlgorithms,
+            ConnectorCapability::DescendingIndexFields,
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::IncludedIndexFields,
+            ConnectorCapability::CreateSkipDuplicates,
+            ConnectorCapability::OrderByNullsFirstLast,
+            ConnectorCapability::JsonFilteringArrayPath,
+            ConnectorCapability::FullTextIndex,
+            ConnectorCapability::FullTextSearch,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -12,7 +12,13 @@ pub struct SqliteDatamodelConnector {
 
 impl SqliteDatamodelConnector {
     pub fn new() -> SqliteDatamodelConnector {
-        let capabilities = vec![ConnectorCapability::RelationFieldsInArbitraryOrder];
+        let capabilities = vec![
+            ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::CreateSkipDuplicates,
+            ConnectorCapability::OrderByNullsFirstLast,
+            ConnectorCapability::Json,
+            ConnectorCapability::JsonFilteringJsonPath,
+        ];
         let constructors: Vec<NativeTypeConstructor> = vec![];
 
         SqliteDatamodelConnector {
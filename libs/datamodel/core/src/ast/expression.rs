@@ -16,7 +16,8 @@ pub enum Expression {
     /// This is used for representing builtin enums.
     ConstantValue(String, Span),
     /// A function with a name and arguments, which is evaluated at client side.
-    Function(String, Vec<Expression>, Span),
+    /// Arguments can be named, like attribute arguments.
+    Function(String, Vec<Argument>, Span),
     /// An array of other values.
     Array(Vec<Expression>, Span),
 }
@@ -30,7 +31,13 @@ impl Expression {
             Expression::ConstantValue(v, s) => Expression::ConstantValue(v.clone(), s.lift_span(offset)),
             Expression::Function(v, a, s) => Expression::Function(
                 v.clone(),
-                a.iter().map(|elem| elem.with_lifted_span(offset)).collect(),
+                a.iter()
+                    .map(|arg| Argument {
+                        name: arg.name.clone(),
+                        value: arg.value.with_lifted_span(offset),
+                        span: arg.span.lift_span(offset),
+                    })
+                    .collect(),
                 s.lift_span(offset),
             ),
             Expression::Array(v, s) => Expression::Array(
@@ -127,12 +134,12 @@ mod test {
             _ => panic!(),
         };
 
-        match func_arguments.get(1) {
+        match func_arguments.get(1).map(|arg| &arg.value) {
             Some(Expression::NumericValue(s, _)) => assert_eq!(s, "8"),
             other => panic!("{:?}", other),
         }
 
-        match func_arguments.get(0) {
+        match func_arguments.get(0).map(|arg| &arg.value) {
             Some(Expression::Array(strings, _)) => {
                 let strings = strings
                     .iter()
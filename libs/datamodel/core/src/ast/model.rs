@@ -15,6 +15,8 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Is this a `view` block rather than a `model` block.
+    pub is_view: bool,
 }
 
 impl Model {
@@ -44,7 +44,7 @@ fn parse_attribute_args(token: &Token, arguments: &mut Vec<Argument>) {
     }
 }
 
-fn parse_attribute_arg(token: &Token) -> Argument {
+pub(crate) fn parse_attribute_arg(token: &Token) -> Argument {
     let mut name: Option<Identifier> = None;
     let mut argument: Option<Expression> = None;
 
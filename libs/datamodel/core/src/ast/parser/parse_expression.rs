@@ -3,6 +3,7 @@ use regex::Regex;
 use std::borrow::Cow;
 
 use super::helpers::{parsing_catch_all, Token, TokenExtensions};
+use super::parse_attribute::parse_attribute_arg;
 use super::Rule;
 use crate::ast::*;
 
@@ -25,12 +26,19 @@ pub fn parse_expression(token: &Token) -> Expression {
 
 fn parse_function(token: &Token) -> Expression {
     let mut name: Option<String> = None;
-    let mut arguments: Vec<Expression> = vec![];
+    let mut arguments: Vec<Argument> = vec![];
 
     for current in token.relevant_children() {
         match current.as_rule() {
             Rule::non_empty_identifier => name = Some(current.as_str().to_string()),
-            Rule::expression => arguments.push(parse_expression(&current)),
+            // This is a named arg.
+            Rule::argument => arguments.push(parse_attribute_arg(&current)),
+            // This is an unnamed arg.
+            Rule::expression => arguments.push(Argument {
+                name: Identifier::new(""),
+                value: parse_expression(&current),
+                span: Span::from_pest(current.as_span()),
+            }),
             _ => parsing_catch_all(&current, "function"),
         }
     }
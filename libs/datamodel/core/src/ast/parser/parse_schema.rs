@@ -135,6 +135,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::expression => "expression",
         Rule::argument_name => "argument name",
         Rule::function => "function expression",
+        Rule::function_argument => "function argument",
         Rule::argument_value => "argument value",
         Rule::argument => "argument",
         Rule::attribute_arguments => "attribute arguments",
@@ -162,6 +163,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::MODEL_KEYWORD => "\"model\" keyword",
         Rule::EMBED_KEYWORD => "\"embed\" keyword",
         Rule::TYPE_KEYWORD => "\"type\" keyword",
+        Rule::VIEW_KEYWORD => "\"view\" keyword",
         Rule::ENUM_KEYWORD => "\"enum\" keyword",
         Rule::GENERATOR_KEYWORD => "\"generator\" keyword",
         Rule::DATASOURCE_KEYWORD => "\"datasource\" keyword",
@@ -235,8 +235,13 @@ impl<'a> Reformatter<'a> {
     }
 
     fn reformat_model(&self, target: &mut Renderer, token: &Token) {
+        let is_view = token
+            .clone()
+            .into_inner()
+            .any(|current| current.as_rule() == Rule::VIEW_KEYWORD);
+
         self.reformat_block_element_internal(
-            "model",
+            if is_view { "view" } else { "model" },
             target,
             &token,
             Box::new(|table, renderer, token, model_name| {
@@ -247,6 +252,7 @@ impl<'a> Reformatter<'a> {
                         Self::reformat_attribute(renderer, &token, "@@");
                     }
                     Rule::field_declaration => self.reformat_field(table, &token, model_name),
+                    Rule::VIEW_KEYWORD => {}
                     _ => Self::reformat_generic_token(table, &token),
                 }
             }),
@@ -680,6 +686,13 @@ impl<'a> Reformatter<'a> {
                     Self::reformat_expression(target, &current);
                     has_seen_one_argument = true;
                 }
+                Rule::argument => {
+                    if has_seen_one_argument {
+                        target.write(", ");
+                    }
+                    Self::reformat_attribute_arg(target, &current);
+                    has_seen_one_argument = true;
+                }
                 Rule::doc_comment | Rule::doc_comment_and_new_line => {
                     panic!("Comments inside expressions not supported yet.")
                 }
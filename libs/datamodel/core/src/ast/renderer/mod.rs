@@ -176,7 +176,9 @@ impl<'a> Renderer<'a> {
 
         Self::render_documentation(self, model);
 
-        self.write(format!("{}model ", comment_out).as_ref());
+        let keyword = if model.is_view { "view" } else { "model" };
+
+        self.write(format!("{}{} ", comment_out, keyword).as_ref());
         self.write(&model.name.name);
         self.write(" {");
         self.end_line();
@@ -204,7 +206,6 @@ impl<'a> Renderer<'a> {
         self.end_line();
     }
 
-
     fn sort_attributes(mut attributes: Vec<Attribute>, is_field_attribute: bool) -> Vec<Attribute> {
         // sort attributes
         attributes.sort_by(|a, b| {
@@ -370,16 +371,10 @@ impl<'a> Renderer<'a> {
         };
     }
 
-    fn render_func(target: &mut dyn LineWriteable, name: &str, vals: &[ast::Expression]) {
+    fn render_func(target: &mut dyn LineWriteable, name: &str, args: &[ast::Argument]) {
         target.write(name);
         target.write("(");
-        for (idx, val) in vals.iter().enumerate() {
-            if idx > 0 {
-                target.write(", ");
-            }
-
-            Self::render_value(target, val);
-        }
+        Self::render_arguments(target, args);
         target.write(")");
     }
 
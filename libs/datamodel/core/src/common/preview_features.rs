@@ -7,6 +7,7 @@ const NATIVE_TYPES: &str = "nativeTypes";
 const SQL_SERVER: &str = "microsoftSqlServer";
 const UNCHECKED_SCALAR_INPUTS: &str = "uncheckedScalarInputs";
 const GROUP_BY: &str = "groupBy";
+const REFERENTIAL_ACTIONS: &str = "referentialActions";
 
 // deprecated preview features
 const ATOMIC_NUMBER_OPERATIONS: &str = "atomicNumberOperations";
@@ -16,7 +17,13 @@ const DISTINCT: &str = "distinct";
 
 pub const DATASOURCE_PREVIEW_FEATURES: &[&str] = &[];
 
-pub const GENERATOR_PREVIEW_FEATURES: &[&str] = &[NATIVE_TYPES, SQL_SERVER, UNCHECKED_SCALAR_INPUTS, GROUP_BY];
+pub const GENERATOR_PREVIEW_FEATURES: &[&str] = &[
+    NATIVE_TYPES,
+    SQL_SERVER,
+    UNCHECKED_SCALAR_INPUTS,
+    GROUP_BY,
+    REFERENTIAL_ACTIONS,
+];
 
 pub const DEPRECATED_GENERATOR_PREVIEW_FEATURES: &[&str] = &[
     ATOMIC_NUMBER_OPERATIONS,
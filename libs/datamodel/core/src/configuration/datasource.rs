@@ -10,6 +10,8 @@ pub struct Datasource {
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    /// the urls of read replicas of the database, in the order they were specified
+    pub replica_urls: Vec<StringFromEnvVar>,
     pub documentation: Option<String>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
@@ -25,6 +27,7 @@ impl std::fmt::Debug for Datasource {
             .field("provider", &self.provider)
             .field("active_provider", &self.active_provider)
             .field("url", &self.url)
+            .field("replica_urls", &self.replica_urls)
             .field("documentation", &self.documentation)
             .field("active_connector", &&"...")
             .finish()
@@ -36,6 +39,10 @@ impl Datasource {
         &self.url
     }
 
+    pub fn replica_urls(&self) -> &[StringFromEnvVar] {
+        &self.replica_urls
+    }
+
     pub fn capabilities(&self) -> ConnectorCapabilities {
         let capabilities = self.active_connector.capabilities().clone();
         ConnectorCapabilities::new(capabilities)
@@ -198,9 +198,25 @@ fn get_relation_to_fields(field: &dml::Field) -> Option<Vec<String>> {
     }
 }
 
+/// Referential actions are rendered in the same casing as the other DMMF enums, e.g. `SET_NULL`.
+fn referential_action_to_dmmf(action: dml::ReferentialAction) -> String {
+    match action {
+        dml::ReferentialAction::Cascade => String::from("CASCADE"),
+        dml::ReferentialAction::Restrict => String::from("RESTRICT"),
+        dml::ReferentialAction::NoAction => String::from("NO_ACTION"),
+        dml::ReferentialAction::SetNull => String::from("SET_NULL"),
+        dml::ReferentialAction::SetDefault => String::from("SET_DEFAULT"),
+    }
+}
+
 fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
     match &field {
-        dml::Field::RelationField(rf) => Some(rf.relation_info.on_delete.to_string()),
+        dml::Field::RelationField(rf) => Some(
+            rf.relation_info
+                .on_delete
+                .map(referential_action_to_dmmf)
+                .unwrap_or_else(|| String::from("NONE")),
+        ),
         _ => None,
     }
 }
@@ -7,6 +7,8 @@ pub struct SourceConfig {
     pub provider: Vec<String>,
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub replica_urls: Vec<StringFromEnvVar>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
@@ -37,6 +39,7 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         provider: source.provider.clone(),
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
+        replica_urls: source.replica_urls().to_vec(),
         documentation: source.documentation.clone(),
     }
 }
@@ -5,12 +5,14 @@ use super::{
     datasource_provider::DatasourceProvider,
 };
 use crate::ast::Span;
+use crate::common::provider_names::SQLITE_SOURCE_NAME;
 use crate::configuration::StringFromEnvVar;
 use crate::diagnostics::{DatamodelError, DatamodelWarning, Diagnostics, ValidatedDatasource, ValidatedDatasources};
 use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const REPLICA_URLS_KEY: &str = "replicaUrls";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -161,6 +163,12 @@ impl DatasourceLoader {
             )));
         }
 
+        let (replica_urls, replica_urls_span) = match args.arg(REPLICA_URLS_KEY).ok() {
+            Some(arg) if ignore_datasource_urls => (Vec::new(), Some(arg.span())),
+            Some(arg) => (self.lift_replica_urls(source_name, &arg)?, Some(arg.span())),
+            None => (Vec::new(), None),
+        };
+
         let preview_features_arg = args.arg(PREVIEW_FEATURES_KEY);
         let (preview_features, span) = match preview_features_arg.ok() {
             Some(x) => (x.as_array().to_str_vec()?, x.span()),
@@ -211,12 +219,39 @@ impl DatasourceLoader {
         let (successes, errors): (Vec<_>, Vec<_>) = validated_providers.into_iter().partition(|result| result.is_ok());
         if !successes.is_empty() {
             let first_successful_provider = successes.into_iter().next().unwrap()?;
+
+            if let Some(replica_urls_span) = replica_urls_span {
+                if first_successful_provider.canonical_name() == SQLITE_SOURCE_NAME {
+                    let msg = format!(
+                        "The datasource `{}` cannot have `replicaUrls`: read replicas are not supported on SQLite.",
+                        source_name
+                    );
+
+                    return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                        &msg,
+                        source_name,
+                        replica_urls_span,
+                    )));
+                }
+
+                for replica_url in &replica_urls {
+                    if let Err(err_msg) = first_successful_provider.can_handle_url(source_name, replica_url) {
+                        return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                            &err_msg,
+                            source_name,
+                            replica_urls_span,
+                        )));
+                    }
+                }
+            }
+
             Ok(ValidatedDatasource {
                 subject: Datasource {
                     name: source_name.to_string(),
                     provider: providers,
                     active_provider: first_successful_provider.canonical_name().to_string(),
                     url,
+                    replica_urls,
                     documentation,
                     combined_connector,
                     active_connector: first_successful_provider.connector(),
@@ -229,6 +264,38 @@ impl DatasourceLoader {
         }
     }
 
+    /// Replica URLs follow the same rules as the primary `url`: they can be read from the environment and must not be
+    /// empty.
+    fn lift_replica_urls(
+        &self,
+        source_name: &str,
+        replica_urls_arg: &ValueValidator,
+    ) -> Result<Vec<StringFromEnvVar>, DatamodelError> {
+        let mut replica_urls = Vec::new();
+
+        for replica_url_arg in replica_urls_arg.as_array() {
+            let (from_env_var, value) = replica_url_arg.as_str_from_env()?;
+            let value = value.trim().to_owned();
+
+            if value.is_empty() {
+                let msg = format!(
+                    "You must provide nonempty replica URLs for the datasource `{}`.",
+                    source_name
+                );
+
+                return Err(DatamodelError::new_source_validation_error(
+                    &msg,
+                    source_name,
+                    replica_url_arg.span(),
+                ));
+            }
+
+            replica_urls.push(StringFromEnvVar { from_env_var, value });
+        }
+
+        Ok(replica_urls)
+    }
+
     fn get_datasource_provider(&self, provider: &str) -> Option<&Box<dyn DatasourceProvider>> {
         self.source_definitions.iter().find(|sd| sd.is_provider(provider))
     }
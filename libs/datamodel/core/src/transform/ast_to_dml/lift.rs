@@ -68,6 +68,7 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
     fn lift_model(&self, ast_model: &ast::Model, ast_schema: &ast::SchemaAst) -> Result<dml::Model, Diagnostics> {
         let mut model = dml::Model::new(ast_model.name.name.clone(), None);
         model.documentation = ast_model.documentation.clone().map(|comment| comment.text);
+        model.is_view = ast_model.is_view;
 
         let mut errors = Diagnostics::new();
 
@@ -4,7 +4,7 @@ use crate::{
     ast,
     common::{NameNormalizer, RelationNames},
     diagnostics::Diagnostics,
-    dml, Field, OnDeleteStrategy, ScalarField, UniqueCriteria,
+    dml, Field, ScalarField, UniqueCriteria,
 };
 use itertools::Itertools;
 use std::collections::HashMap;
@@ -226,7 +226,8 @@ impl Standardiser {
                         fields: vec![],
                         references: vec![],
                         name: rel_info.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: None,
+                        on_update: None,
                     };
                     let mut back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
                     back_relation_field.arity = dml::FieldArity::List;
@@ -297,7 +298,8 @@ impl Standardiser {
                         fields: underlying_field_names,
                         references: unique_criteria_field_names,
                         name: rel_info.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: None,
+                        on_update: None,
                     };
 
                     let back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
@@ -72,6 +72,18 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_fulltext_indexes(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_index_properties(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) =
                 self.validate_field_connector_specific(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
             {
@@ -104,6 +116,12 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_referential_actions(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_referenced_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -292,6 +310,96 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_fulltext_indexes(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        for index in model.indices.iter().filter(|i| i.tpe == dml::IndexType::Fulltext) {
+            let supports_fulltext_index = match self.source {
+                Some(source) => source.combined_connector.supports_fulltext_index(),
+                None => false,
+            };
+
+            if !supports_fulltext_index {
+                errors.push_error(DatamodelError::new_model_validation_error(
+                    "The current connector does not support fulltext indexes.",
+                    &model.name,
+                    ast_model.span,
+                ));
+                continue;
+            }
+
+            let non_string_fields: Vec<&str> = index
+                .fields
+                .iter()
+                .filter(|name| {
+                    model
+                        .find_scalar_field(name)
+                        .map(|f| f.field_type.scalar_type() != Some(dml::ScalarType::String))
+                        .unwrap_or(false)
+                })
+                .map(|name| name.as_str())
+                .collect();
+
+            if !non_string_fields.is_empty() {
+                errors.push_error(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "The fulltext index definition refers to the fields {}. Fulltext indexes can only contain String fields.",
+                        non_string_fields.join(", ")
+                    ),
+                    &model.name,
+                    ast_model.span,
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_index_properties(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        let connector = match self.source {
+            Some(source) => &source.combined_connector,
+            None => return Ok(()),
+        };
+
+        for index in model.indices.iter() {
+            let unsupported_argument =
+                if index.algorithm != dml::IndexAlgorithm::BTree && !connector.supports_index_algorithms() {
+                    Some("type")
+                } else if !index.descending_fields.is_empty() && !connector.supports_descending_index_fields() {
+                    Some("sort")
+                } else if index.predicate.is_some() && !connector.supports_partial_indexes() {
+                    Some("where")
+                } else if !index.included_fields.is_empty() && !connector.supports_included_index_fields() {
+                    Some("include")
+                } else {
+                    None
+                };
+
+            if let Some(argument) = unsupported_argument {
+                errors.push_error(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "The current connector does not support the `{}` argument on indexes.",
+                        argument
+                    ),
+                    &model.name,
+                    ast_model.span,
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_enum_default_values(
         &self,
         data_model: &dml::Datamodel,
@@ -622,6 +730,78 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_referential_actions(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        for field in model.relation_fields() {
+            let rel_info = &field.relation_info;
+            let actions: Vec<(&str, dml::ReferentialAction)> =
+                vec![("onDelete", rel_info.on_delete), ("onUpdate", rel_info.on_update)]
+                    .into_iter()
+                    .filter_map(|(argument, action)| action.map(|action| (argument, action)))
+                    .collect();
+
+            if actions.is_empty() {
+                continue;
+            }
+
+            let ast_field = ast_model.find_field(&field.name);
+
+            if rel_info.fields.is_empty() {
+                errors.push_error(DatamodelError::new_attribute_validation_error(
+                    "Referential actions can only be defined on the relation field that defines `fields` and `references`.",
+                    RELATION_ATTRIBUTE_NAME,
+                    ast_field.span,
+                ));
+                continue;
+            }
+
+            let required_fields: Vec<&str> = rel_info
+                .fields
+                .iter()
+                .filter_map(|name| model.find_scalar_field(name))
+                .filter(|f| f.is_required())
+                .map(|f| f.name.as_str())
+                .collect();
+
+            for (argument, action) in actions {
+                let supports_action = match self.source {
+                    Some(source) => source.combined_connector.supports_referential_action(action),
+                    None => true,
+                };
+
+                if !supports_action {
+                    errors.push_error(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "The referential action `{}` is not supported by the current connector.",
+                            action.to_string()
+                        ),
+                        RELATION_ATTRIBUTE_NAME,
+                        ast_field.span,
+                    ));
+                }
+
+                if action == dml::ReferentialAction::SetNull && !required_fields.is_empty() {
+                    errors.push_error(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "The `{}` referential action `SetNull` requires the fields {} to be optional.",
+                            argument,
+                            required_fields.join(", ")
+                        ),
+                        RELATION_ATTRIBUTE_NAME,
+                        ast_field.span,
+                    ));
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_referenced_fields_for_relation(
         &self,
         datamodel: &dml::Datamodel,
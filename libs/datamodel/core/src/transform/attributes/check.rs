@@ -0,0 +1,56 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@check` attribute.
+pub struct CheckAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for CheckAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"check"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let name = args.default_arg("name")?.as_str()?;
+        let expression = args.arg("expression")?.as_str()?;
+
+        if name.is_empty() || expression.trim().is_empty() {
+            return self.new_attribute_validation_error(
+                "The name and the expression of a check constraint must not be empty.",
+                args.span(),
+            );
+        }
+
+        if obj.check_constraints.iter().any(|check| check.name == name) {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!("The check constraint name `{}` is declared multiple times.", name),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
+        obj.check_constraints.push(dml::CheckConstraint { name, expression });
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        model
+            .check_constraints
+            .iter()
+            .map(|check| {
+                ast::Attribute::new(
+                    self.attribute_name(),
+                    vec![
+                        ast::Argument::new_string("name", &check.name),
+                        ast::Argument::new_string("expression", &check.expression),
+                    ],
+                )
+            })
+            .collect()
+    }
+}
@@ -69,8 +69,12 @@ pub fn lower_default_value(dv: dml::DefaultValue) -> ast::Expression {
     match dv {
         dml::DefaultValue::Single(v) => lower_prisma_value(&v),
         dml::DefaultValue::Expression(e) => {
-            let exprs = e.args.iter().map(lower_prisma_value).collect();
-            ast::Expression::Function(e.name, exprs, ast::Span::empty())
+            let args = e
+                .args
+                .iter()
+                .map(|arg| ast::Argument::new_unnamed(lower_prisma_value(arg)))
+                .collect();
+            ast::Expression::Function(e.name, args, ast::Span::empty())
         }
     }
 }
@@ -1,11 +1,14 @@
 mod attribute_list_validator;
 mod attribute_validator;
+mod check;
 mod default;
 mod id;
 mod map;
 mod relation;
+mod soft_delete;
 mod unique_and_index;
 mod updated_at;
+mod version;
 
 use crate::dml;
 use attribute_list_validator::AttributeListValidator;
@@ -38,6 +41,7 @@ fn new_builtin_field_attributes() -> AttributeListValidator<dml::Field> {
     validator.add(Box::new(unique_and_index::FieldLevelUniqueAttributeValidator {}));
     validator.add(Box::new(default::DefaultAttributeValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtAttributeValidator {}));
+    validator.add(Box::new(version::VersionAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidatorForField {}));
     validator.add(Box::new(relation::RelationAttributeValidator {}));
 
@@ -51,7 +55,10 @@ fn new_builtin_model_attributes() -> AttributeListValidator<dml::Model> {
     validator.add(Box::new(id::ModelLevelIdAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelUniqueAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexAttributeValidator {}));
+    validator.add(Box::new(unique_and_index::ModelLevelFulltextAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidator {}));
+    validator.add(Box::new(soft_delete::SoftDeleteAttributeValidator {}));
+    validator.add(Box::new(check::CheckAttributeValidator {}));
 
     validator
 }
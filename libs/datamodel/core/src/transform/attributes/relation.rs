@@ -32,10 +32,13 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 rf.relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
-            // TODO: bring `onDelete` back once `prisma migrate` is a thing
-            //            if let Ok(on_delete) = args.arg("onDelete") {
-            //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
-            //            }
+            if let Some(on_delete) = args.optional_arg("onDelete") {
+                rf.relation_info.on_delete = Some(self.parse_referential_action(&on_delete)?);
+            }
+
+            if let Some(on_update) = args.optional_arg("onUpdate") {
+                rf.relation_info.on_update = Some(self.parse_referential_action(&on_update)?);
+            }
 
             Ok(())
         } else {
@@ -100,11 +103,12 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 }
             }
 
-            if relation_info.on_delete != dml::OnDeleteStrategy::None {
-                args.push(ast::Argument::new_constant(
-                    "onDelete",
-                    &relation_info.on_delete.to_string(),
-                ));
+            if let Some(on_delete) = relation_info.on_delete {
+                args.push(ast::Argument::new_constant("onDelete", &on_delete.to_string()));
+            }
+
+            if let Some(on_update) = relation_info.on_update {
+                args.push(ast::Argument::new_constant("onUpdate", &on_update.to_string()));
             }
 
             if !args.is_empty() {
@@ -115,3 +119,23 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
         vec![]
     }
 }
+
+impl RelationAttributeValidator {
+    fn parse_referential_action(&self, value: &ValueValidator) -> Result<dml::ReferentialAction, DatamodelError> {
+        let literal = value.as_constant_literal()?;
+
+        literal.parse::<dml::ReferentialAction>().map_err(|_| {
+            let allowed: Vec<String> = dml::ReferentialAction::all().iter().map(|a| a.to_string()).collect();
+
+            DatamodelError::new_attribute_validation_error(
+                &format!(
+                    "Invalid referential action: `{}`. Allowed values: ({})",
+                    literal,
+                    allowed.join(", ")
+                ),
+                self.attribute_name(),
+                value.span(),
+            )
+        })
+    }
+}
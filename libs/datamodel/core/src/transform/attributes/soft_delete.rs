@@ -0,0 +1,63 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@softDelete` attribute.
+pub struct SoftDeleteAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for SoftDeleteAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"softDelete"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let field_name = args.default_arg("field")?.as_constant_literal()?;
+
+        let field = match obj.find_field(&field_name) {
+            Some(dml::Field::ScalarField(sf)) => sf,
+            Some(dml::Field::RelationField(_)) => {
+                return Err(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "The soft delete definition refers to the relation field {}. It must reference a scalar field.",
+                        field_name
+                    ),
+                    &obj.name,
+                    args.span(),
+                ))
+            }
+            None => {
+                return Err(DatamodelError::new_model_validation_error(
+                    &format!("The soft delete definition refers to the unknown field {}.", field_name),
+                    &obj.name,
+                    args.span(),
+                ))
+            }
+        };
+
+        if field.field_type.scalar_type() != Some(dml::ScalarType::DateTime) || field.arity != dml::FieldArity::Optional
+        {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The soft delete definition refers to the field {}, which must be of type DateTime?.",
+                    field_name
+                ),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
+        obj.soft_delete_field = Some(field_name);
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        match &model.soft_delete_field {
+            Some(field_name) => vec![ast::Attribute::new(
+                self.attribute_name(),
+                vec![ast::Argument::new_constant("field", field_name)],
+            )],
+            None => vec![],
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::diagnostics::DatamodelError;
-use crate::{ast, dml, IndexDefinition, IndexType};
+use crate::{ast, dml, IndexAlgorithm, IndexDefinition, IndexType};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
@@ -109,7 +109,32 @@ impl AttributeValidator<dml::Model> for ModelLevelIndexAttributeValidator {
     }
 }
 
-/// common logic for `@@unique` and `@@index`
+/// Prismas builtin `@@fulltext` attribute.
+pub struct ModelLevelFulltextAttributeValidator {}
+
+impl IndexAttributeBase<dml::Model> for ModelLevelFulltextAttributeValidator {}
+impl AttributeValidator<dml::Model> for ModelLevelFulltextAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "fulltext"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let index_def = self.validate_index(args, obj, IndexType::Fulltext)?;
+        obj.indices.push(index_def);
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        self.serialize_index_definitions(&model, IndexType::Fulltext)
+    }
+}
+
+/// common logic for `@@unique`, `@@index` and `@@fulltext`
 trait IndexAttributeBase<T>: AttributeValidator<T> {
     fn validate_index(
         &self,
@@ -121,6 +146,10 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            algorithm: IndexAlgorithm::BTree,
+            descending_fields: vec![],
+            predicate: None,
+            included_fields: vec![],
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg.as_str()?),
@@ -128,20 +157,72 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         };
         index_def.name = name;
 
-        let fields = args
-            .default_arg("fields")?
-            .as_array()
-            .iter()
-            .map(|f| f.as_constant_literal().unwrap())
-            .collect();
-        index_def.fields = fields;
+        for field in args.default_arg("fields")?.as_array() {
+            let (field_name, field_args) = field.as_constant_literal_with_args()?;
+
+            for arg in field_args.iter() {
+                if arg.name.name != "sort" || index_type == IndexType::Fulltext {
+                    return Err(DatamodelError::new_unused_argument_error(&arg.name.name, arg.span));
+                }
+
+                match ValueValidator::new(&arg.value).as_constant_literal()?.as_str() {
+                    "Asc" => (),
+                    "Desc" => index_def.descending_fields.push(field_name.clone()),
+                    other => {
+                        return Err(DatamodelError::new_attribute_validation_error(
+                            &format!("Invalid sort order `{}`. Expected `Asc` or `Desc`.", other),
+                            self.attribute_name(),
+                            arg.span,
+                        ))
+                    }
+                }
+            }
+
+            index_def.fields.push(field_name);
+        }
+
+        if index_type == IndexType::Normal {
+            if let Some(algorithm) = args.optional_arg("type") {
+                index_def.algorithm = match algorithm.as_constant_literal()?.as_str() {
+                    "BTree" => IndexAlgorithm::BTree,
+                    "Hash" => IndexAlgorithm::Hash,
+                    "Gin" => IndexAlgorithm::Gin,
+                    "Gist" => IndexAlgorithm::Gist,
+                    "Brin" => IndexAlgorithm::Brin,
+                    other => {
+                        return Err(DatamodelError::new_attribute_validation_error(
+                            &format!(
+                                "Invalid index type `{}`. Expected one of `BTree`, `Hash`, `Gin`, `Gist` or `Brin`.",
+                                other
+                            ),
+                            self.attribute_name(),
+                            algorithm.span(),
+                        ))
+                    }
+                };
+            }
+
+            if let Some(predicate) = args.optional_arg("where") {
+                index_def.predicate = Some(predicate.as_str()?);
+            }
+        }
+
+        if index_type != IndexType::Fulltext {
+            if let Some(include) = args.optional_arg("include") {
+                index_def.included_fields = include
+                    .as_array()
+                    .iter()
+                    .map(|f| f.as_constant_literal())
+                    .collect::<Result<_, _>>()?;
+            }
+        }
 
         let duplicated_fields = find_duplicates(&index_def.fields);
         if !duplicated_fields.is_empty() {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the fields {} multiple times.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    index_prefix(index_type),
                     duplicated_fields.join(", ")
                 ),
                 &obj.name,
@@ -152,6 +233,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         let undefined_fields: Vec<String> = index_def
             .fields
             .iter()
+            .chain(index_def.included_fields.iter())
             .filter_map(|field| {
                 if obj.find_field(&field).is_none() {
                     Some(field.to_string())
@@ -164,6 +246,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         let referenced_relation_fields: Vec<String> = index_def
             .fields
             .iter()
+            .chain(index_def.included_fields.iter())
             .filter(|field| obj.find_relation_field(&field).is_some())
             .map(|f| f.to_owned())
             .collect();
@@ -172,7 +255,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the unknown fields {}.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    index_prefix(index_type),
                     undefined_fields.join(", ")
                 ),
                 &obj.name,
@@ -210,7 +293,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {prefix}index definition refers to the relation fields {the_fields}. Index definitions must reference only scalar fields.{suggestion}",
-                    prefix = if index_type == IndexType::Unique { "unique " } else { "" },
+                    prefix = index_prefix(index_type),
                     the_fields = referenced_relation_fields.join(", "),
                     suggestion = suggestion
                 ),
@@ -235,13 +318,42 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
                     index_def
                         .fields
                         .iter()
-                        .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
+                        .map(|f| {
+                            if index_def.field_is_descending(f) {
+                                ast::Expression::Function(
+                                    f.to_string(),
+                                    vec![ast::Argument::new_constant("sort", "Desc")],
+                                    ast::Span::empty(),
+                                )
+                            } else {
+                                ast::Expression::ConstantValue(f.to_string(), ast::Span::empty())
+                            }
+                        })
                         .collect(),
                 ));
                 if let Some(name) = &index_def.name {
                     args.push(ast::Argument::new_string("name", &name));
                 }
 
+                if index_def.algorithm != IndexAlgorithm::BTree {
+                    args.push(ast::Argument::new_constant("type", algorithm_name(index_def.algorithm)));
+                }
+
+                if let Some(predicate) = &index_def.predicate {
+                    args.push(ast::Argument::new_string("where", &predicate));
+                }
+
+                if !index_def.included_fields.is_empty() {
+                    args.push(ast::Argument::new_array(
+                        "include",
+                        index_def
+                            .included_fields
+                            .iter()
+                            .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
+                            .collect(),
+                    ));
+                }
+
                 ast::Attribute::new(self.attribute_name(), args)
             })
             .collect();
@@ -251,10 +363,28 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
 }
 
 fn attribute_name(index_type: dml::IndexType) -> &'static str {
-    if index_type == dml::IndexType::Unique {
-        "unique"
-    } else {
-        "index"
+    match index_type {
+        dml::IndexType::Unique => "unique",
+        dml::IndexType::Normal => "index",
+        dml::IndexType::Fulltext => "fulltext",
+    }
+}
+
+fn algorithm_name(algorithm: dml::IndexAlgorithm) -> &'static str {
+    match algorithm {
+        dml::IndexAlgorithm::BTree => "BTree",
+        dml::IndexAlgorithm::Hash => "Hash",
+        dml::IndexAlgorithm::Gin => "Gin",
+        dml::IndexAlgorithm::Gist => "Gist",
+        dml::IndexAlgorithm::Brin => "Brin",
+    }
+}
+
+fn index_prefix(index_type: dml::IndexType) -> &'static str {
+    match index_type {
+        dml::IndexType::Unique => "unique ",
+        dml::IndexType::Normal => "",
+        dml::IndexType::Fulltext => "fulltext ",
     }
 }
 
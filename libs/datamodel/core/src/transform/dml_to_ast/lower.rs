@@ -50,6 +50,7 @@ impl<'a> LowerDmlToAst<'a> {
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             commented_out: model.is_commented_out,
+            is_view: model.is_view,
         }
     }
 
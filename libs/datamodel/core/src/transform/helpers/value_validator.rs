@@ -166,6 +166,15 @@ impl ValueValidator {
         }
     }
 
+    /// Unwraps the wrapped value as a constant literal, optionally followed by
+    /// arguments, e.g. `title(sort: Desc)`.
+    pub fn as_constant_literal_with_args(&self) -> Result<(String, Vec<ast::Argument>), DatamodelError> {
+        match &self.value {
+            ast::Expression::Function(name, args, _) => Ok((name.clone(), args.clone())),
+            _ => self.as_constant_literal().map(|literal| (literal, Vec::new())),
+        }
+    }
+
     /// Unwraps the wrapped value as a constant literal..
     pub fn as_array(&self) -> Vec<ValueValidator> {
         match &self.value {
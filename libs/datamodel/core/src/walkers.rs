@@ -2,7 +2,8 @@
 //! The most prominent functionality is the pain free navigation of relations.
 use crate::{
     dml::{
-        Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, ScalarField, WithDatabaseName,
+        CheckConstraint, Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model,
+        ReferentialAction, ScalarField, WithDatabaseName,
     },
     NativeTypeInstance, RelationField,
 };
@@ -104,10 +105,18 @@ impl<'a> ModelWalker<'a> {
         self.model.indices.iter()
     }
 
+    pub fn check_constraints(&self) -> &'a [CheckConstraint] {
+        &self.model.check_constraints
+    }
+
     pub fn name(&self) -> &'a str {
         &self.model.name
     }
 
+    pub fn is_view(&self) -> bool {
+        self.model.is_view
+    }
+
     pub fn id_fields<'b>(&'b self) -> impl Iterator<Item = ScalarFieldWalker<'a>> + 'b {
         // Single-id models
         self.model
@@ -289,6 +298,16 @@ impl<'a> RelationFieldWalker<'a> {
         self.field.relation_info.name.as_ref()
     }
 
+    /// The `ON DELETE` action of the relation, with defaults resolved.
+    pub fn on_delete(&self) -> ReferentialAction {
+        self.field.resolved_on_delete()
+    }
+
+    /// The `ON UPDATE` action of the relation, with defaults resolved.
+    pub fn on_update(&self) -> ReferentialAction {
+        self.field.resolved_on_update()
+    }
+
     pub fn referenced_model(&self) -> ModelWalker<'a> {
         ModelWalker {
             datamodel: &self.datamodel,
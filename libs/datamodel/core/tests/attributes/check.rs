@@ -0,0 +1,93 @@
+use crate::common::*;
+use datamodel::{render_datamodel_to_string, CheckConstraint};
+
+#[test]
+fn check_constraints_must_work() {
+    let dml = r#"
+    model Product {
+        id       Int @id
+        price    Int
+        discount Int
+
+        @@check(name: "positivePrice", expression: "price > 0")
+        @@check("discountBelowPrice", expression: "discount < price")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product_model = schema.assert_has_model("Product");
+
+    assert_eq!(
+        product_model.check_constraints,
+        &[
+            CheckConstraint {
+                name: "positivePrice".to_owned(),
+                expression: "price > 0".to_owned(),
+            },
+            CheckConstraint {
+                name: "discountBelowPrice".to_owned(),
+                expression: "discount < price".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn check_constraints_must_serialize_to_valid_dml() {
+    let dml = r#"
+    model Product {
+        id    Int    @id
+        price Int
+        name  String
+
+        @@check(name: "positivePrice", expression: "price > 0")
+        @@check(name: "nameNotEmpty", expression: "name <> ''")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema);
+
+    assert!(rendered.contains(r#"@@check(name: "positivePrice", expression: "price > 0")"#));
+    assert_eq!(
+        parse(&rendered).assert_has_model("Product").check_constraints,
+        schema.assert_has_model("Product").check_constraints
+    );
+}
+
+#[test]
+fn check_constraints_must_have_an_expression() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check(name: "positivePrice", expression: "")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@check\": The name and the expression of a check constraint must not be empty.",
+    );
+}
+
+#[test]
+fn check_constraint_names_must_be_unique_per_model() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check(name: "positivePrice", expression: "price > 0")
+        @@check(name: "positivePrice", expression: "price >= 1")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating model \"Product\": The check constraint name `positivePrice` is declared multiple times.",
+    );
+}
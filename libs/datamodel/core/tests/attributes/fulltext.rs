@@ -0,0 +1,107 @@
+use datamodel::{IndexAlgorithm, IndexDefinition, IndexType};
+
+use crate::common::*;
+
+#[test]
+fn fulltext_index_must_work_on_mysql() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Post {{
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content])
+    }}
+    "#,
+        MYSQL_SOURCE
+    );
+
+    let schema = parse(&dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["title".to_string(), "content".to_string()],
+        tpe: IndexType::Fulltext,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
+    });
+}
+
+#[test]
+fn fulltext_index_must_work_on_postgres() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Post {{
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content])
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let schema = parse(&dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["title".to_string(), "content".to_string()],
+        tpe: IndexType::Fulltext,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
+    });
+}
+
+#[test]
+fn fulltext_index_must_error_on_connectors_without_support() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Post {{
+        id    Int    @id
+        title String
+
+        @@fulltext([title])
+    }}
+    "#,
+        SQLITE_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+    errors
+        .assert_is_message("Error validating model \"Post\": The current connector does not support fulltext indexes.");
+}
+
+#[test]
+fn fulltext_index_must_error_on_non_string_fields() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Post {{
+        id    Int    @id
+        title String
+        views Int
+
+        @@fulltext([title, views])
+    }}
+    "#,
+        MYSQL_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+    errors.assert_is_message(
+        "Error validating model \"Post\": The fulltext index definition refers to the fields views. Fulltext indexes can only contain String fields.",
+    );
+}
@@ -1,4 +1,4 @@
-use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexAlgorithm, IndexDefinition, IndexType};
 
 use crate::common::*;
 
@@ -20,6 +20,10 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
     });
 }
 
@@ -45,6 +49,10 @@ fn indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
     });
 }
 
@@ -91,6 +99,10 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
     });
 }
 
@@ -126,12 +138,20 @@ fn multiple_indexes_with_same_name_are_supported_by_mysql() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
     });
 
     post_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
     });
 }
 
@@ -222,12 +242,20 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
     });
 }
 
@@ -265,3 +293,113 @@ fn index_attributes_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema)).is_ok());
 }
+
+#[test]
+fn index_types_sort_orders_predicates_and_included_fields_must_work_on_postgres() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Post {{
+        id        Int       @id
+        title     String
+        content   String
+        tags      String[]
+        createdAt DateTime
+        deletedAt DateTime?
+
+        @@index([tags], type: Gin)
+        @@index([title, createdAt(sort: Desc)], where: "\"deletedAt\" IS NULL", include: [content])
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let schema = parse(&dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["tags".to_string()],
+        tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::Gin,
+        descending_fields: vec![],
+        predicate: None,
+        included_fields: vec![],
+    });
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["title".to_string(), "createdAt".to_string()],
+        tpe: IndexType::Normal,
+        algorithm: IndexAlgorithm::BTree,
+        descending_fields: vec!["createdAt".to_string()],
+        predicate: Some("\"deletedAt\" IS NULL".to_string()),
+        included_fields: vec!["content".to_string()],
+    });
+}
+
+#[test]
+fn index_properties_must_serialize_to_valid_dml() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Post {{
+        id        Int      @id
+        title     String
+        content   String
+        createdAt DateTime
+
+        @@index([title, createdAt(sort: Desc)], type: Hash, where: "id > 10", include: [content])
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let schema = parse(&dml);
+    let rendered = render_datamodel_to_string(&schema);
+
+    assert!(rendered
+        .contains(r#"@@index([title, createdAt(sort: Desc)], type: Hash, where: "id > 10", include: [content])"#));
+    assert_eq!(
+        parse(&rendered).assert_has_model("Post").indices,
+        schema.assert_has_model("Post").indices
+    );
+}
+
+#[test]
+fn partial_indexes_must_error_on_connectors_without_support() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Post {{
+        id        Int       @id
+        title     String
+        deletedAt DateTime?
+
+        @@index([title], where: "deletedAt IS NULL")
+    }}
+    "#,
+        MYSQL_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+    errors.assert_is_message(
+        "Error validating model \"Post\": The current connector does not support the `where` argument on indexes.",
+    );
+}
+
+#[test]
+fn index_field_sort_order_must_be_asc_or_desc() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+
+        @@index([title(sort: Up)])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is_message("Error parsing attribute \"@index\": Invalid sort order `Up`. Expected `Asc` or `Desc`.");
+}
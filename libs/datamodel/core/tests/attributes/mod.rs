@@ -1,19 +1,26 @@
 pub mod builtin_attributes;
+pub mod check;
 pub mod default_negative;
 pub mod default_positive;
+pub mod fulltext;
 pub mod id_negative;
 pub mod id_positive;
 pub mod index;
 pub mod map;
+pub mod referential_actions;
 pub mod relations_basic;
 pub mod relations_consistency;
 pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod soft_delete_negative;
+pub mod soft_delete_positive;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
+pub mod version_negative;
+pub mod version_positive;
 
 pub mod arg_parsing;
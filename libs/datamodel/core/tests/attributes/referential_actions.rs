@@ -0,0 +1,142 @@
+use datamodel::dml::ReferentialAction::{self, *};
+
+use crate::common::*;
+
+fn relation_with_actions(source: &str, on_delete: &str, on_update: &str) -> String {
+    format!(
+        r#"
+    {}
+
+    model User {{
+        id    Int    @id
+        posts Post[]
+    }}
+
+    model Post {{
+        id     Int   @id
+        userId Int?
+        user   User? @relation(fields: [userId], references: [id], onDelete: {}, onUpdate: {})
+    }}
+    "#,
+        source, on_delete, on_update
+    )
+}
+
+#[test]
+fn all_referential_actions_must_parse_on_postgres() {
+    for action in ReferentialAction::all() {
+        let dml = relation_with_actions(POSTGRES_SOURCE, &action.to_string(), &action.to_string());
+
+        parse(&dml)
+            .assert_has_model("Post")
+            .assert_has_relation_field("user")
+            .assert_relation_delete_strategy(action)
+            .assert_relation_update_strategy(action);
+    }
+}
+
+#[test]
+fn referential_actions_must_default_to_none() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let relation_info = &schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("user")
+        .relation_info;
+
+    assert_eq!(None, relation_info.on_delete);
+    assert_eq!(None, relation_info.on_update);
+}
+
+#[test]
+fn invalid_referential_actions_must_error() {
+    let dml = relation_with_actions(POSTGRES_SOURCE, "Explode", "Cascade");
+
+    parse_error(&dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Invalid referential action: `Explode`. Allowed values: (Cascade, Restrict, NoAction, SetNull, SetDefault)",
+    );
+}
+
+#[test]
+fn set_default_must_error_on_mysql() {
+    let dml = relation_with_actions(MYSQL_SOURCE, "SetDefault", "Cascade");
+
+    parse_error(&dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The referential action `SetDefault` is not supported by the current connector.",
+    );
+}
+
+#[test]
+fn restrict_must_error_on_sql_server() {
+    let dml = relation_with_actions(MSSQL_SOURCE, "Cascade", "Restrict");
+
+    parse_error(&dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The referential action `Restrict` is not supported by the current connector.",
+    );
+}
+
+#[test]
+fn set_null_must_error_on_required_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: SetNull)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The `onDelete` referential action `SetNull` requires the fields userId to be optional.",
+    );
+}
+
+#[test]
+fn referential_actions_must_only_be_defined_on_the_side_with_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(onDelete: Cascade)
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Referential actions can only be defined on the relation field that defines `fields` and `references`.",
+    );
+}
+
+#[test]
+fn referential_actions_must_be_rendered() {
+    let dml = relation_with_actions("", "Restrict", "NoAction");
+    let rendered = datamodel::render_datamodel_to_string(&parse(&dml));
+
+    assert!(rendered.contains("@relation(fields: [userId], references: [id], onDelete: Restrict, onUpdate: NoAction)"));
+
+    parse(&rendered)
+        .assert_has_model("Post")
+        .assert_has_relation_field("user")
+        .assert_relation_delete_strategy(Restrict)
+        .assert_relation_update_strategy(NoAction);
+}
@@ -0,0 +1,61 @@
+use crate::common::*;
+use datamodel::{ast::Span, diagnostics::DatamodelError};
+
+#[test]
+fn should_fail_if_field_is_unknown() {
+    let dml = r#"
+    model Post {
+        id Int @id
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The soft delete definition refers to the unknown field deletedAt.",
+        "Post",
+        Span::new(48, 76),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_is_required() {
+    let dml = r#"
+    model Post {
+        id        Int      @id
+        deletedAt DateTime
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The soft delete definition refers to the field deletedAt, which must be of type DateTime?.",
+        "Post",
+        Span::new(87, 115),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_is_not_a_datetime() {
+    let dml = r#"
+    model Post {
+        id        Int      @id
+        deletedAt Boolean?
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The soft delete definition refers to the field deletedAt, which must be of type DateTime?.",
+        "Post",
+        Span::new(87, 115),
+    ));
+}
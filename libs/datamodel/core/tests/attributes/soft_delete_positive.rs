@@ -0,0 +1,35 @@
+use crate::common::*;
+
+#[test]
+fn should_apply_soft_delete_attribute() {
+    let dml = r#"
+    model Post {
+        id        Int       @id
+        deletedAt DateTime?
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+
+    assert_eq!(post_model.soft_delete_field, Some("deletedAt".to_owned()));
+}
+
+#[test]
+fn should_accept_the_field_as_unnamed_argument() {
+    let dml = r#"
+    model Post {
+        id        Int       @id
+        removedAt DateTime?
+
+        @@softDelete(removedAt)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+
+    assert_eq!(post_model.soft_delete_field, Some("removedAt".to_owned()));
+}
@@ -0,0 +1,38 @@
+use crate::common::*;
+use datamodel::{ast::Span, diagnostics::DatamodelError};
+
+#[test]
+fn should_fail_if_field_type_is_string() {
+    let dml = r#"
+    model Post {
+        id      Int    @id
+        version String @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @version must be of type Int.",
+        "version",
+        Span::new(69, 76),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_arity_is_list() {
+    let dml = r#"
+    model Post {
+        id      Int   @id
+        version Int[] @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @version can not be lists.",
+        "version",
+        Span::new(67, 74),
+    ));
+}
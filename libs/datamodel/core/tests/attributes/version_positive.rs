@@ -0,0 +1,22 @@
+use crate::common::*;
+use datamodel::{DefaultValue, ScalarType};
+use prisma_value::PrismaValue;
+
+#[test]
+fn should_apply_version_attribute() {
+    let dml = r#"
+    model Post {
+        id      Int @id
+        version Int @default(0) @version
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_scalar_field("version")
+        .assert_base_type(&ScalarType::Int)
+        .assert_default_value(DefaultValue::Single(PrismaValue::Int(0)))
+        .assert_is_version(true);
+    post_model.assert_has_scalar_field("id").assert_is_version(false);
+}
@@ -24,6 +24,29 @@ fn parse_basic_model() {
         .assert_base_type(&ScalarType::String);
 }
 
+#[test]
+fn parse_basic_view() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String
+    }
+
+    view UserInfo {
+        id Int @id
+        firstName String
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema.assert_has_model("User").assert_is_view(false);
+
+    let view = schema.assert_has_model("UserInfo");
+    view.assert_is_view(true);
+    view.assert_has_scalar_field("firstName")
+        .assert_base_type(&ScalarType::String);
+}
+
 #[test]
 fn parse_basic_enum() {
     let dml = r#"
@@ -24,12 +24,14 @@ pub trait ScalarFieldAsserts {
     fn assert_is_id(&self) -> &Self;
     fn assert_is_unique(&self, b: bool) -> &Self;
     fn assert_is_updated_at(&self, b: bool) -> &Self;
+    fn assert_is_version(&self, b: bool) -> &Self;
 }
 
 pub trait RelationFieldAsserts {
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
+    fn assert_relation_delete_strategy(&self, t: dml::ReferentialAction) -> &Self;
+    fn assert_relation_update_strategy(&self, t: dml::ReferentialAction) -> &Self;
     fn assert_relation_referenced_fields(&self, t: &[&str]) -> &Self;
     fn assert_relation_base_fields(&self, t: &[&str]) -> &Self;
 }
@@ -38,6 +40,7 @@ pub trait ModelAsserts {
     fn assert_has_scalar_field(&self, t: &str) -> &dml::ScalarField;
     fn assert_has_relation_field(&self, t: &str) -> &dml::RelationField;
     fn assert_is_embedded(&self, t: bool) -> &Self;
+    fn assert_is_view(&self, t: bool) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_with_documentation(&self, t: &str) -> &Self;
     fn assert_has_index(&self, def: IndexDefinition) -> &Self;
@@ -151,6 +154,11 @@ impl ScalarFieldAsserts for dml::ScalarField {
         assert_eq!(self.is_updated_at, b);
         self
     }
+
+    fn assert_is_version(&self, b: bool) -> &Self {
+        assert_eq!(self.is_version, b);
+        self
+    }
 }
 
 impl FieldAsserts for dml::RelationField {
@@ -181,8 +189,13 @@ impl RelationFieldAsserts for dml::RelationField {
         self
     }
 
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self {
-        assert_eq!(self.relation_info.on_delete, t);
+    fn assert_relation_delete_strategy(&self, t: dml::ReferentialAction) -> &Self {
+        assert_eq!(self.relation_info.on_delete, Some(t));
+        self
+    }
+
+    fn assert_relation_update_strategy(&self, t: dml::ReferentialAction) -> &Self {
+        assert_eq!(self.relation_info.on_update, Some(t));
         self
     }
 
@@ -225,6 +238,12 @@ impl ModelAsserts for dml::Model {
         self
     }
 
+    fn assert_is_view(&self, t: bool) -> &Self {
+        assert_eq!(self.is_view, t);
+
+        self
+    }
+
     fn assert_with_db_name(&self, t: &str) -> &Self {
         assert_eq!(self.database_name, Some(t.to_owned()));
 
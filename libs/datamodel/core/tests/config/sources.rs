@@ -298,6 +298,103 @@ fn must_succeed_with_overrides() {
     });
 }
 
+#[test]
+#[serial]
+fn replica_urls_must_work() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://primary"
+          replicaUrls = ["postgresql://replica1", env("REPLICA_URL")]
+        }
+    "#;
+    std::env::set_var("REPLICA_URL", "postgresql://replica2");
+
+    let config = parse_configuration(schema);
+    let data_source = config.datasources.first().unwrap();
+
+    assert_eq!(
+        data_source.replica_urls(),
+        &[
+            StringFromEnvVar {
+                from_env_var: None,
+                value: "postgresql://replica1".to_string(),
+            },
+            StringFromEnvVar {
+                from_env_var: Some("REPLICA_URL".to_string()),
+                value: "postgresql://replica2".to_string(),
+            },
+        ]
+    );
+
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://primary"
+          },
+          "replicaUrls": [
+            {
+              "fromEnvVar": null,
+              "value": "postgresql://replica1"
+            },
+            {
+              "fromEnvVar": "REPLICA_URL",
+              "value": "postgresql://replica2"
+            }
+          ]
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+
+    // make sure other tests that run afterwards are not run in a modified environment
+    std::env::remove_var("REPLICA_URL");
+}
+
+#[test]
+fn must_error_if_wrong_protocol_is_used_for_replica_urls() {
+    let schema = r#"
+        datasource myds {
+            provider = "postgresql"
+            url = "postgresql://primary"
+            replicaUrls = ["mysql://replica"]
+        }
+    "#;
+    let config = datamodel::parse_configuration(schema);
+    assert!(config.is_err());
+    let diagnostics = config.err().expect("This must error");
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The URL for datasource `myds` must start with the protocol `postgresql://`.",
+        "myds",
+        Span::new(130, 149),
+    ));
+}
+
+#[test]
+fn must_error_if_replica_urls_are_used_with_sqlite() {
+    let schema = r#"
+        datasource myds {
+            provider = "sqlite"
+            url = "file:dev.db"
+            replicaUrls = ["file:replica.db"]
+        }
+    "#;
+    let config = datamodel::parse_configuration(schema);
+    assert!(config.is_err());
+    let diagnostics = config.err().expect("This must error");
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The datasource `myds` cannot have `replicaUrls`: read replicas are not supported on SQLite.",
+        "myds",
+        Span::new(117, 136),
+    ));
+}
+
 #[test]
 #[serial]
 fn fail_to_load_sources_for_invalid_source() {
@@ -28,6 +28,24 @@ fn test_reformat_model_simple() {
     assert_reformat(input, expected);
 }
 
+#[test]
+fn test_reformat_view_simple() {
+    let input = r#"
+        view UserInfo {
+            id               Int                   @id
+            name String
+        }
+    "#;
+
+    let expected = r#"view UserInfo {
+  id   Int    @id
+  name String
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
 #[test]
 fn test_reformat_model_complex() {
     let input = r#"
@@ -28,6 +28,40 @@ fn test_dmmf_rendering() {
     }
 }
 
+#[test]
+fn referential_actions_are_rendered_in_dmmf_casing() {
+    let dml = parse(
+        r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id], onDelete: SetNull)
+        }
+    "#,
+    );
+
+    let dmmf: serde_json::Value = serde_json::from_str(&datamodel::json::dmmf::render_to_dmmf(&dml)).unwrap();
+    let post = dmmf["models"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|model| model["name"] == "Post")
+        .unwrap();
+    let user_field = post["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|field| field["name"] == "user")
+        .unwrap();
+
+    assert_eq!(user_field["relationOnDelete"], "SET_NULL");
+}
+
 fn assert_eq_json(a: &str, b: &str, msg: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
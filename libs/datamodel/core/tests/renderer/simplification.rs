@@ -78,3 +78,22 @@ fn test_render_relation_name_on_self_relations() {
 
     assert_eq!(rendered, input);
 }
+
+#[test]
+fn views_must_be_rendered_as_view_blocks() {
+    let input = r#"model User {
+  id   Int    @id
+  name String
+}
+
+view UserName {
+  id   Int    @id
+  name String
+}
+"#;
+
+    let dml = parse(input);
+    let rendered = datamodel::render_datamodel_to_string(&dml);
+
+    assert_eq!(rendered, input);
+}
@@ -58,6 +58,7 @@ impl<'a> DatamodelConverter<'a> {
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
                 fields: self.convert_fields(&model),
                 manifestation: model.database_name().map(|s| s.to_owned()),
                 id_field_names: model.id_fields.clone(),
@@ -133,6 +134,7 @@ impl<'a> DatamodelConverter<'a> {
                 typ: match i.tpe {
                     dml::IndexType::Unique => IndexType::Unique,
                     dml::IndexType::Normal => IndexType::Normal,
+                    dml::IndexType::Fulltext => IndexType::Fulltext,
                 },
             })
             .collect()
@@ -384,6 +386,8 @@ impl DatamodelFieldExtensions for dml::ScalarField {
     fn behaviour(&self) -> Option<FieldBehaviour> {
         if self.is_updated_at {
             Some(FieldBehaviour::UpdatedAt)
+        } else if self.is_version {
+            Some(FieldBehaviour::Version)
         } else {
             None
         }
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use datamodel::{FieldArity, RelationInfo};
+use datamodel::{FieldArity, ReferentialAction, RelationInfo};
 use once_cell::sync::OnceCell;
 use std::{
     fmt::Debug,
@@ -160,6 +160,15 @@ impl RelationField {
         !self.is_required
     }
 
+    /// Whether records referencing a related record through this field prevent its deletion.
+    /// Without an explicit `onDelete`, only required relations are checked.
+    pub fn restricts_deletion(&self) -> bool {
+        match self.relation_info.on_delete {
+            Some(action) => matches!(action, ReferentialAction::Restrict | ReferentialAction::NoAction),
+            None => self.is_required,
+        }
+    }
+
     pub fn model(&self) -> ModelRef {
         self.model
             .upgrade()
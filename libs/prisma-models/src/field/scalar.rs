@@ -114,6 +114,7 @@ impl PartialEq for ScalarField {
 pub enum FieldBehaviour {
     CreatedAt,
     UpdatedAt,
+    Version,
     ScalarList { strategy: ScalarListStrategy },
 }
 
@@ -184,6 +185,10 @@ impl ScalarField {
         }
     }
 
+    pub fn is_version(&self) -> bool {
+        matches!(self.behaviour, Some(FieldBehaviour::Version))
+    }
+
     pub fn unique(&self) -> bool {
         self.is_unique || self.is_id()
     }
@@ -151,7 +151,7 @@ impl InternalDataModel {
         self.relation_fields()
             .iter()
             .filter(|rf| &rf.related_model() == model)
-            .filter(|f| f.is_required && !f.is_list)
+            .filter(|f| !f.is_list && f.restricts_deletion())
             .map(|f| Arc::clone(f))
             .collect()
     }
@@ -13,6 +13,7 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
     pub id_field_names: Vec<String>,
@@ -23,6 +24,7 @@ pub struct ModelTemplate {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
 
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
@@ -38,6 +40,7 @@ impl Debug for Model {
         f.debug_struct("Model")
             .field("name", &self.name)
             .field("is_embedded", &self.is_embedded)
+            .field("is_view", &self.is_view)
             .field("manifestation", &self.manifestation)
             .field("fields", &self.fields)
             .field("indexes", &self.indexes)
@@ -53,6 +56,7 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_view: self.is_view,
             manifestation: self.manifestation,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
@@ -139,6 +143,14 @@ impl Model {
             .collect()
     }
 
+    /// The timestamp field marking records as deleted, if the model is soft deleted.
+    pub fn soft_delete_field(&self) -> Option<ScalarFieldRef> {
+        self.dml_model
+            .soft_delete_field
+            .as_ref()
+            .and_then(|name| self.fields().find_from_scalar(name).ok())
+    }
+
     pub fn is_legacy(&self) -> bool {
         self.internal_data_model().is_legacy()
     }
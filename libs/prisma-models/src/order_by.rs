@@ -1,16 +1,101 @@
-use crate::{ModelRef, ScalarFieldRef};
+use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 use std::string::ToString;
 
+/// Ordering of records, either by a scalar field or by an aggregation of a relation.
+///
+/// `path` holds the relation hops from the queried model to the model containing the ordered field
+/// (e.g. `Post -> author` to order posts by `author.name`). Every hop of a scalar ordering is a to-one relation.
+/// For a relation aggregation, `field` is `None` and the last hop is the aggregated to-many relation.
+///
+/// `nulls_order` overrides the database specific placement of null values, if set.
+///
+/// A relevance ordering has neither a field nor a path and orders by the relevance of a full-text search instead.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OrderBy {
-    pub field: ScalarFieldRef,
+    pub field: Option<ScalarFieldRef>,
+    pub path: Vec<RelationFieldRef>,
     pub sort_order: SortOrder,
+    pub sort_aggregation: Option<SortAggregation>,
+    pub nulls_order: Option<NullsOrder>,
+    pub relevance: Option<OrderByRelevance>,
 }
 
 impl OrderBy {
     pub fn new(field: ScalarFieldRef, sort_order: SortOrder) -> Self {
-        Self { field, sort_order }
+        Self::with_path(field, vec![], sort_order)
     }
+
+    pub fn with_path(field: ScalarFieldRef, path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+        Self {
+            field: Some(field),
+            path,
+            sort_order,
+            sort_aggregation: None,
+            nulls_order: None,
+            relevance: None,
+        }
+    }
+
+    pub fn with_nulls_order(mut self, nulls_order: Option<NullsOrder>) -> Self {
+        self.nulls_order = nulls_order;
+        self
+    }
+
+    pub fn relation_aggregation(
+        path: Vec<RelationFieldRef>,
+        sort_aggregation: SortAggregation,
+        sort_order: SortOrder,
+    ) -> Self {
+        Self {
+            field: None,
+            path,
+            sort_order,
+            sort_aggregation: Some(sort_aggregation),
+            nulls_order: None,
+            relevance: None,
+        }
+    }
+
+    pub fn relevance(fields: Vec<ScalarFieldRef>, search: String, sort_order: SortOrder) -> Self {
+        Self {
+            field: None,
+            path: vec![],
+            sort_order,
+            sort_aggregation: None,
+            nulls_order: None,
+            relevance: Some(OrderByRelevance { fields, search }),
+        }
+    }
+
+    /// Returns the field if the ordering is on a scalar field of the queried model itself.
+    pub fn scalar_field(&self) -> Option<&ScalarFieldRef> {
+        if self.path.is_empty() {
+            self.field.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Whether or not the ordering needs data from related records.
+    pub fn contains_relations(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    /// Whether or not the ordered value can be null, either because the field is optional
+    /// or because a to-one relation on the path is optional.
+    pub fn is_nullable(&self) -> bool {
+        let optional_field = self.field.as_ref().map(|f| !f.is_required).unwrap_or(false);
+        let optional_hop = self.path.iter().any(|rf| !rf.is_list && !rf.is_required);
+
+        optional_field || optional_hop
+    }
+}
+
+/// The relevance of the `fields` of a record for the full-text `search` query.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderByRelevance {
+    pub fields: Vec<ScalarFieldRef>,
+    pub search: String,
 }
 
 pub trait IntoOrderBy {
@@ -32,11 +117,21 @@ impl ToString for SortOrder {
     }
 }
 
+/// Placement of null values relative to all other values, independent of the sort order.
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
+/// Aggregations of to-many relations records can be ordered by.
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SortAggregation {
+    Count,
+}
+
 impl From<ScalarFieldRef> for OrderBy {
     fn from(field: ScalarFieldRef) -> Self {
-        Self {
-            field,
-            sort_order: SortOrder::Ascending,
-        }
+        Self::new(field, SortOrder::Ascending)
     }
 }
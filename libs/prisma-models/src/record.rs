@@ -1,5 +1,7 @@
-use crate::{DomainError, ModelProjection, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder};
-use std::collections::HashMap;
+use crate::{
+    DomainError, ModelProjection, NullsOrder, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder,
+};
+use std::{cmp::Ordering, collections::HashMap};
 
 #[derive(Debug, Clone)]
 pub struct SingleRecord {
@@ -64,6 +66,8 @@ impl ManyRecords {
         }
     }
 
+    /// Orders the records in memory. Only supports orderings on scalar fields of the model itself,
+    /// which must be part of the selected fields.
     pub fn order_by(&mut self, order_bys: &[OrderBy]) {
         let field_indices: HashMap<&str, usize> = self
             .field_names
@@ -74,10 +78,22 @@ impl ManyRecords {
 
         self.records.sort_by(|a, b| {
             let mut orderings = order_bys.iter().map(|o| {
-                let index = field_indices[o.field.db_name()];
-                match o.sort_order {
-                    SortOrder::Ascending => a.values[index].cmp(&b.values[index]),
-                    SortOrder::Descending => b.values[index].cmp(&a.values[index]),
+                let field = o
+                    .scalar_field()
+                    .expect("In-memory ordering is only supported on scalar fields of the model.");
+                let index = field_indices[field.db_name()];
+                let (a, b) = (&a.values[index], &b.values[index]);
+
+                match (o.nulls_order, a, b) {
+                    (Some(_), PrismaValue::Null, PrismaValue::Null) => Ordering::Equal,
+                    (Some(NullsOrder::First), PrismaValue::Null, _)
+                    | (Some(NullsOrder::Last), _, PrismaValue::Null) => Ordering::Less,
+                    (Some(NullsOrder::First), _, PrismaValue::Null)
+                    | (Some(NullsOrder::Last), PrismaValue::Null, _) => Ordering::Greater,
+                    _ => match o.sort_order {
+                        SortOrder::Ascending => a.cmp(b),
+                        SortOrder::Descending => b.cmp(a),
+                    },
                 }
             });
 
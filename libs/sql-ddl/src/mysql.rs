@@ -132,21 +132,50 @@ impl Display for ForeignKeyAction {
 #[derive(Debug)]
 pub struct CreateIndex<'a> {
     pub unique: bool,
+    pub fulltext: bool,
     pub index_name: Cow<'a, str>,
-    pub on: (Cow<'a, str>, Vec<Cow<'a, str>>),
+    pub on: (Cow<'a, str>, Vec<IndexColumn<'a>>),
+}
+
+#[derive(Debug)]
+pub struct IndexColumn<'a> {
+    pub name: Cow<'a, str>,
+    pub descending: bool,
+}
+
+impl<'a> From<&'a str> for IndexColumn<'a> {
+    fn from(name: &'a str) -> Self {
+        IndexColumn {
+            name: Cow::Borrowed(name),
+            descending: false,
+        }
+    }
+}
+
+impl Display for IndexColumn<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Ident(&self.name).fmt(f)?;
+
+        if self.descending {
+            f.write_str(" DESC")?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Display for CreateIndex<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CREATE {maybe_unique}INDEX `{index_name}` ON `{table_name}`(",
+            "CREATE {maybe_unique}{maybe_fulltext}INDEX `{index_name}` ON `{table_name}`(",
             maybe_unique = if self.unique { "UNIQUE " } else { "" },
+            maybe_fulltext = if self.fulltext { "FULLTEXT " } else { "" },
             index_name = self.index_name,
             table_name = self.on.0,
         )?;
 
-        self.on.1.iter().map(|s| Ident(s)).join(", ", f)?;
+        self.on.1.iter().join(", ", f)?;
 
         write!(f, ")")
     }
@@ -179,6 +208,30 @@ impl Display for DropIndex<'_> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn create_index_with_descending_columns() {
+        let create_index = CreateIndex {
+            unique: true,
+            fulltext: false,
+            index_name: "meow_idx".into(),
+            on: (
+                "Cat".into(),
+                vec![
+                    "name".into(),
+                    IndexColumn {
+                        name: "age".into(),
+                        descending: true,
+                    },
+                ],
+            ),
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE UNIQUE INDEX `meow_idx` ON `Cat`(`name`, `age` DESC)"
+        );
+    }
+
     #[test]
     fn alter_table_add_foreign_key() {
         let alter_table = AlterTable {
@@ -62,23 +62,81 @@ impl<'a> Display for CreateEnum<'a> {
 pub struct CreateIndex<'a> {
     pub index_name: PostgresIdentifier<'a>,
     pub is_unique: bool,
+    /// Build the index without blocking writes to the table. This cannot run in a transaction.
+    pub concurrently: bool,
     pub table_reference: PostgresIdentifier<'a>,
-    pub columns: Vec<Cow<'a, str>>,
+    /// The index access method (e.g. `gin`). `None` means the default (btree).
+    pub using: Option<&'a str>,
+    pub columns: Vec<IndexColumn<'a>>,
+    /// Non-key columns stored in the index.
+    pub include: Vec<Cow<'a, str>>,
+    /// The predicate of a partial index.
+    pub where_clause: Option<Cow<'a, str>>,
+}
+
+pub struct IndexColumn<'a> {
+    pub name: Cow<'a, str>,
+    pub descending: bool,
+    /// Index the `to_tsvector` of the column in this text search configuration instead of the column
+    /// itself (full-text indexes).
+    pub text_search_config: Option<&'a str>,
+}
+
+impl<'a> From<&'a str> for IndexColumn<'a> {
+    fn from(name: &'a str) -> Self {
+        IndexColumn {
+            name: Cow::Borrowed(name),
+            descending: false,
+            text_search_config: None,
+        }
+    }
+}
+
+impl Display for IndexColumn<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.text_search_config {
+            Some(config) => write!(f, "to_tsvector({}, {})", StrLit(config), Ident(&self.name))?,
+            None => Ident(&self.name).fmt(f)?,
+        }
+
+        if self.descending {
+            f.write_str(" DESC")?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> Display for CreateIndex<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CREATE {uniqueness}INDEX {index_name} ON {table_reference}(",
+            "CREATE {uniqueness}INDEX {concurrently}{index_name} ON {table_reference}",
             uniqueness = if self.is_unique { "UNIQUE " } else { "" },
+            concurrently = if self.concurrently { "CONCURRENTLY " } else { "" },
             index_name = self.index_name,
             table_reference = self.table_reference,
         )?;
 
-        self.columns.iter().map(|s| Ident(s)).join(", ", f)?;
+        if let Some(using) = self.using {
+            write!(f, " USING {} ", using)?;
+        }
+
+        f.write_str("(")?;
+        self.columns.iter().join(", ", f)?;
+        f.write_str(")")?;
 
-        f.write_str(")")
+        if !self.include.is_empty() {
+            f.write_str(" INCLUDE (")?;
+            self.include.iter().map(|s| Ident(s)).join(", ", f)?;
+            f.write_str(")")?;
+        }
+
+        if let Some(where_clause) = &self.where_clause {
+            write!(f, " WHERE {}", where_clause)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -116,9 +174,13 @@ mod tests {
 
         let create_index = CreateIndex {
             is_unique: true,
+            concurrently: false,
             index_name: "meow_idx".into(),
             table_reference: "Cat".into(),
+            using: None,
             columns,
+            include: Vec::new(),
+            where_clause: None,
         };
 
         assert_eq!(
@@ -126,4 +188,79 @@ mod tests {
             "CREATE UNIQUE INDEX \"meow_idx\" ON \"Cat\"(\"name\", \"age\")"
         )
     }
+
+    #[test]
+    fn create_index_concurrently() {
+        let create_index = CreateIndex {
+            is_unique: false,
+            concurrently: true,
+            index_name: "meow_idx".into(),
+            table_reference: "Cat".into(),
+            using: None,
+            columns: vec!["name".into()],
+            include: Vec::new(),
+            where_clause: None,
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE INDEX CONCURRENTLY \"meow_idx\" ON \"Cat\"(\"name\")"
+        )
+    }
+
+    #[test]
+    fn create_partial_index_with_method_sort_order_and_included_columns() {
+        let create_index = CreateIndex {
+            is_unique: false,
+            concurrently: false,
+            index_name: "meow_idx".into(),
+            table_reference: "Cat".into(),
+            using: Some("gist"),
+            columns: vec![
+                "name".into(),
+                IndexColumn {
+                    name: "age".into(),
+                    descending: true,
+                    text_search_config: None,
+                },
+            ],
+            include: vec!["color".into()],
+            where_clause: Some("\"deletedAt\" IS NULL".into()),
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE INDEX \"meow_idx\" ON \"Cat\" USING gist (\"name\", \"age\" DESC) INCLUDE (\"color\") WHERE \"deletedAt\" IS NULL"
+        )
+    }
+
+    #[test]
+    fn create_full_text_index() {
+        let create_index = CreateIndex {
+            is_unique: false,
+            concurrently: false,
+            index_name: "meow_idx".into(),
+            table_reference: "Cat".into(),
+            using: Some("GIN"),
+            columns: vec![
+                IndexColumn {
+                    name: "name".into(),
+                    descending: false,
+                    text_search_config: Some("english"),
+                },
+                IndexColumn {
+                    name: "bio".into(),
+                    descending: false,
+                    text_search_config: Some("english"),
+                },
+            ],
+            include: Vec::new(),
+            where_clause: None,
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE INDEX \"meow_idx\" ON \"Cat\" USING GIN (to_tsvector('english', \"name\"), to_tsvector('english', \"bio\"))"
+        )
+    }
 }
@@ -15,6 +15,7 @@ pub struct CreateTable<'a> {
     pub columns: Vec<Column<'a>>,
     pub primary_key: Option<Vec<Cow<'a, str>>>,
     pub foreign_keys: Vec<ForeignKey<'a>>,
+    pub check_constraints: Vec<CheckConstraint<'a>>,
 }
 
 impl Display for CreateTable<'_> {
@@ -40,6 +41,15 @@ impl Display for CreateTable<'_> {
             )?;
         }
 
+        for check_constraint in &self.check_constraints {
+            write!(
+                f,
+                ",\n{indentation}{check}",
+                indentation = SQL_INDENTATION,
+                check = check_constraint
+            )?;
+        }
+
         write!(f, "\n)")
     }
 }
@@ -122,6 +132,24 @@ impl Display for ForeignKey<'_> {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct CheckConstraint<'a> {
+    pub name: Cow<'a, str>,
+    /// The constraint expression, as raw SQL.
+    pub expression: Cow<'a, str>,
+}
+
+impl Display for CheckConstraint<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CONSTRAINT {name} CHECK ({expression})",
+            name = SqliteIdentifier(&self.name),
+            expression = self.expression,
+        )
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Column<'a> {
     pub name: Cow<'a, str>,
@@ -276,4 +304,33 @@ mod tests {
 
         assert_eq!(create_table.to_string(), expected.trim_matches('\n'))
     }
+
+    #[test]
+    fn create_table_with_check_constraints() {
+        let create_table = CreateTable {
+            table_name: "Product".into(),
+            columns: vec![Column {
+                name: "price".into(),
+                r#type: "INTEGER".into(),
+                not_null: true,
+                ..Default::default()
+            }],
+            check_constraints: vec![CheckConstraint {
+                name: "positivePrice".into(),
+                expression: "price > 0".into(),
+            }],
+            ..Default::default()
+        };
+
+        let expected = indoc!(
+            r#"
+            CREATE TABLE "Product" (
+                "price" INTEGER NOT NULL,
+                CONSTRAINT "positivePrice" CHECK (price > 0)
+            )
+            "#
+        );
+
+        assert_eq!(create_table.to_string(), expected.trim_matches('\n'))
+    }
 }
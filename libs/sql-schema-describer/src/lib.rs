@@ -51,6 +51,8 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -59,6 +61,11 @@ impl SqlSchema {
         self.tables.iter().find(|x| x.name == name)
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     /// Get an enum.
     pub fn get_enum(&self, name: &str) -> Option<&Enum> {
         self.enums.iter().find(|x| x.name == name)
@@ -72,7 +79,8 @@ impl SqlSchema {
                 tables,
                 enums,
                 sequences,
-            } if tables.is_empty() && enums.is_empty() && sequences.is_empty()
+                views,
+            } if tables.is_empty() && enums.is_empty() && sequences.is_empty() && views.is_empty()
         )
     }
 
@@ -97,6 +105,7 @@ impl SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 
@@ -125,6 +134,8 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    pub check_constraints: Vec<CheckConstraint>,
 }
 
 impl Table {
@@ -180,6 +191,16 @@ impl Table {
         }
     }
 }
+
+/// A check constraint on a table.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CheckConstraint {
+    /// The constraint name.
+    pub name: String,
+    /// The checked expression, as returned by the database.
+    pub expression: String,
+}
+
 /// The type of an index.
 #[derive(PartialEq, Debug, Clone)]
 pub enum IndexType {
@@ -187,6 +208,8 @@ pub enum IndexType {
     Unique,
     /// Normal type.
     Normal,
+    /// Full-text index type.
+    Fulltext,
 }
 
 impl IndexType {
@@ -195,6 +218,27 @@ impl IndexType {
     }
 }
 
+/// The access method of an index.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum IndexAlgorithm {
+    /// B-tree, the default on all databases.
+    BTree,
+    /// Hash index (Postgres).
+    Hash,
+    /// Generalized inverted index (Postgres).
+    Gin,
+    /// Generalized search tree (Postgres).
+    Gist,
+    /// Block range index (Postgres).
+    Brin,
+}
+
+impl Default for IndexAlgorithm {
+    fn default() -> Self {
+        IndexAlgorithm::BTree
+    }
+}
+
 /// An index of a table.
 #[derive(PartialEq, Debug, Clone)]
 pub struct Index {
@@ -204,12 +248,25 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// The access method of the index.
+    pub algorithm: IndexAlgorithm,
+    /// The index columns sorted in descending order.
+    pub descending_columns: Vec<String>,
+    /// The `WHERE` clause of a partial index.
+    pub predicate: Option<String>,
+    /// Non-key columns stored in the index (`INCLUDE`).
+    pub included_columns: Vec<String>,
 }
 
 impl Index {
     pub fn is_unique(&self) -> bool {
         self.tpe == IndexType::Unique
     }
+
+    /// True if the given index column is sorted in descending order.
+    pub fn column_is_descending(&self, column_name: &str) -> bool {
+        self.descending_columns.iter().any(|column| column == column_name)
+    }
 }
 
 /// The primary key of a table.
@@ -443,6 +500,23 @@ pub struct Enum {
     pub values: Vec<String>,
 }
 
+/// A SQL view.
+#[derive(PartialEq, Debug, Clone)]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The SQL definition of the view, if the database exposes it.
+    pub definition: Option<String>,
+    /// The view's columns.
+    pub columns: Vec<Column>,
+}
+
+impl View {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
 /// A SQL sequence.
 #[derive(PartialEq, Debug, Clone)]
 pub struct Sequence {
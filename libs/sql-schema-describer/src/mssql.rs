@@ -75,19 +75,29 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_all_columns(schema).await?;
         let mut indexes = self.get_all_indices(schema).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
+        let mut check_constraints = self.get_check_constraints(schema).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in table_names {
-            let table = self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys);
+            let table = self.get_table(
+                &table_name,
+                &mut columns,
+                &mut indexes,
+                &mut foreign_keys,
+                &mut check_constraints,
+            );
             tables.push(table);
         }
 
+        let views = self.get_views(schema, &mut columns).await?;
+
         Ok(SqlSchema {
             tables,
             enums: vec![],
             sequences: vec![],
+            views,
         })
     }
 
@@ -139,6 +149,38 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument(skip(columns))]
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> DescriberResult<Vec<View>> {
+        let select = r#"
+            SELECT v.name AS view_name, m.definition AS view_sql
+            FROM sys.views v
+            LEFT JOIN sys.sql_modules m ON m.object_id = v.object_id
+            WHERE SCHEMA_NAME(v.schema_id) = @P1
+            AND v.is_ms_shipped = 0
+            ORDER BY v.name asc;
+        "#;
+
+        let rows = self.conn.query_raw(select, &[schema.into()]).await?;
+
+        let views: Vec<View> = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get_expect_string("view_name");
+                let columns = columns.remove(&name).unwrap_or_default();
+
+                View {
+                    definition: row.get_string("view_sql"),
+                    columns,
+                    name,
+                }
+            })
+            .collect();
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = indoc! {r#"
@@ -176,11 +218,13 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> Table {
         let columns = columns.remove(name).unwrap_or_default();
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
 
         Table {
             name: name.to_string(),
@@ -188,6 +232,7 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            check_constraints,
         }
     }
 
@@ -208,9 +253,10 @@ impl SqlSchemaDescriber {
                     WHEN c.system_type_id IN (40, 41, 42, 43, 58, 61) THEN NULL
                     ELSE ODBCSCALE(c.system_type_id, c.scale) END) AS numeric_scale
             FROM sys.columns c
-                    INNER JOIN sys.tables t ON c.object_id = t.object_id
+                    INNER JOIN sys.objects t ON c.object_id = t.object_id
             WHERE OBJECT_SCHEMA_NAME(c.object_id) = @P1
             AND t.is_ms_shipped = 0
+            AND t.type IN ('U', 'V')
 
             ORDER BY COLUMNPROPERTY(c.object_id, c.name, 'ordinal');
         "#};
@@ -332,6 +378,10 @@ impl SqlSchemaDescriber {
                 ind.is_primary_key AS is_primary_key,
                 col.name AS column_name,
                 ic.key_ordinal AS seq_in_index,
+                ic.index_column_id AS index_column_id,
+                ic.is_descending_key AS is_descending,
+                ic.is_included_column AS is_included,
+                ind.filter_definition AS filter_definition,
                 t.name AS table_name
             FROM
                 sys.indexes ind
@@ -343,9 +393,10 @@ impl SqlSchemaDescriber {
                 sys.tables t ON ind.object_id = t.object_id
             WHERE SCHEMA_NAME(t.schema_id) = @P1
                 AND t.is_ms_shipped = 0
-                AND ind.filter_definition IS NULL
+                -- filtered unique indexes are not unique constraints
+                AND (ind.filter_definition IS NULL OR ind.is_unique = 0)
 
-            ORDER BY index_name, seq_in_index
+            ORDER BY index_name, seq_in_index, index_column_id
         "#};
 
         let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
@@ -396,22 +447,30 @@ impl SqlSchemaDescriber {
                                 });
                             }
                         };
-                    } else if indexes_map.contains_key(&index_name) {
-                        if let Some(index) = indexes_map.get_mut(&index_name) {
-                            index.columns.push(column_name);
-                        }
                     } else {
-                        indexes_map.insert(
-                            index_name.clone(),
-                            Index {
-                                name: index_name,
-                                columns: vec![column_name],
-                                tpe: match is_unique {
-                                    true => IndexType::Unique,
-                                    false => IndexType::Normal,
-                                },
+                        let index = indexes_map.entry(index_name.clone()).or_insert_with(|| Index {
+                            name: index_name,
+                            columns: vec![],
+                            tpe: match is_unique {
+                                true => IndexType::Unique,
+                                false => IndexType::Normal,
                             },
-                        );
+                            algorithm: IndexAlgorithm::BTree,
+                            descending_columns: vec![],
+                            predicate: row.get_string("filter_definition"),
+                            included_columns: vec![],
+                        });
+
+                        // Included columns have no position in the index key.
+                        if row.get_expect_bool("is_included") {
+                            index.included_columns.push(column_name);
+                        } else {
+                            if row.get_expect_bool("is_descending") {
+                                index.descending_columns.push(column_name.clone());
+                            }
+
+                            index.columns.push(column_name);
+                        }
                     }
                 }
                 None => {
@@ -431,6 +490,36 @@ impl SqlSchemaDescriber {
         Ok(map)
     }
 
+    async fn get_check_constraints(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let sql = indoc! {r#"
+            SELECT t.name AS table_name,
+                cc.name AS constraint_name,
+                cc.definition AS expression
+            FROM sys.check_constraints cc
+                INNER JOIN sys.tables t ON t.object_id = cc.parent_object_id
+            WHERE t.is_ms_shipped = 0
+            AND SCHEMA_NAME(t.schema_id) = @P1
+            ORDER BY table_name, constraint_name
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            trace!("Got check constraint row {:?}", row);
+
+            check_constraints
+                .entry(row.get_expect_string("table_name"))
+                .or_default()
+                .push(CheckConstraint {
+                    name: row.get_expect_string("constraint_name"),
+                    expression: row.get_expect_string("expression"),
+                });
+        }
+
+        Ok(check_constraints)
+    }
+
     async fn get_foreign_keys(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
         // Foreign keys covering multiple columns will return multiple rows, which we need to
         // merge.
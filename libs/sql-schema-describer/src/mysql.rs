@@ -63,18 +63,24 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = Self::get_all_columns(&self.conn, schema, &flavour).await?;
         let mut indexes = Self::get_all_indexes(&self.conn, schema).await?;
         let mut fks = Self::get_foreign_keys(&self.conn, schema).await?;
+        let mut check_constraints = Self::get_check_constraints(&self.conn, schema, &flavour).await?;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) =
+                self.get_table(table_name, &mut columns, &mut indexes, &mut fks, &mut check_constraints);
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
 
+        let (views, view_enums) = self.get_views(schema, &mut columns).await?;
+        enums.extend(view_enums);
+
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            views,
         })
     }
 
@@ -110,7 +116,7 @@ impl SqlSchemaDescriber {
     async fn get_table_names(&self, schema: &str) -> DescriberResult<Vec<String>> {
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = ?
-            -- Views are not supported yet
+            -- Views are described separately
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
@@ -124,6 +130,37 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument(skip(self, columns))]
+    async fn get_views(
+        &self,
+        schema: &str,
+        columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
+    ) -> DescriberResult<(Vec<View>, Vec<Enum>)> {
+        let sql = "SELECT table_name as view_name, view_definition as view_sql
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut views = Vec::with_capacity(rows.len());
+        let mut enums = Vec::new();
+
+        for row in rows {
+            let name = row.get_expect_string("view_name");
+            let (view_columns, view_enums) = columns.remove(&name).unwrap_or((vec![], vec![]));
+
+            enums.extend(view_enums);
+            views.push(View {
+                definition: row.get_string("view_sql"),
+                columns: view_columns,
+                name,
+            });
+        }
+
+        trace!("Found views: {:?}", views);
+
+        Ok((views, enums))
+    }
+
     #[tracing::instrument(skip(self))]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = r#"
@@ -148,18 +185,20 @@ impl SqlSchemaDescriber {
         Ok(size as usize)
     }
 
-    #[tracing::instrument(skip(self, columns, indexes, foreign_keys))]
+    #[tracing::instrument(skip(self, columns, indexes, foreign_keys, check_constraints))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> (Table, Vec<Enum>) {
         let (columns, enums) = columns.remove(name).unwrap_or((vec![], vec![]));
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
 
         (
             Table {
@@ -168,6 +207,7 @@ impl SqlSchemaDescriber {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                check_constraints,
             },
             enums,
         )
@@ -332,7 +372,9 @@ impl SqlSchemaDescriber {
                 non_unique AS non_unique,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
-                table_name AS table_name
+                table_name AS table_name,
+                index_type AS index_type,
+                collation AS collation
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ?
             ORDER BY index_name, seq_in_index
@@ -348,6 +390,9 @@ impl SqlSchemaDescriber {
                     let seq_in_index = row.get_expect_i64("seq_in_index");
                     let pos = seq_in_index - 1;
                     let is_unique = !row.get_expect_bool("non_unique");
+                    let is_fulltext = row.get_expect_string("index_type") == "FULLTEXT";
+                    // `D` for descending, `A` for ascending. MySQL 5.7 parses but ignores `DESC`.
+                    let is_descending = row.get_string("collation").as_deref() == Some("D");
 
                     // Multi-column indices will return more than one row (with different column_name values).
                     // We cannot assume that one row corresponds to one index.
@@ -381,6 +426,10 @@ impl SqlSchemaDescriber {
                         };
                     } else if indexes_map.contains_key(&index_name) {
                         if let Some(index) = indexes_map.get_mut(&index_name) {
+                            if is_descending {
+                                index.descending_columns.push(column_name.clone());
+                            }
+
                             index.columns.push(column_name);
                         }
                     } else {
@@ -388,11 +437,20 @@ impl SqlSchemaDescriber {
                             index_name.clone(),
                             Index {
                                 name: index_name,
+                                descending_columns: if is_descending {
+                                    vec![column_name.clone()]
+                                } else {
+                                    vec![]
+                                },
                                 columns: vec![column_name],
-                                tpe: match is_unique {
-                                    true => IndexType::Unique,
-                                    false => IndexType::Normal,
+                                tpe: match (is_unique, is_fulltext) {
+                                    (true, _) => IndexType::Unique,
+                                    (false, true) => IndexType::Fulltext,
+                                    (false, false) => IndexType::Normal,
                                 },
+                                algorithm: IndexAlgorithm::BTree,
+                                predicate: None,
+                                included_columns: vec![],
                             },
                         );
                     }
@@ -519,6 +577,81 @@ impl SqlSchemaDescriber {
         Ok(fks)
     }
 
+    async fn get_check_constraints(
+        conn: &dyn Queryable,
+        schema_name: &str,
+        flavour: &Flavour,
+    ) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        // information_schema.check_constraints only exists on MySQL 8.0.16+ and MariaDB 10.2+.
+        let has_check_constraints_table_sql = "
+            SELECT COUNT(*) AS count
+            FROM information_schema.tables
+            WHERE table_schema = 'information_schema' AND table_name = 'CHECK_CONSTRAINTS'
+        ";
+
+        let result_set = conn.query_raw(has_check_constraints_table_sql, &[]).await?;
+
+        if result_set.into_iter().next().map(|row| row.get_expect_i64("count")) != Some(1) {
+            return Ok(check_constraints);
+        }
+
+        // The table name is only part of the check constraints table on MariaDB, where constraint
+        // names are unique per table instead of per schema.
+        let sql = match flavour {
+            Flavour::MariaDb => {
+                "
+                SELECT
+                    cc.table_name table_name,
+                    cc.constraint_name constraint_name,
+                    cc.check_clause expression
+                FROM information_schema.check_constraints cc
+                WHERE cc.constraint_schema = ?
+                ORDER BY table_name, constraint_name
+                "
+            }
+            Flavour::Mysql => {
+                "
+                SELECT
+                    tc.table_name table_name,
+                    cc.constraint_name constraint_name,
+                    cc.check_clause expression
+                FROM information_schema.check_constraints cc
+                INNER JOIN information_schema.table_constraints tc ON
+                    tc.constraint_schema = cc.constraint_schema
+                    AND tc.constraint_name = cc.constraint_name
+                    AND tc.constraint_type = 'CHECK'
+                WHERE cc.constraint_schema = ?
+                ORDER BY table_name, constraint_name
+                "
+            }
+        };
+
+        let result_set = conn.query_raw(sql, &[schema_name.into()]).await?;
+
+        for row in result_set.into_iter() {
+            trace!("Got check constraint row {:?}", row);
+
+            let table_name = row.get_expect_string("table_name");
+            let name = row.get_expect_string("constraint_name");
+            let expression = row.get_expect_string("expression");
+
+            // MariaDB implements the JSON type as LONGTEXT with an implicit `json_valid` check
+            // named after the column. These are part of the column type, not user constraints.
+            if matches!(flavour, Flavour::MariaDb) && expression.starts_with("json_valid(") {
+                continue;
+            }
+
+            check_constraints
+                .entry(table_name)
+                .or_default()
+                .push(CheckConstraint { name, expression });
+        }
+
+        Ok(check_constraints)
+    }
+
     fn get_column_type_and_enum(
         table: &str,
         column_name: &str,
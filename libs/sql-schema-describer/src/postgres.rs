@@ -39,18 +39,28 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_columns(schema, &enums, &sequences).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
         let mut indexes = self.get_indices(schema, &sequences).await?;
+        let mut check_constraints = self.get_check_constraints(schema).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            tables.push(self.get_table(
+                &table_name,
+                &mut columns,
+                &mut foreign_keys,
+                &mut indexes,
+                &mut check_constraints,
+            ));
         }
 
+        let views = self.get_views(schema, &mut columns).await?;
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 
@@ -98,7 +108,7 @@ impl SqlSchemaDescriber {
         let sql = "
             SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
-            -- Views are not supported yet
+            -- Views are described separately
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
@@ -112,6 +122,33 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument(skip(columns))]
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> DescriberResult<Vec<View>> {
+        let sql = "
+            SELECT viewname AS view_name, definition AS view_sql
+            FROM pg_catalog.pg_views
+            WHERE schemaname = $1
+            ORDER BY viewname";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let views: Vec<View> = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get_expect_string("view_name");
+                let columns = columns.remove(&name).unwrap_or_default();
+
+                View {
+                    definition: row.get_string("view_sql"),
+                    columns,
+                    name,
+                }
+            })
+            .collect();
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql =
@@ -126,16 +163,18 @@ impl SqlSchemaDescriber {
         Ok(size.try_into().expect("size is not a valid usize"))
     }
 
-    #[tracing::instrument(skip(columns, foreign_keys, indices))]
+    #[tracing::instrument(skip(columns, foreign_keys, indices, check_constraints))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> Table {
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
         let columns = columns.remove(name).unwrap_or_default();
         Table {
             name: name.to_string(),
@@ -143,6 +182,7 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
         }
     }
 
@@ -277,6 +317,38 @@ impl SqlSchemaDescriber {
         }
     }
 
+    /// Returns a map from table name to check constraints.
+    async fn get_check_constraints(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                con.conname AS constraint_name,
+                pg_get_expr(con.conbin, con.conrelid) AS expression
+            FROM pg_constraint con
+            JOIN pg_class cl ON cl.oid = con.conrelid
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1 AND con.contype = 'c'
+            ORDER BY table_name, constraint_name
+        "#;
+
+        let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            trace!("Got check constraint row {:?}", row);
+
+            check_constraints
+                .entry(row.get_expect_string("table_name"))
+                .or_default()
+                .push(CheckConstraint {
+                    name: row.get_expect_string("constraint_name"),
+                    expression: row.get_expect_string("expression"),
+                });
+        }
+
+        Ok(check_constraints)
+    }
+
     /// Returns a map from table name to foreign keys.
     async fn get_foreign_keys(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
         // The `generate_subscripts` in the inner select is needed because the optimizer is free to reorganize the unnested rows if not explicitly ordered.
@@ -410,7 +482,13 @@ impl SqlSchemaDescriber {
             rawIndex.indisprimary AS is_primary_key,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
-            pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
+            pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name,
+            accessMethod.amname AS algorithm,
+            rawIndex.predicate,
+            -- bit 0 of indoption is set for DESC columns
+            COALESCE((rawIndex.indoption[rawIndex.indkeyidx] & 1) = 1, false) AS is_descending,
+            -- the columns after the key columns are INCLUDE columns
+            rawIndex.indkeyidx >= rawIndex.indnkeyatts AS is_included
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
             pg_class tableInfos,
@@ -423,13 +501,19 @@ impl SqlSchemaDescriber {
                     indisunique,
                     indisprimary,
                     pg_index.indkey AS indkey,
-                    generate_subscripts(pg_index.indkey, 1) AS indkeyidx
+                    pg_index.indoption AS indoption,
+                    generate_subscripts(pg_index.indkey, 1) AS indkeyidx,
+                    pg_get_expr(pg_index.indpred, pg_index.indrelid) AS predicate,
+                    -- indnkeyatts only exists on Postgres 11 and later
+                    COALESCE((to_jsonb(pg_index) ->> 'indnkeyatts')::int, pg_index.indnatts) AS indnkeyatts
                 FROM pg_index
-                -- ignores partial indexes
-                Where indpred is Null
-                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
+                -- ignores partial unique indexes, they are not unique constraints
+                WHERE indpred IS NULL OR NOT indisunique
+                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey, indoption, predicate, indnkeyatts
                 ORDER BY indrelid, indexrelid, indkeyidx
             ) rawIndex,
+            -- pg_am stores the index access methods: https://www.postgresql.org/docs/current/catalog-pg-am.html
+            pg_am accessMethod,
             -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
             pg_attribute columnInfos,
             -- pg_namespace stores info about the schema
@@ -439,6 +523,8 @@ impl SqlSchemaDescriber {
             tableInfos.oid = rawIndex.indrelid
             -- find index info
             AND indexInfos.oid = rawIndex.indexrelid
+            -- find the access method of the index
+            AND accessMethod.oid = indexInfos.relam
             -- find table columns
             AND columnInfos.attrelid = tableInfos.oid
             AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
@@ -447,7 +533,7 @@ impl SqlSchemaDescriber {
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx, accessMethod.amname, rawIndex.predicate, rawIndex.indoption, rawIndex.indnkeyatts
         ORDER BY rawIndex.indkeyidx
         "#;
 
@@ -461,8 +547,17 @@ impl SqlSchemaDescriber {
             let is_primary_key = row.get_expect_bool("is_primary_key");
             let table_name = row.get_expect_string("table_name");
             let sequence_name = row.get_string("sequence_name");
+            let algorithm = row.get_expect_string("algorithm");
+            let predicate = row.get_string("predicate");
+            let is_descending = row.get_expect_bool("is_descending");
+            let is_included = row.get_expect_bool("is_included");
 
             if is_primary_key {
+                // Included columns are not part of the primary key.
+                if is_included {
+                    continue;
+                }
+
                 let entry: &mut (Vec<_>, Option<PrimaryKey>) =
                     indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
 
@@ -490,24 +585,119 @@ impl SqlSchemaDescriber {
             } else {
                 let entry: &mut (Vec<Index>, _) = indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
 
-                if let Some(existing_index) = entry.0.iter_mut().find(|idx| idx.name == name) {
-                    existing_index.columns.push(column_name);
+                let index = match entry.0.iter().position(|idx| idx.name == name) {
+                    Some(position) => &mut entry.0[position],
+                    None => {
+                        entry.0.push(Index {
+                            name,
+                            columns: vec![],
+                            tpe: match is_unique {
+                                true => IndexType::Unique,
+                                false => IndexType::Normal,
+                            },
+                            algorithm: index_algorithm(&algorithm),
+                            descending_columns: vec![],
+                            predicate,
+                            included_columns: vec![],
+                        });
+
+                        entry.0.last_mut().unwrap()
+                    }
+                };
+
+                if is_included {
+                    index.included_columns.push(column_name);
                 } else {
-                    entry.0.push(Index {
-                        name,
-                        columns: vec![column_name],
-                        tpe: match is_unique {
-                            true => IndexType::Unique,
-                            false => IndexType::Normal,
-                        },
-                    })
+                    if is_descending {
+                        index.descending_columns.push(column_name.clone());
+                    }
+
+                    index.columns.push(column_name);
                 }
             }
         }
 
+        self.get_fulltext_indices(schema, &mut indexes_map).await?;
+
         Ok(indexes_map)
     }
 
+    /// Fulltext indexes are GIN indexes on `to_tsvector(config, column)` expressions. Expressions
+    /// are not table columns, so these indexes are not found by `get_indices`.
+    async fn get_fulltext_indices(
+        &self,
+        schema: &str,
+        indexes_map: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+    ) -> DescriberResult<()> {
+        let sql = r#"
+        SELECT
+            indexInfos.relname AS name,
+            tableInfos.relname AS table_name,
+            pg_get_indexdef(rawIndex.indexrelid, rawIndex.indkeyidx + 1, true) AS expression
+        FROM
+            pg_class tableInfos,
+            pg_class indexInfos,
+            (
+                SELECT
+                    indrelid,
+                    indexrelid,
+                    generate_subscripts(indkey, 1) AS indkeyidx
+                FROM pg_index
+                WHERE indpred IS NULL AND NOT indisunique
+            ) rawIndex,
+            pg_am accessMethod,
+            pg_namespace schemaInfo
+        WHERE
+            tableInfos.oid = rawIndex.indrelid
+            AND indexInfos.oid = rawIndex.indexrelid
+            AND accessMethod.oid = indexInfos.relam
+            AND accessMethod.amname = 'gin'
+            AND tableInfos.relkind = 'r'
+            AND tableInfos.relnamespace = schemaInfo.oid
+            AND schemaInfo.nspname = $1
+        ORDER BY indexInfos.relname, rawIndex.indkeyidx
+        "#;
+
+        let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
+        let mut fulltext_indexes: Vec<(String, String, Vec<Option<String>>)> = Vec::new();
+
+        for row in rows {
+            trace!("Got fulltext index expression: {:?}", row);
+            let name = row.get_expect_string("name");
+            let table_name = row.get_expect_string("table_name");
+            let column_name = fulltext_index_column(&row.get_expect_string("expression"));
+
+            match fulltext_indexes
+                .iter_mut()
+                .find(|(table, index, _)| table == &table_name && index == &name)
+            {
+                Some((_, _, columns)) => columns.push(column_name),
+                None => fulltext_indexes.push((table_name, name, vec![column_name])),
+            }
+        }
+
+        for (table_name, name, columns) in fulltext_indexes {
+            // GIN indexes on plain columns or on other expressions are not fulltext indexes.
+            let columns: Option<Vec<String>> = columns.into_iter().collect();
+
+            if let Some(columns) = columns {
+                let entry = indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
+
+                entry.0.push(Index {
+                    name,
+                    columns,
+                    tpe: IndexType::Fulltext,
+                    algorithm: IndexAlgorithm::Gin,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument]
     async fn get_sequences(&self, schema: &str) -> DescriberResult<Vec<Sequence>> {
         let sql = "SELECT sequence_name
@@ -640,6 +830,16 @@ impl SqlSchemaDescriber {
     }
 }
 
+fn index_algorithm(access_method: &str) -> IndexAlgorithm {
+    match access_method {
+        "hash" => IndexAlgorithm::Hash,
+        "gin" => IndexAlgorithm::Gin,
+        "gist" => IndexAlgorithm::Gist,
+        "brin" => IndexAlgorithm::Brin,
+        _ => IndexAlgorithm::BTree,
+    }
+}
+
 fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
     use ColumnTypeFamily::*;
     let data_type = row.get_expect_string("data_type");
@@ -725,6 +925,20 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
     }
 }
 
+/// Extracts the column name from a `to_tsvector('english'::regconfig, "column")` index expression.
+fn fulltext_index_column(expression: &str) -> Option<String> {
+    static FULLTEXT_EXPRESSION_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"^to_tsvector\('[^']+'::regconfig, (?:"((?:[^"]|"")+)"|([^\s"(),]+))\)$"#).expect("compile regex")
+    });
+
+    let captures = FULLTEXT_EXPRESSION_RE.captures(expression)?;
+
+    captures
+        .get(1)
+        .map(|quoted| quoted.as_str().replace("\"\"", "\""))
+        .or_else(|| captures.get(2).map(|unquoted| unquoted.as_str().to_owned()))
+}
+
 static RE_SEQ: Lazy<Regex> = Lazy::new(|| Regex::new("^(?:.+\\.)?\"?([^.\"]+)\"?").expect("compile regex"));
 
 static AUTOINCREMENT_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -835,4 +1049,20 @@ mod tests {
         let non_autoincrement = r#"string_default_named_seq"#;
         assert!(is_autoincrement(non_autoincrement, &sequences).is_none());
     }
+
+    #[test]
+    fn postgres_fulltext_index_column_works() {
+        assert_eq!(
+            fulltext_index_column("to_tsvector('english'::regconfig, title)").as_deref(),
+            Some("title")
+        );
+
+        assert_eq!(
+            fulltext_index_column(r#"to_tsvector('simple'::regconfig, "postTitle")"#).as_deref(),
+            Some("postTitle")
+        );
+
+        assert!(fulltext_index_column("tags").is_none());
+        assert!(fulltext_index_column("to_tsvector('english'::regconfig, (title || body))").is_none());
+    }
 }
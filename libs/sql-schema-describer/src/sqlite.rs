@@ -52,12 +52,15 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
+        let views = self.get_views().await?;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables,
+            views,
         })
     }
 
@@ -111,6 +114,28 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument]
+    async fn get_views(&self) -> DescriberResult<Vec<View>> {
+        let sql = r#"SELECT name AS view_name, sql AS view_sql FROM sqlite_master WHERE type='view' ORDER BY name ASC"#;
+        let result_set = self.conn.query_raw(&sql, &[]).await?;
+        let mut views = Vec::with_capacity(result_set.len());
+
+        for row in result_set {
+            let name = row.get("view_name").and_then(|x| x.to_string()).unwrap();
+            let (columns, _) = self.get_columns(&name).await?;
+
+            views.push(View {
+                definition: row.get("view_sql").and_then(|x| x.to_string()),
+                columns,
+                name,
+            });
+        }
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self) -> DescriberResult<usize> {
         let sql = r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#;
@@ -128,6 +153,7 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(name).await?;
         let foreign_keys = self.get_foreign_keys(name).await?;
         let indices = self.get_indices(name).await?;
+        let check_constraints = self.get_check_constraints(name).await?;
 
         Ok(Table {
             name: name.to_string(),
@@ -135,9 +161,26 @@ impl SqlSchemaDescriber {
             indices,
             primary_key,
             foreign_keys,
+            check_constraints,
         })
     }
 
+    /// SQLite has no catalog for check constraints, so we parse them from the table definition.
+    #[tracing::instrument]
+    async fn get_check_constraints(&self, table: &str) -> DescriberResult<Vec<CheckConstraint>> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type='table' AND name = ?"#;
+        let result_set = self.conn.query_raw(sql, &[table.into()]).await?;
+
+        let create_table_sql = result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|sql| sql.to_string()));
+
+        Ok(create_table_sql
+            .map(|sql| parse_check_constraints(&sql))
+            .unwrap_or_default())
+    }
+
     #[tracing::instrument]
     async fn get_columns(&self, table: &str) -> DescriberResult<(Vec<Column>, Option<PrimaryKey>)> {
         let sql = format!(r#"PRAGMA table_info ("{}")"#, table);
@@ -414,6 +457,10 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                algorithm: IndexAlgorithm::BTree,
+                descending_columns: vec![],
+                predicate: None,
+                included_columns: vec![],
             };
 
             let sql = format!(r#"PRAGMA index_info("{}");"#, name);
@@ -493,6 +540,47 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Extract the named check constraints from a `CREATE TABLE` statement. Unnamed constraints are
+/// skipped, since they can't be referenced by the migration engine.
+fn parse_check_constraints(create_table_sql: &str) -> Vec<CheckConstraint> {
+    static CHECK_CONSTRAINT_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?i)CONSTRAINT\s+("[^"]+"|`[^`]+`|\[[^\]]+\]|\w+)\s+CHECK\s*\("#).unwrap());
+
+    CHECK_CONSTRAINT_RE
+        .captures_iter(create_table_sql)
+        .filter_map(|captures| {
+            let name = captures.get(1)?.as_str();
+            let name = name.trim_matches(|c| c == '"' || c == '`' || c == '[' || c == ']');
+            let expression_start = captures.get(0)?.end();
+
+            let mut depth = 1;
+            let mut in_string = false;
+
+            for (idx, c) in create_table_sql[expression_start..].char_indices() {
+                match c {
+                    '\'' => in_string = !in_string,
+                    '(' if !in_string => depth += 1,
+                    ')' if !in_string => {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            return Some(CheckConstraint {
+                                name: name.to_owned(),
+                                expression: create_table_sql[expression_start..expression_start + idx]
+                                    .trim()
+                                    .to_owned(),
+                            });
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            None
+        })
+        .collect()
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -3,8 +3,8 @@
 #![deny(missing_docs)]
 
 use crate::{
-    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index,
-    IndexType, PrimaryKey, SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey,
+    ForeignKeyAction, Index, IndexAlgorithm, IndexType, PrimaryKey, SqlSchema, Table,
 };
 use serde::de::DeserializeOwned;
 use std::fmt;
@@ -257,6 +257,11 @@ impl<'a> TableWalker<'a> {
         self.table().foreign_key_for_column(column)
     }
 
+    /// The check constraints on the table.
+    pub fn check_constraints(&self) -> &'a [CheckConstraint] {
+        &self.table().check_constraints
+    }
+
     /// Traverse to the primary key of the table.
     pub fn primary_key(&self) -> Option<&'a PrimaryKey> {
         self.table().primary_key.as_ref()
@@ -433,6 +438,31 @@ impl<'a> IndexWalker<'a> {
         &self.get().tpe
     }
 
+    /// The access method of the index.
+    pub fn algorithm(&self) -> IndexAlgorithm {
+        self.get().algorithm
+    }
+
+    /// The names of the index columns sorted in descending order.
+    pub fn descending_column_names(&self) -> &[String] {
+        &self.get().descending_columns
+    }
+
+    /// True if the given index column is sorted in descending order.
+    pub fn column_is_descending(&self, column_name: &str) -> bool {
+        self.get().column_is_descending(column_name)
+    }
+
+    /// The `WHERE` clause of a partial index.
+    pub fn predicate(&self) -> Option<&str> {
+        self.get().predicate.as_deref()
+    }
+
+    /// The names of the non-key columns stored in the index.
+    pub fn included_column_names(&self) -> &[String] {
+        &self.get().included_columns
+    }
+
     /// The name of the index.
     pub fn name(&self) -> &str {
         &self.get().name
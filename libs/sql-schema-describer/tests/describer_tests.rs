@@ -169,6 +169,10 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
         }]
     } else {
         vec![]
@@ -194,6 +198,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             }],
+            check_constraints: vec![],
         }
     );
 }
@@ -278,6 +283,10 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
         }]
     } else {
         vec![]
@@ -309,6 +318,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             },],
+            check_constraints: vec![],
         }
     );
 }
@@ -425,6 +435,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 }
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -492,6 +503,10 @@ async fn indices_must_work(api: &TestApi) {
             name: "count".to_string(),
             columns: vec!["count".to_string()],
             tpe: IndexType::Normal,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
         }],
         user_table.indices
     );
@@ -564,12 +579,20 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        algorithm: IndexAlgorithm::BTree,
+        descending_columns: vec![],
+        predicate: None,
+        included_columns: vec![],
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -577,12 +600,20 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                algorithm: IndexAlgorithm::BTree,
+                descending_columns: vec![],
+                predicate: None,
+                included_columns: vec![],
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
         }),
         SqlFamily::Mssql => expected_indices.insert(
             0,
@@ -590,6 +621,10 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "UQ__User__CD572100A176666B".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                algorithm: IndexAlgorithm::BTree,
+                descending_columns: vec![],
+                predicate: None,
+                included_columns: vec![],
             },
         ),
     };
@@ -620,6 +655,7 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    check_constraints: vec![],
                 }
             );
         }
@@ -678,3 +714,49 @@ async fn defaults_must_work(api: &TestApi) {
 
     assert_eq!(&DefaultKind::VALUE(PrismaValue::Int(1)), default.kind());
 }
+
+#[test_each_connector]
+async fn views_must_be_described(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+        })
+        .await;
+
+    let create_view = match api.sql_family() {
+        SqlFamily::Postgres => format!(
+            r#"CREATE VIEW "{0}"."UserView" AS SELECT id, name FROM "{0}"."User""#,
+            api.schema_name()
+        ),
+        SqlFamily::Mysql => format!(
+            "CREATE VIEW `{0}`.`UserView` AS SELECT id, name FROM `{0}`.`User`",
+            api.schema_name()
+        ),
+        SqlFamily::Sqlite => format!(
+            r#"CREATE VIEW "{0}"."UserView" AS SELECT id, name FROM "User""#,
+            api.schema_name()
+        ),
+        SqlFamily::Mssql => format!(
+            "CREATE VIEW [{0}].[UserView] AS SELECT id, name FROM [{0}].[User]",
+            api.schema_name()
+        ),
+    };
+
+    api.database().raw_cmd(&create_view).await?;
+
+    let result = api.describe().await?;
+
+    assert!(result.get_table("UserView").is_none());
+    assert!(result.get_table("User").is_some());
+
+    let view = result.get_view("UserView").expect("getting UserView");
+    let column_names: Vec<&str> = view.columns.iter().map(|c| c.name.as_str()).collect();
+
+    assert_eq!(column_names, &["id", "name"]);
+    assert!(view.definition.is_some());
+
+    Ok(())
+}
@@ -569,6 +569,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -595,7 +596,75 @@ async fn mssql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn mssql_filtered_indexes_with_included_columns_must_be_inferred() {
+    let db_name = "mssql_filtered_indexes_with_included_columns_must_be_inferred";
+
+    let full_sql = format!(
+        r#"
+            CREATE TABLE [{0}].[Post] (
+                id INT PRIMARY KEY,
+                title NVARCHAR(200) NOT NULL,
+                content NVARCHAR(1000) NOT NULL,
+                deletedAt DATETIME2
+            );
+
+            CREATE INDEX [Post_title_idx] ON [{0}].[Post] (title DESC) INCLUDE (content) WHERE deletedAt IS NULL;
+        "#,
+        db_name
+    );
+
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.get_table("Post").expect("couldn't get Post table");
+
+    assert_eq!(
+        table.indices,
+        &[Index {
+            name: "Post_title_idx".into(),
+            columns: vec!["title".to_owned()],
+            tpe: IndexType::Normal,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec!["title".to_owned()],
+            predicate: Some("([deletedAt] IS NULL)".to_owned()),
+            included_columns: vec!["content".to_owned()],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn mssql_check_constraints_must_be_inferred() {
+    let db_name = "mssql_check_constraints_must_be_inferred";
+
+    let full_sql = format!(
+        r#"
+            CREATE TABLE [{0}].[Product] (
+                id INT PRIMARY KEY,
+                price INT NOT NULL,
+                CONSTRAINT [positivePrice] CHECK (price > 0)
+            );
+        "#,
+        db_name
+    );
+
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.get_table("Product").expect("couldn't get Product table");
+
+    assert_eq!(
+        table.check_constraints,
+        &[CheckConstraint {
+            name: "positivePrice".into(),
+            expression: "([price]>(0))".into(),
         }]
     );
 }
@@ -634,6 +703,10 @@ async fn mssql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
         }]
     );
 }
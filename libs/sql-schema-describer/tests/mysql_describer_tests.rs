@@ -625,6 +625,7 @@ async fn all_mysql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -729,21 +730,37 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
                     tpe: IndexType::Normal,
+                    algorithm: IndexAlgorithm::BTree,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
                     tpe: IndexType::Normal,
+                    algorithm: IndexAlgorithm::BTree,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
                     tpe: IndexType::Normal,
+                    algorithm: IndexAlgorithm::BTree,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
                     tpe: IndexType::Normal,
+                    algorithm: IndexAlgorithm::BTree,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
                 }
             ],
             primary_key: Some(PrimaryKey {
@@ -785,6 +802,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -812,6 +830,42 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
             tpe: IndexType::Unique,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn mysql_fulltext_indexes_must_be_inferred() {
+    let db_name = "mysql_fulltext_indexes_must_be_inferred";
+
+    let full_sql = format!(
+        "CREATE TABLE `{0}`.`Post` (
+            id INTEGER PRIMARY KEY,
+            title VARCHAR(191) NOT NULL,
+            content TEXT NOT NULL,
+            FULLTEXT INDEX `title_content_fulltext` (title, content)
+        )",
+        db_name
+    );
+
+    let inspector = get_mysql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.get_table("Post").expect("couldn't get Post table");
+
+    assert_eq!(
+        table.indices,
+        &[Index {
+            name: "title_content_fulltext".into(),
+            columns: vec!["title".to_owned(), "content".to_owned()],
+            tpe: IndexType::Fulltext,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
         }]
     );
 }
@@ -850,6 +904,10 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec![],
         }]
     );
 }
@@ -954,6 +1012,41 @@ async fn mysql_introspected_default_strings_should_be_unescaped() {
     assert_eq!(actual_default, &expected_default);
 }
 
+#[test_each_connector(tags("mysql_8"))]
+async fn mysql_descending_index_columns_must_be_inferred(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE `{0}`.`Post` (
+                `id` INTEGER PRIMARY KEY,
+                `title` VARCHAR(191) NOT NULL,
+                `createdAt` DATETIME(3) NOT NULL,
+                INDEX `Post_title_createdAt_idx` (`title`, `createdAt` DESC)
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Post");
+
+    assert_eq!(
+        table.indices,
+        &[Index {
+            name: "Post_title_createdAt_idx".into(),
+            columns: vec!["title".into(), "createdAt".into()],
+            tpe: IndexType::Normal,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec!["createdAt".into()],
+            predicate: None,
+            included_columns: vec![],
+        }]
+    );
+
+    Ok(())
+}
+
 #[test_each_connector(tags("mysql"))]
 async fn escaped_quotes_in_string_defaults_must_be_unescaped(api: &TestApi) -> TestResult {
     let create_table = format!(
@@ -1035,3 +1128,32 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql_8"))]
+async fn mysql_check_constraints_must_be_inferred(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE `{0}`.`Product` (
+                `id` INTEGER PRIMARY KEY,
+                `price` INTEGER NOT NULL,
+                CONSTRAINT `positivePrice` CHECK (`price` > 0)
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Product");
+
+    assert_eq!(
+        table.check_constraints,
+        &[CheckConstraint {
+            name: "positivePrice".into(),
+            expression: "(`price` > 0)".into(),
+        }]
+    );
+
+    Ok(())
+}
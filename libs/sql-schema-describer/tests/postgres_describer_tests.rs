@@ -640,6 +640,10 @@ async fn all_postgres_column_types_must_work() {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                algorithm: IndexAlgorithm::BTree,
+                descending_columns: vec![],
+                predicate: None,
+                included_columns: vec![],
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -649,6 +653,7 @@ async fn all_postgres_column_types_must_work() {
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -813,6 +818,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -883,6 +889,123 @@ async fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order() {
     assert_eq!(&index.columns, &["age", "name"]);
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn postgres_index_algorithms_sort_orders_and_predicates_must_be_inferred(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Post" (
+                id INTEGER PRIMARY KEY,
+                title TEXT NOT NULL,
+                tags TEXT[] NOT NULL,
+                "createdAt" TIMESTAMP NOT NULL,
+                "deletedAt" TIMESTAMP
+            );
+
+            CREATE INDEX "Post_tags_idx" ON "{0}"."Post" USING GIN (tags);
+            CREATE INDEX "Post_title_createdAt_idx" ON "{0}"."Post" (title, "createdAt" DESC);
+            CREATE INDEX "Post_live_idx" ON "{0}"."Post" (title) WHERE "deletedAt" IS NULL;
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Post");
+
+    let index = table.indices.iter().find(|idx| idx.name == "Post_tags_idx").unwrap();
+    assert_eq!(index.algorithm, IndexAlgorithm::Gin);
+    assert_eq!(&index.columns, &["tags"]);
+
+    let index = table
+        .indices
+        .iter()
+        .find(|idx| idx.name == "Post_title_createdAt_idx")
+        .unwrap();
+    assert_eq!(index.algorithm, IndexAlgorithm::BTree);
+    assert_eq!(&index.columns, &["title", "createdAt"]);
+    assert_eq!(&index.descending_columns, &["createdAt"]);
+
+    let index = table.indices.iter().find(|idx| idx.name == "Post_live_idx").unwrap();
+    assert_eq!(index.predicate.as_deref(), Some(r#"("deletedAt" IS NULL)"#));
+    assert!(index.descending_columns.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_fulltext_indexes_must_be_inferred(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Post" (
+                id INTEGER PRIMARY KEY,
+                title TEXT NOT NULL,
+                "postContent" TEXT NOT NULL,
+                tags TEXT[] NOT NULL
+            );
+
+            CREATE INDEX "Post_fulltext" ON "{0}"."Post" USING GIN (to_tsvector('english', title), to_tsvector('english', "postContent"));
+            CREATE INDEX "Post_tags_idx" ON "{0}"."Post" USING GIN (tags);
+            CREATE INDEX "Post_concatenated_idx" ON "{0}"."Post" USING GIN (to_tsvector('english', title || ' ' || "postContent"));
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Post");
+
+    let index = table.indices.iter().find(|idx| idx.name == "Post_fulltext").unwrap();
+    assert_eq!(index.tpe, IndexType::Fulltext);
+    assert_eq!(index.algorithm, IndexAlgorithm::Gin);
+    assert_eq!(&index.columns, &["title", "postContent"]);
+
+    let index = table.indices.iter().find(|idx| idx.name == "Post_tags_idx").unwrap();
+    assert_eq!(index.tpe, IndexType::Normal);
+
+    // Expression indexes other than fulltext indexes are not described.
+    assert!(!table.indices.iter().any(|idx| idx.name == "Post_concatenated_idx"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres12"))]
+async fn postgres_included_index_columns_must_be_inferred(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Post" (
+                id INTEGER PRIMARY KEY,
+                title TEXT NOT NULL,
+                content TEXT NOT NULL
+            );
+
+            CREATE INDEX "Post_title_idx" ON "{0}"."Post" (title) INCLUDE (content);
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Post");
+
+    assert_eq!(
+        table.indices,
+        &[Index {
+            name: "Post_title_idx".into(),
+            columns: vec!["title".into()],
+            tpe: IndexType::Normal,
+            algorithm: IndexAlgorithm::BTree,
+            descending_columns: vec![],
+            predicate: None,
+            included_columns: vec!["content".into()],
+        }]
+    );
+
+    Ok(())
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn escaped_quotes_in_string_defaults_must_be_unescaped(api: &TestApi) -> TestResult {
     let create_table = format!(
@@ -960,3 +1083,32 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_check_constraints_must_be_inferred(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Product" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL,
+                CONSTRAINT "positivePrice" CHECK (price > 0)
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Product");
+
+    assert_eq!(
+        table.check_constraints,
+        &[CheckConstraint {
+            name: "positivePrice".into(),
+            expression: "(price > 0)".into(),
+        }]
+    );
+
+    Ok(())
+}
@@ -120,6 +120,7 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -275,6 +276,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -395,3 +397,40 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn sqlite_check_constraints_must_be_inferred(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."Product" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL CHECK (price >= 0),
+                name TEXT NOT NULL,
+                CONSTRAINT "positivePrice" CHECK (price > 0),
+                CONSTRAINT nameNotEmpty CHECK (length(name) > 0 AND name <> ')')
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Product");
+
+    assert_eq!(
+        table.check_constraints,
+        &[
+            CheckConstraint {
+                name: "positivePrice".into(),
+                expression: "price > 0".into(),
+            },
+            CheckConstraint {
+                name: "nameNotEmpty".into(),
+                expression: "length(name) > 0 AND name <> ')'".into(),
+            },
+        ]
+    );
+
+    Ok(())
+}
@@ -174,6 +174,16 @@ impl crate::UserFacingError for ShadowDbCreationError {
     }
 }
 
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3015",
+    message = "Timed out after {timeout} ms waiting for the migration lock. Another migration command is running against the database."
+)]
+pub struct MigrationLockTimeout {
+    /// How long the migration engine waited for the lock, in milliseconds.
+    pub timeout: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
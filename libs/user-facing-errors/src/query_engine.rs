@@ -241,3 +241,40 @@ pub struct TableDoesNotExist {
 pub struct ColumnDoesNotExist {
     pub column: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2023", message = "Transaction API error: {error}")]
+pub struct InteractiveTransactionError {
+    pub error: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2024", message = "Unable to lock rows: {reason}")]
+pub struct RowLockUnavailable {
+    pub reason: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2025",
+    message = "The record of model `{model_name}` was not updated, because its version did not match the expected version {expected_version}."
+)]
+pub struct VersionMismatch {
+    pub model_name: String,
+    pub expected_version: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2026",
+    message = "The request was cancelled, because it did not finish within its timeout of {timeout} ms."
+)]
+pub struct RequestTimeout {
+    pub timeout: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2027", message = "Check constraint violation on the {constraint}")]
+pub struct CheckConstraintViolation {
+    pub constraint: DatabaseConstraint,
+}
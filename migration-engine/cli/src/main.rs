@@ -8,7 +8,7 @@ mod logger;
 use enumflags2::BitFlags;
 use migration_connector::MigrationFeature;
 use migration_core::{api::RpcApi, CoreError};
-use std::io;
+use std::{io, time::Duration};
 use structopt::StructOpt;
 
 /// When no subcommand is specified, the migration engine will default to starting as a JSON-RPC
@@ -22,6 +22,10 @@ struct MigrationEngineCli {
     /// A list of blocked preview features to enable (`all` enables everything).
     #[structopt(long, use_delimiter = true)]
     enabled_preview_features: Vec<String>,
+    /// How long commands changing the schema wait for another migration engine to release the
+    /// migration lock, in milliseconds.
+    #[structopt(long, env = "PRISMA_MIGRATION_LOCK_TIMEOUT", default_value = "10000")]
+    migration_lock_timeout: u64,
     #[structopt(subcommand)]
     cli_subcommand: Option<SubCommand>,
 }
@@ -74,7 +78,9 @@ async fn main() {
     match input.cli_subcommand {
         None => {
             if let Some(datamodel_location) = input.datamodel.as_ref() {
-                start_engine(datamodel_location, input.preview_feature_flags()).await
+                let lock_timeout = Duration::from_millis(input.migration_lock_timeout);
+
+                start_engine(datamodel_location, input.preview_feature_flags(), lock_timeout).await
             } else {
                 panic!("Missing --datamodel");
             }
@@ -86,7 +92,11 @@ async fn main() {
     }
 }
 
-async fn start_engine(datamodel_location: &str, enabled_preview_features: BitFlags<MigrationFeature>) -> ! {
+async fn start_engine(
+    datamodel_location: &str,
+    enabled_preview_features: BitFlags<MigrationFeature>,
+    lock_timeout: Duration,
+) -> ! {
     use std::io::Read as _;
 
     tracing::info!(git_hash = env!("GIT_HASH"), "Starting migration engine RPC server",);
@@ -95,7 +105,7 @@ async fn start_engine(datamodel_location: &str, enabled_preview_features: BitFla
     let mut datamodel = String::new();
     file.read_to_string(&mut datamodel).unwrap();
 
-    match RpcApi::new(&datamodel, enabled_preview_features).await {
+    match RpcApi::new_with_lock_timeout(&datamodel, enabled_preview_features, lock_timeout).await {
         // Block the thread and handle IO in async until EOF.
         Ok(api) => json_rpc_stdio::run(api.io_handler()).await.unwrap(),
         Err(err) => {
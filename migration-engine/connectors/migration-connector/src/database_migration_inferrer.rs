@@ -29,6 +29,17 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
         steps: &[MigrationStep],
     ) -> ConnectorResult<T>;
 
+    /// Infer the database migration undoing the passed in migration, taking
+    /// the database from its expected state after the migration back to its
+    /// state before.
+    fn infer_reverse_migration(&self, migration: &T) -> T;
+
+    /// Make the migration avoid long locks on existing tables, where the
+    /// connector supports it. Such a migration may have to be applied outside
+    /// of a transaction, see
+    /// [NON_TRANSACTIONAL_MIGRATION_MARKER](constant.NON_TRANSACTIONAL_MIGRATION_MARKER.html).
+    fn make_online(&self, _migration: &mut T) {}
+
     /// Look at the previous migrations and the target schema, and infer a
     /// database migration taking the database to the expected Prisma schema.
     async fn infer_next_migration(
@@ -1,6 +1,35 @@
 use crate::*;
 use serde::{Deserialize, Serialize};
 
+/// The first line of migration scripts that cannot be applied in a
+/// transaction. These scripts are applied and recorded one step at a time, so
+/// a failed migration can be resumed from the step that failed.
+pub const NON_TRANSACTIONAL_MIGRATION_MARKER: &str = "-- non-transactional";
+
+/// Whether the script starts with the
+/// [NON_TRANSACTIONAL_MIGRATION_MARKER](constant.NON_TRANSACTIONAL_MIGRATION_MARKER.html).
+pub fn is_non_transactional_script(script: &str) -> bool {
+    script.lines().next().map(str::trim) == Some(NON_TRANSACTIONAL_MIGRATION_MARKER)
+}
+
+/// A step of a non-transactional migration script.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptStep {
+    /// The statements of the step, applied one at a time.
+    pub statements: Vec<String>,
+    /// Whether the step can be re-run from its first statement after it
+    /// failed part way through.
+    pub rerunnable: bool,
+}
+
+impl ScriptStep {
+    /// Whether a failed migration can be resumed by re-running this step. A
+    /// step with a single statement was either applied or not applied at all.
+    pub fn can_be_resumed(&self) -> bool {
+        self.rerunnable || self.statements.len() <= 1
+    }
+}
+
 /// Apply a single migration step to the connector's database. At this level, we are working with database migrations,
 /// i.e. the [associated type on MigrationConnector](trait.MigrationConnector.html#associatedtype.DatabaseMigration).
 #[async_trait::async_trait]
@@ -18,6 +47,14 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     /// Apply a migration script to the database. The migration persistence is
     /// managed by the core.
     async fn apply_script(&self, script: &str) -> ConnectorResult<()>;
+
+    /// Split a non-transactional migration script into its steps, at the step
+    /// markers emitted by `render_script()`.
+    fn split_script_steps(&self, script: &str) -> Vec<ScriptStep>;
+
+    /// Apply the statements of one step of a non-transactional migration
+    /// script, one at a time and outside of any transaction.
+    async fn apply_step_statements(&self, statements: &[String]) -> ConnectorResult<()>;
 }
 
 /// A helper struct to serialize a database migration with an additional `raw` field containing the
@@ -19,6 +19,10 @@ where
     /// Check the database migration for destructive or unexecutable steps
     /// without performing any IO.
     fn pure_check(&self, database_migration: &T) -> DestructiveChangeDiagnostics;
+
+    /// Check the migration for steps that would hold long exclusive locks on
+    /// existing tables, inspecting the database to rank them by table size.
+    async fn check_locks(&self, database_migration: &T) -> ConnectorResult<Vec<LockWarning>>;
 }
 
 /// The errors and warnings emitted by the [DestructiveChangeChecker](trait.DestructiveChangeChecker.html).
@@ -28,6 +32,9 @@ pub struct DestructiveChangeDiagnostics {
     pub warnings: Vec<MigrationWarning>,
     /// Steps that are not executable.
     pub unexecutable_migrations: Vec<UnexecutableMigration>,
+    /// Steps that would hold long locks on existing tables. Only populated in
+    /// lock linting mode.
+    pub lock_warnings: Vec<LockWarning>,
 }
 
 impl DestructiveChangeDiagnostics {
@@ -68,6 +75,46 @@ pub struct UnexecutableMigration {
     pub step_index: usize,
 }
 
+/// A migration step that would block reads or writes on a table while it runs,
+/// detected by the [DestructiveChangeChecker](trait.DestructiveChangeChecker.html)
+/// in lock linting mode.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct LockWarning {
+    /// The user-facing warning description.
+    pub description: String,
+    /// How bad the lock would be, based on the size of the table.
+    pub severity: LockSeverity,
+    /// The index of the step in the migration that this warning applies to.
+    pub step_index: usize,
+}
+
+/// The severity of a [LockWarning](struct.LockWarning.html), ranked by the
+/// number of rows in the locked table.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum LockSeverity {
+    /// The table could not be inspected.
+    Unknown,
+    /// Fewer than 10 000 rows.
+    Low,
+    /// Fewer than 1 000 000 rows.
+    Medium,
+    /// 1 000 000 rows or more.
+    High,
+}
+
+impl LockSeverity {
+    /// Rank the severity of a lock on a table with `row_count` rows.
+    pub fn from_row_count(row_count: Option<i64>) -> Self {
+        match row_count {
+            None => LockSeverity::Unknown,
+            Some(count) if count < 10_000 => LockSeverity::Low,
+            Some(count) if count < 1_000_000 => LockSeverity::Medium,
+            Some(_) => LockSeverity::High,
+        }
+    }
+}
+
 /// An implementor of [DestructiveChangeChecker](trait.DestructiveChangeChecker.html) that performs no check.
 #[derive(Default)]
 pub struct EmptyDestructiveChangeChecker<T> {
@@ -86,4 +133,8 @@ where
     fn pure_check(&self, _database_migration: &T) -> DestructiveChangeDiagnostics {
         DestructiveChangeDiagnostics::new()
     }
+
+    async fn check_locks(&self, _database_migration: &T) -> ConnectorResult<Vec<LockWarning>> {
+        Ok(Vec::new())
+    }
 }
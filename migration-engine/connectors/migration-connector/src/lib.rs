@@ -26,7 +26,7 @@ pub use migrations_directory::{create_migration_directory, list_migrations, List
 pub use steps::MigrationStep;
 
 use sha2::{Digest, Sha256};
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 /// The top-level trait for connectors. This is the abstraction the migration engine core relies on to
 /// interface with different database backends.
@@ -51,6 +51,14 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Take the database-wide lock that keeps several migration engines from changing the
+    /// schema at the same time, waiting for at most `timeout` for another engine to release it.
+    /// The lock is held until `release_lock` is called.
+    async fn acquire_lock(&self, timeout: Duration) -> ConnectorResult<()>;
+
+    /// Release the lock taken with `acquire_lock`.
+    async fn release_lock(&self) -> ConnectorResult<()>;
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
@@ -7,6 +7,7 @@
 //! directorys, named after the migration id, and each containing:
 //!
 //! - A migration script
+//! - A down migration script, reverting the changes of the migration script
 
 use sha2::{Digest, Sha256, Sha512};
 use std::{
@@ -23,6 +24,9 @@ use crate::FormatChecksum;
 /// The file name for migration scripts, not including the file extension.
 pub const MIGRATION_SCRIPT_FILENAME: &str = "migration";
 
+/// The file name for down migration scripts, not including the file extension.
+pub const DOWN_MIGRATION_SCRIPT_FILENAME: &str = "down";
+
 /// Create a directory for a new migration.
 pub fn create_migration_directory(
     migrations_directory_path: &Path,
@@ -169,7 +173,17 @@ impl MigrationDirectory {
     /// Write the migration script to the directory.
     #[tracing::instrument]
     pub fn write_migration_script(&self, script: &str, extension: &str) -> std::io::Result<()> {
-        let mut path = self.path.join(MIGRATION_SCRIPT_FILENAME);
+        self.write_script(MIGRATION_SCRIPT_FILENAME, script, extension)
+    }
+
+    /// Write the down migration script to the directory.
+    #[tracing::instrument]
+    pub fn write_down_migration_script(&self, script: &str, extension: &str) -> std::io::Result<()> {
+        self.write_script(DOWN_MIGRATION_SCRIPT_FILENAME, script, extension)
+    }
+
+    fn write_script(&self, file_name: &str, script: &str, extension: &str) -> std::io::Result<()> {
+        let mut path = self.path.join(file_name);
 
         path.set_extension(extension);
 
@@ -187,6 +201,18 @@ impl MigrationDirectory {
         Ok(std::fs::read_to_string(&self.path.join("migration.sql"))?)
     }
 
+    /// Whether the directory contains a down migration script. Migrations created before down
+    /// migrations were written have none.
+    pub fn has_down_migration_script(&self) -> bool {
+        self.path.join("down.sql").is_file()
+    }
+
+    /// Read the down migration script to a string.
+    #[tracing::instrument]
+    pub fn read_down_migration_script(&self) -> Result<String, ReadMigrationScriptError> {
+        Ok(std::fs::read_to_string(&self.path.join("down.sql"))?)
+    }
+
     /// The filesystem path to the directory.
     pub fn path(&self) -> &Path {
         &self.path
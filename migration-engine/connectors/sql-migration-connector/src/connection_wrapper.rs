@@ -1,5 +1,5 @@
-use crate::error::quaint_error_to_connector_error;
-use migration_connector::ConnectorError;
+use crate::{error::quaint_error_to_connector_error, sql_database_step_applier::split_script_steps};
+use migration_connector::{is_non_transactional_script, ConnectorError};
 use quaint::{
     error::{Error as QuaintError, ErrorKind as QuaintKind},
     prelude::{ConnectionInfo, Query, Queryable, ResultSet},
@@ -82,6 +82,21 @@ impl Connection {
         })
     }
 
+    /// Run a migration script. Non-transactional scripts are run one
+    /// statement at a time, because multiple statements sent in one query run
+    /// in an implicit transaction.
+    pub(crate) async fn apply_migration_script(&self, script: &str) -> ConnectionResult<'_, ()> {
+        if !is_non_transactional_script(script) {
+            return self.raw_cmd(script).await;
+        }
+
+        for statement in split_script_steps(script).iter().flat_map(|step| &step.statements) {
+            self.raw_cmd(statement).await?;
+        }
+
+        Ok(())
+    }
+
     pub(crate) async fn version(&self) -> ConnectionResult<'_, Option<String>> {
         self.0.version().await.map_err(|quaint_error| ConnectionError {
             quaint_error,
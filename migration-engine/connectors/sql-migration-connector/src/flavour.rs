@@ -22,7 +22,7 @@ use datamodel::Datamodel;
 use migration_connector::{ConnectorResult, MigrationDirectory, MigrationFeature};
 use quaint::{
     connector::ConnectionInfo,
-    prelude::{SqlFamily, Table},
+    prelude::{ResultSet, SqlFamily, Table},
 };
 use sql_schema_describer::SqlSchema;
 use std::fmt::Debug;
@@ -32,6 +32,16 @@ use std::fmt::Debug;
 /// reference: https://dev.mysql.com/doc/refman/5.7/en/identifier-length.html
 pub(crate) const MYSQL_IDENTIFIER_SIZE_LIMIT: usize = 64;
 
+/// The name of the lock taken while changing the schema the connection works on.
+fn migration_lock_name(connection: &Connection) -> String {
+    format!("prisma_migrate_{}", connection.connection_info().schema_name())
+}
+
+/// The single value returned by a lock function.
+fn lock_result(result_set: ResultSet) -> Option<quaint::Value<'static>> {
+    result_set.into_single().ok()?.into_single().ok()
+}
+
 pub(crate) fn from_connection_info(
     connection_info: &ConnectionInfo,
     features: BitFlags<MigrationFeature>,
@@ -45,6 +55,7 @@ pub(crate) fn from_connection_info(
         ConnectionInfo::Sqlite { file_path, db_name } => Box::new(SqliteFlavour {
             file_path: file_path.clone(),
             attached_name: db_name.clone(),
+            lock_owner: uuid::Uuid::new_v4().to_string(),
         }),
         ConnectionInfo::Mssql(url) => Box::new(MssqlFlavour::new(url.clone(), features)),
         ConnectionInfo::InMemorySqlite { .. } => unreachable!("SqlFlavour for in-memory SQLite"),
@@ -82,6 +93,9 @@ pub(crate) trait SqlFlavour:
     /// Perform the initialization required by connector-test-kit tests.
     async fn qe_setup(&self, database_url: &str) -> ConnectorResult<()>;
 
+    /// Release the migration lock taken with `try_acquire_migration_lock`.
+    async fn release_migration_lock(&self, connection: &Connection) -> ConnectorResult<()>;
+
     /// Drop the database and recreate it empty.
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()>;
 
@@ -96,6 +110,9 @@ pub(crate) trait SqlFlavour:
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema>;
 
+    /// Take the migration lock if nobody holds it, without waiting. Returns whether the lock was taken.
+    async fn try_acquire_migration_lock(&self, connection: &Connection) -> ConnectorResult<bool>;
+
     /// Table to store applied migrations, the name part.
     fn imperative_migrations_table_name(&self) -> &'static str {
         "_prisma_migrations"
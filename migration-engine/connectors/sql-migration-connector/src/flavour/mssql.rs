@@ -1,3 +1,4 @@
+use super::{lock_result, migration_lock_name};
 use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, SqlFlavour};
 use connection_string::JdbcString;
 use enumflags2::BitFlags;
@@ -163,6 +164,17 @@ impl SqlFlavour for MssqlFlavour {
         Ok(())
     }
 
+    async fn release_migration_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        connection
+            .query_raw(
+                "EXEC sp_releaseapplock @Resource = @P1, @LockOwner = 'Session'",
+                &[migration_lock_name(connection).into()],
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn ensure_connection_validity(&self, connection: &Connection) -> ConnectorResult<()> {
         connection.raw_cmd("SELECT 1").await?;
 
@@ -240,6 +252,26 @@ impl SqlFlavour for MssqlFlavour {
         sql_schema_result
     }
 
+    /// Session-owned application locks are released when the connection closes, so a crashed
+    /// engine can't leave the lock behind.
+    async fn try_acquire_migration_lock(&self, connection: &Connection) -> ConnectorResult<bool> {
+        let sql = formatdoc! {r#"
+            DECLARE @result INT;
+            EXEC @result = sp_getapplock @Resource = @P1, @LockMode = 'Exclusive', @LockOwner = 'Session', @LockTimeout = 0;
+            SELECT @result;
+        "#};
+
+        let result_set = connection
+            .query_raw(&sql, &[migration_lock_name(connection).into()])
+            .await?;
+
+        // Negative results mean the lock was not granted.
+        Ok(lock_result(result_set)
+            .and_then(|value| value.as_i64())
+            .map(|result| result >= 0)
+            .unwrap_or(false))
+    }
+
     fn features(&self) -> BitFlags<MigrationFeature> {
         self.features
     }
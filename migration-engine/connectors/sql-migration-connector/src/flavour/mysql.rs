@@ -1,4 +1,4 @@
-use super::SqlFlavour;
+use super::{lock_result, migration_lock_name, SqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT};
 use crate::{
     connect,
     connection_wrapper::Connection,
@@ -29,6 +29,21 @@ impl MysqlFlavour {
             .contains(Circumstances::IsMariadb)
     }
 
+    /// MySQL before 8.0.16 and MariaDB before 10.2.1 parse check constraints, but discard them.
+    pub(crate) fn ignores_check_constraints(&self) -> bool {
+        BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
+            .unwrap_or_default()
+            .contains(Circumstances::IgnoresCheckConstraints)
+    }
+
+    /// MySQL before 8.0 and MariaDB before 10.8 parse `DESC` in index definitions, but create
+    /// ascending indexes.
+    pub(crate) fn ignores_descending_indexes(&self) -> bool {
+        BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
+            .unwrap_or_default()
+            .contains(Circumstances::IgnoresDescendingIndexes)
+    }
+
     pub(crate) fn is_mysql_5_6(&self) -> bool {
         BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
             .unwrap_or_default()
@@ -40,6 +55,14 @@ impl MysqlFlavour {
             .unwrap_or_default()
             .contains(Circumstances::LowerCasesTableNames)
     }
+
+    /// Lock names are server-wide and limited to 64 characters.
+    fn migration_lock_name(&self, connection: &Connection) -> String {
+        migration_lock_name(connection)
+            .chars()
+            .take(MYSQL_IDENTIFIER_SIZE_LIMIT)
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
@@ -156,6 +179,23 @@ impl SqlFlavour for MysqlFlavour {
             if version.contains("MariaDB") {
                 circumstances |= Circumstances::IsMariadb;
             }
+
+            // MariaDB reports its version as `5.5.5-10.5.9-MariaDB` over the MySQL protocol.
+            let (check_constraints_version, descending_indexes_version) = if version.contains("MariaDB") {
+                ((10, 2, 1), (10, 8, 0))
+            } else {
+                ((8, 0, 16), (8, 0, 0))
+            };
+
+            if let Some(version) = parse_version(version.trim_start_matches("5.5.5-")) {
+                if version < check_constraints_version {
+                    circumstances |= Circumstances::IgnoresCheckConstraints;
+                }
+
+                if version < descending_indexes_version {
+                    circumstances |= Circumstances::IgnoresDescendingIndexes;
+                }
+            }
         }
 
         let result_set = connection
@@ -199,6 +239,14 @@ impl SqlFlavour for MysqlFlavour {
         Ok(())
     }
 
+    async fn release_migration_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        connection
+            .query_raw("SELECT RELEASE_LOCK(?)", &[self.migration_lock_name(connection).into()])
+            .await?;
+
+        Ok(())
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let db_name = connection.connection_info().dbname().unwrap();
 
@@ -267,6 +315,16 @@ impl SqlFlavour for MysqlFlavour {
 
         sql_schema_result
     }
+
+    /// User-level locks are released when the connection closes, so a crashed engine can't leave
+    /// the lock behind.
+    async fn try_acquire_migration_lock(&self, connection: &Connection) -> ConnectorResult<bool> {
+        let result_set = connection
+            .query_raw("SELECT GET_LOCK(?, 0)", &[self.migration_lock_name(connection).into()])
+            .await?;
+
+        Ok(lock_result(result_set).and_then(|value| value.as_i64()) == Some(1))
+    }
 }
 
 #[derive(BitFlags, Debug, Clone, Copy, PartialEq)]
@@ -275,6 +333,17 @@ pub enum Circumstances {
     LowerCasesTableNames = 0b0001,
     IsMysql56 = 0b0010,
     IsMariadb = 0b0100,
+    IgnoresCheckConstraints = 0b1000,
+    IgnoresDescendingIndexes = 0b10000,
+}
+
+/// Parse the `major.minor.patch` prefix of a server version string like `8.0.25-0ubuntu0.20.04.1`.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut components = version
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|component| component.parse::<u32>().ok());
+
+    Some((components.next()??, components.next()??, components.next()??))
 }
 
 fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<String>) {
@@ -1,3 +1,4 @@
+use super::{lock_result, migration_lock_name};
 use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, SqlFlavour};
 use indoc::indoc;
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
@@ -153,6 +154,17 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
+    async fn release_migration_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        connection
+            .query_raw(
+                "SELECT pg_advisory_unlock(hashtext($1))",
+                &[migration_lock_name(connection).into()],
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let schema_name = connection.connection_info().schema_name();
 
@@ -212,7 +224,7 @@ impl SqlFlavour for PostgresFlavour {
                     );
 
                     temporary_database
-                        .raw_cmd(&script)
+                        .apply_migration_script(&script)
                         .await
                         .map_err(ConnectorError::from)
                         .map_err(|connector_error| {
@@ -232,6 +244,21 @@ impl SqlFlavour for PostgresFlavour {
 
         sql_schema_result
     }
+
+    /// Session-level advisory locks are released when the connection closes, so a crashed engine
+    /// can't leave the lock behind.
+    async fn try_acquire_migration_lock(&self, connection: &Connection) -> ConnectorResult<bool> {
+        let result_set = connection
+            .query_raw(
+                "SELECT pg_try_advisory_lock(hashtext($1))",
+                &[migration_lock_name(connection).into()],
+            )
+            .await?;
+
+        Ok(lock_result(result_set)
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false))
+    }
 }
 
 fn strip_schema_param_from_url(url: &mut Url) {
@@ -1,14 +1,51 @@
+use super::lock_result;
 use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour::SqlFlavour};
 use indoc::indoc;
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use quaint::prelude::{ConnectionInfo, SqlFamily};
 use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBackend};
-use std::path::Path;
+use std::{path::Path, time::Duration};
+
+/// How long the migration lock of a crashed engine blocks the other engines. Commands running for
+/// longer can lose the lock to another engine.
+const MIGRATION_LOCK_EXPIRY: Duration = Duration::from_secs(10 * 60);
 
 #[derive(Debug)]
 pub(crate) struct SqliteFlavour {
     pub(super) file_path: String,
     pub(super) attached_name: String,
+    /// Identifies the migration lock row taken by this engine.
+    pub(super) lock_owner: String,
+}
+
+impl SqliteFlavour {
+    /// SQLite has no named locks, so the migration lock is a row in a table of its own, which the
+    /// schema differ ignores. Unlike the locks of the other databases, it is not released when the
+    /// connection closes: the row of a crashed engine is taken over once it expired.
+    pub(crate) const MIGRATION_LOCK_TABLE: &'static str = "_prisma_migrations_lock";
+
+    async fn holds_migration_lock(&self, connection: &Connection) -> ConnectorResult<bool> {
+        let table_exists = connection
+            .query_raw(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?",
+                &[Self::MIGRATION_LOCK_TABLE.into()],
+            )
+            .await?;
+
+        if lock_result(table_exists).and_then(|value| value.as_i64()) != Some(1) {
+            return Ok(false);
+        }
+
+        let select_lock = format!(
+            r#"SELECT COUNT(*) FROM "{}" WHERE "owner" = ?"#,
+            Self::MIGRATION_LOCK_TABLE
+        );
+        let result_set = connection
+            .query_raw(&select_lock, &[self.lock_owner.as_str().into()])
+            .await?;
+
+        Ok(lock_result(result_set).and_then(|value| value.as_i64()) == Some(1))
+    }
 }
 
 #[async_trait::async_trait]
@@ -88,10 +125,35 @@ impl SqlFlavour for SqliteFlavour {
         Ok(())
     }
 
+    /// Deletes the lock row of this engine, and the lock table with it, so that it doesn't show up
+    /// in the database once no engine holds the lock.
+    async fn release_migration_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        if !self.holds_migration_lock(connection).await? {
+            return Ok(());
+        }
+
+        let drop_table = format!(r#"DROP TABLE "{}""#, Self::MIGRATION_LOCK_TABLE);
+
+        Ok(connection.raw_cmd(&drop_table).await?)
+    }
+
+    /// Truncates the database file, which deletes the migration lock with everything else: the lock
+    /// is taken again right after, if this engine held it.
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let file_path = connection.connection_info().file_path().unwrap();
+        let held_lock = self.holds_migration_lock(connection).await?;
+
+        std::fs::File::create(file_path).map_err(|err| {
+            ConnectorError::generic(
+                anyhow::Error::new(err).context(format!("Failed to truncate SQLite database at `{}`", file_path)),
+            )
+        })?;
 
-        std::fs::File::create(file_path).expect("failed to truncate sqlite file");
+        if held_lock && !self.try_acquire_migration_lock(connection).await? {
+            return Err(ConnectorError::generic(anyhow::anyhow!(
+                "Another migration engine took the migration lock while the database was reset."
+            )));
+        }
 
         Ok(())
     }
@@ -137,4 +199,35 @@ impl SqlFlavour for SqliteFlavour {
 
         Ok(sql_schema)
     }
+
+    async fn try_acquire_migration_lock(&self, connection: &Connection) -> ConnectorResult<bool> {
+        let create_table = format!(
+            r#"CREATE TABLE IF NOT EXISTS "{}" ("id" INTEGER PRIMARY KEY NOT NULL, "owner" TEXT NOT NULL, "acquired_at" INTEGER NOT NULL)"#,
+            Self::MIGRATION_LOCK_TABLE
+        );
+        let delete_expired_lock = format!(
+            r#"DELETE FROM "{}" WHERE "acquired_at" < ?"#,
+            Self::MIGRATION_LOCK_TABLE
+        );
+        let insert_lock = format!(
+            r#"INSERT OR IGNORE INTO "{}" ("id", "owner", "acquired_at") VALUES (1, ?, ?)"#,
+            Self::MIGRATION_LOCK_TABLE
+        );
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let expired_before = now - MIGRATION_LOCK_EXPIRY.as_millis() as i64;
+
+        connection.raw_cmd(&create_table).await?;
+        connection
+            .query_raw(&delete_expired_lock, &[expired_before.into()])
+            .await?;
+        connection
+            .query_raw(&insert_lock, &[self.lock_owner.as_str().into(), now.into()])
+            .await?;
+
+        // The row was only inserted if nobody else holds the lock.
+        let result_set = connection.query_raw("SELECT changes()", &[]).await?;
+
+        Ok(lock_result(result_set).and_then(|value| value.as_i64()) == Some(1))
+    }
 }
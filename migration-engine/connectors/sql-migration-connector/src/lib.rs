@@ -25,7 +25,11 @@ use migration_connector::*;
 use quaint::{prelude::ConnectionInfo, single::Quaint};
 use sql_migration::SqlMigration;
 use sql_schema_describer::SqlSchema;
-use user_facing_errors::{common::InvalidDatabaseString, KnownError};
+use std::time::{Duration, Instant};
+use user_facing_errors::{common::InvalidDatabaseString, migration_engine::MigrationLockTimeout, KnownError};
+
+/// How often a connector waiting for the migration lock checks whether it was released.
+const MIGRATION_LOCK_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 /// The top-level SQL migration connector.
 pub struct SqlMigrationConnector {
@@ -119,6 +123,27 @@ impl MigrationConnector for SqlMigrationConnector {
         self.flavour.reset(self.conn()).await
     }
 
+    async fn acquire_lock(&self, timeout: Duration) -> ConnectorResult<()> {
+        let deadline = Instant::now() + timeout;
+
+        while !self.flavour.try_acquire_migration_lock(self.conn()).await? {
+            if Instant::now() >= deadline {
+                return Err(ConnectorError::user_facing_error(MigrationLockTimeout {
+                    timeout: timeout.as_millis().to_string(),
+                }));
+            }
+
+            tracing::debug!("Waiting for another migration engine to release the migration lock.");
+            tokio::time::delay_for(MIGRATION_LOCK_POLL_INTERVAL).await;
+        }
+
+        Ok(())
+    }
+
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        self.flavour.release_migration_lock(self.conn()).await
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
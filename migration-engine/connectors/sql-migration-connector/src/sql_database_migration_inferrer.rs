@@ -1,11 +1,16 @@
 use crate::{
-    flavour::SqlFlavour, pair::Pair, sql_migration::SqlMigration, sql_schema_calculator, sql_schema_differ,
+    flavour::SqlFlavour,
+    pair::Pair,
+    sql_migration::SqlMigration,
+    sql_schema_calculator,
+    sql_schema_differ::{self, ColumnRename},
     SqlMigrationConnector,
 };
 use datamodel::*;
 use migration_connector::{
     steps::MigrationStep, ConnectorResult, DatabaseMigrationInferrer, MigrationConnector, MigrationDirectory,
 };
+use quaint::prelude::SqlFamily;
 use sql_schema_describer::*;
 
 #[async_trait::async_trait]
@@ -18,7 +23,12 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.describe_schema().await?;
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            &column_renames(next),
+            self.flavour(),
+        ))
     }
 
     /// Infer the database migration steps, skipping the schema describer and assuming an empty database.
@@ -26,7 +36,12 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         let current_database_schema = SqlSchema::empty();
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
 
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            &column_renames(next),
+            self.flavour(),
+        ))
     }
 
     fn infer_from_datamodels(
@@ -38,7 +53,20 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         let current_database_schema: SqlSchema = sql_schema_calculator::calculate_sql_schema(previous, self.flavour());
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
 
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            &column_renames(next),
+            self.flavour(),
+        ))
+    }
+
+    fn infer_reverse_migration(&self, migration: &SqlMigration) -> SqlMigration {
+        infer(migration.after.clone(), migration.before.clone(), &[], self.flavour())
+    }
+
+    fn make_online(&self, migration: &mut SqlMigration) {
+        migration.online = matches!(self.flavour().sql_family(), SqlFamily::Postgres);
     }
 
     #[tracing::instrument(skip(self, previous_migrations, target_schema))]
@@ -53,7 +81,12 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
             .await?;
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(target_schema, self.flavour());
 
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            &column_renames(target_schema),
+            self.flavour(),
+        ))
     }
 
     #[tracing::instrument(skip(self, applied_migrations))]
@@ -65,7 +98,8 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
 
         let actual_schema = self.describe_schema().await?;
 
-        let steps = sql_schema_differ::calculate_steps(Pair::new(&actual_schema, &expected_schema), self.flavour());
+        let steps =
+            sql_schema_differ::calculate_steps(Pair::new(&actual_schema, &expected_schema), self.flavour(), &[]);
 
         if steps.is_empty() {
             return Ok(None);
@@ -75,6 +109,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
             before: actual_schema,
             after: expected_schema,
             steps,
+            online: false,
         };
 
         let diagnostics = self.destructive_change_checker().pure_check(&migration);
@@ -99,14 +134,37 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
 fn infer(
     current_database_schema: SqlSchema,
     expected_database_schema: SqlSchema,
+    column_renames: &[ColumnRename],
     flavour: &dyn SqlFlavour,
 ) -> SqlMigration {
-    let steps =
-        sql_schema_differ::calculate_steps(Pair::new(&current_database_schema, &expected_database_schema), flavour);
+    let steps = sql_schema_differ::calculate_steps(
+        Pair::new(&current_database_schema, &expected_database_schema),
+        flavour,
+        column_renames,
+    );
 
     SqlMigration {
         before: current_database_schema,
         after: expected_database_schema,
         steps,
+        online: false,
     }
 }
+
+/// The fields of the datamodel that are mapped to a column with a different name. The field name
+/// is taken to be the name of the column before the rename.
+fn column_renames(next: &Datamodel) -> Vec<ColumnRename> {
+    datamodel::walkers::walk_models(next)
+        .flat_map(|model| {
+            model
+                .scalar_fields()
+                .filter(|field| field.name() != field.db_name())
+                .map(|field| ColumnRename {
+                    table: model.database_name().to_owned(),
+                    previous_column: field.name().to_owned(),
+                    next_column: field.db_name().to_owned(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
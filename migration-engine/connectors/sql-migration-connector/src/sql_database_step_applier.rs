@@ -1,25 +1,28 @@
 use crate::{
     pair::Pair,
-    sql_migration::{CreateTable, DropTable, SqlMigration, SqlMigrationStep},
+    sql_migration::{CreateTable, DropTable, RenameTable, SqlMigration, SqlMigrationStep},
     SqlFlavour, SqlMigrationConnector,
 };
 use migration_connector::{
     ConnectorResult, DatabaseMigrationMarker, DatabaseMigrationStepApplier, DestructiveChangeDiagnostics,
-    PrettyDatabaseMigrationStep,
+    PrettyDatabaseMigrationStep, ScriptStep, NON_TRANSACTIONAL_MIGRATION_MARKER,
 };
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
+/// Introduces each step of a non-transactional migration script, followed by
+/// the description of the step: `-- step: CreateIndex`. Steps that can be
+/// re-run from their first statement after a failure are introduced with
+/// `-- step (rerunnable): CreateIndex`.
+const STEP_MARKER: &str = "-- step";
+
+/// Separates the statements of a step in a non-transactional migration script.
+const STATEMENT_MARKER: &str = "-- statement";
+
 #[async_trait::async_trait]
 impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
     #[tracing::instrument(skip(self, database_migration))]
     async fn apply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
-        self.apply_next_step(
-            &database_migration.steps,
-            index,
-            self.flavour(),
-            database_migration.schemas(),
-        )
-        .await
+        self.apply_next_step(database_migration, index, self.flavour()).await
     }
 
     fn render_steps_pretty(
@@ -29,7 +32,13 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
         let mut steps = Vec::with_capacity(database_migration.steps.len());
 
         for step in &database_migration.steps {
-            let sql = render_raw_sql(&step, self.flavour(), database_migration.schemas()).join(";\n");
+            let sql = render_raw_sql(
+                &step,
+                self.flavour(),
+                database_migration.schemas(),
+                database_migration.step_is_online(step),
+            )
+            .join(";\n");
 
             if !sql.is_empty() {
                 steps.push(PrettyDatabaseMigrationStep {
@@ -49,6 +58,11 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
 
         let mut script = String::with_capacity(40 * database_migration.steps.len());
 
+        if !database_migration.is_transactional() {
+            script.push_str(NON_TRANSACTIONAL_MIGRATION_MARKER);
+            script.push('\n');
+        }
+
         // Note: it would be much nicer if we could place the warnings next to
         // the SQL for the steps that triggered them.
         if diagnostics.has_warnings() || !diagnostics.unexecutable_migrations.is_empty() {
@@ -79,6 +93,7 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
                 step,
                 self.flavour(),
                 Pair::new(&database_migration.before, &database_migration.after),
+                database_migration.step_is_online(step),
             );
 
             if !statements.is_empty() {
@@ -92,13 +107,22 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
                 // because we do not want two newlines at the end of the file:
                 // many editors will remove trailing newlines, and automatically
                 // edit the migration.
-                script.push_str("-- ");
-                script.push_str(step.description());
-                script.push('\n');
+                if database_migration.is_transactional() {
+                    script.push_str("-- ");
+                    script.push_str(step.description());
+                    script.push('\n');
 
-                for statement in statements {
-                    script.push_str(&statement);
-                    script.push_str(";\n");
+                    for statement in statements {
+                        script.push_str(&statement);
+                        script.push_str(";\n");
+                    }
+                } else {
+                    render_non_transactional_step(
+                        &mut script,
+                        step.description(),
+                        &statements,
+                        database_migration.step_is_rerunnable(step),
+                    );
                 }
             }
         }
@@ -107,28 +131,45 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
     }
 
     async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
-        Ok(self.conn().raw_cmd(script).await?)
+        Ok(self.conn().apply_migration_script(script).await?)
+    }
+
+    fn split_script_steps(&self, script: &str) -> Vec<ScriptStep> {
+        split_script_steps(script)
+    }
+
+    async fn apply_step_statements(&self, statements: &[String]) -> ConnectorResult<()> {
+        for statement in statements {
+            tracing::debug!(%statement);
+            self.conn().raw_cmd(statement).await?;
+        }
+
+        Ok(())
     }
 }
 
 impl SqlMigrationConnector {
     async fn apply_next_step(
         &self,
-        steps: &[SqlMigrationStep],
+        database_migration: &SqlMigration,
         index: usize,
         renderer: &(dyn SqlFlavour + Send + Sync),
-        schemas: Pair<&SqlSchema>,
     ) -> ConnectorResult<bool> {
-        let has_this_one = steps.get(index).is_some();
+        let has_this_one = database_migration.steps.get(index).is_some();
 
         if !has_this_one {
             return Ok(false);
         }
 
-        let step = &steps[index];
+        let step = &database_migration.steps[index];
         tracing::debug!(?step);
 
-        for sql_string in render_raw_sql(&step, renderer, schemas) {
+        for sql_string in render_raw_sql(
+            &step,
+            renderer,
+            database_migration.schemas(),
+            database_migration.step_is_online(step),
+        ) {
             tracing::debug!(index, %sql_string);
             self.conn().raw_cmd(&sql_string).await?;
         }
@@ -137,10 +178,123 @@ impl SqlMigrationConnector {
     }
 }
 
+/// Render one migration step in a non-transactional script. The steps of
+/// these scripts are applied and recorded one at a time, so every statement
+/// gets its own step, unless the whole step can be re-run after a failure.
+fn render_non_transactional_step(script: &mut String, description: &str, statements: &[String], rerunnable: bool) {
+    for (idx, statement) in statements.iter().enumerate() {
+        if rerunnable && idx == 0 {
+            script.push_str(STEP_MARKER);
+            script.push_str(" (rerunnable): ");
+            script.push_str(description);
+            script.push('\n');
+        } else if rerunnable {
+            script.push_str(STATEMENT_MARKER);
+            script.push('\n');
+        } else {
+            if idx > 0 {
+                script.push('\n');
+            }
+
+            script.push_str(STEP_MARKER);
+            script.push_str(": ");
+            script.push_str(description);
+            script.push('\n');
+        }
+
+        script.push_str(statement);
+        script.push_str(";\n");
+    }
+}
+
+/// Split a non-transactional migration script rendered by `render_script()`
+/// into its steps, at the step markers, and each step into its statements,
+/// at the statement markers. The SQL between two markers is sent to the
+/// database as is, so function bodies and string literals containing `;` or
+/// line breaks need no special treatment.
+///
+/// Anything before the first step marker other than comments is a step of
+/// its own.
+pub(crate) fn split_script_steps(script: &str) -> Vec<ScriptStep> {
+    // The first step collects what comes before the first step marker.
+    let mut steps = vec![ScriptStep {
+        statements: Vec::new(),
+        rerunnable: false,
+    }];
+    let mut statement = String::new();
+
+    for line in script.lines() {
+        let trimmed = line.trim();
+
+        if let Some(rerunnable) = parse_step_marker(trimmed) {
+            push_statement(&mut steps, &mut statement);
+            steps.push(ScriptStep {
+                statements: Vec::new(),
+                rerunnable,
+            });
+            continue;
+        }
+
+        if trimmed == STATEMENT_MARKER {
+            push_statement(&mut steps, &mut statement);
+            continue;
+        }
+
+        statement.push_str(line);
+        statement.push('\n');
+    }
+
+    push_statement(&mut steps, &mut statement);
+
+    if steps[0].statements.iter().all(|statement| is_comment_only(statement)) {
+        steps.remove(0);
+    }
+
+    steps
+}
+
+/// Returns whether the line is a step marker, and whether the step is
+/// rerunnable.
+fn parse_step_marker(line: &str) -> Option<bool> {
+    let rest = line.strip_prefix(STEP_MARKER)?;
+
+    if rest.starts_with(':') {
+        Some(false)
+    } else if rest.starts_with(" (rerunnable):") {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+fn push_statement(steps: &mut [ScriptStep], statement: &mut String) {
+    let trimmed = statement.trim();
+
+    if !trimmed.is_empty() {
+        steps.last_mut().unwrap().statements.push(trimmed.to_owned());
+    }
+
+    statement.clear();
+}
+
+fn is_comment_only(sql: &str) -> bool {
+    let mut in_block_comment = false;
+
+    sql.lines().map(str::trim).all(|line| {
+        if in_block_comment || line.starts_with("/*") {
+            in_block_comment = !line.ends_with("*/");
+            return true;
+        }
+
+        line.is_empty() || line.starts_with("--")
+    })
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
     schemas: Pair<&SqlSchema>,
+    online: bool,
 ) -> Vec<String> {
     match step {
         SqlMigrationStep::AlterEnum(alter_enum) => renderer.render_alter_enum(alter_enum, &schemas),
@@ -159,16 +313,37 @@ fn render_raw_sql(
         SqlMigrationStep::DropTable(DropTable { table_index }) => {
             renderer.render_drop_table(schemas.previous().table_walker_at(*table_index).name())
         }
+        SqlMigrationStep::RenameTable(RenameTable { table_index }) => {
+            let tables = schemas.tables(table_index);
+
+            vec![renderer.render_rename_table(tables.previous().name(), tables.next().name())]
+        }
         SqlMigrationStep::RedefineIndex { table, index } => {
             renderer.render_drop_and_recreate_index(schemas.tables(table).indexes(index).as_ref())
         }
+        SqlMigrationStep::AddCheckConstraint(add_check_constraint) => {
+            let table = schemas.next().table_walker_at(add_check_constraint.table_index);
+            let check_constraint = &table.check_constraints()[add_check_constraint.check_constraint_index];
+
+            vec![renderer.render_add_check_constraint(&table, check_constraint)]
+        }
+        SqlMigrationStep::DropCheckConstraint(drop_check_constraint) => {
+            let table = schemas.previous().table_walker_at(drop_check_constraint.table_index);
+            let check_constraint = &table.check_constraints()[drop_check_constraint.check_constraint_index];
+
+            vec![renderer.render_drop_check_constraint(&table, check_constraint)]
+        }
         SqlMigrationStep::AddForeignKey(add_foreign_key) => {
             let foreign_key = schemas
                 .next()
                 .table_walker_at(add_foreign_key.table_index)
                 .foreign_key_at(add_foreign_key.foreign_key_index);
 
-            vec![renderer.render_add_foreign_key(&foreign_key)]
+            if online {
+                renderer.render_add_foreign_key_not_valid(&foreign_key)
+            } else {
+                vec![renderer.render_add_foreign_key(&foreign_key)]
+            }
         }
         SqlMigrationStep::DropForeignKey(drop_foreign_key) => {
             let foreign_key = schemas
@@ -179,18 +354,30 @@ fn render_raw_sql(
             vec![renderer.render_drop_foreign_key(&foreign_key)]
         }
         SqlMigrationStep::AlterTable(alter_table) => renderer.render_alter_table(alter_table, &schemas),
-        SqlMigrationStep::CreateIndex(create_index) => vec![renderer.render_create_index(
-            &schemas
+        SqlMigrationStep::CreateIndex(create_index) => {
+            let index = schemas
                 .next()
                 .table_walker_at(create_index.table_index)
-                .index_at(create_index.index_index),
-        )],
-        SqlMigrationStep::DropIndex(drop_index) => vec![renderer.render_drop_index(
-            &schemas
+                .index_at(create_index.index_index);
+
+            if online {
+                renderer.render_create_index_concurrently(&index)
+            } else {
+                vec![renderer.render_create_index(&index)]
+            }
+        }
+        SqlMigrationStep::DropIndex(drop_index) => {
+            let index = schemas
                 .previous()
                 .table_walker_at(drop_index.table_index)
-                .index_at(drop_index.index_index),
-        )],
+                .index_at(drop_index.index_index);
+
+            if online {
+                vec![renderer.render_drop_index_concurrently(&index)]
+            } else {
+                vec![renderer.render_drop_index(&index)]
+            }
+        }
         SqlMigrationStep::AlterIndex { table, index } => {
             renderer.render_alter_index(schemas.tables(table).indexes(index).as_ref())
         }
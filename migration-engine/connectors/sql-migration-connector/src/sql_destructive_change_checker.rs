@@ -18,6 +18,7 @@ mod check;
 mod database_inspection_results;
 mod destructive_change_checker_flavour;
 mod destructive_check_plan;
+mod lock_check;
 mod unexecutable_step_check;
 mod warning_check;
 
@@ -30,7 +31,9 @@ use crate::{
     SqlMigration, SqlMigrationConnector,
 };
 use destructive_check_plan::DestructiveCheckPlan;
-use migration_connector::{ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics, MigrationFeature};
+use migration_connector::{
+    ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics, LockWarning, MigrationFeature,
+};
 use sql_schema_describer::{
     walkers::{ColumnWalker, SqlSchemaExt},
     ColumnArity, SqlSchema,
@@ -59,6 +62,25 @@ impl SqlMigrationConnector {
         );
     }
 
+    /// Renames that are not mapped explicitly in the datamodel are inferred from a dropped and an
+    /// added column with similar names, so they are pointed out in case the column was meant to
+    /// be replaced.
+    fn check_column_rename(
+        &self,
+        columns: &Pair<ColumnWalker<'_>>,
+        plan: &mut DestructiveCheckPlan,
+        step_index: usize,
+    ) {
+        plan.push_warning(
+            SqlMigrationWarningCheck::ColumnRename {
+                table: columns.previous().table().name().to_owned(),
+                previous_column: columns.previous().name().to_owned(),
+                next_column: columns.next().name().to_owned(),
+            },
+            step_index,
+        );
+    }
+
     /// Columns cannot be added when all of the following holds:
     ///
     /// - There are existing rows
@@ -120,7 +142,15 @@ impl SqlMigrationConnector {
                                 self.flavour
                                     .check_drop_and_recreate_column(&columns, changes, &mut plan, step_index)
                             }
-                            TableChange::AddPrimaryKey { .. } => (),
+                            TableChange::RenameColumn {
+                                column_index,
+                                inferred: true,
+                            } => {
+                                let columns = tables.columns(column_index);
+
+                                self.check_column_rename(&columns, &mut plan, step_index)
+                            }
+                            TableChange::AddPrimaryKey { .. } | TableChange::RenameColumn { .. } => (),
                         }
                     }
                 }
@@ -150,6 +180,18 @@ impl SqlMigrationConnector {
                         for (column_indexes, changes, type_change) in redefine_table.column_pairs.iter() {
                             let columns = tables.columns(column_indexes);
 
+                            if changes.only_renamed() {
+                                if redefine_table
+                                    .inferred_column_renames
+                                    .iter()
+                                    .any(|renamed| renamed.as_tuple() == column_indexes.as_tuple())
+                                {
+                                    self.check_column_rename(&columns, &mut plan, step_index);
+                                }
+
+                                continue;
+                            }
+
                             let arity_change_is_safe = match (&columns.previous().arity(), &columns.next().arity()) {
                                 // column became required
                                 (ColumnArity::Nullable, ColumnArity::Required) => false,
@@ -262,6 +304,24 @@ impl SqlMigrationConnector {
         plan
     }
 
+    /// Plan the lock-safety checks for the migration. These are kept out of
+    /// `plan()` because they are only run in lock linting mode.
+    fn lock_plan(&self, migration: &SqlMigration) -> DestructiveCheckPlan {
+        let mut plan = DestructiveCheckPlan::new();
+
+        for (step_index, step) in migration.steps.iter().enumerate() {
+            // Online-safe statements do not hold long locks.
+            if migration.step_is_online(step) {
+                continue;
+            }
+
+            self.flavour()
+                .check_locks(step, &migration.schemas(), &mut plan, step_index);
+        }
+
+        plan
+    }
+
     #[tracing::instrument(skip(self, steps, schemas), target = "SqlDestructiveChangeChecker::check")]
     async fn check_impl(
         &self,
@@ -287,4 +347,10 @@ impl DestructiveChangeChecker<SqlMigration> for SqlMigrationConnector {
 
         plan.pure_check()
     }
+
+    async fn check_locks(&self, database_migration: &SqlMigration) -> ConnectorResult<Vec<LockWarning>> {
+        let plan = self.lock_plan(database_migration);
+
+        Ok(plan.execute(self.conn()).await?.lock_warnings)
+    }
 }
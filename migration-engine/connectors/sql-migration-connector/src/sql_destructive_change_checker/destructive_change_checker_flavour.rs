@@ -3,10 +3,14 @@ mod mysql;
 mod postgres;
 mod sqlite;
 
-use sql_schema_describer::walkers::ColumnWalker;
+use sql_schema_describer::{walkers::ColumnWalker, SqlSchema};
 
 use super::DestructiveCheckPlan;
-use crate::{pair::Pair, sql_migration::AlterColumn, sql_schema_differ::ColumnChanges};
+use crate::{
+    pair::Pair,
+    sql_migration::{AlterColumn, SqlMigrationStep},
+    sql_schema_differ::ColumnChanges,
+};
 
 /// Flavour-specific destructive change checks.
 pub(crate) trait DestructiveChangeCheckerFlavour {
@@ -27,4 +31,15 @@ pub(crate) trait DestructiveChangeCheckerFlavour {
         plan: &mut DestructiveCheckPlan,
         step_index: usize,
     );
+
+    /// Check for steps that would hold long exclusive locks on existing
+    /// tables. Flavours without such checks do nothing.
+    fn check_locks(
+        &self,
+        _step: &SqlMigrationStep,
+        _schemas: &Pair<&SqlSchema>,
+        _plan: &mut DestructiveCheckPlan,
+        _step_index: usize,
+    ) {
+    }
 }
@@ -3,13 +3,20 @@ use crate::{
     flavour::PostgresFlavour,
     pair::Pair,
     sql_destructive_change_checker::{
-        destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
-        warning_check::SqlMigrationWarningCheck,
+        destructive_check_plan::DestructiveCheckPlan, lock_check::SqlMigrationLockCheck,
+        unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
     },
-    sql_migration::{AlterColumn, ColumnTypeChange},
+    sql_migration::{
+        AddForeignKey, AlterColumn, AlterTable, ColumnTypeChange, CreateIndex, SqlMigrationStep, TableChange,
+    },
+    sql_renderer::{render_postgres_column_type, render_postgres_type},
     sql_schema_differ::ColumnChanges,
 };
-use sql_schema_describer::{walkers::ColumnWalker, DefaultKind, DefaultValue};
+use native_types::PostgresType;
+use sql_schema_describer::{
+    walkers::{ColumnWalker, SqlSchemaExt},
+    ColumnTypeFamily, DefaultKind, DefaultValue, SqlSchema,
+};
 
 impl DestructiveChangeCheckerFlavour for PostgresFlavour {
     fn check_alter_column(
@@ -110,6 +117,91 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
             )
         }
     }
+
+    fn check_locks(
+        &self,
+        step: &SqlMigrationStep,
+        schemas: &Pair<&SqlSchema>,
+        plan: &mut DestructiveCheckPlan,
+        step_index: usize,
+    ) {
+        match step {
+            SqlMigrationStep::CreateIndex(CreateIndex {
+                table_index,
+                index_index,
+                caused_by_create_table: false,
+            }) => {
+                let index = schemas.next().table_walker_at(*table_index).index_at(*index_index);
+
+                plan.push_lock_warning(
+                    SqlMigrationLockCheck::IndexCreation {
+                        table: index.table().name().to_owned(),
+                        index: index.name().to_owned(),
+                    },
+                    step_index,
+                )
+            }
+            SqlMigrationStep::AlterTable(AlterTable { table_index, changes }) => {
+                let tables = schemas.tables(table_index);
+
+                for change in changes {
+                    match change {
+                        TableChange::AddColumn(add_column) => {
+                            let column = tables.next().column_at(add_column.column_index);
+
+                            if column.arity().is_required() && default_is_volatile(column.default()) {
+                                plan.push_lock_warning(
+                                    SqlMigrationLockCheck::RequiredColumnWithVolatileDefault {
+                                        table: column.table().name().to_owned(),
+                                        column: column.name().to_owned(),
+                                    },
+                                    step_index,
+                                )
+                            }
+                        }
+                        TableChange::AlterColumn(alter_column) if alter_column.changes.type_changed() => {
+                            let columns = tables.columns(&alter_column.column_index);
+                            let types = columns.as_ref().map(postgres_type);
+
+                            if type_change_rewrites_table(types.previous(), types.next()) {
+                                plan.push_lock_warning(
+                                    SqlMigrationLockCheck::TableRewritingTypeChange {
+                                        table: columns.previous().table().name().to_owned(),
+                                        column: columns.previous().name().to_owned(),
+                                        previous_type: render_type(columns.previous(), types.previous()),
+                                        next_type: render_type(columns.next(), types.next()),
+                                    },
+                                    step_index,
+                                )
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            SqlMigrationStep::AddForeignKey(AddForeignKey {
+                table_index,
+                foreign_key_index,
+            }) => {
+                let foreign_key = schemas
+                    .next()
+                    .table_walker_at(*table_index)
+                    .foreign_key_at(*foreign_key_index);
+
+                // There is nothing to validate on tables created by the migration.
+                if schemas.previous().table_walker(foreign_key.table().name()).is_some() {
+                    plan.push_lock_warning(
+                        SqlMigrationLockCheck::ForeignKeyValidation {
+                            table: foreign_key.table().name().to_owned(),
+                            referenced_table: foreign_key.referenced_table().name().to_owned(),
+                        },
+                        step_index,
+                    )
+                }
+            }
+            _ => (),
+        }
+    }
 }
 
 fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
@@ -121,3 +213,78 @@ fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
         Some(DefaultKind::SEQUENCE(_)) => false,
     }
 }
+
+/// Sequences are volatile, and we cannot tell whether a database-generated
+/// expression is, so we assume the worst.
+fn default_is_volatile(default: Option<&DefaultValue>) -> bool {
+    match default.map(|d| d.kind()) {
+        Some(DefaultKind::SEQUENCE(_)) | Some(DefaultKind::DBGENERATED(_)) => true,
+        Some(DefaultKind::VALUE(_)) | Some(DefaultKind::NOW) | None => false,
+    }
+}
+
+/// The Postgres type of a column: its native type, or the type rendered for its type family.
+fn postgres_type(column: &ColumnWalker<'_>) -> Option<PostgresType> {
+    if let Some(native_type) = &column.column_type().native_type {
+        return serde_json::from_value(native_type.clone()).ok();
+    }
+
+    match column.column_type_family() {
+        ColumnTypeFamily::Boolean => Some(PostgresType::Boolean),
+        ColumnTypeFamily::DateTime => Some(PostgresType::Timestamp(Some(3))),
+        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => Some(PostgresType::Decimal(Some((65, 30)))),
+        ColumnTypeFamily::Int => Some(PostgresType::Integer),
+        ColumnTypeFamily::BigInt => Some(PostgresType::BigInt),
+        ColumnTypeFamily::String => Some(PostgresType::Text),
+        ColumnTypeFamily::Json => Some(PostgresType::JSONB),
+        ColumnTypeFamily::Binary => Some(PostgresType::ByteA),
+        ColumnTypeFamily::Uuid | ColumnTypeFamily::Enum(_) | ColumnTypeFamily::Unsupported(_) => None,
+    }
+}
+
+/// Whether changing the type of a column rewrites the table. Postgres keeps the existing rows when
+/// the previous type is binary coercible to the next type, or when only a length limit or a
+/// precision grows. Types we can't tell apart are assumed to rewrite the table.
+fn type_change_rewrites_table(previous: &Option<PostgresType>, next: &Option<PostgresType>) -> bool {
+    use PostgresType::*;
+
+    // `None` is no limit at all.
+    let limit_grows = |previous: &Option<u32>, next: &Option<u32>| match (previous, next) {
+        (_, None) => true,
+        (Some(previous), Some(next)) => next >= previous,
+        (None, Some(_)) => false,
+    };
+
+    let (previous, next) = match (previous, next) {
+        (Some(previous), Some(next)) => (previous, next),
+        _ => return true,
+    };
+
+    match (previous, next) {
+        (VarChar(previous), VarChar(next)) | (VarBit(previous), VarBit(next)) => !limit_grows(previous, next),
+        (VarChar(_), Text) | (Text, VarChar(None)) => false,
+        (Decimal(previous), Decimal(next))
+        | (Decimal(previous), Numeric(next))
+        | (Numeric(previous), Decimal(next))
+        | (Numeric(previous), Numeric(next)) => match (previous, next) {
+            (_, None) => false,
+            (Some((previous_precision, previous_scale)), Some((next_precision, next_scale))) => {
+                next_scale != previous_scale || next_precision < previous_precision
+            }
+            (None, Some(_)) => true,
+        },
+        (Timestamp(previous), Timestamp(next))
+        | (Timestamptz(previous), Timestamptz(next))
+        | (Time(previous), Time(next))
+        | (Timetz(previous), Timetz(next)) => !limit_grows(previous, next),
+        (previous, next) => previous != next,
+    }
+}
+
+/// Renders the type of a column for lock warnings, in the same casing for both sides of a change.
+fn render_type(column: &ColumnWalker<'_>, postgres_type: &Option<PostgresType>) -> String {
+    match postgres_type {
+        Some(postgres_type) => render_postgres_type(postgres_type),
+        None => render_postgres_column_type(column.column_type()),
+    }
+}
@@ -1,10 +1,10 @@
 use super::{
-    check::Check, database_inspection_results::DatabaseInspectionResults,
+    check::Check, database_inspection_results::DatabaseInspectionResults, lock_check::SqlMigrationLockCheck,
     unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
 };
 use crate::connection_wrapper::Connection;
 use migration_connector::{
-    ConnectorError, ConnectorResult, DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration,
+    ConnectorError, ConnectorResult, DestructiveChangeDiagnostics, LockWarning, MigrationWarning, UnexecutableMigration,
 };
 use std::time::Duration;
 use tokio::time::{timeout, Elapsed};
@@ -18,6 +18,7 @@ const DESTRUCTIVE_TIMEOUT_DURATION: Duration = Duration::from_secs(60);
 pub(crate) struct DestructiveCheckPlan {
     warnings: Vec<(SqlMigrationWarningCheck, usize)>,
     unexecutable_migrations: Vec<(UnexecutableStepCheck, usize)>,
+    lock_warnings: Vec<(SqlMigrationLockCheck, usize)>,
 }
 
 impl DestructiveCheckPlan {
@@ -25,6 +26,7 @@ impl DestructiveCheckPlan {
         DestructiveCheckPlan {
             warnings: Vec::new(),
             unexecutable_migrations: Vec::new(),
+            lock_warnings: Vec::new(),
         }
     }
 
@@ -36,6 +38,10 @@ impl DestructiveCheckPlan {
         self.unexecutable_migrations.push((unexecutable_migration, step_index))
     }
 
+    pub(super) fn push_lock_warning(&mut self, lock_warning: SqlMigrationLockCheck, step_index: usize) {
+        self.lock_warnings.push((lock_warning, step_index))
+    }
+
     /// Inspect the current database state to qualify and render destructive change warnings and
     /// errors.
     ///
@@ -53,6 +59,10 @@ impl DestructiveCheckPlan {
                 self.inspect_for_check(warning, &mut results, conn).await?;
             }
 
+            for (lock_warning, _idx) in &self.lock_warnings {
+                self.inspect_for_check(lock_warning, &mut results, conn).await?;
+            }
+
             Ok::<(), ConnectorError>(())
         };
 
@@ -62,27 +72,7 @@ impl DestructiveCheckPlan {
             Ok(Err(err)) => return Err(err),
         };
 
-        let mut diagnostics = DestructiveChangeDiagnostics::new();
-
-        for (unexecutable, step_index) in &self.unexecutable_migrations {
-            if let Some(message) = unexecutable.evaluate(&results) {
-                diagnostics.unexecutable_migrations.push(UnexecutableMigration {
-                    description: message,
-                    step_index: *step_index,
-                })
-            }
-        }
-
-        for (warning, step_index) in &self.warnings {
-            if let Some(message) = warning.evaluate(&results) {
-                diagnostics.warnings.push(MigrationWarning {
-                    description: message,
-                    step_index: *step_index,
-                })
-            }
-        }
-
-        Ok(diagnostics)
+        Ok(self.render_diagnostics(&results))
     }
 
     /// Perform the database inspection for a given [`Check`](trait.Check.html).
@@ -115,11 +105,15 @@ impl DestructiveCheckPlan {
     /// warnings about the production database, when creating a migration in
     /// development.
     pub(super) fn pure_check(&self) -> DestructiveChangeDiagnostics {
-        let results = DatabaseInspectionResults::default();
+        self.render_diagnostics(&DatabaseInspectionResults::default())
+    }
+
+    /// Render the messages of the checks that apply, given the database inspection results.
+    fn render_diagnostics(&self, results: &DatabaseInspectionResults) -> DestructiveChangeDiagnostics {
         let mut diagnostics = DestructiveChangeDiagnostics::new();
 
         for (unexecutable, step_index) in &self.unexecutable_migrations {
-            if let Some(message) = unexecutable.evaluate(&results) {
+            if let Some(message) = unexecutable.evaluate(results) {
                 diagnostics.unexecutable_migrations.push(UnexecutableMigration {
                     description: message,
                     step_index: *step_index,
@@ -128,7 +122,7 @@ impl DestructiveCheckPlan {
         }
 
         for (warning, step_index) in &self.warnings {
-            if let Some(message) = warning.evaluate(&results) {
+            if let Some(message) = warning.evaluate(results) {
                 diagnostics.warnings.push(MigrationWarning {
                     description: message,
                     step_index: *step_index,
@@ -136,6 +130,16 @@ impl DestructiveCheckPlan {
             }
         }
 
+        for (lock_warning, step_index) in &self.lock_warnings {
+            if let Some(message) = lock_warning.evaluate(results) {
+                diagnostics.lock_warnings.push(LockWarning {
+                    description: message,
+                    severity: lock_warning.severity(results),
+                    step_index: *step_index,
+                })
+            }
+        }
+
         diagnostics
     }
 }
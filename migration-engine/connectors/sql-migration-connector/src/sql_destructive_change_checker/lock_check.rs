@@ -0,0 +1,59 @@
+use super::{check::Check, database_inspection_results::DatabaseInspectionResults};
+use migration_connector::LockSeverity;
+
+/// Steps that hold long exclusive locks on existing tables. They do not lose data, but they can
+/// block the application for the duration of the migration on large tables.
+#[derive(Debug)]
+pub(super) enum SqlMigrationLockCheck {
+    /// Creating an index without `CONCURRENTLY` blocks writes until the index is built.
+    IndexCreation { table: String, index: String },
+    /// Adding a required column with a volatile default rewrites the whole table.
+    RequiredColumnWithVolatileDefault { table: String, column: String },
+    /// Changing the type of a column rewrites the whole table, unless Postgres can keep the
+    /// existing values as they are.
+    TableRewritingTypeChange {
+        table: String,
+        column: String,
+        previous_type: String,
+        next_type: String,
+    },
+    /// Adding a foreign key without `NOT VALID` scans the whole table while blocking writes.
+    ForeignKeyValidation { table: String, referenced_table: String },
+}
+
+impl SqlMigrationLockCheck {
+    fn table(&self) -> &str {
+        match self {
+            SqlMigrationLockCheck::IndexCreation { table, .. }
+            | SqlMigrationLockCheck::RequiredColumnWithVolatileDefault { table, .. }
+            | SqlMigrationLockCheck::TableRewritingTypeChange { table, .. }
+            | SqlMigrationLockCheck::ForeignKeyValidation { table, .. } => table,
+        }
+    }
+
+    pub(super) fn severity(&self, database_check_results: &DatabaseInspectionResults) -> LockSeverity {
+        LockSeverity::from_row_count(database_check_results.get_row_count(self.table()))
+    }
+}
+
+impl Check for SqlMigrationLockCheck {
+    fn needed_table_row_count(&self) -> Option<&str> {
+        Some(self.table())
+    }
+
+    fn evaluate(&self, database_check_results: &DatabaseInspectionResults) -> Option<String> {
+        let rows = match database_check_results.get_row_count(self.table()) {
+            Some(0) => return None, // locking an empty table is instantaneous
+            Some(1) => " (1 row)".to_owned(),
+            Some(rows_count) => format!(" ({} rows)", rows_count),
+            None => String::new(),
+        };
+
+        match self {
+            SqlMigrationLockCheck::IndexCreation { table, index } => Some(format!("Creating the index `{index}` on the `{table}` table{rows} will block writes to the table until the index is built. Consider creating it with `CREATE INDEX CONCURRENTLY` instead.", index = index, table = table, rows = rows)),
+            SqlMigrationLockCheck::RequiredColumnWithVolatileDefault { table, column } => Some(format!("Adding the required column `{column}` with a volatile default to the `{table}` table{rows} will rewrite the table, blocking reads and writes until it is done.", column = column, table = table, rows = rows)),
+            SqlMigrationLockCheck::TableRewritingTypeChange { table, column, previous_type, next_type } => Some(format!("Changing the type of the column `{column}` on the `{table}` table{rows} from `{previous_type}` to `{next_type}` will rewrite the table, blocking reads and writes until it is done.", column = column, table = table, rows = rows, previous_type = previous_type, next_type = next_type)),
+            SqlMigrationLockCheck::ForeignKeyValidation { table, referenced_table } => Some(format!("Adding a foreign key from the `{table}` table{rows} to the `{referenced_table}` table will validate all existing rows while blocking writes to both tables. Consider adding it as `NOT VALID` and validating it in a separate migration.", table = table, rows = rows, referenced_table = referenced_table)),
+        }
+    }
+}
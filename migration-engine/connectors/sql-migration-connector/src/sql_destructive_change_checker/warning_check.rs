@@ -36,6 +36,11 @@ pub(super) enum SqlMigrationWarningCheck {
         enm: String,
         values: Vec<String>,
     },
+    ColumnRename {
+        table: String,
+        previous_column: String,
+        next_column: String,
+    },
 }
 
 impl Check for SqlMigrationWarningCheck {
@@ -43,7 +48,8 @@ impl Check for SqlMigrationWarningCheck {
         match self {
             SqlMigrationWarningCheck::NonEmptyTableDrop { table }
             | SqlMigrationWarningCheck::PrimaryKeyChange { table }
-            | SqlMigrationWarningCheck::DropAndRecreateColumn { table, column: _ } => Some(table),
+            | SqlMigrationWarningCheck::DropAndRecreateColumn { table, column: _ }
+            | SqlMigrationWarningCheck::ColumnRename { table, .. } => Some(table),
             SqlMigrationWarningCheck::NonEmptyColumnDrop { .. } | SqlMigrationWarningCheck::RiskyCast { .. } => None,
             _ => None,
         }
@@ -104,6 +110,11 @@ impl Check for SqlMigrationWarningCheck {
             },
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>  Some(format!("The migration will add a unique constraint covering the columns `{columns}` on the table `{table}`. If there are existing duplicate values, the migration will fail.", table = table, columns = format!("[{}]",columns.join(",")))),
             SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            // Renaming a column of an empty table makes no difference.
+            SqlMigrationWarningCheck::ColumnRename { table, previous_column, next_column } => match database_check_results.get_row_count(table) {
+                Some(0) => None,
+                _ => Some(format!("The column `{previous_column}` on the `{table}` table will be renamed to `{next_column}`, keeping its data, because the columns only differ by name and the names are similar. If `{next_column}` is meant to be a new column, add it in a separate migration. To rename the column without this warning, keep the field name and map it to the new column name with `@map(\"{next_column}\")`.", previous_column = previous_column, next_column = next_column, table = table)),
+            },
 
         }
     }
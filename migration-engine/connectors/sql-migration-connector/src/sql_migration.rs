@@ -1,7 +1,7 @@
 use crate::{pair::Pair, sql_schema_differ::ColumnChanges};
 use migration_connector::DatabaseMigrationMarker;
 use serde::{Serialize, Serializer};
-use sql_schema_describer::SqlSchema;
+use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
 /// The database migration type for SqlMigrationConnector.
 #[derive(Debug)]
@@ -9,12 +9,49 @@ pub struct SqlMigration {
     pub(crate) before: SqlSchema,
     pub(crate) after: SqlSchema,
     pub(crate) steps: Vec<SqlMigrationStep>,
+    /// Render the steps that would lock existing tables with online-safe
+    /// statements instead. Only supported on Postgres.
+    pub(crate) online: bool,
 }
 
 impl SqlMigration {
     pub(crate) fn schemas(&self) -> Pair<&SqlSchema> {
         Pair::new(&self.before, &self.after)
     }
+
+    /// Whether the step should be rendered with online-safe statements: index
+    /// creation and deletion on existing tables, and foreign keys added to
+    /// existing tables.
+    pub(crate) fn step_is_online(&self, step: &SqlMigrationStep) -> bool {
+        if !self.online {
+            return false;
+        }
+
+        match step {
+            SqlMigrationStep::CreateIndex(CreateIndex {
+                caused_by_create_table, ..
+            }) => !caused_by_create_table,
+            SqlMigrationStep::DropIndex(_) => true,
+            SqlMigrationStep::AddForeignKey(AddForeignKey { table_index, .. }) => {
+                let table_name = self.after.table_walker_at(*table_index).name();
+
+                self.before.table_walker(table_name).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the step can be re-run from its first statement after it failed
+    /// part way through. Online index creation first drops the invalid index
+    /// a failed `CREATE INDEX CONCURRENTLY` leaves behind.
+    pub(crate) fn step_is_rerunnable(&self, step: &SqlMigrationStep) -> bool {
+        matches!(step, SqlMigrationStep::CreateIndex(_)) && self.step_is_online(step)
+    }
+
+    /// Online-safe statements cannot run inside a transaction block.
+    pub(crate) fn is_transactional(&self) -> bool {
+        !self.steps.iter().any(|step| self.step_is_online(step))
+    }
 }
 
 impl DatabaseMigrationMarker for SqlMigration {
@@ -27,11 +64,14 @@ impl DatabaseMigrationMarker for SqlMigration {
 
 #[derive(Debug)]
 pub(crate) enum SqlMigrationStep {
+    AddCheckConstraint(AddCheckConstraint),
     AddForeignKey(AddForeignKey),
     CreateTable(CreateTable),
     AlterTable(AlterTable),
+    DropCheckConstraint(DropCheckConstraint),
     DropForeignKey(DropForeignKey),
     DropTable(DropTable),
+    RenameTable(RenameTable),
     RedefineIndex { table: Pair<usize>, index: Pair<usize> },
     RedefineTables(Vec<RedefineTable>),
     CreateIndex(CreateIndex),
@@ -59,12 +99,15 @@ impl Serialize for SqlMigrationStep {
 impl SqlMigrationStep {
     pub(crate) fn description(&self) -> &'static str {
         match self {
+            SqlMigrationStep::AddCheckConstraint(_) => "AddCheckConstraint",
             SqlMigrationStep::AddForeignKey(_) => "AddForeignKey",
             SqlMigrationStep::CreateTable(_) => "CreateTable",
             SqlMigrationStep::AlterTable(_) => "AlterTable",
             SqlMigrationStep::RedefineIndex { .. } => "RedefineIndex",
+            SqlMigrationStep::DropCheckConstraint(_) => "DropCheckConstraint",
             SqlMigrationStep::DropForeignKey(_) => "DropForeignKey",
             SqlMigrationStep::DropTable(_) => "DropTable",
+            SqlMigrationStep::RenameTable(_) => "RenameTable",
             SqlMigrationStep::RedefineTables { .. } => "RedefineTables",
             SqlMigrationStep::CreateIndex(_) => "CreateIndex",
             SqlMigrationStep::DropIndex(_) => "DropIndex",
@@ -86,6 +129,12 @@ pub(crate) struct DropTable {
     pub table_index: usize,
 }
 
+#[derive(Debug)]
+pub(crate) struct RenameTable {
+    /// Index in (previous_schema, next_schema).
+    pub table_index: Pair<usize>,
+}
+
 #[derive(Debug)]
 pub(crate) struct AlterTable {
     /// Index in (previous_schema, next_schema).
@@ -104,6 +153,13 @@ pub(crate) enum TableChange {
         /// The change mask for the column.
         changes: ColumnChanges,
     },
+    RenameColumn {
+        /// The index of the column in the table.
+        column_index: Pair<usize>,
+        /// Whether the rename was inferred from the columns, rather than mapped explicitly in
+        /// the datamodel.
+        inferred: bool,
+    },
     DropPrimaryKey,
     AddPrimaryKey {
         columns: Vec<String>,
@@ -134,6 +190,22 @@ pub(crate) enum ColumnTypeChange {
     NotCastable,
 }
 
+#[derive(Debug)]
+pub(crate) struct AddCheckConstraint {
+    /// The index of the table in the next schema.
+    pub(crate) table_index: usize,
+    /// The index of the check constraint in the table.
+    pub(crate) check_constraint_index: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct DropCheckConstraint {
+    /// The index of the table in the previous schema.
+    pub(crate) table_index: usize,
+    /// The index of the check constraint in the table.
+    pub(crate) check_constraint_index: usize,
+}
+
 #[derive(Debug)]
 pub(crate) struct AddForeignKey {
     /// The index of the table in the next schema.
@@ -192,5 +264,8 @@ pub(crate) struct RedefineTable {
     pub dropped_columns: Vec<usize>,
     pub dropped_primary_key: bool,
     pub column_pairs: Vec<(Pair<usize>, ColumnChanges, Option<ColumnTypeChange>)>,
+    /// The column pairs that are renames inferred from the columns, rather than mapped
+    /// explicitly in the datamodel.
+    pub inferred_column_renames: Vec<Pair<usize>>,
     pub table_index: Pair<usize>,
 }
@@ -15,6 +15,7 @@ mod postgres_renderer;
 mod sqlite_renderer;
 
 pub(crate) use common::IteratorJoin;
+pub(crate) use postgres_renderer::{render_column_type as render_postgres_column_type, render_postgres_type};
 
 use crate::{
     pair::Pair,
@@ -26,17 +27,42 @@ use sql_schema_describer::{
     walkers::ForeignKeyWalker,
     walkers::IndexWalker,
     walkers::{ColumnWalker, TableWalker},
-    ColumnTypeFamily, DefaultValue, SqlSchema,
+    CheckConstraint, ColumnTypeFamily, DefaultValue, SqlSchema,
 };
 use std::borrow::Cow;
 
 pub(crate) trait SqlRenderer {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str>;
 
+    /// Render an `AddCheckConstraint` step.
+    fn render_add_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {} ADD {}",
+            self.quote(table.name()),
+            self.render_check_constraint(check_constraint)
+        )
+    }
+
     fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
 
+    /// Render an `AddForeignKey` step in an online migration: the constraint
+    /// is added without checking the existing rows, then validated without
+    /// blocking writes. Defaults to the regular `AddForeignKey` DDL.
+    fn render_add_foreign_key_not_valid(&self, foreign_key: &ForeignKeyWalker<'_>) -> Vec<String> {
+        vec![self.render_add_foreign_key(foreign_key)]
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String>;
 
+    /// Render a check constraint definition, as part of a CREATE or ALTER TABLE statement.
+    fn render_check_constraint(&self, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "CONSTRAINT {} CHECK ({})",
+            self.quote(&check_constraint.name),
+            check_constraint.expression
+        )
+    }
+
     fn render_column(&self, column: &ColumnWalker<'_>) -> String;
 
     fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
@@ -54,6 +80,12 @@ pub(crate) trait SqlRenderer {
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render a `CreateIndex` step in an online migration, building the index
+    /// without blocking writes. Defaults to the regular `CreateIndex` DDL.
+    fn render_create_index_concurrently(&self, index: &IndexWalker<'_>) -> Vec<String> {
+        vec![self.render_create_index(index)]
+    }
+
     /// Render a table creation step.
     fn render_create_table(&self, table: &TableWalker<'_>) -> String {
         self.render_create_table_as(table, table.name())
@@ -66,6 +98,15 @@ pub(crate) trait SqlRenderer {
         unreachable!("unreachable render_drop_and_recreate_index")
     }
 
+    /// Render a `DropCheckConstraint` step.
+    fn render_drop_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {} DROP CONSTRAINT {}",
+            self.quote(table.name()),
+            self.quote(&check_constraint.name)
+        )
+    }
+
     /// Render a `DropEnum` step.
     fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> Vec<String>;
 
@@ -75,6 +116,12 @@ pub(crate) trait SqlRenderer {
     /// Render a `DropIndex` step.
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render a `DropIndex` step in an online migration. Defaults to the
+    /// regular `DropIndex` DDL.
+    fn render_drop_index_concurrently(&self, index: &IndexWalker<'_>) -> String {
+        self.render_drop_index(index)
+    }
+
     /// Render a `DropTable` step.
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
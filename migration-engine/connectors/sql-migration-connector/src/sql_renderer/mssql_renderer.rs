@@ -1,6 +1,6 @@
 mod alter_table;
 
-use super::common::render_on_delete;
+use super::common::{render_on_delete, render_on_update};
 use super::{common, IteratorJoin, Quoted, SqlRenderer};
 use crate::{
     flavour::MssqlFlavour,
@@ -11,7 +11,7 @@ use indoc::formatdoc;
 use prisma_value::PrismaValue;
 use sql_schema_describer::{
     walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    ColumnTypeFamily, DefaultKind, DefaultValue, IndexType, SqlSchema,
+    CheckConstraint, ColumnTypeFamily, DefaultKind, DefaultValue, IndexType, SqlSchema,
 };
 use std::{
     borrow::Cow,
@@ -37,6 +37,14 @@ impl MssqlFlavour {
             name,
         }
     }
+
+    fn render_index_column(&self, index: &IndexWalker<'_>, column_name: &str) -> String {
+        if index.column_is_descending(column_name) {
+            format!("{} DESC", self.quote(column_name))
+        } else {
+            self.quote(column_name).to_string()
+        }
+    }
 }
 
 impl SqlRenderer for MssqlFlavour {
@@ -44,6 +52,14 @@ impl SqlRenderer for MssqlFlavour {
         Quoted::mssql_ident(name)
     }
 
+    fn render_add_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {} ADD {}",
+            self.quote_with_schema(table.name()),
+            self.render_check_constraint(check_constraint)
+        )
+    }
+
     fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> Vec<String> {
         let AlterTable { table_index, changes } = alter_table;
         let tables = schemas.tables(table_index);
@@ -90,10 +106,11 @@ impl SqlRenderer for MssqlFlavour {
             .join(",");
 
         format!(
-            " REFERENCES {}({}) {} ON UPDATE CASCADE",
+            " REFERENCES {}({}) {} {}",
             self.quote_with_schema(&foreign_key.referenced_table().name()),
             cols,
             render_on_delete(&foreign_key.on_delete_action()),
+            render_on_update(&foreign_key.on_update_action()),
         )
     }
 
@@ -123,7 +140,7 @@ impl SqlRenderer for MssqlFlavour {
         let index_with_table = format!(
             "{}.{}.{}",
             self.schema_name(),
-            indexes.previous().table().name(),
+            indexes.next().table().name(),
             indexes.previous().name()
         );
 
@@ -142,20 +159,35 @@ impl SqlRenderer for MssqlFlavour {
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => unreachable!("Fulltext index on SQL Server"),
         };
 
         let index_name = index.name().replace('.', "_");
         let index_name = self.quote(&index_name);
         let table_reference = self.quote_with_schema(index.table().name()).to_string();
 
-        let columns = index.columns().map(|c| self.quote(c.name()));
+        let columns = index.columns().map(|c| self.render_index_column(index, c.name()));
+
+        let include = if index.included_column_names().is_empty() {
+            String::new()
+        } else {
+            let included_columns = index.included_column_names().iter().map(|c| self.quote(c));
+            format!(" INCLUDE ({})", included_columns.join(", "))
+        };
+
+        let where_clause = match index.predicate() {
+            Some(predicate) => format!(" WHERE {}", predicate),
+            None => String::new(),
+        };
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){include}{where_clause}",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
             columns = columns.join(", "),
+            include = include,
+            where_clause = where_clause,
         )
     }
 
@@ -182,7 +214,7 @@ impl SqlRenderer for MssqlFlavour {
 
         let constraints = table
             .indexes()
-            .filter(|index| index.index_type().is_unique())
+            .filter(|index| index.index_type().is_unique() && index.included_column_names().is_empty())
             .collect::<Vec<_>>();
 
         let constraints = if !constraints.is_empty() {
@@ -190,7 +222,7 @@ impl SqlRenderer for MssqlFlavour {
                 .iter()
                 .map(|index| {
                     let name = index.name().replace('.', "_");
-                    let columns = index.columns().map(|col| self.quote(col.name()));
+                    let columns = index.columns().map(|col| self.render_index_column(index, col.name()));
 
                     format!("CONSTRAINT {} UNIQUE ({})", self.quote(&name), columns.join(","))
                 })
@@ -201,15 +233,22 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
+        let check_constraints: String = table
+            .check_constraints()
+            .iter()
+            .map(|check| format!(",\n    {}", self.render_check_constraint(check)))
+            .collect();
+
         formatdoc!(
             r#"
             CREATE TABLE {table_name} (
-                {columns}{primary_key}{constraints}
+                {columns}{primary_key}{constraints}{check_constraints}
             )"#,
             table_name = self.quote_with_schema(table_name),
             columns = columns,
             primary_key = primary_key,
             constraints = constraints,
+            check_constraints = check_constraints,
         )
     }
 
@@ -217,6 +256,14 @@ impl SqlRenderer for MssqlFlavour {
         unreachable!("render_drop_enum on MSSQL")
     }
 
+    fn render_drop_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
+            table = self.quote_with_schema(table.name()),
+            constraint_name = self.quote(&check_constraint.name),
+        )
+    }
+
     fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
@@ -227,7 +274,7 @@ impl SqlRenderer for MssqlFlavour {
 
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String {
         match index.index_type() {
-            IndexType::Normal => format!(
+            IndexType::Normal | IndexType::Fulltext => format!(
                 "DROP INDEX {} ON {}",
                 self.quote(index.name()),
                 self.quote_with_schema(index.table().name())
@@ -26,6 +26,7 @@ pub(crate) fn create_statements(
         add_constraints: BTreeSet::new(),
         add_columns: Vec::new(),
         drop_columns: Vec::new(),
+        rename_columns: Vec::new(),
         column_mods: Vec::new(),
     };
 
@@ -40,6 +41,7 @@ struct AlterTableConstructor<'a> {
     add_constraints: BTreeSet<String>,
     add_columns: Vec<String>,
     drop_columns: Vec<String>,
+    rename_columns: Vec<String>,
     column_mods: Vec<String>,
 }
 
@@ -62,6 +64,9 @@ impl<'a> AlterTableConstructor<'a> {
                 TableChange::DropAndRecreateColumn { column_index, .. } => {
                     self.drop_and_recreate_column(*column_index);
                 }
+                TableChange::RenameColumn { column_index, .. } => {
+                    self.rename_column(*column_index);
+                }
                 TableChange::AlterColumn(AlterColumn {
                     column_index,
                     changes,
@@ -95,6 +100,10 @@ impl<'a> AlterTableConstructor<'a> {
             ));
         }
 
+        if !self.rename_columns.is_empty() {
+            statements.extend(self.rename_columns)
+        }
+
         if !self.add_constraints.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} ADD {}",
@@ -155,6 +164,23 @@ impl<'a> AlterTableConstructor<'a> {
         self.drop_columns.push(format!("{}", name));
     }
 
+    fn rename_column(&mut self, columns: Pair<usize>) {
+        let columns = self.tables.columns(&columns);
+
+        let column_with_table = format!(
+            "{}.{}.{}",
+            self.renderer.schema_name(),
+            self.tables.previous().name(),
+            columns.previous().name()
+        );
+
+        self.rename_columns.push(format!(
+            "EXEC SP_RENAME N'{column_with_table}', N'{column_new_name}', N'COLUMN'",
+            column_with_table = column_with_table,
+            column_new_name = columns.next().name(),
+        ));
+    }
+
     fn drop_and_recreate_column(&mut self, columns: Pair<usize>) {
         let columns = self.tables.columns(&columns);
 
@@ -1,6 +1,6 @@
 use super::{
     common::SQL_INDENTATION,
-    common::{render_nullability, render_on_delete, Quoted},
+    common::{render_nullability, render_on_delete, render_on_update, Quoted},
     IteratorJoin, SqlRenderer,
 };
 use crate::{
@@ -15,7 +15,7 @@ use regex::Regex;
 use sql_ddl::mysql as ddl;
 use sql_schema_describer::{
     walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction, SqlSchema,
+    CheckConstraint, ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction, IndexType, SqlSchema,
 };
 use std::borrow::Cow;
 
@@ -67,8 +67,9 @@ impl SqlRenderer for MysqlFlavour {
     }
 
     fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
+        // Index steps run after table renames, so we use the next table name.
         vec![ddl::AlterTable {
-            table_name: indexes.previous().table().name().into(),
+            table_name: indexes.next().table().name().into(),
             changes: vec![sql_ddl::mysql::AlterTableClause::RenameIndex {
                 previous_name: indexes.previous().name().into(),
                 next_name: indexes.next().name().into(),
@@ -125,6 +126,16 @@ impl SqlRenderer for MysqlFlavour {
                     };
                 }
                 TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on MySQL"),
+                TableChange::RenameColumn { column_index, .. } => {
+                    let columns = tables.columns(column_index);
+
+                    // CHANGE instead of RENAME COLUMN, the latter is only available from MySQL 8.
+                    lines.push(format!(
+                        "CHANGE {} {}",
+                        Quoted::mysql_ident(columns.previous().name()),
+                        self.render_column(columns.next())
+                    ));
+                }
             };
         }
 
@@ -186,10 +197,11 @@ impl SqlRenderer for MysqlFlavour {
             .join(",");
 
         format!(
-            " REFERENCES `{table_name}`({column_names}) {on_delete} ON UPDATE CASCADE",
+            " REFERENCES `{table_name}`({column_names}) {on_delete} {on_update}",
             table_name = foreign_key.referenced_table().name(),
             column_names = referenced_columns,
-            on_delete = render_on_delete(foreign_key.on_delete_action())
+            on_delete = render_on_delete(foreign_key.on_delete_action()),
+            on_update = render_on_update(foreign_key.on_update_action()),
         )
     }
 
@@ -222,10 +234,17 @@ impl SqlRenderer for MysqlFlavour {
 
         ddl::CreateIndex {
             unique: index.index_type().is_unique(),
+            fulltext: index.index_type() == &IndexType::Fulltext,
             index_name: name.into(),
             on: (
                 index.table().name().into(),
-                index.columns().map(|c| c.name().into()).collect(),
+                index
+                    .columns()
+                    .map(|c| ddl::IndexColumn {
+                        name: c.name().into(),
+                        descending: index.column_is_descending(c.name()),
+                    })
+                    .collect(),
             ),
         }
         .to_string()
@@ -247,7 +266,11 @@ impl SqlRenderer for MysqlFlavour {
             let indices: String = table
                 .indexes()
                 .map(|index| {
-                    let tpe = if index.index_type().is_unique() { "UNIQUE " } else { "" };
+                    let tpe = match index.index_type() {
+                        IndexType::Unique => "UNIQUE ",
+                        IndexType::Normal => "",
+                        IndexType::Fulltext => "FULLTEXT ",
+                    };
                     let index_name = if index.name().len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
                         &index.name()[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
                     } else {
@@ -258,7 +281,16 @@ impl SqlRenderer for MysqlFlavour {
                         "{}INDEX {}({})",
                         tpe,
                         self.quote(&index_name),
-                        index.columns().map(|col| self.quote(col.name())).join(", ")
+                        index
+                            .columns()
+                            .map(|col| {
+                                if index.column_is_descending(col.name()) {
+                                    format!("{} DESC", self.quote(col.name()))
+                                } else {
+                                    self.quote(col.name()).to_string()
+                                }
+                            })
+                            .join(", ")
                     )
                 })
                 .join(",\n");
@@ -268,12 +300,19 @@ impl SqlRenderer for MysqlFlavour {
             String::new()
         };
 
+        let check_constraints: String = table
+            .check_constraints()
+            .iter()
+            .map(|check| format!(",\n{}{}", SQL_INDENTATION, self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}{check_constraints}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
             table_name = self.quote(table_name),
             columns = columns,
             indexes = indexes,
             primary_key = primary_key,
+            check_constraints = check_constraints,
         )
     }
 
@@ -283,7 +322,7 @@ impl SqlRenderer for MysqlFlavour {
             self.render_create_index(indexes.next()),
             sql_ddl::mysql::DropIndex {
                 index_name: indexes.previous().name().into(),
-                table_name: indexes.previous().table().name().into(),
+                table_name: indexes.next().table().name().into(),
             }
             .to_string(),
         ]
@@ -293,6 +332,16 @@ impl SqlRenderer for MysqlFlavour {
         Vec::new()
     }
 
+    fn render_drop_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        // MySQL only supports DROP CONSTRAINT since 8.0.19, MariaDB doesn't support DROP CHECK.
+        format!(
+            "ALTER TABLE {table} DROP {constraint_kind} {constraint_name}",
+            table = self.quote(table.name()),
+            constraint_kind = if self.is_mariadb() { "CONSTRAINT" } else { "CHECK" },
+            constraint_name = self.quote(&check_constraint.name),
+        )
+    }
+
     fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         format!(
             "ALTER TABLE {table} DROP FOREIGN KEY {constraint_name}",
@@ -5,10 +5,11 @@ use crate::{
     sql_migration::{AddColumn, AlterColumn, AlterEnum, AlterTable, DropColumn, RedefineTable, TableChange},
     sql_schema_differ::{ColumnChange, ColumnChanges},
 };
+use native_types::PostgresType;
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use regex::Regex;
-use sql_ddl::postgres::{CreateEnum, CreateIndex};
+use sql_ddl::postgres::{CreateEnum, CreateIndex, IndexColumn};
 use sql_schema_describer::{walkers::*, *};
 use std::borrow::Cow;
 
@@ -36,6 +37,32 @@ impl SqlRenderer for PostgresFlavour {
         )
     }
 
+    fn render_add_foreign_key_not_valid(&self, foreign_key: &ForeignKeyWalker<'_>) -> Vec<String> {
+        let constraint_name = match foreign_key.constraint_name() {
+            Some(constraint_name) => constraint_name,
+            // Without a name, the constraint cannot be validated separately.
+            None => return vec![self.render_add_foreign_key(foreign_key)],
+        };
+
+        let table = self.quote(foreign_key.table().name());
+
+        // The constraint is dropped first, in case a previous attempt failed
+        // during validation.
+        vec![
+            format!(
+                "ALTER TABLE {table} DROP CONSTRAINT IF EXISTS {constraint_name}",
+                table = table,
+                constraint_name = self.quote(constraint_name),
+            ),
+            format!("{} NOT VALID", self.render_add_foreign_key(foreign_key)),
+            format!(
+                "ALTER TABLE {table} VALIDATE CONSTRAINT {constraint_name}",
+                table = table,
+                constraint_name = self.quote(constraint_name),
+            ),
+        ]
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String> {
         if alter_enum.dropped_variants.is_empty() {
             let stmts: Vec<String> = alter_enum
@@ -143,6 +170,8 @@ impl SqlRenderer for PostgresFlavour {
         let mut lines = Vec::new();
         let mut before_statements = Vec::new();
         let mut after_statements = Vec::new();
+        // RENAME COLUMN cannot be combined with other ALTER TABLE clauses.
+        let mut rename_statements = Vec::new();
 
         let tables = schemas.tables(table_index);
 
@@ -200,11 +229,21 @@ impl SqlRenderer for PostgresFlavour {
                     let col_sql = self.render_column(columns.next());
                     lines.push(format!("ADD COLUMN {}", col_sql));
                 }
+                TableChange::RenameColumn { column_index, .. } => {
+                    let columns = tables.columns(column_index);
+
+                    rename_statements.push(format!(
+                        "ALTER TABLE {} RENAME COLUMN {} TO {}",
+                        self.quote(tables.previous().name()),
+                        self.quote(columns.previous().name()),
+                        self.quote(columns.next().name()),
+                    ));
+                }
             };
         }
 
         if lines.is_empty() {
-            return Vec::new();
+            return rename_statements;
         }
 
         let alter_table = format!(
@@ -213,8 +252,9 @@ impl SqlRenderer for PostgresFlavour {
             lines.join(",\n")
         );
 
-        before_statements
+        rename_statements
             .into_iter()
+            .chain(before_statements.into_iter())
             .chain(std::iter::once(alter_table))
             .chain(after_statements.into_iter())
             .collect()
@@ -249,10 +289,11 @@ impl SqlRenderer for PostgresFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {}({}) {} ON UPDATE CASCADE",
+            "REFERENCES {}({}) {} {}",
             self.quote(&foreign_key.referenced_table().name()),
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action())
+            render_on_delete(&foreign_key.on_delete_action()),
+            render_on_update(&foreign_key.on_update_action()),
         )
     }
 
@@ -287,12 +328,41 @@ impl SqlRenderer for PostgresFlavour {
         CreateIndex {
             index_name: index.name().into(),
             is_unique: index.index_type().is_unique(),
+            concurrently: false,
             table_reference: index.table().name().into(),
-            columns: index.columns().map(|c| c.name().into()).collect(),
+            using: index_method(index.algorithm()),
+            columns: index_columns(index),
+            include: index
+                .included_column_names()
+                .iter()
+                .map(|c| c.as_str().into())
+                .collect(),
+            where_clause: index.predicate().map(Cow::Borrowed),
         }
         .to_string()
     }
 
+    fn render_create_index_concurrently(&self, index: &IndexWalker<'_>) -> Vec<String> {
+        let create_index = CreateIndex {
+            index_name: index.name().into(),
+            is_unique: index.index_type().is_unique(),
+            concurrently: true,
+            table_reference: index.table().name().into(),
+            using: index_method(index.algorithm()),
+            columns: index_columns(index),
+            include: index
+                .included_column_names()
+                .iter()
+                .map(|c| c.as_str().into())
+                .collect(),
+            where_clause: index.predicate().map(Cow::Borrowed),
+        };
+
+        // A failed concurrent index build leaves an invalid index behind, so
+        // it has to be dropped before retrying.
+        vec![self.render_drop_index_concurrently(index), create_index.to_string()]
+    }
+
     fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
         let columns: String = table.columns().map(|column| self.render_column(&column)).join(",\n");
 
@@ -308,11 +378,18 @@ impl SqlRenderer for PostgresFlavour {
             String::new()
         };
 
+        let check_constraints: String = table
+            .check_constraints()
+            .iter()
+            .map(|check| format!(",\n{}{}", SQL_INDENTATION, self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n)",
+            "CREATE TABLE {table_name} (\n{columns}{primary_key}{check_constraints}\n)",
             table_name = self.quote(table_name),
             columns = columns,
             primary_key = pk,
+            check_constraints = check_constraints,
         )
     }
 
@@ -337,6 +414,10 @@ impl SqlRenderer for PostgresFlavour {
         format!("DROP INDEX {}", self.quote(index.name()))
     }
 
+    fn render_drop_index_concurrently(&self, index: &IndexWalker<'_>) -> String {
+        format!("DROP INDEX CONCURRENTLY IF EXISTS {}", self.quote(index.name()))
+    }
+
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
@@ -380,12 +461,89 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
     }
 }
 
+/// Renders a Postgres native type, e.g. `VARCHAR(10)`.
+pub(crate) fn render_postgres_type(postgres_type: &PostgresType) -> String {
+    fn render(input: Option<u32>) -> String {
+        match input {
+            None => "".to_string(),
+            Some(arg) => format!("({})", arg),
+        }
+    }
+    fn render_decimal(input: Option<(u32, u32)>) -> String {
+        match input {
+            None => "".to_string(),
+            Some((precision, scale)) => format!("({}, {})", precision, scale),
+        }
+    }
+
+    match *postgres_type {
+        PostgresType::SmallInt => "SMALLINT".to_owned(),
+        PostgresType::Integer => "INTEGER".to_owned(),
+        PostgresType::BigInt => "BIGINT".to_owned(),
+        PostgresType::Decimal(precision) => format!("DECIMAL{}", render_decimal(precision)),
+        PostgresType::Numeric(precision) => format!("NUMERIC{}", render_decimal(precision)),
+        PostgresType::Real => "REAL".to_owned(),
+        PostgresType::DoublePrecision => "DOUBLE PRECISION".to_owned(),
+        PostgresType::SmallSerial => "SMALLSERIAL".to_owned(),
+        PostgresType::Serial => "SERIAL".to_owned(),
+        PostgresType::BigSerial => "BIGSERIAL".to_owned(),
+        PostgresType::VarChar(length) => format!("VARCHAR{}", render(length)),
+        PostgresType::Char(length) => format!("CHAR{}", render(length)),
+        PostgresType::Text => "TEXT".to_owned(),
+        PostgresType::ByteA => "BYTEA".to_owned(),
+        PostgresType::Timestamp(precision) => format!("TIMESTAMP{}", render(precision)),
+        PostgresType::Timestamptz(precision) => format!("TIMESTAMPTZ{}", render(precision)),
+        PostgresType::Date => "DATE".to_owned(),
+        PostgresType::Time(precision) => format!("TIME{}", render(precision)),
+        PostgresType::Timetz(precision) => format!("TIMETZ{}", render(precision)),
+        PostgresType::Boolean => "BOOLEAN".to_owned(),
+        PostgresType::Bit(length) => format!("BIT{}", render(length)),
+        PostgresType::VarBit(length) => format!("VARBIT{}", render(length)),
+        PostgresType::UUID => "UUID".to_owned(),
+        PostgresType::Xml => "XML".to_owned(),
+        PostgresType::JSON => "JSON".to_owned(),
+        PostgresType::JSONB => "JSONB".to_owned(),
+    }
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'|\\"#).unwrap());
 
     STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "\\$0")
 }
 
+/// The access method to render in `CREATE INDEX ... USING`. BTree is the default, so we leave it out.
+fn index_method(algorithm: IndexAlgorithm) -> Option<&'static str> {
+    match algorithm {
+        IndexAlgorithm::BTree => None,
+        IndexAlgorithm::Hash => Some("HASH"),
+        IndexAlgorithm::Gin => Some("GIN"),
+        IndexAlgorithm::Gist => Some("GIST"),
+        IndexAlgorithm::Brin => Some("BRIN"),
+    }
+}
+
+/// The text search configuration of fulltext indexes. It must match the configuration of the
+/// full-text searches of the query engine (`FULLTEXT_SEARCH_CONFIG` in sql-query-connector),
+/// otherwise Postgres can't use the index for them.
+const FULLTEXT_SEARCH_CONFIG: &str = "english";
+
+fn index_columns<'a>(index: &IndexWalker<'a>) -> Vec<IndexColumn<'a>> {
+    let text_search_config = match index.index_type() {
+        IndexType::Fulltext => Some(FULLTEXT_SEARCH_CONFIG),
+        _ => None,
+    };
+
+    index
+        .columns()
+        .map(|c| IndexColumn {
+            name: c.name().into(),
+            descending: index.column_is_descending(c.name()),
+            text_search_config,
+        })
+        .collect()
+}
+
 fn render_alter_column(
     renderer: &PostgresFlavour,
     columns: &Pair<ColumnWalker<'_>>,
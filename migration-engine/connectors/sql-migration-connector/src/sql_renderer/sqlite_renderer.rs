@@ -24,6 +24,7 @@ impl SqlRenderer for SqliteFlavour {
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => unreachable!("Fulltext index on SQLite"),
         };
         let index_name = self.quote(index.name());
         let table_reference = self.quote(index.table().name());
@@ -50,10 +51,11 @@ impl SqlRenderer for SqliteFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} ON UPDATE CASCADE",
+            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} {on_update_action}",
             referenced_table = self.quote(foreign_key.referenced_table().name()),
             referenced_fields = referenced_fields,
-            on_delete_action = render_on_delete(foreign_key.on_delete_action())
+            on_delete_action = render_on_delete(foreign_key.on_delete_action()),
+            on_update_action = render_on_update(foreign_key.on_update_action()),
         )
     }
 
@@ -61,6 +63,8 @@ impl SqlRenderer for SqliteFlavour {
         match (default.kind(), family) {
             (DefaultKind::DBGENERATED(val), _) => val.as_str().into(),
             (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
+            | (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json)
+            | (DefaultKind::VALUE(PrismaValue::Json(val)), ColumnTypeFamily::Json)
             | (DefaultKind::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
                 format!("'{}'", escape_quotes(&val)).into()
             }
@@ -75,6 +79,10 @@ impl SqlRenderer for SqliteFlavour {
         }
     }
 
+    fn render_add_check_constraint(&self, _table: &TableWalker<'_>, _check_constraint: &CheckConstraint) -> String {
+        unreachable!("AddCheckConstraint on SQLite")
+    }
+
     fn render_add_foreign_key(&self, _foreign_key: &ForeignKeyWalker<'_>) -> String {
         unreachable!("AddForeignKey on SQLite")
     }
@@ -106,6 +114,9 @@ impl SqlRenderer for SqliteFlavour {
                 TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on SQLite"),
                 TableChange::DropColumn(_) => unreachable!("DropColumn on SQLite"),
                 TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
+                TableChange::RenameColumn { .. } => {
+                    unreachable!("RenameColumn on SQLite: tables with renamed columns are redefined")
+                }
             };
         }
 
@@ -137,7 +148,21 @@ impl SqlRenderer for SqliteFlavour {
                         ForeignKeyAction::SetNull => sql_ddl::sqlite::ForeignKeyAction::SetNull,
                         ForeignKeyAction::SetDefault => sql_ddl::sqlite::ForeignKeyAction::SetDefault,
                     }),
-                    on_update: Some(sql_ddl::sqlite::ForeignKeyAction::Cascade),
+                    on_update: Some(match fk.on_update_action() {
+                        ForeignKeyAction::NoAction => sql_ddl::sqlite::ForeignKeyAction::NoAction,
+                        ForeignKeyAction::Restrict => sql_ddl::sqlite::ForeignKeyAction::Restrict,
+                        ForeignKeyAction::Cascade => sql_ddl::sqlite::ForeignKeyAction::Cascade,
+                        ForeignKeyAction::SetNull => sql_ddl::sqlite::ForeignKeyAction::SetNull,
+                        ForeignKeyAction::SetDefault => sql_ddl::sqlite::ForeignKeyAction::SetDefault,
+                    }),
+                })
+                .collect(),
+            check_constraints: table
+                .check_constraints()
+                .iter()
+                .map(|check| sql_ddl::sqlite::CheckConstraint {
+                    name: check.name.as_str().into(),
+                    expression: check.expression.as_str().into(),
                 })
                 .collect(),
         };
@@ -151,6 +176,10 @@ impl SqlRenderer for SqliteFlavour {
         create_table.to_string()
     }
 
+    fn render_drop_check_constraint(&self, _table: &TableWalker<'_>, _check_constraint: &CheckConstraint) -> String {
+        unreachable!("render_drop_check_constraint on SQLite")
+    }
+
     fn render_drop_enum(&self, _: &EnumWalker<'_>) -> Vec<String> {
         Vec::new()
     }
@@ -221,9 +250,9 @@ fn render_column_type(t: &ColumnType) -> &'static str {
         ColumnTypeFamily::Decimal => "REAL",
         ColumnTypeFamily::Int => "INTEGER",
         ColumnTypeFamily::BigInt => "INTEGER",
-        ColumnTypeFamily::String => "TEXT",
+        // SQLite has no JSON type, its JSON functions work on text.
+        ColumnTypeFamily::String | ColumnTypeFamily::Json => "TEXT",
         ColumnTypeFamily::Binary => "BLOB",
-        ColumnTypeFamily::Json => unreachable!("ColumnTypeFamily::Json on SQLite"),
         ColumnTypeFamily::Enum(_) => unreachable!("ColumnTypeFamily::Enum on SQLite"),
         ColumnTypeFamily::Uuid => unimplemented!("ColumnTypeFamily::Uuid on SQLite"),
         ColumnTypeFamily::Unsupported(x) => unimplemented!("{} not handled yet", x),
@@ -309,6 +338,8 @@ fn render_default<'a>(default: &'a DefaultValue, family: &ColumnTypeFamily) -> C
     match (default.kind(), family) {
         (DefaultKind::DBGENERATED(val), _) => val.as_str().into(),
         (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
+        | (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json)
+        | (DefaultKind::VALUE(PrismaValue::Json(val)), ColumnTypeFamily::Json)
         | (DefaultKind::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
             format!("'{}'", escape_quotes(&val)).into()
         }
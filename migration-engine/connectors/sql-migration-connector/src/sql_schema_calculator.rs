@@ -5,7 +5,8 @@ pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin};
 use datamodel::{
     walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
-    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ScalarType, ValueGenerator, ValueGeneratorFn,
+    Datamodel, DefaultValue, FieldArity, IndexAlgorithm, IndexDefinition, IndexType, ReferentialAction, ScalarType,
+    ValueGenerator, ValueGeneratorFn,
 };
 use prisma_value::PrismaValue;
 use sql_schema_describer::{self as sql, ColumnArity};
@@ -26,7 +27,10 @@ struct SqlSchemaCalculator<'a> {
 impl<'a> SqlSchemaCalculator<'a> {
     fn calculate_internal(&self) -> sql::SqlSchema {
         let mut tables = Vec::with_capacity(self.data_model.models().len());
-        let model_tables_without_inline_relations = self.calculate_model_tables();
+        let model_tables_without_inline_relations = self
+            .calculate_model_tables()
+            // Views are not managed by the migration engine.
+            .filter(|(model, _)| !model.is_view());
 
         for (model, mut table) in model_tables_without_inline_relations {
             self.add_inline_relations_to_model_tables(model, &mut table);
@@ -42,6 +46,7 @@ impl<'a> SqlSchemaCalculator<'a> {
             tables,
             enums,
             sequences,
+            views: Vec::new(),
         }
     }
 
@@ -98,6 +103,10 @@ impl<'a> SqlSchemaCalculator<'a> {
                     name: self.flavour.single_field_index_name(model.db_name(), f.db_name()),
                     columns: vec![f.db_name().to_owned()],
                     tpe: sql::IndexType::Unique,
+                    algorithm: sql::IndexAlgorithm::BTree,
+                    descending_columns: vec![],
+                    predicate: None,
+                    included_columns: vec![],
                 }
             });
 
@@ -111,6 +120,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let index_type = match index_definition.tpe {
                     IndexType::Unique => sql::IndexType::Unique,
                     IndexType::Normal => sql::IndexType::Normal,
+                    IndexType::Fulltext => sql::IndexType::Fulltext,
                 };
 
                 let index_name = index_definition.name.clone().unwrap_or_else(|| {
@@ -118,10 +128,23 @@ impl<'a> SqlSchemaCalculator<'a> {
                         "{table}.{fields}_{qualifier}",
                         table = &model.db_name(),
                         fields = referenced_fields.iter().map(|field| field.db_name()).join("_"),
-                        qualifier = if index_type.is_unique() { "unique" } else { "index" },
+                        qualifier = match index_type {
+                            sql::IndexType::Unique => "unique",
+                            sql::IndexType::Normal => "index",
+                            sql::IndexType::Fulltext => "fulltext",
+                        },
                     )
                 });
 
+                let algorithm = match index_definition.algorithm {
+                    _ if index_type == sql::IndexType::Fulltext => self.flavour.fulltext_index_algorithm(),
+                    IndexAlgorithm::BTree => sql::IndexAlgorithm::BTree,
+                    IndexAlgorithm::Hash => sql::IndexAlgorithm::Hash,
+                    IndexAlgorithm::Gin => sql::IndexAlgorithm::Gin,
+                    IndexAlgorithm::Gist => sql::IndexAlgorithm::Gist,
+                    IndexAlgorithm::Brin => sql::IndexAlgorithm::Brin,
+                };
+
                 sql::Index {
                     name: index_name,
                     // The model index definition uses the model field names, but the SQL Index
@@ -131,6 +154,24 @@ impl<'a> SqlSchemaCalculator<'a> {
                         .map(|field| field.db_name().to_owned())
                         .collect(),
                     tpe: index_type,
+                    algorithm,
+                    descending_columns: referenced_fields
+                        .iter()
+                        .filter(|field| index_definition.field_is_descending(field.name()))
+                        .map(|field| field.db_name().to_owned())
+                        .collect(),
+                    predicate: index_definition.predicate.clone(),
+                    included_columns: index_definition
+                        .included_fields
+                        .iter()
+                        .map(|field_name| {
+                            model
+                                .find_scalar_field(field_name)
+                                .expect("Unknown field in index directive.")
+                                .db_name()
+                                .to_owned()
+                        })
+                        .collect(),
                 }
             });
 
@@ -140,6 +181,14 @@ impl<'a> SqlSchemaCalculator<'a> {
                 indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                 primary_key,
                 foreign_keys: Vec::new(),
+                check_constraints: model
+                    .check_constraints()
+                    .iter()
+                    .map(|check| sql::CheckConstraint {
+                        name: check.name.clone(),
+                        expression: check.expression.clone(),
+                    })
+                    .collect(),
             };
 
             (model, table)
@@ -149,7 +198,9 @@ impl<'a> SqlSchemaCalculator<'a> {
     fn add_inline_relations_to_model_tables(&self, model: ModelWalker<'a>, table: &mut sql::Table) {
         let relation_fields = model
             .relation_fields()
-            .filter(|relation_field| !relation_field.is_virtual());
+            .filter(|relation_field| !relation_field.is_virtual())
+            // There can be no foreign keys pointing to views.
+            .filter(|relation_field| !relation_field.referenced_model().is_view());
 
         for relation_field in relation_fields {
             let fk_columns: Vec<String> = relation_field.referencing_columns().map(String::from).collect();
@@ -166,11 +217,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                     columns: fk_columns,
                     referenced_table: relation_field.referenced_model().database_name().to_owned(),
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
-                    on_update_action: sql::ForeignKeyAction::Cascade,
-                    on_delete_action: match column_arity(relation_field.arity()) {
-                        ColumnArity::Required => sql::ForeignKeyAction::Cascade,
-                        _ => sql::ForeignKeyAction::SetNull,
-                    },
+                    on_update_action: foreign_key_action(relation_field.on_update()),
+                    on_delete_action: foreign_key_action(relation_field.on_delete()),
                 };
 
                 table.foreign_keys.push(fk);
@@ -182,6 +230,7 @@ impl<'a> SqlSchemaCalculator<'a> {
     fn calculate_relation_tables<'b>(&'b self) -> impl Iterator<Item = sql::Table> + 'b {
         walk_relations(self.data_model)
             .filter_map(|relation| relation.as_m2m())
+            .filter(|m2m| !m2m.model_a_id().model().is_view() && !m2m.model_b_id().model().is_view())
             .map(move |m2m| {
                 let table_name = m2m.table_name();
                 let model_a_id = m2m.model_a_id();
@@ -213,11 +262,19 @@ impl<'a> SqlSchemaCalculator<'a> {
                         name: format!("{}_AB_unique", &table_name),
                         columns: vec![m2m.model_a_column().into(), m2m.model_b_column().into()],
                         tpe: sql::IndexType::Unique,
+                        algorithm: sql::IndexAlgorithm::BTree,
+                        descending_columns: vec![],
+                        predicate: None,
+                        included_columns: vec![],
                     },
                     sql::Index {
                         name: format!("{}_B_index", &table_name),
                         columns: vec![m2m.model_b_column().into()],
                         tpe: sql::IndexType::Normal,
+                        algorithm: sql::IndexAlgorithm::BTree,
+                        descending_columns: vec![],
+                        predicate: None,
+                        included_columns: vec![],
                     },
                 ];
 
@@ -242,6 +299,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                     indices: indexes,
                     primary_key: None,
                     foreign_keys,
+                    check_constraints: vec![],
                 }
             })
     }
@@ -297,6 +355,16 @@ fn scalar_type_for_field(field: &ScalarFieldWalker<'_>) -> ScalarType {
     }
 }
 
+fn foreign_key_action(action: ReferentialAction) -> sql::ForeignKeyAction {
+    match action {
+        ReferentialAction::Cascade => sql::ForeignKeyAction::Cascade,
+        ReferentialAction::Restrict => sql::ForeignKeyAction::Restrict,
+        ReferentialAction::NoAction => sql::ForeignKeyAction::NoAction,
+        ReferentialAction::SetNull => sql::ForeignKeyAction::SetNull,
+        ReferentialAction::SetDefault => sql::ForeignKeyAction::SetDefault,
+    }
+}
+
 fn column_arity(arity: FieldArity) -> sql::ColumnArity {
     match &arity {
         FieldArity::Required => sql::ColumnArity::Required,
@@ -334,6 +402,10 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[
         name: format!("{}_{}_unique", table.name, columns_suffix),
         columns: column_names.to_owned(),
         tpe: sql::IndexType::Unique,
+        algorithm: sql::IndexAlgorithm::BTree,
+        descending_columns: vec![],
+        predicate: None,
+        included_columns: vec![],
     };
 
     table.indices.push(index);
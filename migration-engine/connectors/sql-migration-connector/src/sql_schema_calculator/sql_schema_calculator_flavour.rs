@@ -22,6 +22,11 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
         unreachable!("unreachable enum_column_type")
     }
 
+    /// The access method of `@@fulltext` indexes.
+    fn fulltext_index_algorithm(&self) -> sql::IndexAlgorithm {
+        sql::IndexAlgorithm::BTree
+    }
+
     fn field_is_implicit_autoincrement_primary_key(&self, _field: &ScalarFieldWalker<'_>) -> bool {
         false
     }
@@ -1,5 +1,5 @@
 use super::SqlSchemaCalculatorFlavour;
-use crate::flavour::PostgresFlavour;
+use crate::{flavour::PostgresFlavour, sql_renderer::render_postgres_type};
 use datamodel::{walkers::ScalarFieldWalker, Datamodel, NativeTypeInstance, ScalarType, WithDatabaseName};
 use native_types::PostgresType;
 use sql_schema_describer::{self as sql};
@@ -23,47 +23,7 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
     ) -> sql::ColumnType {
         let postgres_type: PostgresType = native_type_instance.deserialize_native_type();
 
-        fn render(input: Option<u32>) -> String {
-            match input {
-                None => "".to_string(),
-                Some(arg) => format!("({})", arg),
-            }
-        }
-        fn render_decimal(input: Option<(u32, u32)>) -> String {
-            match input {
-                None => "".to_string(),
-                Some((precision, scale)) => format!("({}, {})", precision, scale),
-            }
-        }
-
-        let data_type = match postgres_type {
-            PostgresType::SmallInt => "SMALLINT".to_owned(),
-            PostgresType::Integer => "INTEGER".to_owned(),
-            PostgresType::BigInt => "BIGINT".to_owned(),
-            PostgresType::Decimal(precision) => format!("DECIMAL{}", render_decimal(precision)),
-            PostgresType::Numeric(precision) => format!("NUMERIC{}", render_decimal(precision)),
-            PostgresType::Real => "REAL".to_owned(),
-            PostgresType::DoublePrecision => "DOUBLE PRECISION".to_owned(),
-            PostgresType::SmallSerial => "SMALLSERIAL".to_owned(),
-            PostgresType::Serial => "SERIAL".to_owned(),
-            PostgresType::BigSerial => "BIGSERIAL".to_owned(),
-            PostgresType::VarChar(length) => format!("VARCHAR{}", render(length)),
-            PostgresType::Char(length) => format!("CHAR{}", render(length)),
-            PostgresType::Text => "TEXT".to_owned(),
-            PostgresType::ByteA => "BYTEA".to_owned(),
-            PostgresType::Timestamp(precision) => format!("TIMESTAMP{}", render(precision)),
-            PostgresType::Timestamptz(precision) => format!("TIMESTAMPTZ{}", render(precision)),
-            PostgresType::Date => "DATE".to_owned(),
-            PostgresType::Time(precision) => format!("TIME{}", render(precision)),
-            PostgresType::Timetz(precision) => format!("TIMETZ{}", render(precision)),
-            PostgresType::Boolean => "BOOLEAN".to_owned(),
-            PostgresType::Bit(length) => format!("BIT{}", render(length)),
-            PostgresType::VarBit(length) => format!("VARBIT{}", render(length)),
-            PostgresType::UUID => "UUID".to_owned(),
-            PostgresType::Xml => "XML".to_owned(),
-            PostgresType::JSON => "JSON".to_owned(),
-            PostgresType::JSONB => "JSONB".to_owned(),
-        };
+        let data_type = render_postgres_type(&postgres_type);
 
         sql::ColumnType {
             data_type: data_type.clone(),
@@ -79,6 +39,10 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
         }
     }
 
+    fn fulltext_index_algorithm(&self) -> sql::IndexAlgorithm {
+        sql::IndexAlgorithm::Gin
+    }
+
     fn enum_column_type(&self, field: &ScalarFieldWalker<'_>, db_name: &str) -> sql::ColumnType {
         let arity = super::super::column_arity(field.arity());
 
@@ -1,5 +1,6 @@
 mod column;
 mod enums;
+mod expressions;
 mod index;
 mod sql_schema_differ_flavour;
 mod table;
@@ -10,8 +11,9 @@ pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 use crate::{
     pair::Pair,
     sql_migration::{
-        self, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum, CreateIndex, CreateTable,
-        DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, RedefineTable, SqlMigrationStep, TableChange,
+        self, AddCheckConstraint, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum,
+        CreateIndex, CreateTable, DropCheckConstraint, DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable,
+        RedefineTable, RenameTable, SqlMigrationStep, TableChange,
     },
     SqlFlavour, SqlSchema,
 };
@@ -21,8 +23,21 @@ use sql_schema_describer::walkers::{EnumWalker, ForeignKeyWalker, TableWalker};
 use std::collections::HashSet;
 use table::TableDiffer;
 
-pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavour) -> Vec<SqlMigrationStep> {
-    let differ = SqlSchemaDiffer { schemas, flavour };
+/// A column that the next datamodel explicitly renames: the field keeps the name of the previous
+/// column, and is mapped to the name of the next column with `@map`.
+#[derive(Debug)]
+pub(crate) struct ColumnRename {
+    pub(crate) table: String,
+    pub(crate) previous_column: String,
+    pub(crate) next_column: String,
+}
+
+pub(crate) fn calculate_steps(
+    schemas: Pair<&SqlSchema>,
+    flavour: &dyn SqlFlavour,
+    column_renames: &[ColumnRename],
+) -> Vec<SqlMigrationStep> {
+    let differ = SqlSchemaDiffer::new(schemas, flavour, column_renames);
 
     let tables_to_redefine = differ.flavour.tables_to_redefine(&differ);
     let mut alter_indexes = differ.alter_indexes(&tables_to_redefine);
@@ -38,10 +53,13 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
 
     let drop_indexes = differ.drop_indexes(&tables_to_redefine);
     let create_indexes = differ.create_indexes(&tables_to_redefine);
+    let drop_check_constraints = differ.drop_check_constraints(&tables_to_redefine);
+    let add_check_constraints = differ.add_check_constraints(&tables_to_redefine);
 
     let redefine_tables = differ.redefine_tables(&tables_to_redefine);
     let add_foreign_keys = differ.add_foreign_keys(&tables_to_redefine);
     let create_enums = differ.create_enums();
+    let rename_tables = differ.rename_tables(&tables_to_redefine);
 
     let redefine_tables = Some(redefine_tables)
         .filter(|tables| !tables.is_empty())
@@ -53,11 +71,20 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
         .chain(differ.alter_enums().into_iter().map(SqlMigrationStep::AlterEnum))
         .chain(drop_indexes.into_iter().map(SqlMigrationStep::DropIndex))
         .chain(drop_foreign_keys.into_iter().map(SqlMigrationStep::DropForeignKey))
+        // Order matters: check constraints can refer to columns dropped or altered below.
+        .chain(
+            drop_check_constraints
+                .into_iter()
+                .map(SqlMigrationStep::DropCheckConstraint),
+        )
         .chain(
             differ
                 .alter_tables(&tables_to_redefine)
                 .map(SqlMigrationStep::AlterTable),
         )
+        // Order matters: the statements above refer to tables by their previous name, the ones
+        // below by their next name.
+        .chain(rename_tables.into_iter().map(SqlMigrationStep::RenameTable))
         // Order matters: we must drop enums before we create tables,
         // because the new tables might be named the same as the dropped
         // enum, and that conflicts on postgres.
@@ -74,6 +101,11 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
         // Order matters: this needs to come after create_indexes, because the foreign keys can depend on unique
         // indexes created there.
         .chain(add_foreign_keys.into_iter().map(SqlMigrationStep::AddForeignKey))
+        .chain(
+            add_check_constraints
+                .into_iter()
+                .map(SqlMigrationStep::AddCheckConstraint),
+        )
         .chain(alter_indexes.into_iter().map(|idxs| SqlMigrationStep::AlterIndex {
             table: idxs.as_ref().map(|(table, _)| *table),
             index: idxs.as_ref().map(|(_, idx)| *idx),
@@ -92,9 +124,28 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
 pub(crate) struct SqlSchemaDiffer<'a> {
     schemas: Pair<&'a SqlSchema>,
     flavour: &'a dyn SqlFlavour,
+    /// The indexes of the tables that were renamed, in (previous_schema, next_schema).
+    renamed_tables: Vec<Pair<usize>>,
+    column_renames: &'a [ColumnRename],
 }
 
 impl<'schema> SqlSchemaDiffer<'schema> {
+    fn new(
+        schemas: Pair<&'schema SqlSchema>,
+        flavour: &'schema dyn SqlFlavour,
+        column_renames: &'schema [ColumnRename],
+    ) -> Self {
+        let mut differ = SqlSchemaDiffer {
+            schemas,
+            flavour,
+            renamed_tables: Vec::new(),
+            column_renames,
+        };
+
+        differ.renamed_tables = differ.detect_renamed_tables();
+        differ
+    }
+
     #[allow(clippy::needless_lifetimes)] // clippy is wrong here
     fn create_tables<'a>(&'a self) -> impl Iterator<Item = CreateTable> + 'a {
         self.created_tables().map(|created_table| CreateTable {
@@ -150,6 +201,16 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         add_foreign_keys
     }
 
+    fn rename_tables(&self, tables_to_redefine: &HashSet<String>) -> Vec<RenameTable> {
+        // Redefined tables are recreated under their next name.
+        self.renamed_table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next().name()))
+            .map(|differ| RenameTable {
+                table_index: differ.tables.map(|t| t.table_index()),
+            })
+            .collect()
+    }
+
     fn alter_tables<'a, 'b: 'a>(
         &'a self,
         tables_to_redefine: &'b HashSet<String>,
@@ -205,6 +266,12 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
             let column_index = Pair::new(column_differ.previous.column_index(), column_differ.next.column_index());
 
+            if changes.only_renamed() {
+                let inferred = !table_differ.rename_is_explicit(&column_differ.previous, &column_differ.next);
+
+                return Some(TableChange::RenameColumn { column_index, inferred });
+            }
+
             match type_change {
                 Some(ColumnTypeChange::NotCastable) => {
                     Some(TableChange::DropAndRecreateColumn { column_index, changes })
@@ -339,6 +406,43 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         steps
     }
 
+    /// Check constraints on created and redefined tables are part of the CREATE TABLE statement.
+    fn add_check_constraints(&self, tables_to_redefine: &HashSet<String>) -> Vec<AddCheckConstraint> {
+        let mut steps = Vec::new();
+
+        for tables in self
+            .table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next().name()))
+        {
+            for check_constraint_index in tables.created_check_constraints() {
+                steps.push(AddCheckConstraint {
+                    table_index: tables.next().table_index(),
+                    check_constraint_index,
+                })
+            }
+        }
+
+        steps
+    }
+
+    fn drop_check_constraints(&self, tables_to_redefine: &HashSet<String>) -> Vec<DropCheckConstraint> {
+        let mut steps = Vec::new();
+
+        for tables in self
+            .table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next().name()))
+        {
+            for check_constraint_index in tables.dropped_check_constraints() {
+                steps.push(DropCheckConstraint {
+                    table_index: tables.previous().table_index(),
+                    check_constraint_index,
+                })
+            }
+        }
+
+        steps
+    }
+
     fn drop_indexes(&self, tables_to_redefine: &HashSet<String>) -> Vec<DropIndex> {
         let mut drop_indexes = HashSet::new();
 
@@ -411,18 +515,28 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     })
                     .collect();
 
+                let inferred_column_renames = differ
+                    .column_pairs()
+                    .filter(|columns| {
+                        columns.all_changes().0.only_renamed()
+                            && !differ.rename_is_explicit(&columns.previous, &columns.next)
+                    })
+                    .map(|columns| Pair::new(columns.previous.column_index(), columns.next.column_index()))
+                    .collect();
+
                 RedefineTable {
                     table_index: differ.tables.as_ref().map(|t| t.table_index()),
                     dropped_primary_key: SqlSchemaDiffer::drop_primary_key(&differ).is_some(),
                     added_columns: differ.added_columns().map(|col| col.column_index()).collect(),
                     dropped_columns: differ.dropped_columns().map(|col| col.column_index()).collect(),
                     column_pairs,
+                    inferred_column_renames,
                 }
             })
             .collect()
     }
 
-    /// An iterator over the tables that are present in both schemas.
+    /// An iterator over the tables that are present in both schemas, including renamed tables.
     fn table_pairs<'a>(&'a self) -> impl Iterator<Item = TableDiffer<'schema>> + 'a
     where
         'schema: 'a,
@@ -441,8 +555,44 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     .map(move |next_table| TableDiffer {
                         flavour: self.flavour,
                         tables: Pair::new(previous_table, next_table),
+                        column_renames: self.column_renames,
                     })
             })
+            .chain(self.renamed_table_pairs())
+    }
+
+    fn renamed_table_pairs<'a>(&'a self) -> impl Iterator<Item = TableDiffer<'schema>> + 'a
+    where
+        'schema: 'a,
+    {
+        self.renamed_tables.iter().map(move |table_index| TableDiffer {
+            flavour: self.flavour,
+            tables: self.schemas.tables(table_index),
+            column_renames: self.column_renames,
+        })
+    }
+
+    /// Tables that were renamed: a dropped table is paired with a created table when both have
+    /// the same columns, primary key and foreign keys. Ambiguous pairings are left as a dropped
+    /// and a created table.
+    fn detect_renamed_tables(&self) -> Vec<Pair<usize>> {
+        let candidates = self
+            .tables_dropped_by_name()
+            .flat_map(|previous| self.tables_created_by_name().map(move |next| Pair::new(previous, next)))
+            .filter(|tables| {
+                TableDiffer {
+                    flavour: self.flavour,
+                    tables: *tables,
+                    column_renames: self.column_renames,
+                }
+                .tables_are_identical()
+            })
+            .collect();
+
+        unambiguous_pairs(candidates, |table| table.table_index())
+            .into_iter()
+            .map(|tables| tables.map(|table| table.table_index()))
+            .collect()
     }
 
     fn alter_indexes(&self, tables_to_redefine: &HashSet<String>) -> Vec<Pair<(usize, usize)>> {
@@ -464,6 +614,24 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     }
 
     fn created_tables(&self) -> impl Iterator<Item = TableWalker<'_>> {
+        self.tables_created_by_name().filter(move |table| {
+            !self
+                .renamed_tables
+                .iter()
+                .any(|tables| *tables.next() == table.table_index())
+        })
+    }
+
+    fn dropped_tables<'a>(&'a self) -> impl Iterator<Item = TableWalker<'schema>> + 'a {
+        self.tables_dropped_by_name().filter(move |table| {
+            !self
+                .renamed_tables
+                .iter()
+                .any(|tables| *tables.previous() == table.table_index())
+        })
+    }
+
+    fn tables_created_by_name<'a>(&'a self) -> impl Iterator<Item = TableWalker<'schema>> + 'a {
         self.next_tables().filter(move |next_table| {
             !self.previous_tables().any(|previous_table| {
                 self.flavour
@@ -472,7 +640,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
-    fn dropped_tables<'a>(&'a self) -> impl Iterator<Item = TableWalker<'schema>> + 'a {
+    fn tables_dropped_by_name<'a>(&'a self) -> impl Iterator<Item = TableWalker<'schema>> + 'a {
         self.previous_tables().filter(move |previous_table| {
             !self.next_tables().any(|next_table| {
                 self.flavour
@@ -573,11 +741,36 @@ fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'
         .zip(next.referenced_column_names())
         .all(|(previous, next)| previous == next);
 
+    let same_referential_actions = previous.on_delete_action() == next.on_delete_action()
+        && previous.on_update_action() == next.on_update_action();
+
     references_same_table
         && references_same_column_count
         && constrains_same_column_count
         && constrains_same_columns
         && references_same_columns
+        && same_referential_actions
+}
+
+/// Keep the candidate pairs whose previous and next items are not part of any other candidate
+/// pair, identifying items by `index`.
+fn unambiguous_pairs<T: Copy>(candidates: Vec<Pair<T>>, index: impl Fn(&T) -> usize) -> Vec<Pair<T>> {
+    candidates
+        .iter()
+        .filter(|candidate| {
+            let previous_count = candidates
+                .iter()
+                .filter(|other| index(other.previous()) == index(candidate.previous()))
+                .count();
+            let next_count = candidates
+                .iter()
+                .filter(|other| index(other.next()) == index(candidate.next()))
+                .count();
+
+            previous_count == 1 && next_count == 1
+        })
+        .copied()
+        .collect()
 }
 
 fn enums_match(previous: &EnumWalker<'_>, next: &EnumWalker<'_>) -> bool {
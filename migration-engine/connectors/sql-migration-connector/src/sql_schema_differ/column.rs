@@ -180,6 +180,10 @@ impl ColumnChanges {
     pub(crate) fn column_was_renamed(&self) -> bool {
         self.changes.contains(ColumnChange::Renaming)
     }
+
+    pub(crate) fn only_renamed(&self) -> bool {
+        self.changes == BitFlags::from(ColumnChange::Renaming)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
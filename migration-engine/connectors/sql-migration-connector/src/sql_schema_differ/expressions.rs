@@ -0,0 +1,220 @@
+//! Comparison of SQL expressions, like partial index predicates and check constraints.
+//!
+//! Databases do not store these expressions as they were written: they add parentheses, quote
+//! identifiers, and sometimes rewrite them (Postgres turns `status = 'active'` into
+//! `((status)::text = 'active'::text)`). The expressions coming from the datamodel are compared
+//! with the introspected ones token by token, after the flavour has rewritten them into the form
+//! the database normalizes them to.
+
+use super::SqlSchemaDifferFlavour;
+use crate::flavour::SqlFlavour;
+
+/// A token of a SQL expression.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ExpressionToken {
+    /// A keyword, an identifier (quoted or not) or a number, lowercased.
+    Word(String),
+    /// A string literal, without its quotes and with its case preserved.
+    Literal(String),
+    /// An operator, like `=`, `<>` or `::`.
+    Operator(String),
+    /// Parentheses, brackets and commas.
+    Punctuation(char),
+}
+
+impl ExpressionToken {
+    pub(crate) fn is_word(&self, word: &str) -> bool {
+        matches!(self, ExpressionToken::Word(w) if w == word)
+    }
+
+    pub(crate) fn is_operator(&self, operator: &str) -> bool {
+        matches!(self, ExpressionToken::Operator(op) if op == operator)
+    }
+
+    pub(crate) fn is_punctuation(&self, punctuation: char) -> bool {
+        matches!(self, ExpressionToken::Punctuation(c) if *c == punctuation)
+    }
+}
+
+/// Compare two SQL expressions, ignoring whitespace, redundant parentheses, identifier quotes and
+/// the case of everything but string literals.
+pub(super) fn sql_expressions_match(previous: &str, next: &str, flavour: &dyn SqlFlavour) -> bool {
+    normalize(previous, flavour) == normalize(next, flavour)
+}
+
+fn normalize(expression: &str, flavour: &dyn SqlFlavour) -> Vec<ExpressionToken> {
+    let mut tokens = tokenize(expression, flavour.brackets_quote_identifiers());
+
+    flavour.normalize_sql_expression(&mut tokens);
+    remove_redundant_parentheses(&mut tokens);
+
+    tokens
+}
+
+/// Remove the parentheses that don't change how the expression is evaluated, the ones databases
+/// add around the whole expression, around single operands and around each condition:
+/// `((status = 'a') AND (amount > (0)))` becomes `status = 'a' AND amount > 0`. The parentheses
+/// that group conditions, like in `(a = 1 OR b = 2) AND c = 3`, are kept.
+pub(crate) fn remove_redundant_parentheses(tokens: &mut Vec<ExpressionToken>) {
+    while let Some((open, close)) = find_redundant_parentheses(tokens) {
+        tokens.remove(close);
+        tokens.remove(open);
+    }
+}
+
+fn find_redundant_parentheses(tokens: &[ExpressionToken]) -> Option<(usize, usize)> {
+    let mut opened = Vec::new();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        if token.is_punctuation('(') {
+            opened.push(idx);
+        } else if token.is_punctuation(')') {
+            let open = opened.pop()?;
+
+            if parentheses_are_redundant(tokens, open, idx) {
+                return Some((open, idx));
+            }
+        }
+    }
+
+    None
+}
+
+fn parentheses_are_redundant(tokens: &[ExpressionToken], open: usize, close: usize) -> bool {
+    let contents = &tokens[open + 1..close];
+    let before = open.checked_sub(1).map(|idx| &tokens[idx]);
+    let after = tokens.get(close + 1);
+
+    // A function call, or an `IN` list.
+    let is_argument_list = matches!(before, Some(ExpressionToken::Word(word)) if !is_connective(word));
+
+    match contents {
+        [] => false,
+        [_] | [ExpressionToken::Operator(_), _] => !is_argument_list,
+        _ => match (connective_precedence_before(before), connective_precedence_after(after)) {
+            (Some(before), Some(after)) => {
+                let precedence = connective_precedence(contents);
+
+                precedence <= before && precedence <= after
+            }
+            _ => false,
+        },
+    }
+}
+
+/// The loosest binding connective at the top level of the contents: `0` for a single condition,
+/// `1` for conditions joined with `AND` and `2` if any of them is joined with `OR`.
+fn connective_precedence(contents: &[ExpressionToken]) -> u8 {
+    let mut depth = 0;
+    let mut precedence = 0;
+
+    for token in contents {
+        match token {
+            token if token.is_punctuation('(') => depth += 1,
+            token if token.is_punctuation(')') => depth -= 1,
+            token if depth == 0 && token.is_word("or") => precedence = 2,
+            token if depth == 0 && token.is_word("and") => precedence = precedence.max(1),
+            _ => (),
+        }
+    }
+
+    precedence
+}
+
+/// The loosest connective the contents of parentheses can have for the parentheses to be redundant
+/// after this token, or `None` if they group an operand. Conditions bind tighter than `NOT`, `NOT`
+/// tighter than `AND` and `AND` tighter than `OR`.
+fn connective_precedence_before(token: Option<&ExpressionToken>) -> Option<u8> {
+    match token {
+        Some(token) if token.is_word("not") => Some(0),
+        token => connective_precedence_after(token),
+    }
+}
+
+/// Like `connective_precedence_before`, for the token following the parentheses.
+fn connective_precedence_after(token: Option<&ExpressionToken>) -> Option<u8> {
+    match token {
+        None => Some(2),
+        Some(token) if token.is_punctuation('(') || token.is_punctuation(')') || token.is_punctuation(',') => Some(2),
+        Some(token) if token.is_word("or") => Some(2),
+        Some(token) if token.is_word("and") => Some(1),
+        Some(_) => None,
+    }
+}
+
+fn is_connective(word: &str) -> bool {
+    matches!(word, "and" | "or" | "not")
+}
+
+fn tokenize(expression: &str, brackets_quote_identifiers: bool) -> Vec<ExpressionToken> {
+    const OPERATOR_CHARACTERS: &str = "<>=!~*/%+-|&^#@:";
+
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => (),
+            '\'' => tokens.push(ExpressionToken::Literal(take_quoted(&mut chars, '\''))),
+            '"' => tokens.push(ExpressionToken::Word(take_quoted(&mut chars, '"').to_lowercase())),
+            '`' => tokens.push(ExpressionToken::Word(take_quoted(&mut chars, '`').to_lowercase())),
+            '[' if brackets_quote_identifiers => {
+                tokens.push(ExpressionToken::Word(take_quoted(&mut chars, ']').to_lowercase()))
+            }
+            '(' | ')' | '[' | ']' | ',' => tokens.push(ExpressionToken::Punctuation(c)),
+            c if OPERATOR_CHARACTERS.contains(c) => {
+                let mut operator = c.to_string();
+
+                while let Some(next) = chars.peek().filter(|next| OPERATOR_CHARACTERS.contains(**next)) {
+                    operator.push(*next);
+                    chars.next();
+                }
+
+                // Postgres and SQL Server both store `!=` as `<>`.
+                if operator == "!=" {
+                    operator = "<>".to_owned();
+                }
+
+                tokens.push(ExpressionToken::Operator(operator));
+            }
+            c => {
+                let mut word = c.to_lowercase().collect::<String>();
+
+                while let Some(next) = chars
+                    .peek()
+                    .filter(|next| next.is_alphanumeric() || matches!(next, '_' | '.' | '$'))
+                {
+                    word.extend(next.to_lowercase());
+                    chars.next();
+                }
+
+                // A word directly followed by a string literal is a prefix: a character set
+                // introducer on MySQL (`_utf8mb4'a'`), or a unicode marker on SQL Server (`N'a'`).
+                if chars.peek() != Some(&'\'') {
+                    tokens.push(ExpressionToken::Word(word));
+                }
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Consume the characters up to the closing quote. A doubled closing quote is an escaped quote.
+fn take_quoted(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, closing_quote: char) -> String {
+    let mut contents = String::new();
+
+    while let Some(c) = chars.next() {
+        if c == closing_quote {
+            if chars.peek() == Some(&closing_quote) {
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        contents.push(c);
+    }
+
+    contents
+}
@@ -1,3 +1,5 @@
+use super::SqlSchemaDifferFlavour;
+use crate::flavour::SqlFlavour;
 use sql_schema_describer::walkers::{IndexWalker, TableWalker};
 
 pub(super) fn index_covers_fk(table: &TableWalker<'_>, index: &IndexWalker<'_>) -> bool {
@@ -5,3 +7,21 @@ pub(super) fn index_covers_fk(table: &TableWalker<'_>, index: &IndexWalker<'_>)
         .foreign_keys()
         .any(|fk| fk.constrained_column_names() == index.column_names())
 }
+
+/// Compare the properties of two indexes beyond their columns and type: the index algorithm, the
+/// sort order of the columns, the included columns and the predicate of partial indexes.
+pub(super) fn index_properties_match(
+    first: &IndexWalker<'_>,
+    second: &IndexWalker<'_>,
+    flavour: &dyn SqlFlavour,
+) -> bool {
+    first.algorithm() == second.algorithm()
+        && (!flavour.should_diff_index_sort_orders()
+            || first.descending_column_names() == second.descending_column_names())
+        && first.included_column_names() == second.included_column_names()
+        && match (first.predicate(), second.predicate()) {
+            (Some(first), Some(second)) => super::expressions::sql_expressions_match(first, second, flavour),
+            (None, None) => true,
+            _ => false,
+        }
+}
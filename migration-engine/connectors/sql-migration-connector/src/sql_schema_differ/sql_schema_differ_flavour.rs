@@ -1,4 +1,4 @@
-use super::{column::ColumnDiffer, ColumnTypeChange, SqlSchemaDiffer};
+use super::{column::ColumnDiffer, expressions::ExpressionToken, ColumnTypeChange, SqlSchemaDiffer};
 use crate::{pair::Pair, sql_migration::AlterEnum};
 use sql_schema_describer::walkers::IndexWalker;
 use std::collections::HashSet;
@@ -22,6 +22,16 @@ pub(crate) trait SqlSchemaDifferFlavour {
         true
     }
 
+    /// Whether `[` and `]` quote identifiers in SQL expressions, as on SQL Server.
+    fn brackets_quote_identifiers(&self) -> bool {
+        false
+    }
+
+    /// Rewrite the tokens of a SQL expression (a check constraint or an index predicate) into
+    /// the form the database stores it in, so expressions from the datamodel and from the
+    /// database can be compared.
+    fn normalize_sql_expression(&self, _tokens: &mut Vec<ExpressionToken>) {}
+
     /// Return whether a column's type needs to be migrated, and how.
     fn column_type_change(&self, differ: &ColumnDiffer<'_>) -> Option<ColumnTypeChange> {
         if differ.previous.column_type_family() != differ.next.column_type_family() {
@@ -42,6 +52,19 @@ pub(crate) trait SqlSchemaDifferFlavour {
         true
     }
 
+    /// Whether check constraints should be diffed. Databases that parse check constraints but do
+    /// not store them would otherwise get them added on every migration.
+    fn should_diff_check_constraints(&self) -> bool {
+        true
+    }
+
+    /// Whether the sort order of index columns should be diffed. Databases that parse `DESC` in
+    /// index definitions but ignore it would otherwise get the indexes recreated on every
+    /// migration.
+    fn should_diff_index_sort_orders(&self) -> bool {
+        true
+    }
+
     /// Whether the indexes of dropped tables should be dropped before the table
     /// is dropped.
     fn should_drop_indexes_from_dropped_tables(&self) -> bool {
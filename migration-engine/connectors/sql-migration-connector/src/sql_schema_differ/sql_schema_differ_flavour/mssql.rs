@@ -3,6 +3,7 @@ use crate::{
     flavour::{MssqlFlavour, SqlFlavour},
     sql_schema_differ::{
         column::{ColumnDiffer, ColumnTypeChange},
+        expressions::{self, ExpressionToken},
         SqlSchemaDiffer,
     },
 };
@@ -12,8 +13,19 @@ use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
 use std::collections::HashSet;
 
 impl SqlSchemaDifferFlavour for MssqlFlavour {
+    fn brackets_quote_identifiers(&self) -> bool {
+        true
+    }
+
+    fn normalize_sql_expression(&self, tokens: &mut Vec<ExpressionToken>) {
+        // SQL Server stores `x IN ('a', 'b')` as `([x]='a' OR [x]='b')`.
+        expressions::remove_redundant_parentheses(tokens);
+        expand_in_lists(tokens);
+    }
+
     fn should_skip_index_for_new_table(&self, index: &IndexWalker<'_>) -> bool {
-        index.index_type().is_unique()
+        // Unique constraints can't have included columns, these are created as separate indexes.
+        index.index_type().is_unique() && index.included_column_names().is_empty()
     }
 
     fn should_recreate_the_primary_key_on_column_recreate(&self) -> bool {
@@ -1125,3 +1137,57 @@ fn native_type_change_riskyness(previous: MsSqlType, next: MsSqlType) -> ColumnT
         },
     }
 }
+
+/// Turn `x IN (a, b)` into `(x = a OR x = b)`, and `x NOT IN (a, b)` into `(x <> a AND x <> b)`,
+/// for lists of single tokens.
+fn expand_in_lists(tokens: &mut Vec<ExpressionToken>) {
+    let mut idx = 1;
+
+    while idx + 2 < tokens.len() {
+        let negated = idx >= 2 && tokens[idx - 1].is_word("not");
+        let column_idx = if negated { idx - 2 } else { idx - 1 };
+
+        if !tokens[idx].is_word("in")
+            || !tokens[idx + 1].is_punctuation('(')
+            || !matches!(tokens[column_idx], ExpressionToken::Word(_))
+        {
+            idx += 1;
+            continue;
+        }
+
+        let column = tokens[column_idx].clone();
+        let mut values = vec![tokens[idx + 2].clone()];
+        let mut end = idx + 3;
+
+        while end + 1 < tokens.len() && tokens[end].is_punctuation(',') {
+            values.push(tokens[end + 1].clone());
+            end += 2;
+        }
+
+        if !tokens.get(end).map(|token| token.is_punctuation(')')).unwrap_or(false) {
+            idx += 1;
+            continue;
+        }
+
+        let (operator, connective) = if negated { ("<>", "and") } else { ("=", "or") };
+        let mut expanded = Vec::with_capacity(values.len() * 4 + 1);
+
+        expanded.push(ExpressionToken::Punctuation('('));
+
+        for (value_idx, value) in values.into_iter().enumerate() {
+            if value_idx > 0 {
+                expanded.push(ExpressionToken::Word(connective.to_owned()));
+            }
+
+            expanded.push(column.clone());
+            expanded.push(ExpressionToken::Operator(operator.to_owned()));
+            expanded.push(value);
+        }
+
+        expanded.push(ExpressionToken::Punctuation(')'));
+
+        let expanded_len = expanded.len();
+        tokens.splice(column_idx..=end, expanded);
+        idx = column_idx + expanded_len;
+    }
+}
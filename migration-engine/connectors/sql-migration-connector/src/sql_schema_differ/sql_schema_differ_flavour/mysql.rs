@@ -66,6 +66,14 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
         false
     }
 
+    fn should_diff_check_constraints(&self) -> bool {
+        !self.ignores_check_constraints()
+    }
+
+    fn should_diff_index_sort_orders(&self) -> bool {
+        !self.ignores_descending_indexes()
+    }
+
     fn should_ignore_json_defaults(&self) -> bool {
         true
     }
@@ -4,6 +4,7 @@ use crate::{
     pair::Pair,
     sql_migration::AlterEnum,
     sql_schema_differ::column::{ColumnDiffer, ColumnTypeChange},
+    sql_schema_differ::expressions::{self, ExpressionToken},
     sql_schema_differ::SqlSchemaDiffer,
 };
 use once_cell::sync::Lazy;
@@ -69,6 +70,15 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         }
     }
 
+    fn normalize_sql_expression(&self, tokens: &mut Vec<ExpressionToken>) {
+        // `pg_get_expr()` and `pg_get_constraintdef()` add casts everywhere, turn `IN` lists into
+        // array comparisons and `LIKE` into its operator form.
+        strip_casts(tokens);
+        expressions::remove_redundant_parentheses(tokens);
+        rewrite_array_comparisons(tokens);
+        rewrite_pattern_matching_operators(tokens);
+    }
+
     fn index_should_be_renamed(&self, pair: &Pair<IndexWalker<'_>>) -> bool {
         // Implements correct comparison for truncated index names.
         let (previous_name, next_name) = pair.as_ref().map(|idx| idx.name()).into_tuple();
@@ -93,3 +103,125 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         POSTGRES_IGNORED_TABLES.is_match(table_name)
     }
 }
+
+/// Remove the casts in `'active'::text`, `(0)::numeric(65,30)` or `'{}'::character varying[]`.
+///
+/// `pg_get_expr()` casts every literal and most columns, and there is no telling these implicit
+/// casts from the ones written in the datamodel. All casts are therefore stripped, from both
+/// sides of the comparison: two expressions that only differ in their casts, like `amount > 0` and
+/// `amount::bigint > 0`, are considered equal, and changing only a cast doesn't migrate the index
+/// or constraint.
+fn strip_casts(tokens: &mut Vec<ExpressionToken>) {
+    /// The words that can follow the first word of a multi-word type name, like `character
+    /// varying` or `timestamp without time zone`.
+    const TYPE_NAME_CONTINUATIONS: &[&str] = &["varying", "precision", "with", "without", "time", "zone"];
+
+    let mut iter = std::mem::take(tokens).into_iter().peekable();
+
+    while let Some(token) = iter.next() {
+        if !token.is_operator("::") {
+            tokens.push(token);
+            continue;
+        }
+
+        // The type name.
+        iter.next();
+
+        while iter
+            .peek()
+            .map(|next| TYPE_NAME_CONTINUATIONS.iter().any(|word| next.is_word(word)))
+            .unwrap_or(false)
+        {
+            iter.next();
+        }
+
+        // The type modifiers and array brackets.
+        for (open, close) in &[('(', ')'), ('[', ']')] {
+            while iter.peek().map(|next| next.is_punctuation(*open)).unwrap_or(false) {
+                for skipped in &mut iter {
+                    if skipped.is_punctuation(*close) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Turn `x = ANY (ARRAY['a', 'b'])` back into `x IN ('a', 'b')`, and `x <> ALL (ARRAY['a', 'b'])`
+/// into `x NOT IN ('a', 'b')`. Redundant parentheses, like the ones around the array once its cast
+/// is stripped, must have been removed beforehand.
+fn rewrite_array_comparisons(tokens: &mut Vec<ExpressionToken>) {
+    let mut idx = 0;
+
+    while idx + 4 < tokens.len() {
+        let replacement: &[&str] = match (&tokens[idx], &tokens[idx + 1]) {
+            (operator, quantifier) if operator.is_operator("=") && quantifier.is_word("any") => &["in"],
+            (operator, quantifier) if operator.is_operator("<>") && quantifier.is_word("all") => &["not", "in"],
+            _ => {
+                idx += 1;
+                continue;
+            }
+        };
+
+        if !tokens[idx + 2].is_punctuation('(')
+            || !tokens[idx + 3].is_word("array")
+            || !tokens[idx + 4].is_punctuation('[')
+        {
+            idx += 1;
+            continue;
+        }
+
+        let mut depth = 0;
+        let closing_bracket = tokens[idx + 4..].iter().position(|token| {
+            if token.is_punctuation('[') {
+                depth += 1;
+            } else if token.is_punctuation(']') {
+                depth -= 1;
+            }
+
+            depth == 0
+        });
+
+        if let Some(closing_bracket) = closing_bracket {
+            tokens.remove(idx + 4 + closing_bracket);
+        }
+
+        // The parenthesis around the array becomes the one of the list.
+        tokens.splice(
+            idx..idx + 5,
+            replacement
+                .iter()
+                .map(|word| ExpressionToken::Word((*word).to_owned()))
+                .chain(std::iter::once(ExpressionToken::Punctuation('('))),
+        );
+
+        idx += replacement.len() + 1;
+    }
+}
+
+/// Turn the `~~`, `~~*`, `!~~` and `!~~*` operators back into `LIKE`, `ILIKE`, `NOT LIKE` and
+/// `NOT ILIKE`.
+fn rewrite_pattern_matching_operators(tokens: &mut Vec<ExpressionToken>) {
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        let replacement: &[&str] = match &tokens[idx] {
+            ExpressionToken::Operator(operator) if operator == "~~" => &["like"],
+            ExpressionToken::Operator(operator) if operator == "~~*" => &["ilike"],
+            ExpressionToken::Operator(operator) if operator == "!~~" => &["not", "like"],
+            ExpressionToken::Operator(operator) if operator == "!~~*" => &["not", "ilike"],
+            _ => {
+                idx += 1;
+                continue;
+            }
+        };
+
+        tokens.splice(
+            idx..=idx,
+            replacement.iter().map(|word| ExpressionToken::Word((*word).to_owned())),
+        );
+
+        idx += replacement.len();
+    }
+}
@@ -12,6 +12,10 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
     fn column_type_change(&self, differ: &ColumnDiffer<'_>) -> Option<ColumnTypeChange> {
         match (differ.previous.column_type_family(), differ.next.column_type_family()) {
             (a, b) if a == b => None,
+            // Json columns are stored as text, and introspected back as strings.
+            (ColumnTypeFamily::String, ColumnTypeFamily::Json) | (ColumnTypeFamily::Json, ColumnTypeFamily::String) => {
+                None
+            }
             (_, ColumnTypeFamily::String) => Some(ColumnTypeChange::SafeCast),
             (_, _) => Some(ColumnTypeChange::RiskyCast),
         }
@@ -21,6 +25,10 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
         true
     }
 
+    fn table_should_be_ignored(&self, table_name: &str) -> bool {
+        table_name == SqliteFlavour::MIGRATION_LOCK_TABLE
+    }
+
     fn tables_to_redefine(&self, differ: &SqlSchemaDiffer<'_>) -> HashSet<String> {
         differ
             .table_pairs()
@@ -29,11 +37,15 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
                     || differ.dropped_primary_key().is_some()
                     || differ.dropped_columns().next().is_some()
                     || differ.added_columns().any(|col| col.arity().is_required())
+                    // This includes renamed columns, so RenameColumn steps are never rendered on SQLite
                     || differ.column_pairs().any(|columns| columns.all_changes().0.differs_in_something())
                     // ALTER INDEX does not exist on SQLite
                     || differ.index_pairs().any(|pair| self.index_should_be_renamed(&pair))
                     || differ.created_foreign_keys().next().is_some()
                     || differ.dropped_foreign_keys().next().is_some()
+                    // SQLite can't add or drop check constraints on existing tables
+                    || differ.created_check_constraints().next().is_some()
+                    || differ.dropped_check_constraints().next().is_some()
             })
             .map(|table| table.next().name().to_owned())
             .collect()
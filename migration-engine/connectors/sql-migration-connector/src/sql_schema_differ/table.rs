@@ -1,13 +1,14 @@
-use super::column::ColumnDiffer;
+use super::{column::ColumnDiffer, ColumnRename};
 use crate::{flavour::SqlFlavour, pair::Pair};
 use sql_schema_describer::{
     walkers::{ColumnWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    PrimaryKey,
+    CheckConstraint, PrimaryKey,
 };
 
 pub(crate) struct TableDiffer<'a> {
     pub(crate) flavour: &'a dyn SqlFlavour,
     pub(crate) tables: Pair<TableWalker<'a>>,
+    pub(crate) column_renames: &'a [ColumnRename],
 }
 
 impl<'schema> TableDiffer<'schema> {
@@ -18,6 +19,7 @@ impl<'schema> TableDiffer<'schema> {
                     .find(|next_column| columns_match(&previous_column, next_column))
                     .map(|next_column| (previous_column, next_column))
             })
+            .chain(self.renamed_columns().into_iter().map(|columns| columns.into_tuple()))
             .map(move |(previous, next)| ColumnDiffer {
                 flavour: self.flavour,
                 previous,
@@ -26,6 +28,93 @@ impl<'schema> TableDiffer<'schema> {
     }
 
     pub(crate) fn dropped_columns<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
+        let renamed_columns = self.renamed_columns();
+
+        self.columns_dropped_by_name().filter(move |column| {
+            !renamed_columns
+                .iter()
+                .any(|columns| columns.previous().column_index() == column.column_index())
+        })
+    }
+
+    pub(crate) fn added_columns<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
+        let renamed_columns = self.renamed_columns();
+
+        self.columns_added_by_name().filter(move |column| {
+            !renamed_columns
+                .iter()
+                .any(|columns| columns.next().column_index() == column.column_index())
+        })
+    }
+
+    /// Columns that were renamed. A column without a match by name in `next` is paired with a
+    /// column without a match by name in `previous` when they only differ by name, and either the
+    /// datamodel maps the field to the new column name, or the pairing is unambiguous and the
+    /// names are similar. Other pairings are left as a dropped and an added column.
+    fn renamed_columns(&self) -> Vec<Pair<ColumnWalker<'schema>>> {
+        let candidates: Vec<Pair<ColumnWalker<'schema>>> = self
+            .columns_dropped_by_name()
+            .flat_map(|previous| self.columns_added_by_name().map(move |next| Pair::new(previous, next)))
+            .filter(|columns| {
+                let (previous, next) = columns.as_tuple();
+                let differ = ColumnDiffer {
+                    flavour: self.flavour,
+                    previous: *previous,
+                    next: *next,
+                };
+                let (changes, type_change) = differ.all_changes();
+
+                changes.only_renamed()
+                    && type_change.is_none()
+                    && previous.column_type_family() == next.column_type_family()
+                    && previous.is_part_of_primary_key() == next.is_part_of_primary_key()
+            })
+            .collect();
+
+        let (explicit, inferred): (Vec<_>, Vec<_>) = candidates
+            .into_iter()
+            .partition(|columns| self.rename_is_explicit(columns.previous(), columns.next()));
+
+        let inferred = inferred
+            .into_iter()
+            .filter(|columns| {
+                !explicit.iter().any(|renamed| {
+                    renamed.previous().column_index() == columns.previous().column_index()
+                        || renamed.next().column_index() == columns.next().column_index()
+                })
+            })
+            .collect();
+
+        let inferred = super::unambiguous_pairs(inferred, |column| column.column_index())
+            .into_iter()
+            .filter(|columns| column_names_are_similar(columns.previous().name(), columns.next().name()));
+
+        explicit.into_iter().chain(inferred).collect()
+    }
+
+    /// Whether the datamodel maps a field named like the `previous` column to the `next` column.
+    pub(crate) fn rename_is_explicit(&self, previous: &ColumnWalker<'_>, next: &ColumnWalker<'_>) -> bool {
+        self.column_renames.iter().any(|rename| {
+            rename.table == self.next().name()
+                && rename.previous_column == previous.name()
+                && rename.next_column == next.name()
+        })
+    }
+
+    /// Whether both tables have the same columns, primary key and foreign keys.
+    pub(crate) fn tables_are_identical(&self) -> bool {
+        self.dropped_columns().next().is_none()
+            && self.added_columns().next().is_none()
+            && self
+                .column_pairs()
+                .all(|columns| !columns.all_changes().0.differs_in_something())
+            && self.created_primary_key().is_none()
+            && self.dropped_primary_key().is_none()
+            && self.created_foreign_keys().next().is_none()
+            && self.dropped_foreign_keys().next().is_none()
+    }
+
+    fn columns_dropped_by_name<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
         self.previous_columns().filter(move |previous_column| {
             self.next_columns()
                 .find(|next_column| columns_match(previous_column, next_column))
@@ -33,7 +122,7 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
-    pub(crate) fn added_columns<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
+    fn columns_added_by_name<'a>(&'a self) -> impl Iterator<Item = ColumnWalker<'schema>> + 'a {
         self.next_columns().filter(move |next_column| {
             self.previous_columns()
                 .find(|previous_column| columns_match(previous_column, next_column))
@@ -57,11 +146,45 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    /// The indexes of the check constraints in the next table that are not in the previous table.
+    pub(crate) fn created_check_constraints<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        let previous_checks = self.previous().check_constraints();
+
+        self.next()
+            .check_constraints()
+            .iter()
+            .enumerate()
+            .filter(move |(_, next_check)| {
+                self.flavour.should_diff_check_constraints()
+                    && !previous_checks
+                        .iter()
+                        .any(|previous_check| check_constraints_match(previous_check, next_check, self.flavour))
+            })
+            .map(|(check_constraint_index, _)| check_constraint_index)
+    }
+
+    /// The indexes of the check constraints in the previous table that are not in the next table.
+    pub(crate) fn dropped_check_constraints<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        let next_checks = self.next().check_constraints();
+
+        self.previous()
+            .check_constraints()
+            .iter()
+            .enumerate()
+            .filter(move |(_, previous_check)| {
+                self.flavour.should_diff_check_constraints()
+                    && !next_checks
+                        .iter()
+                        .any(|next_check| check_constraints_match(previous_check, next_check, self.flavour))
+            })
+            .map(|(check_constraint_index, _)| check_constraint_index)
+    }
+
     pub(crate) fn created_indexes<'a>(&'a self) -> impl Iterator<Item = IndexWalker<'schema>> + 'a {
         self.next_indexes().filter(move |next_index| {
             !self
                 .previous_indexes()
-                .any(move |previous_index| indexes_match(&previous_index, next_index))
+                .any(move |previous_index| indexes_match(&previous_index, next_index, self.flavour))
         })
     }
 
@@ -69,14 +192,14 @@ impl<'schema> TableDiffer<'schema> {
         self.previous_indexes().filter(move |previous_index| {
             !self
                 .next_indexes()
-                .any(|next_index| indexes_match(previous_index, &next_index))
+                .any(|next_index| indexes_match(previous_index, &next_index, self.flavour))
         })
     }
 
     pub(crate) fn index_pairs<'a>(&'a self) -> impl Iterator<Item = Pair<IndexWalker<'schema>>> + 'a {
         self.previous_indexes().filter_map(move |previous_index| {
             self.next_indexes()
-                .find(|next_index| indexes_match(&previous_index, next_index))
+                .find(|next_index| indexes_match(&previous_index, next_index, self.flavour))
                 .map(|renamed_index| Pair::new(previous_index, renamed_index))
         })
     }
@@ -162,7 +285,49 @@ pub(crate) fn columns_match(a: &ColumnWalker<'_>, b: &ColumnWalker<'_>) -> bool
     a.name() == b.name()
 }
 
+/// Whether two column names look like versions of the same name: once lowercased and without
+/// underscores, one contains the other (`name` and `fullName`), or they are a few edits apart.
+fn column_names_are_similar(previous: &str, next: &str) -> bool {
+    let normalize = |name: &str| {
+        name.chars()
+            .filter(|c| *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect::<String>()
+    };
+    let (previous, next) = (normalize(previous), normalize(next));
+
+    previous.contains(&next)
+        || next.contains(&previous)
+        || edit_distance(&previous, &next) * 3 <= previous.len().max(next.len())
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = if a_char == *b_char { diagonal } else { diagonal + 1 };
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+fn check_constraints_match(previous: &CheckConstraint, next: &CheckConstraint, flavour: &dyn SqlFlavour) -> bool {
+    previous.name == next.name
+        && super::expressions::sql_expressions_match(&previous.expression, &next.expression, flavour)
+}
+
 /// Compare two SQL indexes and return whether they only differ by name.
-fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
-    first.column_names() == second.column_names() && first.index_type() == second.index_type()
+fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>, flavour: &dyn SqlFlavour) -> bool {
+    first.column_names() == second.column_names()
+        && first.index_type() == second.index_type()
+        && super::index::index_properties_match(first, second, flavour)
 }
@@ -5,18 +5,32 @@ pub use rpc::RpcApi;
 
 use crate::{commands::*, CoreResult};
 use migration_connector::MigrationConnector;
+use std::time::Duration;
 use tracing_futures::Instrument;
 
+/// How long commands changing the schema wait for the migration lock, unless configured otherwise.
+pub const DEFAULT_MIGRATION_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub struct MigrationApi<C>
 where
     C: MigrationConnector,
 {
     connector: C,
+    lock_timeout: Duration,
 }
 
 impl<C: MigrationConnector> MigrationApi<C> {
     pub fn new(connector: C) -> Self {
-        MigrationApi { connector }
+        MigrationApi {
+            connector,
+            lock_timeout: DEFAULT_MIGRATION_LOCK_TIMEOUT,
+        }
+    }
+
+    /// Set how long commands changing the schema wait for the migration lock.
+    pub fn with_lock_timeout(mut self, lock_timeout: Duration) -> Self {
+        self.lock_timeout = lock_timeout;
+        self
     }
 
     pub async fn handle_command<'a, E>(&'a self, input: &'a E::Input) -> CoreResult<E::Output>
@@ -26,6 +40,23 @@ impl<C: MigrationConnector> MigrationApi<C> {
         Ok(E::execute(input, self).await?)
     }
 
+    /// Like `handle_command`, but holding the migration lock, so that no other migration engine
+    /// changes the schema at the same time.
+    pub async fn handle_locked_command<'a, E>(&'a self, input: &'a E::Input) -> CoreResult<E::Output>
+    where
+        E: MigrationCommand,
+    {
+        self.connector.acquire_lock(self.lock_timeout).await?;
+
+        let result = self.handle_command::<E>(input).await;
+        let released = self.connector.release_lock().await;
+
+        let output = result?;
+        released?;
+
+        Ok(output)
+    }
+
     pub fn connector(&self) -> &C {
         &self.connector
     }
@@ -56,6 +87,7 @@ pub trait GenericApi: Send + Sync + 'static {
     ) -> CoreResult<MarkMigrationRolledBackOutput>;
     async fn plan_migration(&self, input: &PlanMigrationInput) -> CoreResult<PlanMigrationOutput>;
     async fn reset(&self, input: &()) -> CoreResult<()>;
+    async fn rollback_migration(&self, input: &RollbackMigrationInput) -> CoreResult<RollbackMigrationOutput>;
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
 }
 
@@ -68,7 +100,7 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
     }
 
     async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput> {
-        self.handle_command::<ApplyMigrationsCommand>(input)
+        self.handle_locked_command::<ApplyMigrationsCommand>(input)
             .instrument(tracing::info_span!("ApplyMigrations"))
             .await
     }
@@ -156,13 +188,19 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
     }
 
     async fn reset(&self, input: &()) -> CoreResult<()> {
-        self.handle_command::<ResetCommand>(input)
+        self.handle_locked_command::<ResetCommand>(input)
             .instrument(tracing::info_span!("Reset"))
             .await
     }
 
+    async fn rollback_migration(&self, input: &RollbackMigrationInput) -> CoreResult<RollbackMigrationOutput> {
+        self.handle_locked_command::<RollbackMigrationCommand>(input)
+            .instrument(tracing::info_span!("RollbackMigration"))
+            .await
+    }
+
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput> {
-        self.handle_command::<SchemaPushCommand>(input)
+        self.handle_locked_command::<SchemaPushCommand>(input)
             .instrument(tracing::info_span!("SchemaPush"))
             .await
     }
@@ -4,7 +4,7 @@ use enumflags2::BitFlags;
 use futures::{FutureExt, TryFutureExt};
 use jsonrpc_core::{types::error::Error as JsonRpcError, IoHandler, Params};
 use migration_connector::MigrationFeature;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 pub struct RpcApi {
     io_handler: jsonrpc_core::IoHandler<()>,
@@ -26,6 +26,7 @@ enum RpcCommand {
     MarkMigrationRolledBack,
     PlanMigration,
     Reset,
+    RollbackMigration,
     SchemaPush,
 }
 
@@ -45,6 +46,7 @@ impl RpcCommand {
             RpcCommand::MarkMigrationRolledBack => "markMigrationRolledBack",
             RpcCommand::PlanMigration => "planMigration",
             RpcCommand::Reset => "reset",
+            RpcCommand::RollbackMigration => "rollbackMigration",
             RpcCommand::SchemaPush => "schemaPush",
         }
     }
@@ -64,14 +66,29 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::MarkMigrationRolledBack,
     RpcCommand::PlanMigration,
     RpcCommand::Reset,
+    RpcCommand::RollbackMigration,
     RpcCommand::SchemaPush,
 ];
 
 impl RpcApi {
     pub async fn new(datamodel: &str, enabled_preview_features: BitFlags<MigrationFeature>) -> CoreResult<Self> {
+        Self::new_with_lock_timeout(
+            datamodel,
+            enabled_preview_features,
+            super::DEFAULT_MIGRATION_LOCK_TIMEOUT,
+        )
+        .await
+    }
+
+    /// Like `new`, setting how long commands changing the schema wait for the migration lock.
+    pub async fn new_with_lock_timeout(
+        datamodel: &str,
+        enabled_preview_features: BitFlags<MigrationFeature>,
+        lock_timeout: Duration,
+    ) -> CoreResult<Self> {
         let mut rpc_api = Self {
             io_handler: IoHandler::default(),
-            executor: crate::migration_api(datamodel, enabled_preview_features).await?,
+            executor: crate::migration_api_with_lock_timeout(datamodel, enabled_preview_features, lock_timeout).await?,
         };
 
         for cmd in AVAILABLE_COMMANDS {
@@ -132,6 +149,7 @@ impl RpcApi {
             RpcCommand::MarkMigrationRolledBack => render(executor.mark_migration_rolled_back(&params.parse()?).await?),
             RpcCommand::PlanMigration => render(executor.plan_migration(&params.parse()?).await?),
             RpcCommand::Reset => render(executor.reset(&()).await?),
+            RpcCommand::RollbackMigration => render(executor.rollback_migration(&params.parse()?).await?),
             RpcCommand::SchemaPush => render(executor.schema_push(&params.parse()?).await?),
         })
     }
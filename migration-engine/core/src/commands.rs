@@ -17,6 +17,7 @@ mod mark_migration_applied;
 mod mark_migration_rolled_back;
 mod plan_migration;
 mod reset;
+mod rollback_migration;
 mod schema_push;
 
 pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
@@ -38,4 +39,5 @@ pub use mark_migration_rolled_back::{
 };
 pub use plan_migration::{PlanMigrationCommand, PlanMigrationInput, PlanMigrationOutput};
 pub use reset::ResetCommand;
+pub use rollback_migration::{RollbackMigrationCommand, RollbackMigrationInput, RollbackMigrationOutput};
 pub use schema_push::{SchemaPushCommand, SchemaPushInput, SchemaPushOutput};
@@ -1,5 +1,8 @@
 use crate::{api::MigrationApi, CoreError, CoreResult};
-use migration_connector::{ConnectorError, MigrationDirectory, MigrationRecord, PersistenceNotInitializedError};
+use migration_connector::{
+    is_non_transactional_script, ConnectorError, DatabaseMigrationStepApplier, ImperativeMigrationsPersistence,
+    MigrationDirectory, MigrationRecord, PersistenceNotInitializedError, ScriptStep,
+};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use user_facing_errors::migration_engine::FoundFailedMigrations;
@@ -49,7 +52,45 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
             .await?
             .map_err(PersistenceNotInitializedError::into_connector_error)?;
 
-        detect_failed_migrations(&migrations_from_database)?;
+        let resumable_migration = detect_failed_migrations(&migrations_from_database, &migrations_from_filesystem)?;
+
+        let mut applied_migration_names: Vec<String> = Vec::new();
+
+        if let Some((failed_migration, directory)) = resumable_migration {
+            tracing::info!(
+                "Resuming `{}` after {} applied steps",
+                failed_migration.migration_name,
+                failed_migration.applied_steps_count
+            );
+
+            let script = directory.read_migration_script().map_err(ConnectorError::from)?;
+            let steps = applier.split_script_steps(&script);
+            let applied_steps_count = failed_migration.applied_steps_count as usize;
+
+            // The failed step is re-run from its first statement.
+            if let Some(failed_step) = steps.get(applied_steps_count) {
+                if !failed_step.can_be_resumed() {
+                    let details = format!(
+                        "The `{name}` migration failed on its step number {step}. That step has several statements and may have been partially applied, so it cannot be re-run. Apply the rest of the migration manually and mark it as applied, or revert it and mark it as rolled back.",
+                        name = failed_migration.migration_name,
+                        step = applied_steps_count + 1,
+                    );
+
+                    return Err(CoreError::user_facing(FoundFailedMigrations { details }));
+                }
+            }
+
+            apply_non_transactional_migration(
+                &steps,
+                &failed_migration.id,
+                applied_steps_count,
+                applier,
+                migration_persistence,
+            )
+            .await?;
+
+            applied_migration_names.push(failed_migration.migration_name.clone());
+        }
 
         // We are now on the Happy Path™.
         tracing::debug!("Migration history is OK, applying unapplied migrations.");
@@ -63,8 +104,6 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
             })
             .collect();
 
-        let mut applied_migration_names: Vec<String> = Vec::with_capacity(unapplied_migrations.len());
-
         for unapplied_migration in unapplied_migrations {
             let span = tracing::info_span!(
                 "Applying migration",
@@ -86,6 +125,14 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
                 .record_migration_started(unapplied_migration.migration_name(), &script)
                 .await?;
 
+            if is_non_transactional_script(&script) {
+                let steps = applier.split_script_steps(&script);
+
+                apply_non_transactional_migration(&steps, &migration_id, 0, applier, migration_persistence).await?;
+                applied_migration_names.push(unapplied_migration.migration_name().to_owned());
+                continue;
+            }
+
             match applier.apply_script(&script).await {
                 Ok(()) => {
                     tracing::debug!("Successfully applied the script.");
@@ -111,18 +158,60 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
     }
 }
 
-fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> CoreResult<()> {
+/// Apply the steps of a non-transactional migration one at a time, recording
+/// each of them in the migration persistence. The first
+/// `applied_steps_count` steps were applied by a previous attempt, and are
+/// skipped.
+async fn apply_non_transactional_migration<T>(
+    steps: &[ScriptStep],
+    migration_id: &str,
+    applied_steps_count: usize,
+    applier: &dyn DatabaseMigrationStepApplier<T>,
+    migration_persistence: &dyn ImperativeMigrationsPersistence,
+) -> CoreResult<()> {
+    for step in steps.iter().skip(applied_steps_count) {
+        if let Err(err) = applier.apply_step_statements(&step.statements).await {
+            tracing::debug!("Failed to apply a step of the non-transactional script.");
+
+            migration_persistence
+                .record_failed_step(migration_id, &err.to_string())
+                .await?;
+
+            return Err(err.into());
+        }
+
+        migration_persistence.record_successful_step(migration_id).await?;
+    }
+
+    migration_persistence.record_migration_finished(migration_id).await?;
+
+    Ok(())
+}
+
+/// Failed migrations prevent applying new migrations, except for a single
+/// non-transactional migration whose script was not edited since it failed:
+/// it is returned, to be resumed from the step that failed.
+fn detect_failed_migrations<'a>(
+    migrations_from_database: &'a [MigrationRecord],
+    migrations_from_filesystem: &'a [MigrationDirectory],
+) -> CoreResult<Option<(&'a MigrationRecord, &'a MigrationDirectory)>> {
     use std::fmt::Write as _;
 
     tracing::debug!("Checking for failed migrations.");
 
-    let mut failed_migrations = migrations_from_database
+    let failed_migrations: Vec<&MigrationRecord> = migrations_from_database
         .iter()
         .filter(|migration| migration.finished_at.is_none() && migration.rolled_back_at.is_none())
-        .peekable();
+        .collect();
 
-    if failed_migrations.peek().is_none() {
-        return Ok(());
+    match failed_migrations.as_slice() {
+        [] => return Ok(None),
+        [failed_migration] => {
+            if let Some(directory) = resumable_migration_directory(failed_migration, migrations_from_filesystem) {
+                return Ok(Some((failed_migration, directory)));
+            }
+        }
+        _ => (),
     }
 
     let mut details = String::new();
@@ -144,3 +233,21 @@ fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> Cor
 
     Err(CoreError::user_facing(FoundFailedMigrations { details }))
 }
+
+/// The directory of a failed migration that can be resumed: its script is
+/// non-transactional and unchanged since it was applied.
+fn resumable_migration_directory<'a>(
+    failed_migration: &MigrationRecord,
+    migrations_from_filesystem: &'a [MigrationDirectory],
+) -> Option<&'a MigrationDirectory> {
+    let directory = migrations_from_filesystem
+        .iter()
+        .find(|directory| directory.migration_name() == failed_migration.migration_name)?;
+    let script = directory.read_migration_script().ok()?;
+
+    if is_non_transactional_script(&script) && directory.matches_checksum(&failed_migration.checksum).ok()? {
+        Some(directory)
+    } else {
+        None
+    }
+}
@@ -1,4 +1,4 @@
-use super::MigrationCommand;
+use super::{LockFeedback, MigrationCommand};
 use crate::{api::MigrationApi, parse_datamodel, CoreError, CoreResult};
 use migration_connector::{DatabaseMigrationMarker, MigrationConnector};
 use serde::{Deserialize, Serialize};
@@ -20,6 +20,14 @@ pub struct CreateMigrationInput {
     pub migration_name: String,
     /// If true, always generate a migration, but do not apply.
     pub draft: bool,
+    /// Also check the generated migration for steps that would hold long
+    /// locks on existing tables.
+    #[serde(default)]
+    pub lint_locks: bool,
+    /// Avoid long locks on existing tables in the generated migration, where the
+    /// database supports it. The migration may then not run in a transaction.
+    #[serde(default)]
+    pub online: bool,
 }
 
 /// The output of the `createMigration` command.
@@ -28,6 +36,10 @@ pub struct CreateMigrationInput {
 pub struct CreateMigrationOutput {
     /// The name of the newly generated migration directory, if any.
     pub generated_migration_name: Option<String>,
+    /// Steps of the generated migration that would hold long locks on
+    /// existing tables, ranked by the size of the tables on the development
+    /// database. Empty unless `lintLocks` was set in the input.
+    pub lock_warnings: Vec<LockFeedback>,
 }
 
 #[async_trait::async_trait]
@@ -49,13 +61,18 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
         let previous_migrations = migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
-        let migration = database_migration_inferrer
+        let mut migration = database_migration_inferrer
             .infer_next_migration(&previous_migrations, &target_schema)
             .await?;
 
+        if input.online {
+            database_migration_inferrer.make_online(&mut migration);
+        }
+
         if migration.is_empty() && !input.draft {
             return Ok(CreateMigrationOutput {
                 generated_migration_name: None,
+                lock_warnings: Vec::new(),
             });
         }
 
@@ -63,6 +80,22 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
 
         let migration_script = applier.render_script(&migration, &destructive_change_diagnostics);
 
+        let lock_warnings = if input.lint_locks {
+            checker
+                .check_locks(&migration)
+                .await?
+                .into_iter()
+                .map(LockFeedback::from)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // Infer the down migration, reverting the changes of the migration.
+        let down_migration = database_migration_inferrer.infer_reverse_migration(&migration);
+        let down_migration_diagnostics = checker.pure_check(&down_migration);
+        let down_migration_script = applier.render_script(&down_migration, &down_migration_diagnostics);
+
         // Write the migration script to a file.
         let directory = migration_connector::create_migration_directory(
             &Path::new(&input.migrations_directory_path),
@@ -79,8 +112,18 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
                 )))
             })?;
 
+        directory
+            .write_down_migration_script(&down_migration_script, C::DatabaseMigration::FILE_EXTENSION)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::Error::new(err).context(format!(
+                    "Failed to write the down migration script to `{:?}`",
+                    directory.path(),
+                )))
+            })?;
+
         Ok(CreateMigrationOutput {
             generated_migration_name: Some(directory.migration_name().to_owned()),
+            lock_warnings,
         })
     }
 }
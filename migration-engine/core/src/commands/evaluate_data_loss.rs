@@ -1,6 +1,6 @@
 use super::MigrationCommand;
 use crate::{api::MigrationApi, parse_datamodel, CoreResult};
-use migration_connector::{list_migrations, MigrationConnector};
+use migration_connector::{list_migrations, LockSeverity, LockWarning, MigrationConnector};
 use serde::{Deserialize, Serialize};
 
 /// Development command for migrations. Evaluate the data loss induced by the
@@ -18,6 +18,14 @@ pub struct EvaluateDataLossInput {
     pub migrations_directory_path: String,
     /// The prisma schema to migrate to.
     pub prisma_schema: String,
+    /// Also check the migration for steps that would hold long locks on
+    /// existing tables.
+    #[serde(default)]
+    pub lint_locks: bool,
+    /// Avoid long locks on existing tables in the evaluated migration, where the
+    /// database supports it. The migration may then not run in a transaction.
+    #[serde(default)]
+    pub online: bool,
 }
 
 /// The output of the `evaluateDataLoss` command.
@@ -33,6 +41,9 @@ pub struct EvaluateDataLossOutput {
     /// Steps that cannot be executed on the local database in the migration
     /// that would be generated.
     pub unexecutable_steps: Vec<MigrationFeedback>,
+    /// Steps that would hold long locks on existing tables on the local
+    /// database. Empty unless `lintLocks` was set in the input.
+    pub lock_warnings: Vec<LockFeedback>,
 }
 
 /// A data loss warning or an unexecutable migration error, associated with the step that triggered it.
@@ -45,6 +56,28 @@ pub struct MigrationFeedback {
     pub step_index: usize,
 }
 
+/// A lock-safety warning, associated with the step that triggered it.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LockFeedback {
+    /// The human-readable message.
+    pub message: String,
+    /// The severity, ranked by the size of the locked table.
+    pub severity: LockSeverity,
+    /// The index of the step this pertains to.
+    pub step_index: usize,
+}
+
+impl From<LockWarning> for LockFeedback {
+    fn from(warning: LockWarning) -> Self {
+        LockFeedback {
+            message: warning.description,
+            severity: warning.severity,
+            step_index: warning.step_index,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl MigrationCommand for EvaluateDataLoss {
     type Input = EvaluateDataLossInput;
@@ -59,10 +92,14 @@ impl MigrationCommand for EvaluateDataLoss {
         let migrations_from_directory = list_migrations(input.migrations_directory_path.as_ref())?;
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
-        let migration = inferrer
+        let mut migration = inferrer
             .infer_next_migration(&migrations_from_directory, &target_schema)
             .await?;
 
+        if input.online {
+            inferrer.make_online(&mut migration);
+        }
+
         let rendered_migration_steps = applier
             .render_steps_pretty(&migration)?
             .into_iter()
@@ -89,10 +126,22 @@ impl MigrationCommand for EvaluateDataLoss {
             })
             .collect();
 
+        let lock_warnings = if input.lint_locks {
+            checker
+                .check_locks(&migration)
+                .await?
+                .into_iter()
+                .map(LockFeedback::from)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         Ok(EvaluateDataLossOutput {
             migration_steps: rendered_migration_steps,
             warnings,
             unexecutable_steps,
+            lock_warnings,
         })
     }
 }
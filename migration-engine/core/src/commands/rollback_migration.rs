@@ -0,0 +1,99 @@
+use super::MigrationCommand;
+use crate::{api::MigrationApi, CoreError, CoreResult};
+use migration_connector::{ConnectorError, MigrationConnector, PersistenceNotInitializedError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `rollbackMigration` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// The output of the `rollbackMigration` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationOutput {
+    /// The name of the migration that was rolled back, if any.
+    pub rolled_back_migration_name: Option<String>,
+}
+
+/// Apply the down migration script of the most recently applied migration,
+/// and mark that migration as rolled back in the migrations table. Migrations
+/// without a down migration script can't be rolled back.
+pub struct RollbackMigrationCommand;
+
+#[async_trait::async_trait]
+impl MigrationCommand for RollbackMigrationCommand {
+    type Input = RollbackMigrationInput;
+    type Output = RollbackMigrationOutput;
+
+    async fn execute<C: MigrationConnector>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output> {
+        let connector = engine.connector();
+        let applier = connector.database_migration_step_applier();
+        let persistence = connector.new_migration_persistence();
+
+        let migrations_from_database = persistence
+            .list_migrations()
+            .await?
+            .map_err(PersistenceNotInitializedError::into_connector_error)?;
+
+        let last_applied_migration = migrations_from_database
+            .iter()
+            .filter(|migration| migration.finished_at.is_some() && migration.rolled_back_at.is_none())
+            .max_by_key(|migration| migration.started_at);
+
+        let last_applied_migration = match last_applied_migration {
+            Some(migration) => migration,
+            None => {
+                tracing::info!("No applied migration to roll back.");
+
+                return Ok(RollbackMigrationOutput {
+                    rolled_back_migration_name: None,
+                });
+            }
+        };
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+
+        let migration_directory = migrations_from_filesystem
+            .iter()
+            .find(|directory| directory.migration_name() == last_applied_migration.migration_name)
+            .ok_or_else(|| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "The migration `{}` could not be found in the migrations directory.",
+                    last_applied_migration.migration_name
+                ))
+            })?;
+
+        if !migration_directory.has_down_migration_script() {
+            return Err(CoreError::Generic(anyhow::anyhow!(
+                "The migration `{}` has no down migration script (`down.sql`), so it cannot be rolled back.",
+                last_applied_migration.migration_name
+            )));
+        }
+
+        let script = migration_directory
+            .read_down_migration_script()
+            .map_err(ConnectorError::from)?;
+
+        tracing::info!(
+            script = script.as_str(),
+            "Rolling back `{}`",
+            last_applied_migration.migration_name
+        );
+
+        applier.apply_script(&script).await?;
+
+        persistence
+            .mark_migration_rolled_back_by_id(&last_applied_migration.id)
+            .await?;
+
+        Ok(RollbackMigrationOutput {
+            rolled_back_migration_name: Some(last_applied_migration.migration_name.clone()),
+        })
+    }
+}
@@ -25,13 +25,23 @@ use datamodel::{
 };
 use migration_connector::{features, ConnectorError, MigrationFeature};
 use sql_migration_connector::SqlMigrationConnector;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use user_facing_errors::{common::InvalidDatabaseString, migration_engine::DeprecatedProviderArray, KnownError};
 
 /// Top-level constructor for the migration engine API.
 pub async fn migration_api(
     datamodel: &str,
     enabled_preview_features: BitFlags<MigrationFeature>,
+) -> CoreResult<Arc<dyn api::GenericApi>> {
+    migration_api_with_lock_timeout(datamodel, enabled_preview_features, api::DEFAULT_MIGRATION_LOCK_TIMEOUT).await
+}
+
+/// Like `migration_api`, setting how long commands changing the schema wait for the migration
+/// lock held by another migration engine.
+pub async fn migration_api_with_lock_timeout(
+    datamodel: &str,
+    enabled_preview_features: BitFlags<MigrationFeature>,
+    lock_timeout: Duration,
 ) -> CoreResult<Arc<dyn api::GenericApi>> {
     let config = parse_configuration(datamodel)?;
     let features = features::from_config(&config);
@@ -87,7 +97,7 @@ pub async fn migration_api(
         x => unimplemented!("Connector {} is not supported yet", x),
     };
 
-    let api = api::MigrationApi::new(connector);
+    let api = api::MigrationApi::new(connector).with_lock_timeout(lock_timeout);
 
     Ok(Arc::new(api))
 }
@@ -1,7 +1,7 @@
 use pretty_assertions::assert_eq;
 use sql_schema_describer::{
-    Column, ColumnTypeFamily, DefaultKind, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index, IndexType,
-    PrimaryKey, SqlSchema, Table,
+    Column, ColumnTypeFamily, DefaultKind, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index, IndexAlgorithm,
+    IndexType, PrimaryKey, SqlSchema, Table,
 };
 
 pub(crate) type AssertionResult<T> = Result<T, anyhow::Error>;
@@ -162,6 +162,29 @@ impl<'a> TableAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_check_constraints_count(self, n: usize) -> AssertionResult<Self> {
+        let check_count = self.0.check_constraints.len();
+        anyhow::ensure!(
+            check_count == n,
+            anyhow::anyhow!("Expected {} check constraints, found {}.", n, check_count)
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_has_check_constraint(self, name: &str) -> AssertionResult<Self> {
+        let matching_check = self.0.check_constraints.iter().any(|check| check.name == name);
+
+        anyhow::ensure!(
+            matching_check,
+            "Assertion failed. Could not find check constraint `{}` on {}.",
+            name,
+            self.0.name
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_fk_on_columns<F>(self, columns: &[&str], fk_assertions: F) -> AssertionResult<Self>
     where
         F: FnOnce(ForeignKeyAssertion<'a>) -> AssertionResult<ForeignKeyAssertion<'a>>,
@@ -541,6 +564,28 @@ impl<'a> ForeignKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_on_delete(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_delete_action == action,
+            "Assertion failed: expected foreign key to {:?} on delete, found {:?}.",
+            action,
+            self.0.on_delete_action,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_on_update(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_update_action == action,
+            "Assertion failed: expected foreign key to {:?} on update, found {:?}.",
+            action,
+            self.0.on_update_action,
+        );
+
+        Ok(self)
+    }
 }
 
 pub struct IndexAssertion<'a>(&'a Index);
@@ -563,4 +608,34 @@ impl<'a> IndexAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_is_fulltext(self) -> AssertionResult<Self> {
+        assert_eq!(self.0.tpe, IndexType::Fulltext);
+
+        Ok(self)
+    }
+
+    pub fn assert_algorithm(self, algorithm: IndexAlgorithm) -> AssertionResult<Self> {
+        assert_eq!(self.0.algorithm, algorithm);
+
+        Ok(self)
+    }
+
+    pub fn assert_descending_columns(self, columns: &[&str]) -> AssertionResult<Self> {
+        assert_eq!(self.0.descending_columns, columns);
+
+        Ok(self)
+    }
+
+    pub fn assert_predicate(self, predicate: Option<&str>) -> AssertionResult<Self> {
+        assert_eq!(self.0.predicate.as_deref(), predicate);
+
+        Ok(self)
+    }
+
+    pub fn assert_included_columns(self, columns: &[&str]) -> AssertionResult<Self> {
+        assert_eq!(self.0.included_columns, columns);
+
+        Ok(self)
+    }
 }
@@ -7,9 +7,10 @@ mod list_migration_directories;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
 mod reset;
+mod rollback_migration;
 mod schema_push;
 
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 
 pub use apply_migrations::ApplyMigrations;
 pub use create_migration::CreateMigration;
@@ -17,6 +18,7 @@ pub use diagnose_migration_history::DiagnoseMigrationHistory;
 pub use evaluate_data_loss::EvaluateDataLoss;
 pub use mark_migration_applied::MarkMigrationApplied;
 pub use reset::Reset;
+pub use rollback_migration::RollbackMigration;
 pub use schema_push::SchemaPush;
 
 use self::{dev_diagnostic::DevDiagnostic, mark_migration_rolled_back::MarkMigrationRolledBack};
@@ -46,6 +48,7 @@ use test_setup::*;
 /// connectors.
 pub struct TestApi {
     database: Quaint,
+    url: String,
     api: MigrationApi<SqlMigrationConnector>,
     tags: BitFlags<Tags>,
 }
@@ -59,6 +62,21 @@ impl TestApi {
         &self.database
     }
 
+    /// Open another connection to the test database, with a session of its own.
+    pub async fn new_connection(&self) -> anyhow::Result<Quaint> {
+        Ok(Quaint::new(&self.url).await?)
+    }
+
+    /// Another migration engine on the test database, waiting `lock_timeout` for the migration lock.
+    pub async fn migration_api_with_lock_timeout(
+        &self,
+        lock_timeout: Duration,
+    ) -> anyhow::Result<MigrationApi<SqlMigrationConnector>> {
+        let connector = SqlMigrationConnector::new(&self.url, BitFlags::empty()).await?;
+
+        Ok(MigrationApi::new(connector).with_lock_timeout(lock_timeout))
+    }
+
     pub fn is_sqlite(&self) -> bool {
         self.tags.contains(Tags::Sqlite)
     }
@@ -170,6 +188,10 @@ impl TestApi {
         Reset::new(&self.api)
     }
 
+    pub fn rollback_migration<'a>(&'a self, migrations_directory: &'a TempDir) -> RollbackMigration<'a> {
+        RollbackMigration::new(&self.api, migrations_directory)
+    }
+
     pub fn schema_push(&self, dm: impl Into<String>) -> SchemaPush<'_> {
         SchemaPush::new(&self.api, dm.into())
     }
@@ -296,6 +318,7 @@ pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -309,6 +332,7 @@ pub async fn mysql_5_6_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -322,6 +346,7 @@ pub async fn mysql_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -335,6 +360,7 @@ pub async fn mysql_mariadb_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -348,6 +374,7 @@ pub async fn postgres9_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -361,6 +388,7 @@ pub async fn postgres_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -374,6 +402,7 @@ pub async fn postgres11_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -386,6 +415,7 @@ pub async fn postgres12_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -398,6 +428,7 @@ pub async fn postgres13_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -410,6 +441,7 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         database: connector.quaint().clone(),
+        url: sqlite_test_url(db_name),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
@@ -434,6 +466,7 @@ async fn mssql_test_api(connection_string: String, args: TestAPIArgs) -> TestApi
 
     TestApi {
         database: connector.quaint().clone(),
+        url: connection_string,
         api: MigrationApi::new(connector),
         tags: args.test_tag,
     }
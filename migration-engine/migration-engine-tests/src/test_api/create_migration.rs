@@ -1,7 +1,8 @@
 use anyhow::Context;
+use migration_connector::LockSeverity;
 use migration_core::{commands::CreateMigrationInput, commands::CreateMigrationOutput, GenericApi};
 use pretty_assertions::assert_eq;
-use std::path::Path;
+use std::{borrow::Cow, path::Path};
 use tempfile::TempDir;
 
 use crate::AssertionResult;
@@ -11,6 +12,8 @@ pub struct CreateMigration<'a> {
     schema: &'a str,
     migrations_directory: &'a TempDir,
     draft: bool,
+    lint_locks: bool,
+    online: bool,
     name: &'a str,
 }
 
@@ -21,6 +24,8 @@ impl<'a> CreateMigration<'a> {
             schema,
             migrations_directory,
             draft: false,
+            lint_locks: false,
+            online: false,
             name,
         }
     }
@@ -31,6 +36,18 @@ impl<'a> CreateMigration<'a> {
         self
     }
 
+    pub fn lint_locks(mut self, lint_locks: bool) -> Self {
+        self.lint_locks = lint_locks;
+
+        self
+    }
+
+    pub fn online(mut self, online: bool) -> Self {
+        self.online = online;
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<CreateMigrationAssertion<'a>> {
         let output = self
             .api
@@ -39,6 +56,8 @@ impl<'a> CreateMigration<'a> {
                 prisma_schema: self.schema.to_owned(),
                 draft: self.draft,
                 migration_name: self.name.to_owned(),
+                lint_locks: self.lint_locks,
+                online: self.online,
             })
             .await?;
 
@@ -118,6 +137,24 @@ impl<'a> CreateMigrationAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_lock_warnings(self, lock_warnings: &[(Cow<'_, str>, LockSeverity, usize)]) -> AssertionResult<Self> {
+        let actual: Vec<(Cow<'_, str>, LockSeverity, usize)> = self
+            .output
+            .lock_warnings
+            .iter()
+            .map(|warning| (warning.message.as_str().into(), warning.severity, warning.step_index))
+            .collect();
+
+        anyhow::ensure!(
+            actual == lock_warnings,
+            "Expected lock warnings {:#?}, got {:#?}.",
+            lock_warnings,
+            actual
+        );
+
+        Ok(self)
+    }
+
     pub fn output(&self) -> &CreateMigrationOutput {
         &self.output
     }
@@ -167,4 +204,14 @@ impl MigrationAssertion<'_> {
 
         Ok(self)
     }
+
+    pub fn assert_down_contents(self, expected_contents: &str) -> AssertionResult<Self> {
+        let down_migration_file_path = self.path.join("down.sql");
+        let contents: String = std::fs::read_to_string(&down_migration_file_path)
+            .with_context(|| format!("Trying to read down migration file at {:?}", down_migration_file_path))?;
+
+        assert_eq!(expected_contents, contents);
+
+        Ok(self)
+    }
 }
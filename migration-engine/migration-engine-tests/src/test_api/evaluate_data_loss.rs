@@ -1,4 +1,5 @@
 use crate::AssertionResult;
+use migration_connector::LockSeverity;
 use migration_core::{
     commands::{EvaluateDataLossInput, EvaluateDataLossOutput},
     GenericApi,
@@ -11,6 +12,8 @@ pub struct EvaluateDataLoss<'a> {
     api: &'a dyn GenericApi,
     migrations_directory: &'a TempDir,
     prisma_schema: String,
+    lint_locks: bool,
+    online: bool,
 }
 
 impl<'a> EvaluateDataLoss<'a> {
@@ -19,15 +22,31 @@ impl<'a> EvaluateDataLoss<'a> {
             api,
             migrations_directory,
             prisma_schema,
+            lint_locks: false,
+            online: false,
         }
     }
 
+    pub fn lint_locks(mut self, lint_locks: bool) -> Self {
+        self.lint_locks = lint_locks;
+
+        self
+    }
+
+    pub fn online(mut self, online: bool) -> Self {
+        self.online = online;
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<EvaluateDataLossAssertion<'a>> {
         let output = self
             .api
             .evaluate_data_loss(&EvaluateDataLossInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
                 prisma_schema: self.prisma_schema,
+                lint_locks: self.lint_locks,
+                online: self.online,
             })
             .await?;
 
@@ -148,6 +167,24 @@ impl<'a> EvaluateDataLossAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_lock_warnings(self, lock_warnings: &[(Cow<'_, str>, LockSeverity, usize)]) -> AssertionResult<Self> {
+        let actual: Vec<(Cow<'_, str>, LockSeverity, usize)> = self
+            .output
+            .lock_warnings
+            .iter()
+            .map(|warning| (warning.message.as_str().into(), warning.severity, warning.step_index))
+            .collect();
+
+        anyhow::ensure!(
+            actual == lock_warnings,
+            "Expected lock warnings {:#?}, got {:#?}.",
+            lock_warnings,
+            actual
+        );
+
+        Ok(self)
+    }
+
     pub fn into_output(self) -> EvaluateDataLossOutput {
         self.output
     }
@@ -0,0 +1,61 @@
+use migration_core::{commands::RollbackMigrationInput, commands::RollbackMigrationOutput, CoreResult, GenericApi};
+use tempfile::TempDir;
+
+use crate::AssertionResult;
+
+#[must_use = "This struct does nothing on its own. See RollbackMigration::send()"]
+pub struct RollbackMigration<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+}
+
+impl<'a> RollbackMigration<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir) -> Self {
+        RollbackMigration {
+            api,
+            migrations_directory,
+        }
+    }
+
+    pub async fn send(self) -> CoreResult<RollbackMigrationAssertion<'a>> {
+        let output = self
+            .api
+            .rollback_migration(&RollbackMigrationInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+            })
+            .await?;
+
+        Ok(RollbackMigrationAssertion {
+            output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct RollbackMigrationAssertion<'a> {
+    output: RollbackMigrationOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for RollbackMigrationAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RollbackMigrationAssertion {{ .. }}")
+    }
+}
+
+impl<'a> RollbackMigrationAssertion<'a> {
+    pub fn assert_rolled_back_migration(self, name: Option<&str>) -> AssertionResult<Self> {
+        let found_name = self.output.rolled_back_migration_name.as_ref().map(|name| &name[15..]);
+
+        anyhow::ensure!(
+            found_name == name,
+            "Assertion failed. The rolled back migration does not match the expectations. ({:?} vs {:?})",
+            found_name,
+            name
+        );
+
+        Ok(self)
+    }
+}
@@ -239,3 +239,226 @@ async fn migrations_should_succeed_on_an_uninitialized_nonempty_database_with_po
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn non_transactional_migrations_are_resumed_from_the_failed_step(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+    api.insert("Cat")
+        .value("id", 2)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String @unique
+            age     Int?
+        }
+    "#;
+
+    api.create_migration("second-migration", dm2, &migrations_directory)
+        .online(true)
+        .send()
+        .await?;
+
+    // The unique index cannot be built on the duplicate names.
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    let second = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    second
+        .assert_migration_name("second-migration")?
+        .assert_applied_steps_count(1)?
+        .assert_failed()?;
+
+    api.database().raw_cmd(r#"DELETE FROM "Cat" WHERE "id" = 2"#).await?;
+
+    // The column was already added, it must not be added again.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    let second = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    second
+        .assert_migration_name("second-migration")?
+        .assert_applied_steps_count(2)?
+        .assert_success()?;
+
+    Ok(())
+}
+
+// The hand-written scripts do not qualify table names with the schema, which SQL Server requires here.
+#[test_each_connector(ignore("mssql_2017", "mssql_2019"))]
+async fn non_transactional_scripts_are_split_at_step_markers_and_resumed(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?;
+
+    // The string literal contains a semicolon at the end of a line, and a line
+    // that looks like a comment.
+    let script = indoc::indoc! {r#"
+        -- non-transactional
+        -- step: CreateTable
+        CREATE TABLE dogs (id INTEGER PRIMARY KEY, name VARCHAR(100));
+
+        -- step: Insert
+        INSERT INTO dogs (id, name) VALUES (1, 'semi;
+        -- not a comment');
+
+        -- step: Insert
+        INSERT INTO dogs (id, name) VALUES (2, 'Rex');
+
+        -- step: Insert
+        INSERT INTO dogs (id, name) VALUES (2, 'Rex again');
+    "#};
+
+    api.create_migration("hand-written", dm, &migrations_directory)
+        .draft(true)
+        .send()
+        .await?
+        .modify_migration(|contents| *contents = script.to_owned())?;
+
+    // The last insert violates the primary key.
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    api.imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap()
+        .assert_migration_name("hand-written")?
+        .assert_applied_steps_count(3)?
+        .assert_failed()?;
+
+    api.database().raw_cmd("DELETE FROM dogs WHERE id = 2").await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["hand-written"])?;
+
+    api.imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap()
+        .assert_applied_steps_count(4)?
+        .assert_success()?;
+
+    let names: Vec<String> = api
+        .database()
+        .query_raw("SELECT name FROM dogs ORDER BY id", &[])
+        .await?
+        .into_iter()
+        .map(|row| row.at(0).and_then(|name| name.to_string()).unwrap())
+        .collect();
+
+    assert_eq!(names, &["semi;\n-- not a comment", "Rex again"]);
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("mssql_2017", "mssql_2019"))]
+async fn partially_applied_steps_are_not_rerun(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?;
+
+    let script = indoc::indoc! {r#"
+        -- non-transactional
+        -- step: CreateTable
+        CREATE TABLE dogs (id INTEGER PRIMARY KEY);
+
+        -- step: Insert
+        INSERT INTO dogs (id) VALUES (1);
+        -- statement
+        INSERT INTO dogs (id) VALUES (1);
+    "#};
+
+    api.create_migration("hand-written", dm, &migrations_directory)
+        .draft(true)
+        .send()
+        .await?
+        .modify_migration(|contents| *contents = script.to_owned())?;
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    // The first insert of the failed step was applied, the step must not be re-run.
+    let error = api
+        .apply_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("cannot be re-run"), "{}", error);
+
+    let count = api
+        .database()
+        .query_raw("SELECT COUNT(*) FROM dogs", &[])
+        .await?
+        .into_single()?
+        .at(0)
+        .and_then(|count| count.as_i64())
+        .unwrap();
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::*;
 use indoc::indoc;
+use migration_connector::LockSeverity;
 use migration_engine_tests::{test_each_connector, TestResult};
 
 #[test_each_connector]
@@ -403,3 +404,133 @@ async fn create_enum_renders_correctly(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn online_migrations_create_indexes_concurrently(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm1, &dir).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+
+            @@index([name])
+        }
+    "#;
+
+    api.create_migration("index-cats", dm2, &dir)
+        .online(true)
+        .send()
+        .await?
+        .assert_migration_directories_count(2)?
+        .assert_migration("index-cats", |migration| {
+            let expected_script = indoc! {
+                r#"
+                -- non-transactional
+                -- step (rerunnable): CreateIndex
+                DROP INDEX CONCURRENTLY IF EXISTS "Cat.name_index";
+                -- statement
+                CREATE INDEX CONCURRENTLY "Cat.name_index" ON "Cat"("name");
+                "#
+            };
+
+            migration.assert_contents(expected_script)
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("postgres"))]
+async fn online_migrations_fall_back_to_regular_ddl_outside_of_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm1, &dir).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+
+            @@index([name])
+        }
+    "#;
+
+    let output = api
+        .create_migration("index-cats", dm2, &dir)
+        .online(true)
+        .send()
+        .await?
+        .into_output();
+
+    let script_path = dir
+        .path()
+        .join(output.generated_migration_name.unwrap())
+        .join("migration.sql");
+    let script = std::fs::read_to_string(&script_path)?;
+
+    assert!(!migration_connector::is_non_transactional_script(&script), "{}", script);
+
+    api.apply_migrations(&dir)
+        .send()
+        .await?
+        .assert_applied_migrations(&["create-cats", "index-cats"])?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |idx| idx.assert_is_not_unique())
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn create_migration_lints_locks_against_the_dev_database(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let directory = api.create_migrations_directory()?;
+    api.create_migration("1-initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+
+            @@index([name])
+        }
+    "#;
+
+    api.create_migration("2-index", dm2, &directory)
+        .lint_locks(true)
+        .send()
+        .await?
+        .assert_lock_warnings(&[("Creating the index `Cat.name_index` on the `Cat` table (1 row) will block writes to the table until the index is built. Consider creating it with `CREATE INDEX CONCURRENTLY` instead.".into(), LockSeverity::Low, 0)])?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use migration_connector::LockSeverity;
 use migration_core::commands::EvaluateDataLossOutput;
 use pretty_assertions::assert_eq;
 
@@ -21,6 +22,7 @@ async fn evaluate_data_loss_with_an_up_to_date_database_returns_no_step(api: &Te
         migration_steps: vec![],
         warnings: vec![],
         unexecutable_steps: vec![],
+        lock_warnings: vec![],
     };
 
     assert_eq!(output, expected_output);
@@ -289,3 +291,178 @@ async fn evaluate_data_loss_maps_warnings_to_the_right_steps(api: &TestApi) -> T
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn evaluate_data_loss_lints_locks_on_non_empty_tables(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let directory = api.create_migrations_directory()?;
+    api.create_migration("1-initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+
+            @@index([name])
+        }
+    "#;
+
+    api.evaluate_data_loss(&directory, dm2)
+        .lint_locks(true)
+        .send()
+        .await?
+        .assert_lock_warnings(&[])?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    api.evaluate_data_loss(&directory, dm2)
+        .lint_locks(true)
+        .send()
+        .await?
+        .assert_warnings(&[])?
+        .assert_lock_warnings(&[("Creating the index `Cat.name_index` on the `Cat` table (1 row) will block writes to the table until the index is built. Consider creating it with `CREATE INDEX CONCURRENTLY` instead.".into(), LockSeverity::Low, 0)])?;
+
+    api.evaluate_data_loss(&directory, dm2)
+        .send()
+        .await?
+        .assert_lock_warnings(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn evaluate_data_loss_lints_volatile_defaults_type_changes_and_foreign_keys(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            age Int
+        }
+
+        model Dog {
+            id Int @id
+            catId Int?
+        }
+    "#;
+
+    let directory = api.create_migrations_directory()?;
+    api.create_migration("1-initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    for id in 1..=2 {
+        api.insert("Cat").value("id", id).value("age", 4).result_raw().await?;
+        api.insert("Dog").value("id", id).result_raw().await?;
+    }
+
+    let volatile_default = r#"
+        model Cat {
+            id Int @id
+            age Int
+            score Float @default(dbgenerated("random()"))
+        }
+
+        model Dog {
+            id Int @id
+            catId Int?
+        }
+    "#;
+
+    api.evaluate_data_loss(&directory, volatile_default)
+        .lint_locks(true)
+        .send()
+        .await?
+        .assert_lock_warnings(&[("Adding the required column `score` with a volatile default to the `Cat` table (2 rows) will rewrite the table, blocking reads and writes until it is done.".into(), LockSeverity::Low, 0)])?;
+
+    let type_change = r#"
+        model Cat {
+            id Int @id
+            age String
+        }
+
+        model Dog {
+            id Int @id
+            catId Int?
+        }
+    "#;
+
+    api.evaluate_data_loss(&directory, type_change)
+        .lint_locks(true)
+        .send()
+        .await?
+        .assert_lock_warnings(&[("Changing the type of the column `age` on the `Cat` table (2 rows) from `INTEGER` to `TEXT` will rewrite the table, blocking reads and writes until it is done.".into(), LockSeverity::Low, 0)])?;
+
+    let foreign_key = r#"
+        model Cat {
+            id Int @id
+            age Int
+            dogs Dog[]
+        }
+
+        model Dog {
+            id Int @id
+            catId Int?
+            cat Cat? @relation(fields: [catId], references: [id])
+        }
+    "#;
+
+    api.evaluate_data_loss(&directory, foreign_key)
+        .lint_locks(true)
+        .send()
+        .await?
+        .assert_lock_warnings(&[("Adding a foreign key from the `Dog` table (2 rows) to the `Cat` table will validate all existing rows while blocking writes to both tables. Consider adding it as `NOT VALID` and validating it in a separate migration.".into(), LockSeverity::Low, 0)])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"), features("native_types"))]
+async fn evaluate_data_loss_does_not_lint_type_changes_that_keep_the_rows(api: &TestApi) -> TestResult {
+    let dm1 = api.native_types_datamodel(
+        r#"
+        model Cat {
+            id   Int    @id @test_db.Integer
+            name String @test_db.VarChar(10)
+            bio  String @test_db.VarChar(100)
+        }
+    "#,
+    );
+
+    let directory = api.create_migrations_directory()?;
+    api.create_migration("1-initial", &dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .value("bio", "Sleeps")
+        .result_raw()
+        .await?;
+
+    // Growing a length limit and turning a varchar into text keep the existing rows.
+    let dm2 = api.native_types_datamodel(
+        r#"
+        model Cat {
+            id   Int    @id @test_db.Integer
+            name String @test_db.VarChar(20)
+            bio  String @test_db.Text
+        }
+    "#,
+    );
+
+    api.evaluate_data_loss(&directory, dm2)
+        .lint_locks(true)
+        .send()
+        .await?
+        .assert_lock_warnings(&[])?;
+
+    Ok(())
+}
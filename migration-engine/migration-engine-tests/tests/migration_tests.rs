@@ -1717,6 +1717,10 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat_id_unique".into(),
         columns: vec!["cat_id".into()],
         tpe: IndexType::Unique,
+        algorithm: IndexAlgorithm::BTree,
+        descending_columns: vec![],
+        predicate: None,
+        included_columns: vec![],
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
@@ -0,0 +1,159 @@
+use migration_engine_tests::sql::*;
+
+// MySQL before 8.0.16 parses check constraints, but silently ignores them.
+#[test_each_connector(ignore("mysql"))]
+async fn check_constraints_must_be_created_and_dropped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check(name: "positivePrice", expression: "price > 0")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_check_constraints_count(1)?
+            .assert_has_check_constraint("positivePrice")
+    })?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check(name: "discountBelowPrice", expression: "discount < price")
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_check_constraints_count(1)?
+            .assert_has_check_constraint("discountBelowPrice")
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8", "mariadb"))]
+async fn check_constraints_must_be_dropped_on_mysql_8_and_mariadb(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check(name: "positivePrice", expression: "price > 0")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_has_check_constraint("positivePrice"))?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_check_constraints_count(0))?;
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("mysql"))]
+async fn check_constraints_with_literals_must_not_be_recreated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id     Int    @id
+            name   String
+            status String
+            price  Float
+
+            @@check(name: "knownStatus", expression: "status IN ('active', 'archived')")
+            @@check(name: "notDraft", expression: "name <> 'Draft' AND price >= 0.5")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_check_constraints_count(2))?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Changing the case of a literal is a real change.
+    let dm2 = r#"
+        model Product {
+            id     Int    @id
+            name   String
+            status String
+            price  Float
+
+            @@check(name: "knownStatus", expression: "status IN ('Active', 'Archived')")
+            @@check(name: "notDraft", expression: "name <> 'Draft' AND price >= 0.5")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8", "mariadb"))]
+async fn check_constraints_with_literals_must_not_be_recreated_on_mysql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id     Int    @id
+            status String
+
+            @@check(name: "knownStatus", expression: "status IN ('active', 'archived')")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_5_6"))]
+async fn check_constraints_are_not_migrated_on_mysql_versions_that_discard_them(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check(name: "positivePrice", expression: "price > 0")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
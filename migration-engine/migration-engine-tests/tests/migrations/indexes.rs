@@ -1,5 +1,5 @@
 use migration_engine_tests::sql::*;
-use sql_schema_describer::IndexType;
+use sql_schema_describer::{IndexAlgorithm, IndexType};
 
 #[test_each_connector]
 async fn index_on_compound_relation_fields_must_work(api: &TestApi) -> TestResult {
@@ -534,3 +534,296 @@ async fn new_index_with_same_name_as_index_from_dropped_table_works(api: &TestAp
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql", "postgres"))]
+async fn fulltext_indexes_must_be_migrated(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id      Int    @id
+            title   String
+            content String
+
+            @@fulltext([title, content], name: "titleAndContent")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["title", "content"], |idx| {
+            idx.assert_is_fulltext()?.assert_name("titleAndContent")
+        })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn fulltext_indexes_are_gin_indexes_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id      Int    @id
+            title   String
+            content String
+
+            @@fulltext([title, content])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["title", "content"], |idx| {
+            idx.assert_is_fulltext()?
+                .assert_algorithm(IndexAlgorithm::Gin)?
+                .assert_name("Post.title_content_fulltext")
+        })
+    })?;
+
+    let dm2 = r#"
+        model Post {
+            id      Int    @id
+            title   String
+            content String
+
+            @@fulltext([title])
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table
+            .assert_indexes_count(1)?
+            .assert_index_on_columns(&["title"], |idx| idx.assert_is_fulltext())
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres_12"))]
+async fn index_types_sort_orders_predicates_and_included_columns_must_be_migrated(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id        Int       @id
+            title     String
+            content   String
+            tags      Json
+            createdAt DateTime
+            deletedAt DateTime?
+
+            @@index([tags], type: Gin, name: "tagsIdx")
+            @@index([title, createdAt(sort: Desc)], where: "\"deletedAt\" IS NULL", include: [content], name: "liveIdx")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table
+            .assert_index_on_columns(&["tags"], |idx| idx.assert_algorithm(IndexAlgorithm::Gin))?
+            .assert_index_on_columns(&["title", "createdAt"], |idx| {
+                idx.assert_algorithm(IndexAlgorithm::BTree)?
+                    .assert_descending_columns(&["createdAt"])?
+                    .assert_predicate(Some("(\"deletedAt\" IS NULL)"))?
+                    .assert_included_columns(&["content"])
+            })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_the_predicate_of_a_partial_index_recreates_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id        Int       @id
+            title     String
+            deletedAt DateTime?
+
+            @@index([title], where: "\"deletedAt\" IS NULL", name: "liveIdx")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Post {
+            id        Int       @id
+            title     String
+            deletedAt DateTime?
+
+            @@index([title], where: "\"deletedAt\" IS NOT NULL", name: "liveIdx")
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["title"], |idx| {
+            idx.assert_predicate(Some("(\"deletedAt\" IS NOT NULL)"))
+        })
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019"))]
+async fn partial_index_predicates_with_literals_must_not_be_recreated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id     Int    @id
+            title  String
+            status String
+
+            @@index([title], where: "status IN ('published', 'archived') AND title <> 'Draft'", name: "listedIdx")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Changing the case of a literal is a real change.
+    let dm2 = r#"
+        model Post {
+            id     Int    @id
+            title  String
+            status String
+
+            @@index([title], where: "status IN ('published', 'archived') AND title <> 'DRAFT'", name: "listedIdx")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn descending_index_fields_must_be_migrated_on_mysql_8(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id        Int      @id
+            title     String
+            createdAt DateTime
+
+            @@index([title, createdAt(sort: Desc)], name: "recentIdx")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["title", "createdAt"], |idx| {
+            idx.assert_descending_columns(&["createdAt"])
+        })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_5_6", "mariadb"))]
+async fn descending_index_fields_do_not_cause_perpetual_diffs_where_mysql_ignores_them(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id        Int      @id
+            title     String
+            createdAt DateTime
+
+            @@index([title, createdAt(sort: Desc)], name: "recentIdx")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["title", "createdAt"], |idx| idx.assert_descending_columns(&[]))
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql_2017", "mssql_2019"))]
+async fn sort_orders_predicates_and_included_columns_must_be_migrated_on_sql_server(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id        Int       @id
+            title     String
+            content   String
+            createdAt DateTime
+            deletedAt DateTime?
+
+            @@index([title, createdAt(sort: Desc)], where: "[deletedAt] IS NULL", include: [content], name: "liveIdx")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["title", "createdAt"], |idx| {
+            idx.assert_descending_columns(&["createdAt"])?
+                .assert_predicate(Some("([deletedAt] IS NULL)"))?
+                .assert_included_columns(&["content"])
+        })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019"))]
+async fn changing_only_the_grouping_of_a_partial_index_predicate_recreates_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id    Int    @id
+            title String
+            views Int
+            likes Int
+            rank  Int
+
+            @@index([title], where: "(views > 10 OR likes > 5) AND rank = 1", name: "popularIdx")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Post {
+            id    Int    @id
+            title String
+            views Int
+            likes Int
+            rank  Int
+
+            @@index([title], where: "views > 10 OR (likes > 5 AND rank = 1)", name: "popularIdx")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
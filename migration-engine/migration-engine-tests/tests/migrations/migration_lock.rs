@@ -0,0 +1,99 @@
+use migration_engine_tests::sql::*;
+use quaint::{
+    prelude::{Queryable, SqlFamily},
+    single::Quaint,
+};
+use std::time::Duration;
+use user_facing_errors::{migration_engine::MigrationLockTimeout, UserFacingError};
+
+/// Takes the migration lock from `conn`, like another migration engine would, without waiting.
+async fn take_migration_lock(api: &TestApi, conn: &Quaint) -> TestResult {
+    let lock_name = migration_lock_name(api);
+
+    let result_set = match api.sql_family() {
+        SqlFamily::Postgres => {
+            conn.query_raw("SELECT pg_try_advisory_lock(hashtext($1))::int", &[lock_name.into()])
+                .await?
+        }
+        SqlFamily::Mysql => conn.query_raw("SELECT GET_LOCK(?, 0)", &[lock_name.into()]).await?,
+        SqlFamily::Mssql => {
+            conn.query_raw(
+                "DECLARE @result INT; EXEC @result = sp_getapplock @Resource = @P1, @LockMode = 'Exclusive', @LockOwner = 'Session', @LockTimeout = 0; SELECT CASE WHEN @result >= 0 THEN 1 ELSE 0 END;",
+                &[lock_name.into()],
+            )
+            .await?
+        }
+        SqlFamily::Sqlite => unreachable!("SQLite has no named locks"),
+    };
+
+    let granted = result_set.into_single()?.into_single()?.as_i64();
+
+    anyhow::ensure!(granted == Some(1), "The migration lock is held by another session.");
+
+    Ok(())
+}
+
+async fn release_migration_lock(api: &TestApi, conn: &Quaint) -> TestResult {
+    let lock_name = migration_lock_name(api);
+
+    match api.sql_family() {
+        SqlFamily::Postgres => {
+            conn.query_raw("SELECT pg_advisory_unlock(hashtext($1))", &[lock_name.into()])
+                .await?
+        }
+        SqlFamily::Mysql => conn.query_raw("SELECT RELEASE_LOCK(?)", &[lock_name.into()]).await?,
+        SqlFamily::Mssql => {
+            conn.query_raw(
+                "EXEC sp_releaseapplock @Resource = @P1, @LockOwner = 'Session'",
+                &[lock_name.into()],
+            )
+            .await?
+        }
+        SqlFamily::Sqlite => unreachable!("SQLite has no named locks"),
+    };
+
+    Ok(())
+}
+
+/// MySQL lock names are limited to 64 characters.
+fn migration_lock_name(api: &TestApi) -> String {
+    format!("prisma_migrate_{}", api.schema_name())
+        .chars()
+        .take(64)
+        .collect()
+}
+
+#[test_each_connector(tags("postgres", "mysql", "mssql_2017", "mssql_2019"))]
+async fn schema_push_waits_for_the_lock_of_another_engine(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id  Int @id
+        }
+    "#;
+
+    let other_engine = api.new_connection().await?;
+    take_migration_lock(api, &other_engine).await?;
+
+    let engine = api.migration_api_with_lock_timeout(Duration::from_millis(500)).await?;
+
+    let err = SchemaPush::new(&engine, dm.to_owned())
+        .send()
+        .await
+        .map(drop)
+        .unwrap_err()
+        .render_user_facing();
+
+    assert_eq!(err.as_known().unwrap().error_code, MigrationLockTimeout::ERROR_CODE);
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    release_migration_lock(api, &other_engine).await?;
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    // The lock is released once the command finished, so the other engine can take it again.
+    take_migration_lock(api, &other_engine).await?;
+    release_migration_lock(api, &other_engine).await?;
+
+    api.assert_schema().await?.assert_tables_count(1)?;
+
+    Ok(())
+}
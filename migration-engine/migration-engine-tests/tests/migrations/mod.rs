@@ -1,4 +1,5 @@
 mod apply_script_tests;
+mod check_constraints;
 mod dev_diagnostic_tests;
 mod enums;
 mod indexes;
@@ -6,9 +7,14 @@ mod json;
 mod mariadb;
 mod mark_migration_applied_tests;
 mod mark_migration_rolled_back_tests;
+mod migration_lock;
 mod mysql;
 mod postgres;
+mod relations;
+mod renames;
+mod rollback_migration_tests;
 mod sql;
 mod sqlite;
 mod squashing_tests;
 mod types;
+mod views;
@@ -0,0 +1,120 @@
+use migration_engine_tests::sql::*;
+use sql_schema_describer::ForeignKeyAction;
+
+#[test_each_connector(tags("postgres"))]
+async fn referential_actions_must_be_rendered_in_foreign_keys(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id], onDelete: Restrict, onUpdate: SetNull)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["userId"], |fk| {
+            fk.assert_references("User", &["id"])?
+                .assert_on_delete(ForeignKeyAction::Restrict)?
+                .assert_on_update(ForeignKeyAction::SetNull)
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn default_referential_actions_must_be_used_when_none_are_defined(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+            pets  Pet[]
+        }
+
+        model Post {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id])
+        }
+
+        model Pet {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["userId"], |fk| {
+                fk.assert_on_delete(ForeignKeyAction::Cascade)?
+                    .assert_on_update(ForeignKeyAction::Cascade)
+            })
+        })?
+        .assert_table("Pet", |table| {
+            table.assert_fk_on_columns(&["userId"], |fk| {
+                fk.assert_on_delete(ForeignKeyAction::SetNull)?
+                    .assert_on_update(ForeignKeyAction::Cascade)
+            })
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn changing_a_referential_action_must_recreate_the_foreign_key(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id], onDelete: NoAction, onUpdate: Restrict)
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["userId"], |fk| {
+            fk.assert_on_delete(ForeignKeyAction::NoAction)?
+                .assert_on_update(ForeignKeyAction::Restrict)
+        })
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
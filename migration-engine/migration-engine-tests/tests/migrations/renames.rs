@@ -0,0 +1,246 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector]
+async fn renaming_a_column_must_keep_its_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abcd")
+        .value("name", "Georgina")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id   String @id
+            name String @map("fullName")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table.assert_columns_count(2)?.assert_has_column("fullName")
+    })?;
+
+    api.dump_table("Test").await?.assert_single_row(|row| {
+        row.assert_text_value("id", "abcd")?
+            .assert_text_value("fullName", "Georgina")
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn renaming_a_model_must_keep_its_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "abcd")
+        .value("name", "Georgina")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Animal {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_has_table("Animal")?;
+
+    api.dump_table("Animal").await?.assert_single_row(|row| {
+        row.assert_text_value("id", "abcd")?
+            .assert_text_value("name", "Georgina")
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn ambiguous_column_renames_must_drop_and_add_columns(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String  @id
+            a  String?
+            b  String?
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abcd")
+        .value("a", "first")
+        .value("b", "second")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String  @id
+            c  String?
+            d  String?
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_warnings(&[
+        "You are about to drop the column `a` on the `Test` table, which still contains 1 non-null values.".into(),
+        "You are about to drop the column `b` on the `Test` table, which still contains 1 non-null values.".into(),
+    ])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn replacing_a_column_with_an_unrelated_one_drops_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model User {
+            id       String  @id
+            nickname String?
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("User")
+        .value("id", "abcd")
+        .value("nickname", "Gigi")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model User {
+            id             String  @id
+            favouriteColor String?
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_warnings(&[
+            "You are about to drop the column `nickname` on the `User` table, which still contains 1 non-null values."
+                .into(),
+        ])?
+        .assert_no_steps()?;
+
+    api.assert_schema().await?.assert_table("User", |table| {
+        table.assert_has_column("nickname")?.assert_columns_count(2)
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn inferred_column_renames_must_be_confirmed(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abcd")
+        .value("name", "Georgina")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id       String @id
+            fullName String
+        }
+    "#;
+
+    let warning = "The column `name` on the `Test` table will be renamed to `fullName`, keeping its data, because the columns only differ by name and the names are similar. If `fullName` is meant to be a new column, add it in a separate migration. To rename the column without this warning, keep the field name and map it to the new column name with `@map(\"fullName\")`.";
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_warnings(&[warning.into()])?
+        .assert_no_steps()?;
+
+    api.schema_push(dm2)
+        .force(true)
+        .send()
+        .await?
+        .assert_executable()?
+        .assert_has_executed_steps()?;
+
+    api.dump_table("Test").await?.assert_single_row(|row| {
+        row.assert_text_value("id", "abcd")?
+            .assert_text_value("fullName", "Georgina")
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn renaming_a_column_on_sqlite_redefines_the_table(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &dir).send().await?;
+
+    let dm2 = r#"
+        model Test {
+            id   String @id
+            name String @map("fullName")
+        }
+    "#;
+
+    let output = api.create_migration("rename", dm2, &dir).send().await?.into_output();
+
+    let script_path = dir
+        .path()
+        .join(output.generated_migration_name.unwrap())
+        .join("migration.sql");
+    let script = std::fs::read_to_string(&script_path)?;
+
+    assert!(
+        script.contains(r#"INSERT INTO "new_Test" ("id", "fullName") SELECT "id", "name" FROM "Test""#),
+        "{}",
+        script
+    );
+    assert!(!script.contains("RENAME COLUMN"), "{}", script);
+
+    Ok(())
+}
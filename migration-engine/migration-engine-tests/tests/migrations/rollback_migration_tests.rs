@@ -0,0 +1,147 @@
+use crate::*;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector(tags("postgres"))]
+async fn create_migration_writes_a_down_migration_script(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm, &dir)
+        .send()
+        .await?
+        .assert_migration("create-cats", |migration| {
+            migration.assert_down_contents(indoc! {
+                r#"
+                -- DropTable
+                DROP TABLE "Cat";
+                "#
+            })
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rollback_migration_with_no_applied_migration_is_a_noop(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.rollback_migration(&migrations_directory)
+        .send()
+        .await?
+        .assert_rolled_back_migration(None)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rollback_migration_reverts_the_last_applied_migration(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+    let persistence = api.imperative_migration_persistence();
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.create_migration("01init", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.create_migration("02add-dogs", dm2, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01init", "02add-dogs"])?;
+
+    api.rollback_migration(&migrations_directory)
+        .send()
+        .await?
+        .assert_rolled_back_migration(Some("02add-dogs"))?;
+
+    api.assert_schema().await?.assert_has_table("Cat")?;
+    let schema = api.describe_database().await?;
+    assert!(schema.table("Dog").is_err());
+
+    let migrations = persistence.list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 2);
+    assert!(migrations[0].rolled_back_at.is_none());
+    assert!(migrations[1].rolled_back_at.is_some());
+
+    // The rolled back migration gets applied again on the next deployment.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["02add-dogs"])?;
+
+    api.assert_schema().await?.assert_has_table("Dog")?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rollback_migration_without_a_down_migration_script_fails(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let output = api
+        .create_migration("01init", dm, &migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    let migration_directory = migrations_directory
+        .path()
+        .join(output.generated_migration_name.unwrap());
+    std::fs::remove_file(migration_directory.join("down.sql"))?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01init"])?;
+
+    let error = api
+        .rollback_migration(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("has no down migration script (`down.sql`), so it cannot be rolled back."),
+        "{}",
+        error
+    );
+
+    api.assert_schema().await?.assert_has_table("Cat")?;
+
+    Ok(())
+}
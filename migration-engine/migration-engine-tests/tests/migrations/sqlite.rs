@@ -1,4 +1,7 @@
 use migration_engine_tests::sql::*;
+use quaint::prelude::Queryable;
+use std::time::Duration;
+use user_facing_errors::{migration_engine::MigrationLockTimeout, UserFacingError};
 
 #[test_each_connector(tags("sqlite"))]
 async fn sqlite_must_recreate_indexes(api: &TestApi) -> TestResult {
@@ -88,3 +91,81 @@ async fn creating_a_model_with_a_non_autoincrement_id_column_is_idempotent(api:
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn schema_push_waits_for_the_migration_lock(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id  Int @id
+        }
+    "#;
+
+    // Another migration engine holds the lock.
+    api.database()
+        .raw_cmd(r#"CREATE TABLE "_prisma_migrations_lock" ("id" INTEGER PRIMARY KEY NOT NULL, "owner" TEXT NOT NULL, "acquired_at" INTEGER NOT NULL)"#)
+        .await?;
+    api.database()
+        .raw_cmd(r#"INSERT INTO "_prisma_migrations_lock" ("id", "owner", "acquired_at") VALUES (1, 'other', CAST((julianday('now') - 2440587.5) * 86400000 AS INTEGER))"#)
+        .await?;
+
+    let engine = api.migration_api_with_lock_timeout(Duration::from_millis(500)).await?;
+
+    let err = SchemaPush::new(&engine, dm.to_owned())
+        .send()
+        .await
+        .map(drop)
+        .unwrap_err()
+        .render_user_facing();
+
+    assert_eq!(err.as_known().unwrap().error_code, MigrationLockTimeout::ERROR_CODE);
+
+    api.database()
+        .raw_cmd(r#"DROP TABLE "_prisma_migrations_lock""#)
+        .await?;
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    // The lock is released once the command finished.
+    api.assert_schema().await?.assert_tables_count(1)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn the_migration_lock_of_a_crashed_engine_expires(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id  Int @id
+        }
+    "#;
+
+    // A migration engine took the lock long ago, and never released it.
+    api.database()
+        .raw_cmd(r#"CREATE TABLE "_prisma_migrations_lock" ("id" INTEGER PRIMARY KEY NOT NULL, "owner" TEXT NOT NULL, "acquired_at" INTEGER NOT NULL)"#)
+        .await?;
+    api.database()
+        .raw_cmd(r#"INSERT INTO "_prisma_migrations_lock" ("id", "owner", "acquired_at") VALUES (1, 'crashed', 0)"#)
+        .await?;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_tables_count(1)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn reset_keeps_the_migration_lock(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id  Int @id
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.reset().send().await?;
+
+    // The lock was released after the reset, along with its table.
+    api.assert_schema().await?.assert_tables_count(0)?;
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    Ok(())
+}
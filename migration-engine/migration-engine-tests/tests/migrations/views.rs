@@ -0,0 +1,65 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("postgres"))]
+async fn views_are_left_alone_by_migrations(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let create_view = format!(
+        r#"CREATE VIEW "{0}"."UserName" AS SELECT id, name FROM "{0}"."User""#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_view).await?;
+
+    let dm = r#"
+        model User {
+            id   Int    @id
+            name String
+        }
+
+        view UserName {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_table("UserName").is_none());
+    assert!(schema.get_view("UserName").is_some());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn views_in_the_schema_do_not_create_tables(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id   Int    @id
+            name String
+        }
+
+        view UserName {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_has_table("User")?
+        .assert_tables_count(1)?;
+
+    Ok(())
+}
@@ -43,6 +43,14 @@ pub trait ScalarCompare {
     where
         T: Into<PrismaValue>;
 
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
     fn less_than<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
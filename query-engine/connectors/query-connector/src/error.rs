@@ -20,6 +20,11 @@ impl ConnectorError {
                     constraint: constraint.to_owned(),
                 },
             )),
+            ErrorKind::CheckConstraintViolation { constraint } => Some(KnownError::new(
+                user_facing_errors::query_engine::CheckConstraintViolation {
+                    constraint: constraint.to_owned(),
+                },
+            )),
             ErrorKind::TableDoesNotExist { table } => {
                 Some(KnownError::new(user_facing_errors::query_engine::TableDoesNotExist {
                     table: table.clone(),
@@ -30,6 +35,16 @@ impl ConnectorError {
                     column: column.clone(),
                 }))
             }
+            ErrorKind::RowLockUnavailable(reason) => {
+                Some(KnownError::new(user_facing_errors::query_engine::RowLockUnavailable {
+                    reason: reason.clone(),
+                }))
+            }
+            ErrorKind::UnsupportedFilter(details) => {
+                Some(KnownError::new(user_facing_errors::query_engine::InputError {
+                    details: details.clone(),
+                }))
+            }
             ErrorKind::InvalidDatabaseUrl { details, url } => {
                 let details = user_facing_errors::quaint::invalid_url_description(url, details);
 
@@ -59,6 +74,9 @@ pub enum ErrorKind {
     #[error("Foreign key constraint failed")]
     ForeignKeyConstraintViolation { constraint: DatabaseConstraint },
 
+    #[error("Check constraint failed: {}", constraint)]
+    CheckConstraintViolation { constraint: DatabaseConstraint },
+
     #[error("Record does not exist.")]
     RecordDoesNotExist,
 
@@ -136,6 +154,12 @@ pub enum ErrorKind {
 
     #[error("{}", details)]
     InvalidDatabaseUrl { details: String, url: String },
+
+    #[error("Unable to lock rows: {}", _0)]
+    RowLockUnavailable(String),
+
+    #[error("Unsupported filter: {}", _0)]
+    UnsupportedFilter(String),
 }
 
 impl From<DomainError> for ConnectorError {
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+
+tokio::task_local! {
+    /// The log of the operation being explained. Only set while an operation runs in explain mode,
+    /// connectors record every statement they send to the database into it.
+    pub static EXPLAIN_LOG: ExplainLog;
+}
+
+/// How much of its execution an operation reports alongside its result.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExplainMode {
+    /// Nothing is reported.
+    Off,
+
+    /// The query graph and the statements sent to the database are reported.
+    Statements,
+
+    /// Like `Statements`, additionally reporting the database's plan for every read.
+    Plans,
+}
+
+impl ExplainMode {
+    pub fn is_on(self) -> bool {
+        self != ExplainMode::Off
+    }
+}
+
+impl Default for ExplainMode {
+    fn default() -> Self {
+        ExplainMode::Off
+    }
+}
+
+/// A statement sent to the database, rendered with placeholders instead of parameter values.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExplainedStatement {
+    pub sql: String,
+
+    /// The plan of the statement as returned by the database, for reads in `ExplainMode::Plans`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<Value>,
+}
+
+/// Collects the statements of an explained operation, in the order they were sent.
+#[derive(Debug, Clone)]
+pub struct ExplainLog {
+    mode: ExplainMode,
+    statements: Arc<Mutex<Vec<ExplainedStatement>>>,
+}
+
+impl ExplainLog {
+    pub fn new(mode: ExplainMode) -> Self {
+        Self {
+            mode,
+            statements: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn mode(&self) -> ExplainMode {
+        self.mode
+    }
+
+    pub fn record(&self, sql: impl Into<String>, plan: Option<Value>) {
+        self.statements
+            .lock()
+            .unwrap()
+            .push(ExplainedStatement { sql: sql.into(), plan });
+    }
+
+    pub fn statements(&self) -> Vec<ExplainedStatement> {
+        self.statements.lock().unwrap().clone()
+    }
+}
+
+/// The log of the operation currently being explained, if any.
+pub fn current_explain_log() -> Option<ExplainLog> {
+    EXPLAIN_LOG.try_with(Clone::clone).ok()
+}
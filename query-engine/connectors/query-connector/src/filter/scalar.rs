@@ -140,6 +140,53 @@ pub enum ScalarCondition {
     GreaterThanOrEquals(PrismaValue),
     In(PrismaListValue),
     NotIn(PrismaListValue),
+    JsonCompare(JsonCondition),
+    Search(PrismaValue),
+    NotSearch(PrismaValue),
+}
+
+impl ScalarCondition {
+    /// Moves the condition onto the value at `path` of a Json field.
+    pub fn with_json_path(self, path: JsonFilterPath) -> Self {
+        match self {
+            ScalarCondition::JsonCompare(mut json) => {
+                json.path = Some(path);
+                ScalarCondition::JsonCompare(json)
+            }
+            condition => ScalarCondition::JsonCompare(JsonCondition {
+                condition: Box::new(condition),
+                path: Some(path),
+                target_type: None,
+            }),
+        }
+    }
+}
+
+/// A condition on a Json field, optionally applied to the value at a path inside the document.
+///
+/// The target type decides how `Contains`, `StartsWith` and `EndsWith` (and their negations)
+/// of the inner condition are interpreted: on the text of a string value or on the elements of an array.
+/// All other conditions compare Json values.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonCondition {
+    pub condition: Box<ScalarCondition>,
+    pub path: Option<JsonFilterPath>,
+    pub target_type: Option<JsonTargetType>,
+}
+
+/// Path to a value inside a Json document, in the format of the database.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonFilterPath {
+    /// A JSON path expression, e.g. `$.pets[0].name` (MySQL).
+    String(String),
+    /// A list of keys and array indexes, e.g. `["pets", "0", "name"]` (Postgres).
+    Array(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonTargetType {
+    String,
+    Array,
 }
 
 impl ScalarCompare for ScalarFieldRef {
@@ -263,6 +310,30 @@ impl ScalarCompare for ScalarFieldRef {
         })
     }
 
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not match the given full-text search query.
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::NotSearch(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
     /// Field is less than the given value.
     fn less_than<T>(&self, val: T) -> Filter
     where
@@ -433,6 +504,30 @@ impl ScalarCompare for ModelProjection {
         })
     }
 
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not match the given full-text search query.
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotSearch(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
     /// Field is less than the given value.
     fn less_than<T>(&self, val: T) -> Filter
     where
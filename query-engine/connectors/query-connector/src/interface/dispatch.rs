@@ -9,10 +9,11 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        lock: Option<RowLock>,
     ) -> crate::Result<Option<SingleRecord>> {
         match self {
-            Self::Connection(c) => c.get_single_record(model, filter, selected_fields).await,
-            Self::Transaction(tx) => tx.get_single_record(model, filter, selected_fields).await,
+            Self::Connection(c) => c.get_single_record(model, filter, selected_fields, lock).await,
+            Self::Transaction(tx) => tx.get_single_record(model, filter, selected_fields, lock).await,
         }
     }
 
@@ -39,6 +40,18 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn count_related_records(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+        filter: Option<Filter>,
+    ) -> crate::Result<Vec<(RecordProjection, usize)>> {
+        match self {
+            Self::Connection(c) => c.count_related_records(from_field, from_record_ids, filter).await,
+            Self::Transaction(tx) => tx.count_related_records(from_field, from_record_ids, filter).await,
+        }
+    }
+
     async fn aggregate_records(
         &self,
         model: &ModelRef,
@@ -69,6 +82,18 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::Result<usize> {
+        match self {
+            Self::Connection(c) => c.create_records(model, args, skip_duplicates).await,
+            Self::Transaction(tx) => tx.create_records(model, args, skip_duplicates).await,
+        }
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
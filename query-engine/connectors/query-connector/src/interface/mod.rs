@@ -12,6 +12,10 @@ pub trait Connector {
     /// Returns a connection to a data source.
     async fn get_connection(&self) -> crate::Result<Box<dyn Connection>>;
 
+    /// Returns a connection for reads that happen outside of a transaction.
+    /// Connectors with read replicas hand out a replica connection, all others the same as `get_connection`.
+    async fn get_read_connection(&self) -> crate::Result<Box<dyn Connection>>;
+
     /// Returns name of the connector.
     fn name(&self) -> String;
 }
@@ -19,6 +23,16 @@ pub trait Connector {
 #[async_trait]
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     async fn start_transaction<'a>(&'a self) -> crate::Result<Box<dyn Transaction + 'a>>;
+
+    /// Returns a handle to cancel the statement running on this connection from the outside,
+    /// or `None` if the data source can't cancel statements of other connections.
+    async fn statement_canceller(&self) -> crate::Result<Option<Box<dyn StatementCanceller>>>;
+}
+
+/// Cancels the statement running on a connection, e.g. when the request running it timed out.
+#[async_trait]
+pub trait StatementCanceller: Send + Sync {
+    async fn cancel(&self) -> crate::Result<()>;
 }
 
 #[async_trait]
@@ -167,11 +181,13 @@ pub trait ReadOperations {
     /// - The `Filter` defines what item we want back and is guaranteed to be
     ///   defined to filter at most one item by the core.
     /// - The `SelectedFields` defines the values to be returned.
+    /// - The `RowLock` optionally locks the record until the transaction ends.
     async fn get_single_record(
         &self,
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        lock: Option<RowLock>,
     ) -> crate::Result<Option<SingleRecord>>;
 
     /// Gets multiple records from the database.
@@ -200,6 +216,18 @@ pub trait ReadOperations {
         from_record_ids: &[RecordProjection],
     ) -> crate::Result<Vec<(RecordProjection, RecordProjection)>>;
 
+    /// Counts the records related to each of the given parent records through `from_field`,
+    /// optionally only counting related records matching `filter`.
+    ///
+    /// The parent records are identified by the values of the linking fields of `from_field`.
+    /// Parents without related records are omitted from the result.
+    async fn count_related_records(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+        filter: Option<Filter>,
+    ) -> crate::Result<Vec<(RecordProjection, usize)>>;
+
     /// Aggregates records for a specific model based on the given selections.
     /// Whether or not the aggregations can be executed in a single query or
     /// requires multiple roundtrips to the underlying data source is at the
@@ -219,6 +247,17 @@ pub trait WriteOperations {
     /// Insert a single record to the database.
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection>;
 
+    /// Insert many records into the database in as few statements as possible.
+    /// If `skip_duplicates` is set, records violating a unique constraint are silently ignored.
+    ///
+    /// Returns the number of inserted records.
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::Result<usize>;
+
     /// Update records in the `Model` with the given `WriteArgs` filtered by the
     /// `Filter`.
     async fn update_records(
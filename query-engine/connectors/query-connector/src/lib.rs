@@ -1,6 +1,7 @@
 #![deny(warnings)]
 
 pub mod error;
+pub mod explain;
 pub mod filter;
 
 mod compare;
@@ -9,6 +10,7 @@ mod query_arguments;
 mod write_args;
 
 pub use compare::*;
+pub use explain::{ExplainLog, ExplainMode, ExplainedStatement};
 pub use filter::*;
 pub use interface::*;
 pub use query_arguments::*;
@@ -12,6 +12,7 @@ pub struct SkipAndLimit {
 /// - `filter` scopes the data by defining conditions (akin to `WHERE` in SQL).
 /// - `order_by` defines the ordering of records, from most high to low precedence.
 /// - `distinct` designates the fields on which the records should be distinct.
+/// - `lock` takes row locks on the queried records for the rest of the transaction.
 /// - The `ignore_*` flags are a temporary bandaid to tell the connector to do not
 ///   include certain constraints when building queries, because the core is already
 ///   performing these action in a different manner (e.g. in-memory on all records).
@@ -28,10 +29,40 @@ pub struct QueryArguments {
     pub filter: Option<Filter>,
     pub order_by: Vec<OrderBy>,
     pub distinct: Option<ModelProjection>,
+    pub lock: Option<RowLock>,
     pub ignore_skip: bool,
     pub ignore_take: bool,
 }
 
+/// A lock on the rows returned by a read, held until the enclosing transaction ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RowLock {
+    pub strength: LockStrength,
+    pub wait: LockWait,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockStrength {
+    /// Exclusive lock, blocking concurrent updates, deletes and other locks on the rows.
+    ForUpdate,
+
+    /// Shared lock, blocking concurrent updates and deletes, but not other shared locks.
+    ForShare,
+}
+
+/// What a read does if a row it wants to lock is already locked by another transaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockWait {
+    /// Wait until the other transaction releases the lock.
+    Wait,
+
+    /// Leave out the locked rows.
+    SkipLocked,
+
+    /// Fail right away.
+    NoWait,
+}
+
 impl QueryArguments {
     pub fn new(model: ModelRef) -> Self {
         Self {
@@ -42,6 +73,7 @@ impl QueryArguments {
             filter: None,
             order_by: vec![],
             distinct: None,
+            lock: None,
             ignore_take: false,
             ignore_skip: false,
         }
@@ -69,20 +101,21 @@ impl QueryArguments {
         self.cursor.is_some() && !self.is_stable_ordering()
     }
 
-    /// A null cursor is a cursor that is used in conjunction with a nullable order by (i.e. a field is optional).
+    /// A null cursor is a cursor that is used in conjunction with a nullable order by (i.e. a field is optional)
+    /// that doesn't define where null values are placed.
     pub fn contains_null_cursor(&self) -> bool {
-        self.cursor.is_some() && self.order_by.iter().any(|o| !o.field.is_required)
+        self.cursor.is_some() && self.order_by.iter().any(|o| o.is_nullable() && o.nulls_order.is_none())
     }
 
     /// Checks if the orderBy provided is guaranteeing a stable ordering of records for the model. Assumes that `model`
     /// is the same as the model used
     /// `true` if at least one unique field is present, or contains a combination of fields that is marked as unique.
-    /// `false` otherwise.
+    /// `false` otherwise. Orderings on related records never contribute to a stable ordering.
     pub fn is_stable_ordering(&self) -> bool {
-        let order_fields: Vec<_> = self.order_by.iter().map(|o| &o.field).collect();
+        let order_fields: Vec<_> = self.order_by.iter().filter_map(|o| o.scalar_field()).collect();
 
         !self.order_by.is_empty()
-            && (self.order_by.iter().any(|o| o.field.unique())
+            && (order_fields.iter().any(|f| f.unique())
                 || self
                     .model
                     .unique_indexes()
@@ -94,8 +127,11 @@ impl QueryArguments {
         self.take.clone().map(|t| if t < 0 { t * -1 } else { t })
     }
 
+    /// Batched queries are ordered in memory, which is only possible for orderings on scalar fields of the model.
     pub fn can_batch(&self) -> bool {
-        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false) && self.cursor.is_none()
+        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
+            && self.cursor.is_none()
+            && self.order_by.iter().all(|o| o.scalar_field().is_some())
     }
 
     pub fn batched(self) -> Vec<Self> {
@@ -107,6 +143,7 @@ impl QueryArguments {
                 let skip = self.skip;
                 let order_by = self.order_by;
                 let distinct = self.distinct;
+                let lock = self.lock;
                 let ignore_skip = self.ignore_skip;
                 let ignore_take = self.ignore_take;
 
@@ -121,6 +158,7 @@ impl QueryArguments {
                         filter: Some(filter),
                         order_by: order_by.clone(),
                         distinct: distinct.clone(),
+                        lock,
                         ignore_skip,
                         ignore_take,
                     })
@@ -61,6 +61,12 @@ pub enum WriteExpression {
 
     /// Divide field by value.
     Divide(PrismaValue),
+
+    /// Append the values of a list to a scalar list field.
+    Push(PrismaValue),
+
+    /// Remove the value of an optional field.
+    Unset,
 }
 
 impl From<PrismaValue> for WriteExpression {
@@ -179,6 +185,23 @@ impl WriteArgs {
         }
     }
 
+    /// Replaces the expected version given for the version field of the model, if it has one, with
+    /// an increment of the version. Returns the expected version, which updates must filter on.
+    pub fn update_version(&mut self, model: ModelRef) -> Option<PrismaValue> {
+        let field = model.fields().version().clone()?;
+        let expected = match self.args.remove(field.db_name()) {
+            Some(WriteExpression::Value(pv)) if pv != PrismaValue::Null => Some(pv),
+            _ => None,
+        };
+
+        if !self.args.is_empty() || expected.is_some() {
+            self.args
+                .insert((&field).into(), WriteExpression::Add(PrismaValue::Int(1)));
+        }
+
+        expected
+    }
+
     pub fn as_record_projection(&self, model_projection: ModelProjection) -> Option<RecordProjection> {
         let pairs: Vec<_> = model_projection
             .scalar_fields()
@@ -0,0 +1,52 @@
+use crate::{QueryExt, SqlError};
+use once_cell::sync::OnceCell;
+use quaint::{connector::SqlFamily, prelude::ConnectionInfo};
+use std::sync::Arc;
+
+/// The connection a connector operation runs on. Passed down to the query builders for the parts
+/// of statements without a common syntax across databases.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Context<'a> {
+    connection_info: &'a ConnectionInfo,
+    server_version: &'a ServerVersion,
+}
+
+impl<'a> Context<'a> {
+    pub(crate) fn new(connection_info: &'a ConnectionInfo, server_version: &'a ServerVersion) -> Self {
+        Self {
+            connection_info,
+            server_version,
+        }
+    }
+
+    pub(crate) fn connection_info(&self) -> &'a ConnectionInfo {
+        self.connection_info
+    }
+
+    pub(crate) fn sql_family(&self) -> SqlFamily {
+        self.connection_info.sql_family()
+    }
+
+    /// The version string of the database server, e.g. `8.0.23` or `10.5.8-MariaDB`.
+    pub(crate) async fn server_version(&self, conn: &dyn QueryExt) -> crate::Result<Option<&'a str>> {
+        self.server_version.get(conn).await
+    }
+}
+
+/// The version of the database server behind a connection pool. It is queried the first time it
+/// is needed and shared by all connections of the pool.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ServerVersion(Arc<OnceCell<Option<String>>>);
+
+impl ServerVersion {
+    async fn get(&self, conn: &dyn QueryExt) -> crate::Result<Option<&str>> {
+        if self.0.get().is_none() {
+            let version = conn.version().await.map_err(SqlError::from)?;
+
+            // Another connection may have been first, both read the same version.
+            let _ = self.0.set(version);
+        }
+
+        Ok(self.0.get().and_then(|version| version.as_deref()))
+    }
+}
@@ -1,4 +1,7 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{
+    ordering::{self, OrderByDefinition},
+    query_arguments_ext::QueryArgumentsExt,
+};
 use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
@@ -6,35 +9,36 @@ use quaint::ast::*;
 static ORDER_TABLE_ALIAS: &str = "order_cmp";
 
 /// Builds a cursor query condition based on the cursor arguments and if necessary a table that the condition depends on.
+/// The table is meant to be joined with the condition as join condition, after all joins required by the orderings.
 ///
 /// An example query for 4 order-by fields is:
 /// ```sql
 /// SELECT
 ///   `TestModel`.`id`
 /// FROM
-///   `TestModel`,
+///   `TestModel`
 ///   -- >>> Begin Part #1
-///   (
+///   INNER JOIN (
 ///       SELECT
-///           `TestModel`.`fieldA`,
-///           `TestModel`.`fieldB`,
-///           `TestModel`.`fieldC`,
-///           `TestModel`.`fieldD`
+///           `TestModel`.`fieldA` AS `cmp_0`,
+///           `TestModel`.`fieldB` AS `cmp_1`,
+///           `TestModel`.`fieldC` AS `cmp_2`,
+///           `TestModel`.`fieldD` AS `cmp_3`
 ///       FROM
 ///           `TestModel`
 ///       WHERE
 ///           (`TestModel`.`id`) = (4)
 ///   ) AS `order_cmp`
 ///   -- <<< End Part #1
-/// WHERE
+/// ON
 ///   -- >>> Begin Part #2
-///   (`TestModel`.`fieldA` = `order_cmp`.`fieldA` AND `TestModel`.`fieldB` = `order_cmp`.`fieldB` AND `TestModel`.`fieldC` = `order_cmp`.`fieldC` AND `TestModel`.`fieldD` <= `order_cmp`.`fieldD`)
+///   (`TestModel`.`fieldA` = `order_cmp`.`cmp_0` AND `TestModel`.`fieldB` = `order_cmp`.`cmp_1` AND `TestModel`.`fieldC` = `order_cmp`.`cmp_2` AND `TestModel`.`fieldD` <= `order_cmp`.`cmp_3`)
 ///   OR
-///   (`TestModel`.`fieldA` = `order_cmp`.`fieldA` AND `TestModel`.`fieldB` = `order_cmp`.`fieldB` AND `TestModel`.`fieldC` > `order_cmp`.`fieldC`)
+///   (`TestModel`.`fieldA` = `order_cmp`.`cmp_0` AND `TestModel`.`fieldB` = `order_cmp`.`cmp_1` AND `TestModel`.`fieldC` > `order_cmp`.`cmp_2`)
 ///   OR
-///   (`TestModel`.`fieldA` = `order_cmp`.`fieldA` AND `TestModel`.`fieldB` > `order_cmp`.`fieldB`)
+///   (`TestModel`.`fieldA` = `order_cmp`.`cmp_0` AND `TestModel`.`fieldB` > `order_cmp`.`cmp_1`)
 ///   OR
-///   (`TestModel`.`fieldA` < `order_cmp`.`fieldA`)
+///   (`TestModel`.`fieldA` < `order_cmp`.`cmp_0`)
 ///   -- <<< End Part #2
 /// ORDER BY
 ///   `TestModel`.`fieldA` DESC,
@@ -43,26 +47,33 @@ static ORDER_TABLE_ALIAS: &str = "order_cmp";
 ///   `TestModel`.`fieldD` DESC;
 /// ```
 ///
+/// Orderings on related records use the same joins in the subquery as in the main query, e.g. `orderby_0_0`.`name`
+/// instead of `TestModel`.`fieldA`. The comparison values are therefore aliased by their position instead of their name.
+///
 /// The above assumes that all field are non-nullable. If a field is nullable, #2 conditions slighty change:
 /// ```sql
 ///   -- ... The first (4 - condition) block:
 ///   (
 ///     (
-///       `TestModel`.`fieldA` = `order_cmp`.`fieldA`
-///       OR `order_cmp`.`fieldA` IS NULL
+///       `TestModel`.`fieldA` = `order_cmp`.`cmp_0`
+///       OR `order_cmp`.`cmp_0` IS NULL
 ///       OR `TestModel`.`fieldA` IS NULL
 ///     )
 ///     AND -- ...
 ///   )
 ///   -- ...The other blocks (3, 2) in between, then the single condition block:
 ///   OR (
-///     `TestModel`.`fieldA` < `order_cmp`.`fieldA`
-///     OR `order_cmp`.`fieldA` IS NULL
+///     `TestModel`.`fieldA` < `order_cmp`.`cmp_0`
+///     OR `order_cmp`.`cmp_0` IS NULL
 ///     OR `TestModel`.`fieldA` IS NULL
 ///   )
 ///   -- ...
 /// ```
-pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Table<'static>>, ConditionTree<'static>) {
+pub fn build(
+    query_arguments: &QueryArguments,
+    model: &ModelRef,
+    order_by_definitions: &[OrderByDefinition],
+) -> (Option<Table<'static>>, ConditionTree<'static>) {
     match query_arguments.cursor {
         None => (None, ConditionTree::NoCondition),
         Some(ref cursor) => {
@@ -78,13 +89,21 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             let cursor_condition = cursor_row.clone().equals(cursor_values.clone());
 
             // Orderings for this query. Influences which fields we need to fetch for comparing order fields.
-            let mut order_definitions = order_definitions(query_arguments, model);
+            let order_definitions = order_definitions(model, order_by_definitions);
 
             // Subquery to find the value of the order field(s) that we need for comparison. Builds part #1 of the query example in the docs.
             let order_subquery = order_definitions
                 .iter()
-                .fold(Select::from_table(model.as_table()), |select, (field, _)| {
-                    select.column(field.as_column())
+                .flat_map(|definition| definition.joins.clone())
+                .fold(Select::from_table(model.as_table()), |select, join| {
+                    select.left_join(join)
+                });
+
+            let order_subquery = order_definitions
+                .iter()
+                .enumerate()
+                .fold(order_subquery, |select, (index, definition)| {
+                    select.value(definition.order_column.clone().alias(cmp_alias(index)))
                 })
                 .so_that(cursor_condition);
 
@@ -95,17 +114,19 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             // Builds part #2 of the example query.
             // If we only have one ordering, we only want a single, slightly different, condition of (orderField [<= / >=] cmp_field).
             let condition_tree = if len == 1 {
-                let (field, order) = order_definitions.pop().unwrap();
-                ConditionTree::Single(Box::new(map_orderby_condition(&field, &order, reverse, true)))
+                ConditionTree::Single(Box::new(map_orderby_condition(&order_definitions[0], 0, reverse, true)))
             } else {
                 let or_conditions = (0..len).fold(Vec::with_capacity(len), |mut conditions_acc, n| {
                     let (head, tail) = order_definitions.split_at(len - n - 1);
                     let mut and_conditions = Vec::with_capacity(head.len() + 1);
 
-                    for (field, _) in head {
-                        and_conditions.push(map_equality_condition(field));
+                    for (index, definition) in head.iter().enumerate() {
+                        and_conditions.push(map_equality_condition(definition, index));
                     }
 
+                    let definition = tail.first().unwrap();
+                    let index = head.len();
+
                     if head.len() == len - 1 {
                         // Special case where we build lte / gte, not lt / gt.
                         // - We use the combination of all order-by fields as comparator for the the cursor.
@@ -131,12 +152,9 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
                         //
                         // Said differently, we handle all the cases in which the prefixes are equal to len - 1 to account for possible identical comparators,
                         // but everything else must come strictly "after" the cursor.
-                        let (field, order) = tail.first().unwrap();
-
-                        and_conditions.push(map_orderby_condition(field, order, reverse, true));
+                        and_conditions.push(map_orderby_condition(definition, index, reverse, true));
                     } else {
-                        let (field, order) = tail.first().unwrap();
-                        and_conditions.push(map_orderby_condition(field, order, reverse, false));
+                        and_conditions.push(map_orderby_condition(definition, index, reverse, false));
                     }
 
                     conditions_acc.push(ConditionTree::And(and_conditions));
@@ -154,15 +172,15 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
 // A negative `take` value signifies that values should be taken before the cursor,
 // requiring the correct comarison operator to be used to fit the reversed order.
 fn map_orderby_condition(
-    field: &ScalarFieldRef,
-    order: &SortOrder,
+    definition: &OrderByDefinition,
+    index: usize,
     reverse: bool,
     include_eq: bool,
 ) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
+    let order_column = definition.order_column.clone();
+    let cmp_column = Column::from((ORDER_TABLE_ALIAS, cmp_alias(index)));
 
-    let order_expr: Expression<'static> = match order {
+    let order_expr: Expression<'static> = match definition.order_by.sort_order {
         // If it's ASC but we want to take from the back, the ORDER BY will be DESC, meaning that comparisons done need to be lt(e).
         SortOrder::Ascending if reverse => {
             if include_eq {
@@ -199,50 +217,78 @@ fn map_orderby_condition(
     }
     .into();
 
-    // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
-    // statement over their order relative to the cursor.
-    if !field.is_required {
-        order_expr
-            .or(field.as_column().is_null())
-            .or(Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned())).is_null())
-            .into()
-    } else {
-        order_expr
+    let order_column = definition.order_column.clone();
+    let cmp_column = Column::from((ORDER_TABLE_ALIAS, cmp_alias(index)));
+
+    if !definition.order_by.is_nullable() {
+        return order_expr;
+    }
+
+    match definition.nulls_first(reverse) {
+        // If null values are placed explicitly, we know their order relative to the cursor:
+        // - If nulls come first, all non-null values come after a null cursor value.
+        // - If nulls come last, all null values come after a non-null cursor value.
+        Some(nulls_first) => {
+            let after_cursor = if nulls_first {
+                cmp_column.clone().is_null().and(order_column.clone().is_not_null())
+            } else {
+                cmp_column.clone().is_not_null().and(order_column.clone().is_null())
+            };
+
+            let condition = order_expr.or(after_cursor);
+
+            if include_eq {
+                condition.or(order_column.is_null().and(cmp_column.is_null())).into()
+            } else {
+                condition.into()
+            }
+        }
+
+        // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
+        // statement over their order relative to the cursor.
+        None => order_expr.or(order_column.is_null()).or(cmp_column.is_null()).into(),
     }
 }
 
-fn map_equality_condition(field: &ScalarFieldRef) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
+fn map_equality_condition(definition: &OrderByDefinition, index: usize) -> Expression<'static> {
+    let order_column = definition.order_column.clone();
+    let cmp_column = Column::from((ORDER_TABLE_ALIAS, cmp_alias(index)));
+
+    match (definition.order_by.is_nullable(), definition.order_by.nulls_order) {
+        (false, _) => order_column.equals(cmp_column).into(),
 
-    // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
-    // statement over their order relative to the cursor.
-    if !field.is_required {
-        order_column
+        // If null values are placed explicitly, null values are equal to each other.
+        (true, Some(_)) => order_column
+            .clone()
+            .equals(cmp_column.clone())
+            .or(order_column.is_null().and(cmp_column.is_null()))
+            .into(),
+
+        // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
+        // statement over their order relative to the cursor.
+        (true, None) => order_column
             .clone()
             .equals(cmp_column.clone())
             .or(cmp_column.is_null())
             .or(order_column.is_null())
-            .into()
-    } else {
-        order_column.equals(cmp_column).into()
+            .into(),
     }
 }
 
-fn order_definitions(query_arguments: &QueryArguments, model: &ModelRef) -> Vec<(ScalarFieldRef, SortOrder)> {
-    let defined_ordering: Vec<_> = query_arguments
-        .order_by
-        .iter()
-        .map(|o| (o.field.clone(), o.sort_order))
-        .collect();
-
-    if defined_ordering.is_empty() {
+/// Falls back to ordering by the primary identifier if the query doesn't define an ordering.
+fn order_definitions(model: &ModelRef, order_by_definitions: &[OrderByDefinition]) -> Vec<OrderByDefinition> {
+    if order_by_definitions.is_empty() {
         model
             .primary_identifier()
             .scalar_fields()
-            .map(|f| (f, SortOrder::Ascending))
+            .enumerate()
+            .map(|(index, f)| ordering::build_definition(index, &OrderBy::new(f, SortOrder::Ascending)))
             .collect()
     } else {
-        defined_ordering
+        order_by_definitions.to_vec()
     }
 }
+
+fn cmp_alias(index: usize) -> String {
+    format!("cmp_{}", index)
+}
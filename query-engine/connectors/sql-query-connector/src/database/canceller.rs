@@ -0,0 +1,105 @@
+use crate::{QueryExt, SqlError};
+use async_trait::async_trait;
+use connector_interface::StatementCanceller;
+use futures::lock::Mutex;
+use quaint::{
+    connector::{Queryable, SqlFamily},
+    prelude::ConnectionInfo,
+    single,
+};
+use std::sync::Arc;
+
+/// Cancels the statement running on a connection by terminating the session of the connection
+/// from a dedicated connection to the same database:
+///
+/// - PostgreSQL: `SELECT pg_terminate_backend(<backend pid>)`
+/// - MySQL: `KILL <connection id>`
+/// - SQL Server: `KILL <session id>`
+///
+/// Terminating the whole session rather than only the statement makes sure the connection, whose
+/// state is unknown after the timeout, is never reused: it fails the check on checking it out of
+/// the pool again and gets discarded. SQLite runs in process, so it doesn't support cancelling.
+pub(crate) struct SqlStatementCanceller {
+    connection: CancellingConnection,
+    connection_info: ConnectionInfo,
+    kill_statement: String,
+}
+
+impl SqlStatementCanceller {
+    /// Looks up the session id of `conn` on the database.
+    pub(crate) async fn for_connection(
+        conn: &impl QueryExt,
+        connection_info: &ConnectionInfo,
+        connection: &CancellingConnection,
+    ) -> crate::Result<Option<Self>> {
+        let id_query = match connection_info.sql_family() {
+            SqlFamily::Postgres => "SELECT pg_backend_pid()",
+            SqlFamily::Mysql => "SELECT CONNECTION_ID()",
+            SqlFamily::Mssql => "SELECT @@SPID",
+            SqlFamily::Sqlite => return Ok(None),
+        };
+
+        let result_set = conn.query_raw(id_query, &[]).await?;
+        let id = result_set
+            .into_single()
+            .ok()
+            .and_then(|row| row.into_single().ok())
+            .and_then(|value| value.as_i64())
+            .ok_or_else(|| SqlError::ConversionError(anyhow::anyhow!("Unable to read the session id.")))?;
+
+        let kill_statement = match connection_info.sql_family() {
+            SqlFamily::Postgres => format!("SELECT pg_terminate_backend({})", id),
+            _ => format!("KILL {}", id),
+        };
+
+        Ok(Some(Self {
+            connection: connection.clone(),
+            connection_info: connection_info.clone(),
+            kill_statement,
+        }))
+    }
+}
+
+#[async_trait]
+impl StatementCanceller for SqlStatementCanceller {
+    async fn cancel(&self) -> connector_interface::Result<()> {
+        self.connection
+            .execute(&self.kill_statement)
+            .await
+            .map_err(|err| err.into_connector_error(&self.connection_info))
+    }
+}
+
+/// A connection outside of the pool, shared by all cancellers of a pool.
+///
+/// Cancelling never checks out of the pool: the pool may well be exhausted by the very statements
+/// that timed out, and waiting for one of them to free a connection would never end. The connection
+/// is opened on the first cancellation, and opened again on the next one if it failed.
+#[derive(Clone)]
+pub(crate) struct CancellingConnection {
+    url: Arc<str>,
+    connection: Arc<Mutex<Option<single::Quaint>>>,
+}
+
+impl CancellingConnection {
+    pub(crate) fn new(url: &str) -> Self {
+        Self {
+            url: Arc::from(url),
+            connection: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn execute(&self, statement: &str) -> crate::Result<()> {
+        let mut connection = self.connection.lock().await;
+
+        let conn = match connection.take() {
+            Some(conn) => conn,
+            None => single::Quaint::new(&self.url).await?,
+        };
+
+        conn.raw_cmd(statement).await?;
+        *connection = Some(conn);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
-use super::transaction::SqlConnectorTransaction;
-use crate::{database::operations::*, QueryExt, SqlError};
+use super::{
+    canceller::{CancellingConnection, SqlStatementCanceller},
+    pool::Checkout,
+    transaction::SqlConnectorTransaction,
+};
+use crate::{database::operations::*, Context, QueryExt, ServerVersion, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, QueryArguments,
-    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
+    ReadOperations, RecordFilter, RowLock, StatementCanceller, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -13,15 +17,31 @@ use std::future::Future;
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    _checkout: Checkout,
+    /// The connection of the pool that cancels statements of this connection.
+    cancelling_connection: CancellingConnection,
+    server_version: ServerVersion,
 }
 
 impl<C> SqlConnection<C>
 where
     C: QueryExt + Send + Sync + 'static,
 {
-    pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
+    pub(crate) fn new(
+        inner: C,
+        connection_info: &ConnectionInfo,
+        checkout: Checkout,
+        cancelling_connection: CancellingConnection,
+        server_version: ServerVersion,
+    ) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+        Self {
+            inner,
+            connection_info,
+            _checkout: checkout,
+            cancelling_connection,
+            server_version,
+        }
     }
 
     async fn catch<O>(
@@ -43,9 +63,24 @@ where
     async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
         let fut_tx = self.inner.start_transaction();
         let connection_info = &self.connection_info;
+        let server_version = &self.server_version;
         self.catch(async move {
             let tx: quaint::connector::Transaction = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
+            Ok(Box::new(SqlConnectorTransaction::new(
+                tx,
+                &connection_info,
+                server_version.clone(),
+            )) as Box<dyn Transaction>)
+        })
+        .await
+    }
+
+    async fn statement_canceller(&self) -> connector::Result<Option<Box<dyn StatementCanceller>>> {
+        self.catch(async move {
+            let canceller =
+                SqlStatementCanceller::for_connection(&self.inner, &self.connection_info, &self.cancelling_connection)
+                    .await?;
+            Ok(canceller.map(|canceller| Box::new(canceller) as Box<dyn StatementCanceller>))
         })
         .await
     }
@@ -61,9 +96,14 @@ where
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        lock: Option<RowLock>,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move {
+            ensure_no_lock(lock)?;
+            read::get_single_record(&self.inner, model, filter, selected_fields, lock, &ctx).await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -72,8 +112,12 @@ where
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
-            .await
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move {
+            ensure_no_lock(query_arguments.lock)?;
+            read::get_many_records(&self.inner, model, query_arguments, selected_fields, &ctx).await
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -81,8 +125,24 @@ where
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
-            .await
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(
+            async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids, &ctx).await },
+        )
+        .await
+    }
+
+    async fn count_related_records(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+        filter: Option<Filter>,
+    ) -> connector::Result<Vec<(RecordProjection, usize)>> {
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(
+            async move { read::count_related_records(&self.inner, from_field, from_record_ids, filter, &ctx).await },
+        )
+        .await
     }
 
     async fn aggregate_records(
@@ -93,9 +153,10 @@ where
         group_by: Vec<ScalarFieldRef>,
         having: Option<Filter>,
     ) -> connector::Result<Vec<AggregationRow>> {
-        self.catch(
-            async move { read::aggregate(&self.inner, model, query_arguments, selections, group_by, having).await },
-        )
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move {
+            read::aggregate(&self.inner, model, query_arguments, selections, group_by, having, &ctx).await
+        })
         .await
     }
 }
@@ -106,7 +167,19 @@ where
     C: QueryExt + Send + Sync + 'static,
 {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::create_record(&self.inner, model, args, &ctx).await })
+            .await
+    }
+
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::Result<usize> {
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::create_records(&self.inner, model, args, skip_duplicates, &ctx).await })
             .await
     }
 
@@ -116,12 +189,14 @@ where
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::update_records(&self.inner, model, record_filter, args, &ctx).await })
             .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::delete_records(&self.inner, model, record_filter, &ctx).await })
             .await
     }
 
@@ -131,7 +206,8 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids, &ctx).await })
             .await
     }
 
@@ -141,7 +217,8 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids, &ctx).await })
             .await
     }
 
@@ -155,3 +232,14 @@ where
             .await
     }
 }
+
+/// Row locks are released as soon as the statement taking them commits, so outside of a
+/// transaction they would not protect anything.
+fn ensure_no_lock(lock: Option<RowLock>) -> crate::Result<()> {
+    match lock {
+        Some(_) => Err(SqlError::RowLockUnavailable(
+            "Row locks can only be taken inside of transactions.".into(),
+        )),
+        None => Ok(()),
+    }
+}
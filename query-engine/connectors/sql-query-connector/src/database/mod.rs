@@ -1,7 +1,10 @@
+mod canceller;
 mod connection;
 mod mssql;
 mod mysql;
+mod pool;
 mod postgresql;
+mod replicas;
 mod sqlite;
 mod transaction;
 
@@ -13,6 +16,7 @@ use datamodel::Datasource;
 
 pub use mssql::*;
 pub use mysql::*;
+pub use pool::record_pool_connections;
 pub use postgresql::*;
 pub use sqlite::*;
 
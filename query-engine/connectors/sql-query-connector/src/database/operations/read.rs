@@ -1,9 +1,10 @@
 use crate::{
     query_arguments_ext::QueryArgumentsExt,
     query_builder::{self, read},
-    QueryExt, SqlError,
+    Context, QueryExt, SqlError, SqlRow,
 };
 use connector_interface::*;
+use datamodel::FieldArity;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
 use quaint::ast::*;
@@ -13,17 +14,31 @@ pub async fn get_single_record(
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &ModelProjection,
+    lock: Option<RowLock>,
+    ctx: &Context<'_>,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.as_columns(), filter);
+    let query = read::get_records(&model, selected_fields.as_columns(), filter, ctx)?;
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
 
-    let record = (match conn.find(query, idents.as_slice()).await {
-        Ok(result) => Ok(Some(result)),
-        Err(_e @ SqlError::RecordNotFoundForWhere(_)) => Ok(None),
-        Err(_e @ SqlError::RecordDoesNotExist) => Ok(None),
-        Err(e) => Err(e),
-    })?
+    let record = match lock {
+        Some(lock) => {
+            let server_version = ctx.server_version(conn).await?;
+            let (sql, params) = read::lock_records(model, query.limit(1), lock, server_version, ctx)?;
+            conn.filter_locked(sql, params, idents.as_slice())
+                .await?
+                .into_iter()
+                .next()
+        }
+        None => {
+            (match conn.find(query, idents.as_slice(), ctx).await {
+                Ok(result) => Ok(Some(result)),
+                Err(_e @ SqlError::RecordNotFoundForWhere(_)) => Ok(None),
+                Err(_e @ SqlError::RecordDoesNotExist) => Ok(None),
+                Err(e) => Err(e),
+            })?
+        }
+    }
     .map(Record::from)
     .map(|record| SingleRecord { record, field_names });
 
@@ -35,8 +50,10 @@ pub async fn get_many_records(
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
+    ctx: &Context<'_>,
 ) -> crate::Result<ManyRecords> {
     let reversed = query_arguments.needs_reversed_order();
+    let lock = query_arguments.lock;
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
     let mut records = ManyRecords::new(field_names);
@@ -56,8 +73,8 @@ pub async fn get_many_records(
         let mut futures = FuturesUnordered::new();
 
         for args in batches.into_iter() {
-            let query = read::get_records(model, selected_fields.as_columns(), args);
-            futures.push(conn.filter(query.into(), idents.as_slice()));
+            let query = read::get_records(model, selected_fields.as_columns(), args, ctx)?;
+            futures.push(filter_records(conn, model, query, lock, idents.as_slice(), ctx));
         }
 
         while let Some(result) = futures.next().await {
@@ -70,9 +87,12 @@ pub async fn get_many_records(
             records.order_by(&order)
         }
     } else {
-        let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+        let query = read::get_records(model, selected_fields.as_columns(), query_arguments, ctx)?;
 
-        for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
+        for item in filter_records(conn, model, query, lock, idents.as_slice(), ctx)
+            .await?
+            .into_iter()
+        {
             records.push(Record::from(item))
         }
     };
@@ -84,10 +104,30 @@ pub async fn get_many_records(
     Ok(records)
 }
 
+/// Reads the rows selected by `query`, locking them if a lock is requested.
+async fn filter_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    query: Select<'static>,
+    lock: Option<RowLock>,
+    idents: &[(TypeIdentifier, FieldArity)],
+    ctx: &Context<'_>,
+) -> crate::Result<Vec<SqlRow>> {
+    match lock {
+        Some(lock) => {
+            let server_version = ctx.server_version(conn).await?;
+            let (sql, params) = read::lock_records(model, query, lock, server_version, ctx)?;
+            conn.filter_locked(sql, params, idents).await
+        }
+        None => conn.filter(query.into(), idents, ctx).await,
+    }
+}
+
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
     from_field: &RelationFieldRef,
     from_record_ids: &[RecordProjection],
+    ctx: &Context<'_>,
 ) -> crate::Result<Vec<(RecordProjection, RecordProjection)>> {
     let mut idents = vec![];
     idents.extend(from_field.model().primary_identifier().type_identifiers_with_arities());
@@ -117,7 +157,7 @@ pub async fn get_related_m2m_record_ids(
 
     // first parent id, then child id
     Ok(conn
-        .filter(select.into(), idents.as_slice())
+        .filter(select.into(), idents.as_slice(), ctx)
         .await?
         .into_iter()
         .map(|row| {
@@ -145,6 +185,44 @@ pub async fn get_related_m2m_record_ids(
         .collect())
 }
 
+pub async fn count_related_records(
+    conn: &dyn QueryExt,
+    from_field: &RelationFieldRef,
+    from_record_ids: &[RecordProjection],
+    filter: Option<Filter>,
+    ctx: &Context<'_>,
+) -> crate::Result<Vec<(RecordProjection, usize)>> {
+    let link_fields: Vec<_> = from_field.linking_fields().scalar_fields().collect();
+    let mut idents: Vec<_> = from_field.linking_fields().type_identifiers_with_arities();
+    idents.push((TypeIdentifier::Int, FieldArity::Required));
+
+    let query = read::count_related(from_field, from_record_ids, filter, ctx)?;
+
+    // first the parent link values, then the count
+    Ok(conn
+        .filter(query.into(), idents.as_slice(), ctx)
+        .await?
+        .into_iter()
+        .map(|row| {
+            let mut values = row.values;
+
+            let count = match values.pop() {
+                Some(PrismaValue::Int(count)) => count as usize,
+                _ => 0,
+            };
+
+            let link: RecordProjection = link_fields
+                .iter()
+                .zip(values)
+                .map(|(sf, val)| (sf.clone(), val))
+                .collect::<Vec<_>>()
+                .into();
+
+            (link, count)
+        })
+        .collect())
+}
+
 pub async fn aggregate(
     conn: &dyn QueryExt,
     model: &ModelRef,
@@ -152,11 +230,12 @@ pub async fn aggregate(
     selections: Vec<AggregationSelection>,
     group_by: Vec<ScalarFieldRef>,
     having: Option<Filter>,
+    ctx: &Context<'_>,
 ) -> crate::Result<Vec<AggregationRow>> {
     if group_by.len() > 0 {
-        group_by_aggregate(conn, model, query_arguments, selections, group_by, having).await
+        group_by_aggregate(conn, model, query_arguments, selections, group_by, having, ctx).await
     } else {
-        plain_aggregate(conn, model, query_arguments, selections)
+        plain_aggregate(conn, model, query_arguments, selections, ctx)
             .await
             .map(|v| vec![v])
     }
@@ -167,15 +246,16 @@ async fn plain_aggregate(
     model: &ModelRef,
     query_arguments: QueryArguments,
     selections: Vec<AggregationSelection>,
+    ctx: &Context<'_>,
 ) -> crate::Result<Vec<AggregationResult>> {
-    let query = read::aggregate(model, &selections, query_arguments);
+    let query = read::aggregate(model, &selections, query_arguments, ctx)?;
 
     let idents: Vec<_> = selections
         .iter()
         .flat_map(|aggregator| aggregator.identifiers())
         .collect();
 
-    let mut rows = conn.filter(query.into(), idents.as_slice()).await?;
+    let mut rows = conn.filter(query.into(), idents.as_slice(), ctx).await?;
     let row = rows
         .pop()
         .expect("Expected exactly one return row for aggregation query.");
@@ -190,15 +270,16 @@ async fn group_by_aggregate(
     selections: Vec<AggregationSelection>,
     group_by: Vec<ScalarFieldRef>,
     having: Option<Filter>,
+    ctx: &Context<'_>,
 ) -> crate::Result<Vec<AggregationRow>> {
-    let query = read::group_by_aggregate(model, query_arguments, &selections, group_by, having);
+    let query = read::group_by_aggregate(model, query_arguments, &selections, group_by, having, ctx)?;
 
     let idents: Vec<_> = selections
         .iter()
         .flat_map(|aggregator| aggregator.identifiers())
         .collect();
 
-    let rows = conn.filter(query.into(), idents.as_slice()).await?;
+    let rows = conn.filter(query.into(), idents.as_slice(), ctx).await?;
 
     Ok(rows
         .into_iter()
@@ -1,18 +1,39 @@
-use crate::{error::SqlError, query_builder::write, QueryExt};
+use crate::{
+    error::SqlError,
+    explain,
+    query_builder::{read, write},
+    sql_trace::{record_rows, sql_span},
+    AliasedCondition, Context, QueryExt,
+};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
-use quaint::error::ErrorKind;
+use quaint::{
+    ast::{Column, Select},
+    connector::SqlFamily,
+    error::ErrorKind,
+};
 use std::{collections::HashMap, convert::TryFrom};
+use tracing_futures::Instrument;
 use user_facing_errors::query_engine::DatabaseConstraint;
 
 /// Create a single record to the database defined in `conn`, resulting into a
 /// `RecordProjection` as an identifier pointing to the just-created record.
-pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection> {
+pub async fn create_record(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    args: WriteArgs,
+    ctx: &Context<'_>,
+) -> crate::Result<RecordProjection> {
     let (insert, returned_id) = write::create_record(model, args);
+    explain::record(conn, &insert.clone().into(), ctx).await?;
+    let span = sql_span(&insert, ctx.sql_family());
 
-    let result_set = match conn.insert(insert).await {
-        Ok(id) => id,
+    let result_set = match conn.insert(insert).instrument(span.clone()).await {
+        Ok(result_set) => {
+            record_rows(&span, result_set.len());
+            result_set
+        }
         Err(e) => match e.kind() {
             ErrorKind::UniqueConstraintViolation { constraint } => match constraint {
                 quaint::error::DatabaseConstraint::Index(name) => {
@@ -63,6 +84,54 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
     }
 }
 
+/// Create multiple records in the database defined in `conn` with as few multi-row `INSERT`
+/// statements as the bind parameter and row limits of the database allow. Results in the number of
+/// inserted records, which excludes duplicates skipped with `skip_duplicates`.
+pub async fn create_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    ctx: &Context<'_>,
+) -> crate::Result<usize> {
+    if args.is_empty() {
+        return Ok(0);
+    }
+
+    let inserts = write::create_records(
+        model,
+        args,
+        skip_duplicates,
+        max_bind_values(ctx),
+        max_rows_per_insert(ctx),
+    );
+    let mut count = 0;
+
+    for insert in inserts {
+        count += conn.traced_execute(insert, ctx).await? as usize;
+    }
+
+    Ok(count)
+}
+
+/// The maximum number of bind parameters a single statement can have on the database.
+fn max_bind_values(ctx: &Context<'_>) -> usize {
+    match ctx.sql_family() {
+        SqlFamily::Postgres => 32767,
+        SqlFamily::Mysql => 65535,
+        SqlFamily::Sqlite => 999,
+        SqlFamily::Mssql => 2099,
+    }
+}
+
+/// The maximum number of rows a single `INSERT ... VALUES` statement can insert on the database.
+fn max_rows_per_insert(ctx: &Context<'_>) -> usize {
+    match ctx.sql_family() {
+        SqlFamily::Mssql => 1000,
+        _ => usize::MAX,
+    }
+}
+
 /// Update multiple records in a database defined in `conn` and the records
 /// defined in `args`, resulting the identifiers that were modified in the
 /// operation.
@@ -71,8 +140,18 @@ pub async fn update_records(
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
+    ctx: &Context<'_>,
 ) -> crate::Result<Vec<RecordProjection>> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let lock_versions = model.fields().version().is_some()
+        && record_filter.selectors.is_none()
+        && ctx.sql_family() != SqlFamily::Sqlite;
+
+    let ids = if lock_versions {
+        lock_ids(conn, model, record_filter.filter, ctx).await?
+    } else {
+        conn.filter_selectors(model, record_filter, ctx).await?
+    };
+
     let id_args = pick_args(&model.primary_identifier(), &args);
 
     if ids.is_empty() {
@@ -85,19 +164,52 @@ pub async fn update_records(
     };
 
     for update in updates {
-        conn.query(update).await?;
+        conn.traced_query(update, ctx).await?;
     }
 
     Ok(merge_write_args(ids, id_args))
 }
 
+/// Reads the identifiers of the records matching `filter`, locking them until the end of the
+/// transaction. Versioned models filter on the expected version, and without the lock a
+/// concurrent update could change the version between the read and the update.
+async fn lock_ids(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    filter: Filter,
+    ctx: &Context<'_>,
+) -> crate::Result<Vec<RecordProjection>> {
+    let model_id = model.primary_identifier();
+    let id_cols: Vec<Column<'static>> = model_id.as_columns().collect();
+    let idents: Vec<_> = model_id.type_identifiers_with_arities();
+
+    let select = Select::from_table(model.as_table())
+        .columns(id_cols)
+        .so_that(filter.aliased_cond(None, ctx)?);
+
+    let lock = RowLock {
+        strength: LockStrength::ForUpdate,
+        wait: LockWait::Wait,
+    };
+
+    let server_version = ctx.server_version(conn).await?;
+    let (sql, params) = read::lock_records(model, select, lock, server_version, ctx)?;
+    let rows = conn.filter_locked(sql, params, idents.as_slice()).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| RecordProjection::new(model_id.scalar_fields().zip(row.values.into_iter()).collect()))
+        .collect())
+}
+
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
 pub async fn delete_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     record_filter: RecordFilter,
+    ctx: &Context<'_>,
 ) -> crate::Result<usize> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn.filter_selectors(model, record_filter, ctx).await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -106,7 +218,7 @@ pub async fn delete_records(
     }
 
     for delete in write::delete_many(model, ids.as_slice()) {
-        conn.query(delete).await?;
+        conn.traced_query(delete, ctx).await?;
     }
 
     Ok(count)
@@ -119,9 +231,10 @@ pub async fn connect(
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
     child_ids: &[RecordProjection],
+    ctx: &Context<'_>,
 ) -> crate::Result<()> {
     let query = write::create_relation_table_records(field, parent_id, child_ids);
-    conn.query(query).await?;
+    conn.traced_query(query, ctx).await?;
 
     Ok(())
 }
@@ -133,9 +246,10 @@ pub async fn disconnect(
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
     child_ids: &[RecordProjection],
+    ctx: &Context<'_>,
 ) -> crate::Result<()> {
     let query = write::delete_relation_table_records(field, parent_id, child_ids);
-    conn.delete(query).await?;
+    conn.traced_query(query.into(), ctx).await?;
 
     Ok(())
 }
@@ -210,5 +324,13 @@ fn apply_expression(val: PrismaValue, expr: WriteExpression) -> PrismaValue {
         WriteExpression::Substract(rhs) => val - rhs,
         WriteExpression::Multiply(rhs) => val * rhs,
         WriteExpression::Divide(rhs) => val / rhs,
+        WriteExpression::Push(rhs) => match (val, rhs) {
+            (PrismaValue::List(mut values), PrismaValue::List(pushed)) => {
+                values.extend(pushed);
+                PrismaValue::List(values)
+            }
+            (_, pushed) => pushed,
+        },
+        WriteExpression::Unset => PrismaValue::Null,
     }
 }
@@ -0,0 +1,195 @@
+use super::{canceller::CancellingConnection, connection::SqlConnection};
+use crate::{ServerVersion, SqlError};
+use once_cell::sync::Lazy;
+use quaint::{
+    pooled::{PooledConnection, Quaint},
+    prelude::ConnectionInfo,
+};
+use std::{
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc, Mutex, Weak,
+    },
+    time::Instant,
+};
+
+/// The pools of the process, for `record_pool_connections` to read their idle connections.
+static POOLS: Lazy<Mutex<Vec<Weak<PoolMetrics>>>> = Lazy::new(Default::default);
+
+/// A `quaint` pool that records metrics about its connections, labeled with the name of the pool:
+///
+/// - `prisma_pool_checkout_wait_microseconds`: how long a checkout waited for a connection.
+/// - `prisma_pool_connections`: the number of `active` (checked out) and `idle` (open but free) connections.
+///   The active connections are recorded on every checkout, the idle ones by `record_pool_connections`.
+pub(crate) struct Pool {
+    inner: Quaint,
+    metrics: Arc<PoolMetrics>,
+    server_version: ServerVersion,
+    cancelling_connection: CancellingConnection,
+}
+
+impl Pool {
+    /// Wraps `inner`, a pool connecting to `url`.
+    pub(crate) async fn new(inner: Quaint, url: &str, name: impl Into<String>) -> Self {
+        let metrics = Arc::new(PoolMetrics {
+            name: name.into(),
+            pool: inner.clone(),
+            active: AtomicI64::new(0),
+        });
+
+        POOLS.lock().unwrap().push(Arc::downgrade(&metrics));
+
+        Self {
+            inner,
+            metrics,
+            server_version: ServerVersion::default(),
+            cancelling_connection: CancellingConnection::new(url),
+        }
+    }
+
+    pub(crate) fn connection_info(&self) -> &ConnectionInfo {
+        self.inner.connection_info()
+    }
+
+    /// Checks a connection out of the pool. The connection counts as active until it is dropped.
+    pub(crate) async fn check_out(&self) -> crate::Result<SqlConnection<PooledConnection>> {
+        let start = Instant::now();
+        let conn = self.inner.check_out().await.map_err(SqlError::from)?;
+
+        metrics::value!(
+            "prisma_pool_checkout_wait_microseconds",
+            start.elapsed().as_micros() as u64,
+            "pool" => self.metrics.name.clone()
+        );
+
+        let checkout = Checkout::new(&self.metrics);
+
+        Ok(SqlConnection::new(
+            conn,
+            self.connection_info(),
+            checkout,
+            self.cancelling_connection.clone(),
+            self.server_version.clone(),
+        ))
+    }
+}
+
+/// Records the idle connections of every pool of the process, to be called before the metrics are
+/// scraped. Only the pool knows which of its connections are still open, and reading its state is
+/// async, so the idle connections can't be recorded when a connection is returned.
+pub async fn record_pool_connections() {
+    let pools: Vec<Arc<PoolMetrics>> = {
+        let mut pools = POOLS.lock().unwrap();
+        pools.retain(|pool| pool.strong_count() > 0);
+        pools.iter().filter_map(Weak::upgrade).collect()
+    };
+
+    for pool in pools {
+        pool.record_idle().await;
+    }
+}
+
+struct PoolMetrics {
+    name: String,
+    pool: Quaint,
+    active: AtomicI64,
+}
+
+impl PoolMetrics {
+    fn add_active(&self, delta: i64) {
+        let active = self.active.fetch_add(delta, Ordering::SeqCst) + delta;
+
+        metrics::gauge!("prisma_pool_connections", active, "pool" => self.name.clone(), "state" => "active");
+    }
+
+    async fn record_idle(&self) {
+        let idle = self.pool.inner.state().await.idle as i64;
+
+        metrics::gauge!("prisma_pool_connections", idle, "pool" => self.name.clone(), "state" => "idle");
+    }
+}
+
+/// Keeps track of a checked out connection for the pool metrics.
+pub(crate) struct Checkout {
+    metrics: Arc<PoolMetrics>,
+}
+
+impl Checkout {
+    fn new(metrics: &Arc<PoolMetrics>) -> Self {
+        metrics.add_active(1);
+
+        Self {
+            metrics: Arc::clone(metrics),
+        }
+    }
+}
+
+impl Drop for Checkout {
+    fn drop(&mut self) {
+        self.metrics.add_active(-1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use metrics::{Key, Recorder};
+    use std::collections::HashMap;
+
+    /// Keeps the last value of each gauge, by sorted labels.
+    #[derive(Default)]
+    struct GaugeRecorder {
+        gauges: Mutex<HashMap<Vec<(String, String)>, i64>>,
+    }
+
+    impl Recorder for GaugeRecorder {
+        fn increment_counter(&self, _key: Key, _value: u64) {}
+
+        fn update_gauge(&self, key: Key, value: i64) {
+            let (_, labels) = key.into_parts();
+
+            let mut labels: Vec<(String, String)> = labels
+                .into_iter()
+                .map(|label| {
+                    let (key, value) = label.into_parts();
+                    (key.into_owned(), value.into_owned())
+                })
+                .collect();
+
+            labels.sort();
+
+            self.gauges.lock().unwrap().insert(labels, value);
+        }
+
+        fn record_histogram(&self, _key: Key, _value: u64) {}
+    }
+
+    static RECORDER: Lazy<GaugeRecorder> = Lazy::new(Default::default);
+
+    fn pool_connections(state: &str) -> Option<i64> {
+        let labels = vec![
+            ("pool".to_owned(), "test".to_owned()),
+            ("state".to_owned(), state.to_owned()),
+        ];
+
+        RECORDER.gauges.lock().unwrap().get(&labels).copied()
+    }
+
+    #[tokio::test]
+    async fn pool_connection_gauges_follow_checkouts() {
+        metrics::set_recorder(&*RECORDER).unwrap();
+
+        let url = format!("file:{}", std::env::temp_dir().join("pool_metrics.db").display());
+        let pool = Pool::new(Quaint::builder(&url).unwrap().build(), &url, "test").await;
+
+        let conn = pool.check_out().await.unwrap();
+        assert_eq!(Some(1), pool_connections("active"));
+
+        // The gauge is up to date as soon as the connection is returned, without waiting for a task.
+        drop(conn);
+        assert_eq!(Some(0), pool_connections("active"));
+
+        record_pool_connections().await;
+        assert_eq!(Some(1), pool_connections("idle"));
+    }
+}
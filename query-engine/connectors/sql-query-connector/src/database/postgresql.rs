@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{pool::Pool, replicas::Replicas};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -10,8 +10,9 @@ use quaint::{pooled::Quaint, prelude::ConnectionInfo};
 use std::time::Duration;
 
 pub struct PostgreSql {
-    pool: Quaint,
+    pool: Pool,
     connection_info: ConnectionInfo,
+    replicas: Replicas,
 }
 
 #[async_trait]
@@ -34,9 +35,14 @@ impl FromSource for PostgreSql {
         builder.health_check_interval(Duration::from_secs(15));
         builder.test_on_check_out(true);
 
-        let pool = builder.build();
+        let pool = Pool::new(builder.build(), database_str, "primary").await;
         let connection_info = pool.connection_info().to_owned();
-        Ok(PostgreSql { pool, connection_info })
+        let replicas = Replicas::from_source(source).await?;
+        Ok(PostgreSql {
+            pool,
+            connection_info,
+            replicas,
+        })
     }
 }
 
@@ -44,13 +50,20 @@ impl FromSource for PostgreSql {
 impl Connector for PostgreSql {
     async fn get_connection<'a>(&'a self) -> connector_interface::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let conn = self.pool.check_out().await?;
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
         .await
     }
 
+    async fn get_read_connection<'a>(&'a self) -> connector_interface::Result<Box<dyn Connection + 'static>> {
+        if self.replicas.is_empty() {
+            self.get_connection().await
+        } else {
+            self.replicas.get_connection().await
+        }
+    }
+
     fn name(&self) -> String {
         "postgres".to_owned()
     }
@@ -0,0 +1,78 @@
+use super::pool::Pool;
+use crate::SqlError;
+use connector_interface::{
+    self as connector,
+    error::{ConnectorError, ErrorKind},
+    Connection,
+};
+use datamodel::Datasource;
+use quaint::{pooled::Quaint, prelude::ConnectionInfo};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// Connection pools to the read replicas of a datasource.
+/// Connections are checked out from the pools in round-robin order.
+pub(crate) struct Replicas {
+    pools: Vec<Pool>,
+    next: AtomicUsize,
+}
+
+impl Replicas {
+    /// Builds one pool per replica URL of the datasource, with the same settings as the primary pool.
+    pub(crate) async fn from_source(source: &Datasource) -> connector::Result<Self> {
+        let mut pools = Vec::with_capacity(source.replica_urls().len());
+
+        for (idx, url) in source.replica_urls().iter().enumerate() {
+            let pool = build_pool(&url.value)?;
+            pools.push(Pool::new(pool, &url.value, format!("replica_{}", idx)).await);
+        }
+
+        Ok(Self {
+            pools,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pools.is_empty()
+    }
+
+    /// Checks out a connection from the next replica pool.
+    /// Must not be called if there are no replicas.
+    pub(crate) async fn get_connection(&self) -> connector::Result<Box<dyn Connection + 'static>> {
+        let pool = self.next_pool();
+
+        super::catch(pool.connection_info(), async move {
+            let conn = pool.check_out().await?;
+
+            Ok(Box::new(conn) as Box<dyn Connection>)
+        })
+        .await
+    }
+
+    fn next_pool(&self) -> &Pool {
+        let idx = self.next.fetch_add(1, Ordering::Relaxed) % self.pools.len();
+        &self.pools[idx]
+    }
+}
+
+fn build_pool(url: &str) -> connector::Result<Quaint> {
+    let connection_info = ConnectionInfo::from_url(url).map_err(|err| {
+        ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
+            details: err.to_string(),
+            url: url.to_string(),
+        })
+    })?;
+
+    let mut builder = Quaint::builder(url)
+        .map_err(SqlError::from)
+        .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
+
+    builder.max_idle_lifetime(Duration::from_secs(300));
+    builder.health_check_interval(Duration::from_secs(15));
+    builder.test_on_check_out(true);
+
+    Ok(builder.build())
+}
@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::pool::Pool;
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -11,7 +11,7 @@ use quaint::{connector::SqliteParams, error::ErrorKind as QuaintKind, pooled::Qu
 use std::{convert::TryFrom, time::Duration};
 
 pub struct Sqlite {
-    pool: Quaint,
+    pool: Pool,
     file_path: String,
 }
 
@@ -69,7 +69,7 @@ impl FromSource for Sqlite {
         builder.health_check_interval(Duration::from_secs(15));
         builder.test_on_check_out(true);
 
-        let pool = builder.build();
+        let pool = Pool::new(builder.build(), &url_with_db, "primary").await;
 
         Ok(Sqlite { pool, file_path })
     }
@@ -87,14 +87,17 @@ fn invalid_file_path_error(file_path: &str, connection_info: &ConnectionInfo) ->
 impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info(), async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, self.connection_info());
+            let conn = self.pool.check_out().await?;
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
         .await
     }
 
+    async fn get_read_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
+        self.get_connection().await
+    }
+
     fn name(&self) -> String {
         "sqlite".to_owned()
     }
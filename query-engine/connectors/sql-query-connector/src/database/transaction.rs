@@ -1,9 +1,9 @@
 use crate::database::operations::*;
-use crate::SqlError;
+use crate::{Context, ServerVersion, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector, filter::Filter, AggregationRow, AggregationSelection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    RecordFilter, RowLock, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -12,14 +12,20 @@ use quaint::prelude::ConnectionInfo;
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    server_version: ServerVersion,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
-    pub fn new<'b: 'tx>(tx: quaint::connector::Transaction<'tx>, connection_info: &ConnectionInfo) -> Self {
+    pub(crate) fn new<'b: 'tx>(
+        tx: quaint::connector::Transaction<'tx>,
+        connection_info: &ConnectionInfo,
+        server_version: ServerVersion,
+    ) -> Self {
         let connection_info = connection_info.clone();
         Self {
             inner: tx,
             connection_info,
+            server_version,
         }
     }
 
@@ -38,12 +44,18 @@ impl<'tx> SqlConnectorTransaction<'tx> {
 impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
     async fn commit(&self) -> connector::Result<()> {
         self.catch(async move { Ok(self.inner.commit().await.map_err(SqlError::from)?) })
-            .await
+            .await?;
+
+        metrics::counter!("prisma_transactions_total", 1, "outcome" => "commit");
+        Ok(())
     }
 
     async fn rollback(&self) -> connector::Result<()> {
         self.catch(async move { Ok(self.inner.rollback().await.map_err(SqlError::from)?) })
-            .await
+            .await?;
+
+        metrics::counter!("prisma_transactions_total", 1, "outcome" => "rollback");
+        Ok(())
     }
 }
 
@@ -54,9 +66,13 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        lock: Option<RowLock>,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(
+            async move { read::get_single_record(&self.inner, model, filter, selected_fields, lock, &ctx).await },
+        )
+        .await
     }
 
     async fn get_many_records(
@@ -65,8 +81,11 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
-            .await
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(
+            async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields, &ctx).await },
+        )
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -74,8 +93,24 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
-            .await
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(
+            async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids, &ctx).await },
+        )
+        .await
+    }
+
+    async fn count_related_records(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+        filter: Option<Filter>,
+    ) -> connector::Result<Vec<(RecordProjection, usize)>> {
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(
+            async move { read::count_related_records(&self.inner, from_field, from_record_ids, filter, &ctx).await },
+        )
+        .await
     }
 
     async fn aggregate_records(
@@ -86,9 +121,10 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         group_by: Vec<ScalarFieldRef>,
         having: Option<Filter>,
     ) -> connector::Result<Vec<AggregationRow>> {
-        self.catch(
-            async move { read::aggregate(&self.inner, model, query_arguments, selections, group_by, having).await },
-        )
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move {
+            read::aggregate(&self.inner, model, query_arguments, selections, group_by, having, &ctx).await
+        })
         .await
     }
 }
@@ -96,7 +132,19 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
 #[async_trait]
 impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::create_record(&self.inner, model, args, &ctx).await })
+            .await
+    }
+
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::Result<usize> {
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::create_records(&self.inner, model, args, skip_duplicates, &ctx).await })
             .await
     }
 
@@ -106,12 +154,14 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::update_records(&self.inner, model, record_filter, args, &ctx).await })
             .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::delete_records(&self.inner, model, record_filter, &ctx).await })
             .await
     }
 
@@ -121,7 +171,8 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids, &ctx).await })
             .await
     }
 
@@ -131,7 +182,8 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
+        let ctx = Context::new(&self.connection_info, &self.server_version);
+        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids, &ctx).await })
             .await
     }
 
@@ -49,6 +49,9 @@ pub enum SqlError {
     #[error("Foreign key constraint failed")]
     ForeignKeyConstraintViolation { constraint: DatabaseConstraint },
 
+    #[error("Check constraint failed: {:?}", constraint)]
+    CheckConstraintViolation { constraint: DatabaseConstraint },
+
     #[error("Record does not exist.")]
     RecordDoesNotExist,
 
@@ -107,6 +110,12 @@ pub enum SqlError {
 
     #[error("Database error. error code: {}, error message: {}", code, message)]
     RawError { code: String, message: String },
+
+    #[error("Unable to lock rows: {0}")]
+    RowLockUnavailable(String),
+
+    #[error("Unsupported filter: {0}")]
+    UnsupportedFilter(String),
 }
 
 impl SqlError {
@@ -126,6 +135,9 @@ impl SqlError {
             SqlError::ForeignKeyConstraintViolation { constraint } => {
                 ConnectorError::from_kind(ErrorKind::ForeignKeyConstraintViolation { constraint })
             }
+            SqlError::CheckConstraintViolation { constraint } => {
+                ConnectorError::from_kind(ErrorKind::CheckConstraintViolation { constraint })
+            }
             SqlError::RecordDoesNotExist => ConnectorError::from_kind(ErrorKind::RecordDoesNotExist),
             SqlError::TableDoesNotExist(table) => ConnectorError::from_kind(ErrorKind::TableDoesNotExist { table }),
             SqlError::ColumnDoesNotExist(column) => ConnectorError::from_kind(ErrorKind::ColumnDoesNotExist { column }),
@@ -180,6 +192,8 @@ impl SqlError {
                 )),
                 kind: ErrorKind::RawError { code, message },
             },
+            SqlError::RowLockUnavailable(reason) => ConnectorError::from_kind(ErrorKind::RowLockUnavailable(reason)),
+            SqlError::UnsupportedFilter(details) => ConnectorError::from_kind(ErrorKind::UnsupportedFilter(details)),
         }
     }
 }
@@ -192,6 +206,12 @@ impl From<prisma_models::ConversionFailure> for SqlError {
 
 impl From<quaint::error::Error> for SqlError {
     fn from(e: quaint::error::Error) -> Self {
+        if let Some(constraint_name) = violated_check_constraint(&e) {
+            return Self::CheckConstraintViolation {
+                constraint: DatabaseConstraint::Index(constraint_name),
+            };
+        }
+
         match QuaintKind::from(e) {
             QuaintKind::FromRowError(_) => todo!("QuaintKind::FromRowError"),
             QuaintKind::QueryError(qe) => Self::QueryError(qe),
@@ -231,6 +251,36 @@ impl From<quaint::error::Error> for SqlError {
     }
 }
 
+/// Quaint has no dedicated error kind for check constraint violations, so we
+/// recognize them from the original database error and extract the name of
+/// the violated constraint from the message.
+fn violated_check_constraint(e: &quaint::error::Error) -> Option<String> {
+    check_constraint_name(e.original_code().unwrap_or_default(), e.original_message()?)
+}
+
+fn check_constraint_name(code: &str, message: &str) -> Option<String> {
+    let name = match code {
+        // Postgres: new row for relation "Product" violates check constraint "positivePrice"
+        "23514" => message.split("violates check constraint ").nth(1),
+        // MySQL: Check constraint 'positivePrice' is violated.
+        "3819" => message.split("Check constraint ").nth(1),
+        // MariaDB: CONSTRAINT `positivePrice` failed for `db`.`Product`
+        "4025" => message.split("CONSTRAINT ").nth(1),
+        // SQL Server: The INSERT statement conflicted with the CHECK constraint "positivePrice".
+        "547" if message.contains("CHECK constraint") => message.split("CHECK constraint ").nth(1),
+        // SQLite: CHECK constraint failed: positivePrice
+        _ if message.starts_with("CHECK constraint failed: ") => message.split(": ").nth(1),
+        _ => None,
+    }?;
+
+    let name = match name.chars().next()? {
+        quote @ '"' | quote @ '\'' | quote @ '`' => name[1..].split(quote).next()?,
+        _ => name.trim(),
+    };
+
+    Some(name.to_owned())
+}
+
 impl From<DomainError> for SqlError {
     fn from(e: DomainError) -> SqlError {
         SqlError::DomainError(e)
@@ -254,3 +304,57 @@ impl From<FromUtf8Error> for SqlError {
         SqlError::ColumnReadFailure(e.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_constraint_names_are_extracted_from_database_errors() {
+        let errors = &[
+            (
+                "23514",
+                r#"new row for relation "Product" violates check constraint "positivePrice""#,
+            ),
+            ("3819", "Check constraint 'positivePrice' is violated."),
+            ("4025", "CONSTRAINT `positivePrice` failed for `db`.`Product`"),
+            (
+                "547",
+                r#"The INSERT statement conflicted with the CHECK constraint "positivePrice". The conflict occurred in database "db", table "dbo.Product", column 'price'."#,
+            ),
+            ("275", "CHECK constraint failed: positivePrice"),
+        ];
+
+        for (code, message) in errors {
+            assert_eq!(
+                Some("positivePrice".to_owned()),
+                check_constraint_name(code, message),
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn other_constraint_violations_are_not_check_constraint_violations() {
+        let errors = &[
+            (
+                "547",
+                r#"The INSERT statement conflicted with the FOREIGN KEY constraint "Product_categoryId_fkey"."#,
+            ),
+            (
+                "23505",
+                r#"duplicate key value violates unique constraint "Product_name_key""#,
+            ),
+            (
+                "1452",
+                "Cannot add or update a child row: a foreign key constraint fails",
+            ),
+            ("2067", "UNIQUE constraint failed: Product.name"),
+        ];
+
+        for (code, message) in errors {
+            assert_eq!(None, check_constraint_name(code, message), "{}", message);
+        }
+    }
+}
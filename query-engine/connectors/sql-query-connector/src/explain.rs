@@ -0,0 +1,51 @@
+use crate::{query_ext::result_set_to_json, sql_trace::build_sql, Context, SqlError};
+use connector_interface::{explain::current_explain_log, ExplainMode};
+use quaint::{
+    ast::Query,
+    connector::{Queryable, SqlFamily},
+};
+
+/// Records a statement into the explain log of the current operation, if the operation is explained.
+/// In `ExplainMode::Plans`, the plan of a read is requested from the database before the read runs.
+pub(crate) async fn record<C>(conn: &C, query: &Query<'_>, ctx: &Context<'_>) -> crate::Result<()>
+where
+    C: Queryable + Send + Sync + ?Sized,
+{
+    let log = match current_explain_log() {
+        Some(log) => log,
+        None => return Ok(()),
+    };
+
+    let (sql, params) = build_sql(query.clone(), ctx.sql_family()).map_err(SqlError::from)?;
+    let is_read = matches!(query, Query::Select(_) | Query::Union(_));
+
+    let plan = match plan_prefix(ctx.sql_family()) {
+        Some(prefix) if is_read && log.mode() == ExplainMode::Plans => {
+            let result_set = conn.query_raw(&format!("{}{}", prefix, sql), &params).await?;
+            Some(result_set_to_json(result_set))
+        }
+        _ => None,
+    };
+
+    log.record(sql, plan);
+
+    Ok(())
+}
+
+/// Records a raw statement into the explain log of the current operation, if the operation is explained.
+/// Plans are not requested for raw statements, as they can't be told apart from writes.
+pub(crate) fn record_raw(sql: &str) {
+    if let Some(log) = current_explain_log() {
+        log.record(sql, None);
+    }
+}
+
+/// The prefix turning a read into a request for its plan. SQL Server only returns plans for
+/// separate batches after `SET SHOWPLAN_XML ON`, so plans are not requested there.
+fn plan_prefix(family: SqlFamily) -> Option<&'static str> {
+    match family {
+        SqlFamily::Postgres | SqlFamily::Mysql => Some("EXPLAIN "),
+        SqlFamily::Sqlite => Some("EXPLAIN QUERY PLAN "),
+        SqlFamily::Mssql => None,
+    }
+}
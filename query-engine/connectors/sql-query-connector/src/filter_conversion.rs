@@ -1,6 +1,7 @@
+use crate::{Context, SqlError};
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::SqlFamily};
 
 #[derive(Clone, Copy, Debug)]
 /// A distinction in aliasing to separate the parent table and the joined data
@@ -59,8 +60,8 @@ pub trait AliasedCondition {
     /// alias if provided, otherwise using the fully qualified path.
     ///
     /// Alias should be used only when nesting, making the top level queries
-    /// more explicit.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static>;
+    /// more explicit. Fails for filters the database of the connection can't express.
+    fn aliased_cond(self, alias: Option<Alias>, ctx: &Context<'_>) -> crate::Result<ConditionTree<'static>>;
 }
 
 trait AliasedSelect {
@@ -69,55 +70,52 @@ trait AliasedSelect {
     ///
     /// Alias should be used only when nesting, making the top level queries
     /// more explicit.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static>;
+    fn aliased_sel(self, alias: Option<Alias>, ctx: &Context<'_>) -> crate::Result<Select<'static>>;
 }
 
 impl AliasedCondition for Filter {
     /// Conversion from a `Filter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
-        match self {
+    fn aliased_cond(self, alias: Option<Alias>, ctx: &Context<'_>) -> crate::Result<ConditionTree<'static>> {
+        let tree = match self {
             Filter::And(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, ctx)?,
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
-                        .map(Expression::from)
-                        .collect();
+                        .map(|f| f.aliased_cond(alias, ctx).map(Expression::from))
+                        .collect::<crate::Result<Vec<_>>>()?;
 
                     ConditionTree::And(exprs)
                 }
             },
             Filter::Or(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NegativeCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, ctx)?,
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
-                        .map(Expression::from)
-                        .collect();
+                        .map(|f| f.aliased_cond(alias, ctx).map(Expression::from))
+                        .collect::<crate::Result<Vec<_>>>()?;
 
                     ConditionTree::Or(exprs)
                 }
             },
             Filter::Not(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias).not(),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, ctx)?.not(),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias).not())
-                        .map(Expression::from)
-                        .collect();
+                        .map(|f| f.aliased_cond(alias, ctx).map(|cond| Expression::from(cond.not())))
+                        .collect::<crate::Result<Vec<_>>>()?;
 
                     ConditionTree::And(exprs)
                 }
             },
-            Filter::Scalar(filter) => filter.aliased_cond(alias),
-            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
-            Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Scalar(filter) => filter.aliased_cond(alias, ctx)?,
+            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias, ctx)?,
+            Filter::Relation(filter) => filter.aliased_cond(alias, ctx)?,
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -125,16 +123,18 @@ impl AliasedCondition for Filter {
                     ConditionTree::NegativeCondition
                 }
             }
-            Filter::Aggregation(filter) => filter.aliased_cond(alias),
+            Filter::Aggregation(filter) => filter.aliased_cond(alias, ctx)?,
             Filter::Empty => ConditionTree::NoCondition,
             _ => unimplemented!(),
-        }
+        };
+
+        Ok(tree)
     }
 }
 
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, ctx: &Context<'_>) -> crate::Result<ConditionTree<'static>> {
         match (alias, self.projection) {
             (Some(alias), ScalarProjection::Single(field)) => {
                 let comparable: Expression = match self.mode {
@@ -142,7 +142,7 @@ impl AliasedCondition for ScalarFilter {
                     QueryMode::Insensitive => lower(field.as_column().table(alias.to_string(None))).into(),
                 };
 
-                convert_scalar_filter(comparable, self.condition, self.mode, &[field])
+                convert_scalar_filter(comparable, self.condition, self.mode, &[field], ctx)
             }
             (Some(alias), ScalarProjection::Compound(fields)) => {
                 let columns: Vec<Column<'static>> = fields
@@ -151,7 +151,7 @@ impl AliasedCondition for ScalarFilter {
                     .map(|field| field.as_column().table(alias.to_string(None)))
                     .collect();
 
-                convert_scalar_filter(Row::from(columns), self.condition, self.mode, &fields)
+                convert_scalar_filter(Row::from(columns), self.condition, self.mode, &fields, ctx)
             }
             (None, ScalarProjection::Single(field)) => {
                 let comparable: Expression = match self.mode {
@@ -159,12 +159,12 @@ impl AliasedCondition for ScalarFilter {
                     QueryMode::Insensitive => lower(field.as_column()).into(),
                 };
 
-                convert_scalar_filter(comparable, self.condition, self.mode, &[field])
+                convert_scalar_filter(comparable, self.condition, self.mode, &[field], ctx)
             }
             (None, ScalarProjection::Compound(fields)) => {
                 let columns: Vec<Column<'static>> = fields.clone().into_iter().map(|field| field.as_column()).collect();
 
-                convert_scalar_filter(Row::from(columns), self.condition, self.mode, &fields)
+                convert_scalar_filter(Row::from(columns), self.condition, self.mode, &fields, ctx)
             }
         }
     }
@@ -172,7 +172,7 @@ impl AliasedCondition for ScalarFilter {
 
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, ctx: &Context<'_>) -> crate::Result<ConditionTree<'static>> {
         let ids = self.field.model().primary_identifier().as_columns();
         let columns: Vec<Column<'static>> = match alias {
             Some(alias) => ids.map(|c| c.table(alias.to_string(None))).collect(),
@@ -180,7 +180,7 @@ impl AliasedCondition for RelationFilter {
         };
 
         let condition = self.condition;
-        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
+        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)), ctx)?;
 
         let comparison = match condition {
             RelationCondition::AtLeastOneRelatedRecord => Row::from(columns).in_selection(sub_select),
@@ -189,13 +189,13 @@ impl AliasedCondition for RelationFilter {
             RelationCondition::ToOneRelatedRecord => Row::from(columns).in_selection(sub_select),
         };
 
-        comparison.into()
+        Ok(comparison.into())
     }
 }
 
 impl AliasedSelect for RelationFilter {
     /// The subselect part of the `RelationFilter` `ConditionTree`.
-    fn aliased_sel<'a>(self, alias: Option<Alias>) -> Select<'static> {
+    fn aliased_sel<'a>(self, alias: Option<Alias>, ctx: &Context<'_>) -> crate::Result<Select<'static>> {
         let alias = alias.unwrap_or_default();
         let condition = self.condition;
 
@@ -223,7 +223,7 @@ impl AliasedSelect for RelationFilter {
 
         let nested_conditions = self
             .nested_filter
-            .aliased_cond(Some(alias.flip(AliasMode::Join)))
+            .aliased_cond(Some(alias.flip(AliasMode::Join)), ctx)?
             .invert_if(condition.invert_of_subselect());
 
         let conditions = selected_identifier
@@ -235,16 +235,16 @@ impl AliasedSelect for RelationFilter {
             .alias(alias.to_string(Some(AliasMode::Join)))
             .on(Row::from(related_join_columns).equals(Row::from(join_columns)));
 
-        Select::from_table(table.alias(alias.to_string(Some(AliasMode::Table))))
+        Ok(Select::from_table(table.alias(alias.to_string(Some(AliasMode::Table))))
             .columns(selected_identifier)
             .inner_join(join)
-            .so_that(conditions)
+            .so_that(conditions))
     }
 }
 
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, _ctx: &Context<'_>) -> crate::Result<ConditionTree<'static>> {
         let alias = alias.map(|a| a.to_string(None));
 
         let condition = if self.field.relation_is_inlined_in_parent() {
@@ -292,24 +292,29 @@ impl AliasedCondition for OneRelationIsNullFilter {
             Row::from(id_columns).not_in_selection(sub_select).into()
         };
 
-        ConditionTree::single(condition)
+        Ok(ConditionTree::single(condition))
     }
 }
 
 impl AliasedCondition for AggregationFilter {
     /// Conversion from an `AggregationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, ctx: &Context<'_>) -> crate::Result<ConditionTree<'static>> {
         match self {
-            AggregationFilter::Count(filter) => aggregate_conditions(*filter, alias, |x| count(x).into()),
-            AggregationFilter::Average(filter) => aggregate_conditions(*filter, alias, |x| avg(x).into()),
-            AggregationFilter::Sum(filter) => aggregate_conditions(*filter, alias, |x| sum(x).into()),
-            AggregationFilter::Min(filter) => aggregate_conditions(*filter, alias, |x| min(x).into()),
-            AggregationFilter::Max(filter) => aggregate_conditions(*filter, alias, |x| max(x).into()),
+            AggregationFilter::Count(filter) => aggregate_conditions(*filter, alias, ctx, |x| count(x).into()),
+            AggregationFilter::Average(filter) => aggregate_conditions(*filter, alias, ctx, |x| avg(x).into()),
+            AggregationFilter::Sum(filter) => aggregate_conditions(*filter, alias, ctx, |x| sum(x).into()),
+            AggregationFilter::Min(filter) => aggregate_conditions(*filter, alias, ctx, |x| min(x).into()),
+            AggregationFilter::Max(filter) => aggregate_conditions(*filter, alias, ctx, |x| max(x).into()),
         }
     }
 }
 
-fn aggregate_conditions<T>(filter: Filter, alias: Option<Alias>, field_transformer: T) -> ConditionTree<'static>
+fn aggregate_conditions<T>(
+    filter: Filter,
+    alias: Option<Alias>,
+    ctx: &Context<'_>,
+    field_transformer: T,
+) -> crate::Result<ConditionTree<'static>>
 where
     T: Fn(Column) -> Expression,
 {
@@ -324,25 +329,34 @@ where
         }
         (Some(alias), ScalarProjection::Single(field)) => {
             let comparable: Expression = field_transformer(field.as_column().table(alias.to_string(None)).into());
-            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field])
+            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], ctx)
         }
         (None, ScalarProjection::Single(field)) => {
             let comparable: Expression = field_transformer(field.as_column().into());
-            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field])
+            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], ctx)
         }
     }
 }
 
-fn convert_scalar_filter(
-    comparable: impl Comparable<'static>,
+fn convert_scalar_filter<T>(
+    comparable: T,
     cond: ScalarCondition,
     mode: QueryMode,
     fields: &[ScalarFieldRef],
-) -> ConditionTree<'static> {
-    match mode {
-        QueryMode::Default => default_scalar_filter(comparable, cond, fields),
-        QueryMode::Insensitive => insensitive_scalar_filter(comparable, cond, fields),
-    }
+    ctx: &Context<'_>,
+) -> crate::Result<ConditionTree<'static>>
+where
+    T: Comparable<'static> + Into<Expression<'static>>,
+{
+    let tree = match (cond, mode) {
+        (ScalarCondition::JsonCompare(json), _) => json_filter(comparable.into(), json, fields, ctx)?,
+        (ScalarCondition::Search(query), _) => ConditionTree::single(search_condition(comparable.into(), query, ctx)?),
+        (ScalarCondition::NotSearch(query), _) => ConditionTree::not(search_condition(comparable.into(), query, ctx)?),
+        (cond, QueryMode::Default) => default_scalar_filter(comparable, cond, fields),
+        (cond, QueryMode::Insensitive) => insensitive_scalar_filter(comparable, cond, fields),
+    };
+
+    Ok(tree)
 }
 
 fn default_scalar_filter(
@@ -483,3 +497,483 @@ fn convert_values<'a>(fields: &[ScalarFieldRef], values: Vec<PrismaValue>) -> Ve
         values.into_iter().map(|value| field.value(value)).collect()
     }
 }
+
+/// The text search configuration of full-text searches on Postgres. The migration engine creates
+/// fulltext indexes in the same configuration, and Postgres only uses an index on
+/// `to_tsvector(config, column)` if the query names the same configuration.
+const FULLTEXT_SEARCH_CONFIG: &str = "english";
+
+/// Full-text search. Postgres reads the search text with `plainto_tsquery`, so plain text like
+/// `hello world` is a valid search. Quaint renders no text search configurations, so the calls are
+/// spliced into raw comparisons, starting from a `TRUE` parameter. MySQL requires a `FULLTEXT`
+/// index on exactly the searched column:
+/// ```sql
+/// WHERE $1 AND to_tsvector('english', ("Post"."title") ) @@ plainto_tsquery('english', $2 ) AND $3
+/// WHERE MATCH (`Post`.`title`) AGAINST (? IN BOOLEAN MODE)
+/// ```
+fn search_condition(
+    comparable: Expression<'static>,
+    query: PrismaValue,
+    ctx: &Context<'_>,
+) -> crate::Result<Compare<'static>> {
+    let query = format!("{}", query);
+
+    match ctx.sql_family() {
+        SqlFamily::Postgres => {
+            let vector: Expression<'static> = Expression::from(Value::from(true))
+                .compare_raw(
+                    format!("AND to_tsvector('{}',", FULLTEXT_SEARCH_CONFIG),
+                    Row::from(vec![comparable]),
+                )
+                .into();
+
+            let matches: Expression<'static> = vector
+                .compare_raw(format!(") @@ plainto_tsquery('{}',", FULLTEXT_SEARCH_CONFIG), query)
+                .into();
+
+            Ok(matches.compare_raw(") AND", Value::from(true)))
+        }
+        SqlFamily::Mysql => Ok(Expression::from(text_search(&[comparable])).matches(query)),
+        SqlFamily::Sqlite | SqlFamily::Mssql => Err(full_text_search_unsupported()),
+    }
+}
+
+fn full_text_search_unsupported() -> SqlError {
+    SqlError::UnsupportedFilter("Full-text search is only supported on PostgreSQL and MySQL.".into())
+}
+
+/// The relevance of the columns for a full-text search, to order by. On Postgres, the rank is
+/// spliced into a raw sum with two `0` parameters, the same way as the search filter:
+/// ```sql
+/// $1 + ts_rank(to_tsvector('english', concat_ws ($2, "Post"."title", "Post"."content") ), plainto_tsquery('english', $3 ))::float8 + $4
+/// MATCH (`Post`.`title`, `Post`.`content`) AGAINST (? IN BOOLEAN MODE)
+/// ```
+pub(crate) fn relevance(
+    columns: Vec<Column<'static>>,
+    search: String,
+    ctx: &Context<'_>,
+) -> crate::Result<Expression<'static>> {
+    match ctx.sql_family() {
+        SqlFamily::Postgres => {
+            let mut arguments: Vec<Expression<'static>> = vec![Value::from(" ").into()];
+            arguments.extend(columns.into_iter().map(Expression::from));
+
+            let vector: Expression<'static> = Expression::from(Value::from(0.0))
+                .compare_raw(
+                    format!("+ ts_rank(to_tsvector('{}', concat_ws", FULLTEXT_SEARCH_CONFIG),
+                    Row::from(arguments),
+                )
+                .into();
+
+            let rank: Expression<'static> = vector
+                .compare_raw(format!("), plainto_tsquery('{}',", FULLTEXT_SEARCH_CONFIG), search)
+                .into();
+
+            Ok(rank.compare_raw("))::float8 +", Value::from(0.0)).into())
+        }
+        SqlFamily::Mysql => Ok(text_search_relevance(&columns, search).into()),
+        SqlFamily::Sqlite | SqlFamily::Mssql => Err(full_text_search_unsupported()),
+    }
+}
+
+/// Conversion of filters on Json fields. The format of a path is specific to the database family,
+/// so a path that doesn't match the family of the connector is rejected.
+fn json_filter(
+    comparable: Expression<'static>,
+    json: JsonCondition,
+    fields: &[ScalarFieldRef],
+    ctx: &Context<'_>,
+) -> crate::Result<ConditionTree<'static>> {
+    let JsonCondition {
+        condition,
+        path,
+        target_type,
+    } = json;
+
+    match (ctx.sql_family(), path) {
+        (_, None) => Ok(default_scalar_filter(comparable, *condition, fields)),
+        (SqlFamily::Postgres, Some(JsonFilterPath::Array(path))) => {
+            postgres_json_filter(comparable, *condition, path, target_type, fields)
+        }
+        (SqlFamily::Mysql, Some(JsonFilterPath::String(path))) => {
+            mysql_json_filter(comparable, *condition, path, target_type, fields)
+        }
+        (SqlFamily::Sqlite, Some(JsonFilterPath::String(path))) => {
+            sqlite_json_filter(comparable, *condition, path, target_type, fields)
+        }
+        (SqlFamily::Postgres, Some(_)) => Err(SqlError::UnsupportedFilter(
+            "Json paths on PostgreSQL must be an array of keys.".into(),
+        )),
+        (SqlFamily::Mysql, Some(_)) => Err(SqlError::UnsupportedFilter(
+            "Json paths on MySQL must be a string path, like `$.name`.".into(),
+        )),
+        (SqlFamily::Sqlite, Some(_)) => Err(SqlError::UnsupportedFilter(
+            "Json paths on SQLite must be a string path, like `$.name`.".into(),
+        )),
+        (SqlFamily::Mssql, Some(_)) => Err(SqlError::UnsupportedFilter(
+            "Json path filters are only supported on PostgreSQL, MySQL and SQLite.".into(),
+        )),
+    }
+}
+
+fn unsupported_json_condition(condition: ScalarCondition) -> SqlError {
+    SqlError::UnsupportedFilter(format!("Unsupported Json filter condition: {:?}", condition))
+}
+
+/// Json filters on Postgres extract the value at the path with `#>` (as `jsonb`) or `#>>` (as text):
+/// ```sql
+/// WHERE "User"."meta" #>> $1 LIKE $2 -- string_contains
+/// WHERE "User"."meta" #> $1 @> $2 -- array_contains
+/// WHERE "User"."meta" #> $1 > $2 -- gt
+/// ```
+fn postgres_json_filter(
+    comparable: Expression<'static>,
+    condition: ScalarCondition,
+    path: Vec<String>,
+    target_type: Option<JsonTargetType>,
+    fields: &[ScalarFieldRef],
+) -> crate::Result<ConditionTree<'static>> {
+    let json_at =
+        |path: Vec<String>| -> Expression<'static> { comparable.clone().compare_raw("#>", path_array(path)).into() };
+    let text_at =
+        |path: Vec<String>| -> Expression<'static> { comparable.clone().compare_raw("#>>", path_array(path)).into() };
+
+    let with_index = |index: &str| {
+        let mut path = path.clone();
+        path.push(index.to_owned());
+        path
+    };
+
+    let condition = match (target_type, condition) {
+        (Some(JsonTargetType::String), ScalarCondition::Contains(value)) => text_at(path).like(format!("{}", value)),
+        (Some(JsonTargetType::String), ScalarCondition::NotContains(value)) => {
+            text_at(path).not_like(format!("{}", value))
+        }
+        (Some(JsonTargetType::String), ScalarCondition::StartsWith(value)) => {
+            text_at(path).begins_with(format!("{}", value))
+        }
+        (Some(JsonTargetType::String), ScalarCondition::NotStartsWith(value)) => {
+            text_at(path).not_begins_with(format!("{}", value))
+        }
+        (Some(JsonTargetType::String), ScalarCondition::EndsWith(value)) => {
+            text_at(path).ends_into(format!("{}", value))
+        }
+        (Some(JsonTargetType::String), ScalarCondition::NotEndsWith(value)) => {
+            text_at(path).not_ends_into(format!("{}", value))
+        }
+
+        (Some(JsonTargetType::Array), ScalarCondition::Contains(value)) => {
+            json_at(path).compare_raw("@>", Value::Json(Some(json_array(value))))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotContains(value)) => {
+            return Ok(ConditionTree::not(
+                json_at(path).compare_raw("@>", Value::Json(Some(json_array(value)))),
+            ))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::StartsWith(value)) => {
+            json_at(with_index("0")).equals(convert_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotStartsWith(value)) => {
+            json_at(with_index("0")).not_equals(convert_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::EndsWith(value)) => {
+            json_at(with_index("-1")).equals(convert_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotEndsWith(value)) => {
+            json_at(with_index("-1")).not_equals(convert_value(fields, value))
+        }
+
+        (_, ScalarCondition::Equals(PrismaValue::Null)) => json_at(path).is_null(),
+        (_, ScalarCondition::NotEquals(PrismaValue::Null)) => json_at(path).is_not_null(),
+        (_, ScalarCondition::Equals(value)) => json_at(path).equals(convert_value(fields, value)),
+        (_, ScalarCondition::NotEquals(value)) => json_at(path).not_equals(convert_value(fields, value)),
+        (_, ScalarCondition::LessThan(value)) => json_at(path).less_than(convert_value(fields, value)),
+        (_, ScalarCondition::LessThanOrEquals(value)) => {
+            json_at(path).less_than_or_equals(convert_value(fields, value))
+        }
+        (_, ScalarCondition::GreaterThan(value)) => json_at(path).greater_than(convert_value(fields, value)),
+        (_, ScalarCondition::GreaterThanOrEquals(value)) => {
+            json_at(path).greater_than_or_equals(convert_value(fields, value))
+        }
+
+        (_, condition) => return Err(unsupported_json_condition(condition)),
+    };
+
+    Ok(ConditionTree::single(condition))
+}
+
+/// Json filters on MySQL extract the value at the path with `JSON_EXTRACT`, unquoting strings with
+/// `JSON_UNQUOTE`. Unlike the `->` and `->>` operators, which only take a string literal, the function
+/// takes the path as a parameter (see `mysql_json_compare` for the leading parameter):
+/// ```sql
+/// WHERE ? AND JSON_UNQUOTE(JSON_EXTRACT (`User`.`meta`, ?)) LIKE ? -- string_contains
+/// WHERE ? AND JSON_EXTRACT (`User`.`meta`, ?) > ? -- gt
+/// WHERE `User`.`meta` IS NOT NULL AND JSON_CONTAINS (`User`.`meta`, ?, ?) -- array_contains
+/// ```
+fn mysql_json_filter(
+    comparable: Expression<'static>,
+    condition: ScalarCondition,
+    path: String,
+    target_type: Option<JsonTargetType>,
+    fields: &[ScalarFieldRef],
+) -> crate::Result<ConditionTree<'static>> {
+    let json_at =
+        |path: String, op: &str, value: Value<'static>| mysql_json_compare(comparable.clone(), path, false, op, value);
+    let text_at = |op: &str, pattern: String| mysql_json_compare(comparable.clone(), path.clone(), true, op, pattern);
+    let first = format!("{}[0]", path);
+    let last = format!("{}[last]", path);
+
+    let condition = match (target_type, condition) {
+        (Some(JsonTargetType::String), ScalarCondition::Contains(value)) => text_at("LIKE", format!("%{}%", value)),
+        (Some(JsonTargetType::String), ScalarCondition::NotContains(value)) => {
+            text_at("NOT LIKE", format!("%{}%", value))
+        }
+        (Some(JsonTargetType::String), ScalarCondition::StartsWith(value)) => text_at("LIKE", format!("{}%", value)),
+        (Some(JsonTargetType::String), ScalarCondition::NotStartsWith(value)) => {
+            text_at("NOT LIKE", format!("{}%", value))
+        }
+        (Some(JsonTargetType::String), ScalarCondition::EndsWith(value)) => text_at("LIKE", format!("%{}", value)),
+        (Some(JsonTargetType::String), ScalarCondition::NotEndsWith(value)) => {
+            text_at("NOT LIKE", format!("%{}", value))
+        }
+
+        // `JSON_CONTAINS` is a function, so the column is repeated in the argument row.
+        (Some(JsonTargetType::Array), ScalarCondition::Contains(value)) => {
+            mysql_json_contains(comparable.clone(), value, path.clone())
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotContains(value)) => {
+            return Ok(ConditionTree::not(mysql_json_contains(
+                comparable.clone(),
+                value,
+                path.clone(),
+            )))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::StartsWith(value)) => {
+            json_at(first, "=", json_scalar_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotStartsWith(value)) => {
+            json_at(first, "<>", json_scalar_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::EndsWith(value)) => {
+            json_at(last, "=", json_scalar_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotEndsWith(value)) => {
+            json_at(last, "<>", json_scalar_value(fields, value))
+        }
+
+        // `IS NULL` takes no parameter, the null-safe equality does.
+        (_, ScalarCondition::Equals(PrismaValue::Null)) => json_at(path.clone(), "<=>", Value::Json(None)),
+        (_, ScalarCondition::NotEquals(PrismaValue::Null)) => {
+            return Ok(ConditionTree::not(json_at(path.clone(), "<=>", Value::Json(None))))
+        }
+        (_, ScalarCondition::Equals(value)) => json_at(path.clone(), "=", json_scalar_value(fields, value)),
+        (_, ScalarCondition::NotEquals(value)) => json_at(path.clone(), "<>", json_scalar_value(fields, value)),
+        (_, ScalarCondition::LessThan(value)) => json_at(path.clone(), "<", json_scalar_value(fields, value)),
+        (_, ScalarCondition::LessThanOrEquals(value)) => json_at(path.clone(), "<=", json_scalar_value(fields, value)),
+        (_, ScalarCondition::GreaterThan(value)) => json_at(path.clone(), ">", json_scalar_value(fields, value)),
+        (_, ScalarCondition::GreaterThanOrEquals(value)) => {
+            json_at(path.clone(), ">=", json_scalar_value(fields, value))
+        }
+
+        (_, condition) => return Err(unsupported_json_condition(condition)),
+    };
+
+    Ok(ConditionTree::single(condition))
+}
+
+/// Compares the value at `path` with `op`. Quaint renders no function calls of its own, so the call
+/// is appended to a raw comparison, starting from a `TRUE` parameter that leaves the result of the
+/// comparison, `NULL` included, as it is:
+/// ```sql
+/// ? AND JSON_EXTRACT (`User`.`meta`, ?) = ?
+/// ```
+fn mysql_json_compare(
+    comparable: Expression<'static>,
+    path: String,
+    unquote: bool,
+    op: &str,
+    value: impl Into<Expression<'static>>,
+) -> Compare<'static> {
+    let (function, op) = match unquote {
+        true => ("AND JSON_UNQUOTE(JSON_EXTRACT", format!(") {}", op)),
+        false => ("AND JSON_EXTRACT", op.to_owned()),
+    };
+
+    let arguments: Vec<Expression<'static>> = vec![comparable, Value::from(path).into()];
+    let extracted: Expression<'static> = Expression::from(Value::from(true))
+        .compare_raw(function, Row::from(arguments))
+        .into();
+
+    extracted.compare_raw(op, value)
+}
+
+fn mysql_json_contains(comparable: Expression<'static>, value: PrismaValue, path: String) -> Compare<'static> {
+    let candidate: Value<'static> = json_array(value).to_string().into();
+    let arguments: Vec<Expression<'static>> = vec![comparable.clone(), candidate.into(), Value::from(path).into()];
+
+    comparable.compare_raw("IS NOT NULL AND JSON_CONTAINS", Row::from(arguments))
+}
+
+/// Json filters on SQLite extract the value at the path with `json_extract`, which returns JSON
+/// strings as text and other scalars as their SQL counterparts. SQLite has no `JSON_CONTAINS`, so
+/// arrays are searched for each element with the `json_each` table function (see
+/// `mysql_json_compare` for the leading parameter):
+/// ```sql
+/// WHERE ? AND json_extract ("User"."meta", ?) LIKE ? -- string_contains
+/// WHERE ? AND json_extract ("User"."meta", ?) > ? -- gt
+/// WHERE "User"."meta" IS NOT NULL AND (? AND EXISTS (SELECT 1 FROM json_each ("User"."meta", ?) WHERE json_each.value IS ? ) AND ?) -- array_contains
+/// ```
+fn sqlite_json_filter(
+    comparable: Expression<'static>,
+    condition: ScalarCondition,
+    path: String,
+    target_type: Option<JsonTargetType>,
+    fields: &[ScalarFieldRef],
+) -> crate::Result<ConditionTree<'static>> {
+    let json_at =
+        |path: String, op: &str, value: Value<'static>| sqlite_json_compare(comparable.clone(), path, op, value);
+    let text_at = |op: &str, pattern: String| sqlite_json_compare(comparable.clone(), path.clone(), op, pattern);
+    let first = format!("{}[0]", path);
+    let last = format!("{}[#-1]", path);
+
+    let condition = match (target_type, condition) {
+        (Some(JsonTargetType::String), ScalarCondition::Contains(value)) => text_at("LIKE", format!("%{}%", value)),
+        (Some(JsonTargetType::String), ScalarCondition::NotContains(value)) => {
+            text_at("NOT LIKE", format!("%{}%", value))
+        }
+        (Some(JsonTargetType::String), ScalarCondition::StartsWith(value)) => text_at("LIKE", format!("{}%", value)),
+        (Some(JsonTargetType::String), ScalarCondition::NotStartsWith(value)) => {
+            text_at("NOT LIKE", format!("{}%", value))
+        }
+        (Some(JsonTargetType::String), ScalarCondition::EndsWith(value)) => text_at("LIKE", format!("%{}", value)),
+        (Some(JsonTargetType::String), ScalarCondition::NotEndsWith(value)) => {
+            text_at("NOT LIKE", format!("%{}", value))
+        }
+
+        (Some(JsonTargetType::Array), ScalarCondition::Contains(value)) => {
+            return Ok(sqlite_json_contains(comparable.clone(), value, path.clone()))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotContains(value)) => {
+            return Ok(ConditionTree::not(sqlite_json_contains(
+                comparable.clone(),
+                value,
+                path.clone(),
+            )))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::StartsWith(value)) => {
+            json_at(first, "=", json_scalar_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotStartsWith(value)) => {
+            json_at(first, "<>", json_scalar_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::EndsWith(value)) => {
+            json_at(last, "=", json_scalar_value(fields, value))
+        }
+        (Some(JsonTargetType::Array), ScalarCondition::NotEndsWith(value)) => {
+            json_at(last, "<>", json_scalar_value(fields, value))
+        }
+
+        // `IS` is the null-safe equality of SQLite.
+        (_, ScalarCondition::Equals(PrismaValue::Null)) => json_at(path.clone(), "IS", Value::Json(None)),
+        (_, ScalarCondition::NotEquals(PrismaValue::Null)) => json_at(path.clone(), "IS NOT", Value::Json(None)),
+        (_, ScalarCondition::Equals(value)) => json_at(path.clone(), "=", json_scalar_value(fields, value)),
+        (_, ScalarCondition::NotEquals(value)) => json_at(path.clone(), "<>", json_scalar_value(fields, value)),
+        (_, ScalarCondition::LessThan(value)) => json_at(path.clone(), "<", json_scalar_value(fields, value)),
+        (_, ScalarCondition::LessThanOrEquals(value)) => json_at(path.clone(), "<=", json_scalar_value(fields, value)),
+        (_, ScalarCondition::GreaterThan(value)) => json_at(path.clone(), ">", json_scalar_value(fields, value)),
+        (_, ScalarCondition::GreaterThanOrEquals(value)) => {
+            json_at(path.clone(), ">=", json_scalar_value(fields, value))
+        }
+
+        (_, condition) => return Err(unsupported_json_condition(condition)),
+    };
+
+    Ok(ConditionTree::single(condition))
+}
+
+fn sqlite_json_compare(
+    comparable: Expression<'static>,
+    path: String,
+    op: &str,
+    value: impl Into<Expression<'static>>,
+) -> Compare<'static> {
+    let arguments: Vec<Expression<'static>> = vec![comparable, Value::from(path).into()];
+    let extracted: Expression<'static> = Expression::from(Value::from(true))
+        .compare_raw("AND json_extract", Row::from(arguments))
+        .into();
+
+    extracted.compare_raw(op, value)
+}
+
+/// An array contains the candidate if it contains each of its elements. Like `JSON_CONTAINS` on MySQL,
+/// a `NULL` column contains nothing, so negating the condition doesn't match it either.
+fn sqlite_json_contains(comparable: Expression<'static>, value: PrismaValue, path: String) -> ConditionTree<'static> {
+    let elements = match json_array(value) {
+        serde_json::Value::Array(elements) => elements,
+        json => vec![json],
+    };
+
+    let mut conditions: Vec<Expression<'static>> = vec![comparable.clone().is_not_null().into()];
+
+    conditions.extend(elements.into_iter().map(|element| {
+        let arguments: Vec<Expression<'static>> = vec![comparable.clone(), Value::from(path.clone()).into()];
+        let each: Expression<'static> = Expression::from(Value::from(true))
+            .compare_raw("AND EXISTS (SELECT 1 FROM json_each", Row::from(arguments))
+            .into();
+        let matched: Expression<'static> = each
+            .compare_raw("WHERE json_each.value IS", sqlite_json_element(element))
+            .into();
+
+        matched.compare_raw(") AND", Value::from(true)).into()
+    }));
+
+    ConditionTree::And(conditions)
+}
+
+/// `json_each` returns scalars as SQL values, booleans as integers, and nested arrays and objects
+/// as JSON text.
+fn sqlite_json_element(element: serde_json::Value) -> Value<'static> {
+    match element {
+        serde_json::Value::String(s) => s.into(),
+        serde_json::Value::Bool(b) => (b as i64).into(),
+        serde_json::Value::Number(n) if n.is_i64() => n.as_i64().unwrap().into(),
+        serde_json::Value::Number(n) => n.as_f64().unwrap().into(),
+        serde_json::Value::Null => Value::Json(None),
+        json => json.to_string().into(),
+    }
+}
+
+/// MySQL compares JSON with scalar SQL values, but treats JSON parameters as strings, and SQLite
+/// extracts JSON scalars as SQL values. Scalars are therefore passed as their SQL counterparts.
+fn json_scalar_value(fields: &[ScalarFieldRef], value: PrismaValue) -> Value<'static> {
+    let json = match &value {
+        PrismaValue::Json(s) => serde_json::from_str(s).ok(),
+        _ => None,
+    };
+
+    match json {
+        Some(serde_json::Value::String(s)) => s.into(),
+        Some(serde_json::Value::Bool(b)) => b.into(),
+        Some(serde_json::Value::Number(n)) if n.is_i64() => n.as_i64().unwrap().into(),
+        Some(serde_json::Value::Number(n)) => n.as_f64().unwrap().into(),
+        _ => convert_value(fields, value),
+    }
+}
+
+fn path_array(path: Vec<String>) -> Value<'static> {
+    Value::Array(Some(path.into_iter().map(Value::from).collect()))
+}
+
+/// The Json value as an array, scalars are wrapped into an array of one element.
+fn json_array(value: PrismaValue) -> serde_json::Value {
+    let json = match value {
+        PrismaValue::Json(s) => serde_json::from_str(&s).unwrap_or(serde_json::Value::Null),
+        PrismaValue::String(s) => serde_json::Value::String(s),
+        _ => serde_json::Value::Null,
+    };
+
+    match json {
+        serde_json::Value::Array(_) => json,
+        json => serde_json::Value::Array(vec![json]),
+    }
+}
@@ -1,13 +1,17 @@
+mod context;
 mod cursor_condition;
 mod database;
 mod error;
+mod explain;
 mod filter_conversion;
 mod ordering;
 mod query_arguments_ext;
 mod query_builder;
 mod query_ext;
 mod row;
+mod sql_trace;
 
+use context::{Context, ServerVersion};
 use filter_conversion::*;
 use query_ext::QueryExt;
 use row::*;
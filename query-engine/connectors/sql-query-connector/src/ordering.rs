@@ -1,20 +1,239 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{filter_conversion::relevance, Context};
 use connector_interface::QueryArguments;
 use prisma_models::*;
-use quaint::ast::*;
-
-/// Builds all expressions for an `ORDER BY` clause based on the query arguments.
-pub fn build(query_arguments: &QueryArguments) -> Vec<OrderDefinition<'static>> {
-    let needs_reversed_order = query_arguments.needs_reversed_order();
-
-    query_arguments.order_by.iter().fold(vec![], |mut acc, next_order_by| {
-        match (next_order_by.sort_order, needs_reversed_order) {
-            (SortOrder::Ascending, true) => acc.push(next_order_by.field.as_column().descend()),
-            (SortOrder::Descending, true) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Ascending, false) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Descending, false) => acc.push(next_order_by.field.as_column().descend()),
-        }
+use quaint::{ast::*, connector::SqlFamily};
+
+static ORDER_JOIN_PREFIX: &str = "orderby_";
+static ORDER_AGGREGATOR_ALIAS: &str = "orderby_aggregator";
+static ORDER_PARENT_ALIAS: &str = "orderby_parent";
+static ORDER_CHILD_ALIAS: &str = "orderby_child";
+
+/// An ordering translated to SQL: the expression to order by and the joins required to reach it.
+#[derive(Debug, Clone)]
+pub struct OrderByDefinition {
+    pub order_by: OrderBy,
+    pub order_column: Expression<'static>,
+    pub joins: Vec<JoinData<'static>>,
+}
+
+impl OrderByDefinition {
+    /// Builds the expressions for an `ORDER BY` clause.
+    ///
+    /// An explicit placement of null values is emulated with plain `ASC` and `DESC` orderings. Postgres,
+    /// MySQL and SQLite order by `IS NULL` first:
+    /// ```sql
+    /// ORDER BY `Post`.`publishedAt` IS NULL ASC, `Post`.`publishedAt` DESC
+    /// ```
+    ///
+    /// SQL Server can't order by a condition, so it orders by a marker that is `1` for null values
+    /// and `NULL` otherwise, which it sorts first:
+    /// ```sql
+    /// ORDER BY (SELECT @P1 WHERE [Post].[publishedAt] IS NULL) ASC, [Post].[publishedAt] DESC
+    /// ```
+    pub fn order_definitions(&self, needs_reversed_order: bool, ctx: &Context<'_>) -> Vec<OrderDefinition<'static>> {
+        let column = self.order_column.clone();
+        let ascending = matches!(self.order_by.sort_order, SortOrder::Ascending) != needs_reversed_order;
+
+        let nulls_first = match self.nulls_first(needs_reversed_order) {
+            Some(nulls_first) => nulls_first,
+            None => return vec![if ascending { column.ascend() } else { column.descend() }],
+        };
+
+        let null_marker: Expression<'static> = match ctx.sql_family() {
+            SqlFamily::Mssql => Select::default().value(1).so_that(column.clone().is_null()).into(),
+            SqlFamily::Postgres | SqlFamily::Mysql | SqlFamily::Sqlite => column.clone().is_null().into(),
+        };
+
+        // The marker of null values is greater than the one of other values on every database.
+        let null_marker_order = if nulls_first { Order::Desc } else { Order::Asc };
+
+        vec![
+            (null_marker, Some(null_marker_order)),
+            if ascending { column.ascend() } else { column.descend() },
+        ]
+    }
+
+    /// Whether null values come first in the SQL ordering, if the ordering places null values explicitly.
+    /// Reversed orderings reverse the placement of null values as well.
+    pub fn nulls_first(&self, needs_reversed_order: bool) -> Option<bool> {
+        self.order_by
+            .nulls_order
+            .map(|nulls_order| (nulls_order == NullsOrder::First) != needs_reversed_order)
+    }
+}
 
-        acc
+/// Builds the definitions for all orderings of the query arguments.
+pub fn build(query_arguments: &QueryArguments, ctx: &Context<'_>) -> crate::Result<Vec<OrderByDefinition>> {
+    query_arguments
+        .order_by
+        .iter()
+        .enumerate()
+        .map(|(index, order_by)| match &order_by.relevance {
+            Some(order_by_relevance) => relevance_definition(order_by, order_by_relevance, ctx),
+            None => Ok(build_definition(index, order_by)),
+        })
+        .collect()
+}
+
+/// Relevance orderings need no joins and order by the relevance of a full-text search:
+/// ```sql
+/// ORDER BY MATCH (`Post`.`title`, `Post`.`content`) AGAINST (? IN BOOLEAN MODE) DESC
+/// ```
+fn relevance_definition(
+    order_by: &OrderBy,
+    order_by_relevance: &OrderByRelevance,
+    ctx: &Context<'_>,
+) -> crate::Result<OrderByDefinition> {
+    let columns: Vec<Column<'static>> = order_by_relevance.fields.iter().map(|f| f.as_column()).collect();
+
+    Ok(OrderByDefinition {
+        order_by: order_by.clone(),
+        order_column: relevance(columns, order_by_relevance.search.clone(), ctx)?,
+        joins: vec![],
     })
 }
+
+/// Builds the definition of a single ordering. Related records are left-joined under aliases
+/// derived from the position of the ordering, so the joins of different orderings never clash:
+/// ```sql
+/// SELECT `Post`.`id` FROM `Post`
+/// LEFT JOIN `User` AS `orderby_0_0` ON (`orderby_0_0`.`id` = `Post`.`authorId`)
+/// ORDER BY `orderby_0_0`.`name` ASC
+/// ```
+///
+/// Aggregations of to-many relations are computed in a grouped subquery over the parent records,
+/// which guarantees a count of 0 instead of `NULL` for parents without related records:
+/// ```sql
+/// SELECT `User`.`id` FROM `User`
+/// LEFT JOIN (
+///     SELECT `orderby_parent`.`id`, COUNT(`orderby_child`.`authorId`) AS `orderby_aggregator`
+///     FROM `User` AS `orderby_parent`
+///     LEFT JOIN `Post` AS `orderby_child` ON (`orderby_child`.`authorId` = `orderby_parent`.`id`)
+///     GROUP BY `orderby_parent`.`id`
+/// ) AS `orderby_0_0` ON (`orderby_0_0`.`id` = `User`.`id`)
+/// ORDER BY `orderby_0_0`.`orderby_aggregator` ASC
+/// ```
+pub fn build_definition(index: usize, order_by: &OrderBy) -> OrderByDefinition {
+    let (to_one_hops, aggregated_hop) = match order_by.sort_aggregation {
+        Some(_) => {
+            let (last, rest) = order_by
+                .path
+                .split_last()
+                .expect("Relation aggregations require a relation path.");
+
+            (rest, Some(last))
+        }
+        None => (order_by.path.as_slice(), None),
+    };
+
+    let mut joins = Vec::with_capacity(order_by.path.len());
+    let mut parent_alias: Option<String> = None;
+
+    for (hop, rf) in to_one_hops.iter().enumerate() {
+        let alias = join_alias(index, hop);
+
+        joins.push(to_one_join(rf, parent_alias.as_deref(), &alias));
+        parent_alias = Some(alias);
+    }
+
+    let order_column: Column<'static> = match (&order_by.field, aggregated_hop) {
+        (Some(field), _) => aliased_column(field.as_column(), parent_alias.as_deref()),
+        (None, Some(rf)) => {
+            let alias = join_alias(index, to_one_hops.len());
+
+            joins.push(aggregation_join(rf, parent_alias.as_deref(), &alias));
+            Column::from((alias, ORDER_AGGREGATOR_ALIAS))
+        }
+        (None, None) => unreachable!("Orderings require either a field or a relation aggregation."),
+    };
+
+    OrderByDefinition {
+        order_by: order_by.clone(),
+        order_column: order_column.into(),
+        joins,
+    }
+}
+
+/// Joins the related record of a to-one relation of the parent.
+fn to_one_join(rf: &RelationFieldRef, parent_alias: Option<&str>, alias: &str) -> JoinData<'static> {
+    let parent_columns: Vec<Column<'static>> = rf
+        .linking_fields()
+        .as_columns()
+        .map(|c| aliased_column(c, parent_alias))
+        .collect();
+
+    let related_columns: Vec<Column<'static>> = rf
+        .related_field()
+        .linking_fields()
+        .as_columns()
+        .map(|c| c.table(alias.to_owned()))
+        .collect();
+
+    rf.related_model()
+        .as_table()
+        .alias(alias.to_owned())
+        .on(Row::from(related_columns).equals(Row::from(parent_columns)))
+}
+
+/// Joins the count of related records of a to-many relation of the parent.
+fn aggregation_join(rf: &RelationFieldRef, parent_alias: Option<&str>, alias: &str) -> JoinData<'static> {
+    let parent_fields: Vec<ScalarFieldRef> = rf.linking_fields().scalar_fields().collect();
+
+    let (child_table, child_columns): (Table<'static>, Vec<Column<'static>>) = if rf.relation().is_many_to_many() {
+        (rf.as_table(), rf.identifier_columns().collect())
+    } else {
+        (
+            rf.related_model().as_table(),
+            rf.related_field().linking_fields().as_columns().collect(),
+        )
+    };
+
+    let child_columns: Vec<Column<'static>> = child_columns.into_iter().map(|c| c.table(ORDER_CHILD_ALIAS)).collect();
+
+    let inner_parent_columns: Vec<Column<'static>> = parent_fields
+        .iter()
+        .map(|f| f.as_column().table(ORDER_PARENT_ALIAS))
+        .collect();
+
+    let child_join = child_table
+        .alias(ORDER_CHILD_ALIAS)
+        .on(Row::from(child_columns.clone()).equals(Row::from(inner_parent_columns.clone())));
+
+    let counted_column = child_columns
+        .into_iter()
+        .next()
+        .expect("Relations are always linked by at least one column.");
+
+    let aggregation = inner_parent_columns.clone().into_iter().fold(
+        Select::from_table(rf.model().as_table().alias(ORDER_PARENT_ALIAS))
+            .columns(inner_parent_columns)
+            .value(count(counted_column).alias(ORDER_AGGREGATOR_ALIAS))
+            .left_join(child_join),
+        |select, column| select.group_by(column),
+    );
+
+    let aggregated_columns: Vec<Column<'static>> = parent_fields
+        .iter()
+        .map(|f| Column::from((alias.to_owned(), f.db_name().to_owned())))
+        .collect();
+
+    let parent_columns: Vec<Column<'static>> = parent_fields
+        .iter()
+        .map(|f| aliased_column(f.as_column(), parent_alias))
+        .collect();
+
+    Table::from(aggregation)
+        .alias(alias.to_owned())
+        .on(Row::from(aggregated_columns).equals(Row::from(parent_columns)))
+}
+
+fn aliased_column(column: Column<'static>, alias: Option<&str>) -> Column<'static> {
+    match alias {
+        Some(alias) => column.table(alias.to_owned()),
+        None => column,
+    }
+}
+
+fn join_alias(index: usize, hop: usize) -> String {
+    format!("{}{}_{}", ORDER_JOIN_PREFIX, index, hop)
+}
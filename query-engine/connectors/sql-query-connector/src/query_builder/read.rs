@@ -1,75 +1,89 @@
-use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering};
-use connector_interface::{filter::Filter, AggregationSelection, QueryArguments};
+use crate::{
+    cursor_condition, filter_conversion::AliasedCondition, ordering, query_arguments_ext::QueryArgumentsExt,
+    sql_trace::build_sql, Context, SqlError,
+};
+use connector_interface::{filter::Filter, AggregationSelection, LockStrength, LockWait, QueryArguments, RowLock};
 use itertools::Itertools;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::SqlFamily};
 
 pub trait SelectDefinition {
-    fn into_select(self, _: &ModelRef) -> Select<'static>;
+    fn into_select(self, _: &ModelRef, ctx: &Context<'_>) -> crate::Result<Select<'static>>;
 }
 
 impl SelectDefinition for Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef, ctx: &Context<'_>) -> crate::Result<Select<'static>> {
         let args = QueryArguments::from((model.clone(), self));
-        args.into_select(model)
+        args.into_select(model, ctx)
     }
 }
 
 impl SelectDefinition for &Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        self.clone().into_select(model)
+    fn into_select(self, model: &ModelRef, ctx: &Context<'_>) -> crate::Result<Select<'static>> {
+        self.clone().into_select(model, ctx)
     }
 }
 
 impl SelectDefinition for Select<'static> {
-    fn into_select(self, _: &ModelRef) -> Select<'static> {
-        self
+    fn into_select(self, _: &ModelRef, _ctx: &Context<'_>) -> crate::Result<Select<'static>> {
+        Ok(self)
     }
 }
 
 impl SelectDefinition for QueryArguments {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        let (table_opt, cursor_condition) = cursor_condition::build(&self, &model);
-        let orderings = ordering::build(&self);
+    fn into_select(self, model: &ModelRef, ctx: &Context<'_>) -> crate::Result<Select<'static>> {
+        let order_by_definitions = ordering::build(&self, ctx)?;
+        let (table_opt, cursor_condition) = cursor_condition::build(&self, &model, &order_by_definitions);
+        let reverse = self.needs_reversed_order();
 
         let limit = if self.ignore_take { None } else { self.take_abs() };
         let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
 
-        let filter: ConditionTree = self
-            .filter
-            .map(|f| f.aliased_cond(None))
-            .unwrap_or(ConditionTree::NoCondition);
-
-        let conditions = match (filter, cursor_condition) {
-            (ConditionTree::NoCondition, cursor) => cursor,
-            (filter, ConditionTree::NoCondition) => filter,
-            (filter, cursor) => ConditionTree::and(filter, cursor),
+        let filter: ConditionTree = match self.filter {
+            Some(filter) => filter.aliased_cond(None, ctx)?,
+            None => ConditionTree::NoCondition,
         };
 
         let select_ast = Select::from_table(model.as_table())
-            .so_that(conditions)
+            .so_that(filter)
             .offset(skip as usize);
 
+        // Joins for orderings on related records. Must come before the cursor table, which refers to them.
+        let select_ast = order_by_definitions
+            .iter()
+            .flat_map(|definition| definition.joins.clone())
+            .fold(select_ast, |acc, join| acc.left_join(join));
+
         let select_ast = if let Some(table) = table_opt {
-            select_ast.and_from(table)
+            select_ast.inner_join(table.on(cursor_condition))
         } else {
             select_ast
         };
 
-        let select_ast = orderings.into_iter().fold(select_ast, |acc, ord| acc.order_by(ord));
+        let select_ast = order_by_definitions
+            .iter()
+            .flat_map(|definition| definition.order_definitions(reverse, ctx))
+            .fold(select_ast, |acc, ord| acc.order_by(ord));
 
-        match limit {
+        let select_ast = match limit {
             Some(limit) => select_ast.limit(limit as usize),
             None => select_ast,
-        }
+        };
+
+        Ok(select_ast)
     }
 }
 
-pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
+pub fn get_records<T>(
+    model: &ModelRef,
+    columns: impl Iterator<Item = Column<'static>>,
+    query: T,
+    ctx: &Context<'_>,
+) -> crate::Result<Select<'static>>
 where
     T: SelectDefinition,
 {
-    columns.fold(query.into_select(model), |acc, col| acc.column(col))
+    Ok(columns.fold(query.into_select(model, ctx)?, |acc, col| acc.column(col)))
 }
 
 /// Generates a query of the form:
@@ -98,12 +112,17 @@ where
 /// ```
 /// Important note: Do not use the AsColumn trait here as we need to construct column references that are relative,
 /// not absolute - e.g. `SELECT "field" FROM (...)` NOT `SELECT "full"."path"."to"."field" FROM (...)`.
-pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: QueryArguments) -> Select<'static> {
+pub fn aggregate(
+    model: &ModelRef,
+    selections: &[AggregationSelection],
+    args: QueryArguments,
+    ctx: &Context<'_>,
+) -> crate::Result<Select<'static>> {
     let columns = extract_columns(model, &selections);
-    let sub_query = get_records(model, columns.into_iter(), args);
+    let sub_query = get_records(model, columns.into_iter(), args, ctx)?;
     let sub_table = Table::from(sub_query).alias("sub");
 
-    selections
+    let select = selections
         .iter()
         .fold(Select::from_table(sub_table), |select, next_op| match next_op {
             AggregationSelection::Field(field) => select.column(Column::from(field.db_name().to_owned())),
@@ -135,7 +154,9 @@ pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: Qu
             AggregationSelection::Max(fields) => fields.iter().fold(select, |select, next_field| {
                 select.value(max(Column::from(next_field.db_name().to_owned())))
             }),
-        })
+        });
+
+    Ok(select)
 }
 
 pub fn group_by_aggregate(
@@ -144,8 +165,9 @@ pub fn group_by_aggregate(
     selections: &[AggregationSelection],
     group_by: Vec<ScalarFieldRef>,
     having: Option<Filter>,
-) -> Select<'static> {
-    let base_query: Select = args.into_select(model);
+    ctx: &Context<'_>,
+) -> crate::Result<Select<'static>> {
+    let base_query: Select = args.into_select(model, ctx)?;
 
     let select_query = selections.iter().fold(base_query, |select, next_op| match next_op {
         AggregationSelection::Field(field) => select.column(field.as_column()),
@@ -184,11 +206,184 @@ pub fn group_by_aggregate(
         .fold(select_query, |query, field| query.group_by(field.as_column()));
 
     match having {
-        Some(filter) => grouped.having(filter.aliased_cond(None)),
-        None => grouped,
+        Some(filter) => Ok(grouped.having(filter.aliased_cond(None, ctx)?)),
+        None => Ok(grouped),
     }
 }
 
+/// Counts the records related to each of the given parent records in a single grouped query.
+/// The rows contain the parent link values, followed by the count.
+///
+/// One-to-many relations are grouped by the foreign key on the related records:
+/// ```sql
+/// SELECT `Post`.`authorId`, COUNT(*) FROM `Post`
+/// WHERE `Post`.`authorId` IN (...) AND <filter>
+/// GROUP BY `Post`.`authorId`
+/// ```
+///
+/// Many-to-many relations are grouped by the parent column of the join table:
+/// ```sql
+/// SELECT `_CategoryToPost`.`B`, COUNT(*) FROM `_CategoryToPost`
+/// WHERE `_CategoryToPost`.`B` IN (...)
+///   AND `_CategoryToPost`.`A` IN (SELECT `Category`.`id` FROM `Category` WHERE <filter>)
+/// GROUP BY `_CategoryToPost`.`B`
+/// ```
+pub fn count_related(
+    from_field: &RelationFieldRef,
+    from_record_ids: &[RecordProjection],
+    filter: Option<Filter>,
+    ctx: &Context<'_>,
+) -> crate::Result<Select<'static>> {
+    let related_model = from_field.related_model();
+
+    let (select, group_columns) = if from_field.relation().is_many_to_many() {
+        let parent_columns: Vec<_> = from_field.related_field().m2m_columns();
+        let related_columns: Vec<_> = from_field.m2m_columns();
+
+        let select = Select::from_table(from_field.relation().as_table())
+            .so_that(super::conditions(&parent_columns, from_record_ids));
+
+        let select = match filter {
+            Some(filter) => {
+                let related_ids = Select::from_table(related_model.as_table())
+                    .columns(related_model.primary_identifier().as_columns())
+                    .so_that(filter.aliased_cond(None, ctx)?);
+
+                select.and_where(Row::from(related_columns).in_selection(related_ids))
+            }
+            None => select,
+        };
+
+        (select, parent_columns)
+    } else {
+        let link_columns: Vec<_> = from_field.related_field().linking_fields().as_columns().collect();
+
+        let select =
+            Select::from_table(related_model.as_table()).so_that(super::conditions(&link_columns, from_record_ids));
+
+        let select = match filter {
+            Some(filter) => select.and_where(filter.aliased_cond(None, ctx)?),
+            None => select,
+        };
+
+        (select, link_columns)
+    };
+
+    let select = select.columns(group_columns.clone()).value(count(asterisk()));
+
+    Ok(group_columns
+        .into_iter()
+        .fold(select, |select, column| select.group_by(column)))
+}
+
+/// Renders a read of `model` that locks the records it returns. Quaint has no notion of locking
+/// clauses, so they are appended to the rendered statement:
+/// ```sql
+/// -- Postgres, MySQL 8 leaves out the `OF` part
+/// SELECT ... FROM "Item" WHERE ... FOR UPDATE OF "Item" SKIP LOCKED
+/// -- MySQL 5.7 and MariaDB only know the two lock strengths
+/// SELECT ... FROM `Item` WHERE ... LOCK IN SHARE MODE
+/// -- SQL Server
+/// SELECT ... FROM [dbo].[Item] WHERE ... OPTION (TABLE HINT([dbo].[Item], UPDLOCK, ROWLOCK, READPAST))
+/// ```
+pub fn lock_records(
+    model: &ModelRef,
+    select: Select<'static>,
+    lock: RowLock,
+    server_version: Option<&str>,
+    ctx: &Context<'_>,
+) -> crate::Result<(String, Vec<Value<'static>>)> {
+    let family = ctx.sql_family();
+    let (sql, params) = build_sql(select, family)?;
+
+    let clause = match family {
+        SqlFamily::Postgres => {
+            // Postgres refuses to lock the nullable side of the outer joins ordering by relations.
+            let of = format!("OF \"{}\"", model.db_name().replace('"', "\"\""));
+
+            vec![
+                Some(lock_strength(lock.strength)),
+                Some(of.as_str()),
+                lock_wait(lock.wait),
+            ]
+            .into_iter()
+            .flatten()
+            .join(" ")
+        }
+        SqlFamily::Mysql if server_version.map(mysql_supports_lock_options).unwrap_or(false) => {
+            vec![Some(lock_strength(lock.strength)), lock_wait(lock.wait)]
+                .into_iter()
+                .flatten()
+                .join(" ")
+        }
+        SqlFamily::Mysql => {
+            if lock.wait != LockWait::Wait {
+                return Err(SqlError::RowLockUnavailable(format!(
+                    "SKIP LOCKED and NOWAIT require MySQL 8.0 or later, the database server version is {}.",
+                    server_version.unwrap_or("unknown")
+                )));
+            }
+
+            match lock.strength {
+                LockStrength::ForUpdate => "FOR UPDATE".to_owned(),
+                LockStrength::ForShare => "LOCK IN SHARE MODE".to_owned(),
+            }
+        }
+        SqlFamily::Mssql => {
+            let mut hints = match lock.strength {
+                LockStrength::ForUpdate => vec!["UPDLOCK", "ROWLOCK"],
+                LockStrength::ForShare => vec!["HOLDLOCK", "ROWLOCK"],
+            };
+
+            match lock.wait {
+                LockWait::Wait => (),
+                LockWait::SkipLocked => hints.push("READPAST"),
+                LockWait::NoWait => hints.push("NOWAIT"),
+            }
+
+            // Table hints given as a query hint apply to the table with the given exposed name,
+            // which is the schema-qualified name of the model table the statement selects from.
+            let table = format!(
+                "[{}].[{}]",
+                model.internal_data_model().db_name.replace(']', "]]"),
+                model.db_name().replace(']', "]]")
+            );
+
+            format!("OPTION (TABLE HINT({}, {}))", table, hints.join(", "))
+        }
+        SqlFamily::Sqlite => {
+            return Err(SqlError::RowLockUnavailable(
+                "SQLite does not support row locks, transactions lock the whole database instead.".into(),
+            ))
+        }
+    };
+
+    Ok((format!("{} {}", sql, clause), params))
+}
+
+fn lock_strength(strength: LockStrength) -> &'static str {
+    match strength {
+        LockStrength::ForUpdate => "FOR UPDATE",
+        LockStrength::ForShare => "FOR SHARE",
+    }
+}
+
+fn lock_wait(wait: LockWait) -> Option<&'static str> {
+    match wait {
+        LockWait::Wait => None,
+        LockWait::SkipLocked => Some("SKIP LOCKED"),
+        LockWait::NoWait => Some("NOWAIT"),
+    }
+}
+
+/// `FOR SHARE`, `SKIP LOCKED` and `NOWAIT` exist since MySQL 8.0. MariaDB reports versions like
+/// `10.5.8-MariaDB` and doesn't support `FOR SHARE`.
+fn mysql_supports_lock_options(version: &str) -> bool {
+    let major: Option<u32> = version.split('.').next().and_then(|major| major.parse().ok());
+
+    !version.contains("MariaDB") && major.map(|major| major >= 8).unwrap_or(false)
+}
+
 fn extract_columns(model: &ModelRef, selections: &[AggregationSelection]) -> Vec<Column<'static>> {
     let fields: Vec<_> = selections
         .iter()
@@ -1,7 +1,7 @@
 use connector_interface::{DatasourceFieldName, WriteArgs, WriteExpression};
 use prisma_models::*;
 use quaint::ast::*;
-use std::convert::TryInto;
+use std::{cmp, collections::BTreeMap, convert::TryInto};
 
 /// `INSERT` a new record to the database. Resulting an `INSERT` ast and an
 /// optional `RecordProjection` if available from the arguments or model.
@@ -33,6 +33,88 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
     )
 }
 
+/// Multi-row `INSERT`s for the given records. All rows of a multi-row insert must write the same columns,
+/// so records are grouped by the fields they set. Each statement binds at most `max_bind_values` values
+/// and inserts at most `max_rows` rows.
+pub fn create_records(
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    max_bind_values: usize,
+    max_rows: usize,
+) -> Vec<Query<'static>> {
+    let scalar_fields = model.fields().scalar();
+    let mut groups: BTreeMap<Vec<usize>, Vec<WriteArgs>> = BTreeMap::new();
+
+    for record in args {
+        let field_indices = scalar_fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| record.has_arg_for(field.db_name()))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        groups.entry(field_indices).or_insert_with(Vec::new).push(record);
+    }
+
+    let mut queries = Vec::new();
+
+    for (field_indices, records) in groups {
+        let fields: Vec<&ScalarFieldRef> = field_indices.iter().map(|idx| &scalar_fields[*idx]).collect();
+
+        // Records writing no column at all only consist of database defaults.
+        if fields.is_empty() {
+            for _ in records {
+                let insert = Insert::from(Insert::single_into(model.as_table()));
+                queries.push(with_conflict_handling(insert, skip_duplicates));
+            }
+
+            continue;
+        }
+
+        let rows: Vec<Vec<Value<'static>>> = records
+            .into_iter()
+            .map(|mut record| {
+                fields
+                    .iter()
+                    .map(|field| {
+                        let value: PrismaValue = record
+                            .take_field_value(field.db_name())
+                            .unwrap()
+                            .try_into()
+                            .expect("Create calls can only use PrismaValue write expressions (right now).");
+
+                        field.value(value)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let columns: Vec<String> = fields.iter().map(|field| field.db_name().to_owned()).collect();
+        let rows_per_statement = cmp::max(1, cmp::min(max_rows, max_bind_values / columns.len()));
+
+        for chunk in rows.chunks(rows_per_statement) {
+            let insert = chunk
+                .iter()
+                .fold(Insert::multi_into(model.as_table(), columns.clone()), |insert, row| {
+                    insert.values(row.clone())
+                });
+
+            queries.push(with_conflict_handling(insert.build(), skip_duplicates));
+        }
+    }
+
+    queries
+}
+
+fn with_conflict_handling(insert: Insert<'static>, skip_duplicates: bool) -> Query<'static> {
+    if skip_duplicates {
+        insert.on_conflict(OnConflict::DoNothing).into()
+    } else {
+        insert.into()
+    }
+}
+
 pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs) -> crate::Result<Vec<Query<'static>>> {
     if args.args.is_empty() || ids.is_empty() {
         return Ok(Vec::new());
@@ -72,6 +154,10 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
                     let e: Expression<'_> = Column::from(name.clone()).into();
                     e / field.value(rhs).into()
                 }
+
+                // Concatenating in the database keeps concurrent appends from overwriting each other.
+                WriteExpression::Push(rhs) => Column::from(name.clone()).compare_raw("||", field.value(rhs)).into(),
+                WriteExpression::Unset => field.value(PrismaValue::Null).into(),
             };
 
             acc.set(name, value)
@@ -1,4 +1,9 @@
-use crate::{error::*, AliasedCondition, SqlRow, ToSqlRow};
+use crate::{
+    error::*,
+    explain,
+    sql_trace::{raw_sql_span, record_rows, sql_span},
+    AliasedCondition, Context, SqlRow, ToSqlRow,
+};
 use async_trait::async_trait;
 use connector_interface::{filter::Filter, RecordFilter};
 use datamodel::FieldArity;
@@ -6,12 +11,13 @@ use futures::future::FutureExt;
 use prisma_models::*;
 use quaint::{
     ast::*,
-    connector::{self, Queryable},
+    connector::{self, Queryable, ResultSet},
     pooled::PooledConnection,
 };
 
 use serde_json::{Map, Value};
 use std::{convert::TryFrom, panic::AssertUnwindSafe};
+use tracing_futures::Instrument;
 
 impl<'t> QueryExt for connector::Transaction<'t> {}
 impl QueryExt for PooledConnection {}
@@ -20,9 +26,57 @@ impl QueryExt for PooledConnection {}
 /// database operations on top of `Queryable`.
 #[async_trait]
 pub trait QueryExt: Queryable + Send + Sync {
+    /// Execute a statement inside of a `prisma:sql` span, returning the result set.
+    /// The statement is recorded into the explain log if the operation is explained.
+    async fn traced_query(&self, q: Query<'_>, ctx: &Context<'_>) -> crate::Result<ResultSet> {
+        explain::record(self, &q, ctx).await?;
+        let span = sql_span(&q, ctx.sql_family());
+        let result_set = self.query(q).instrument(span.clone()).await?;
+        record_rows(&span, result_set.len());
+
+        Ok(result_set)
+    }
+
+    /// Execute a statement inside of a `prisma:sql` span, returning the number of affected rows.
+    /// The statement is recorded into the explain log if the operation is explained.
+    async fn traced_execute(&self, q: Query<'_>, ctx: &Context<'_>) -> crate::Result<u64> {
+        explain::record(self, &q, ctx).await?;
+        let span = sql_span(&q, ctx.sql_family());
+        let changes = self.execute(q).instrument(span.clone()).await?;
+        record_rows(&span, changes as usize);
+
+        Ok(changes)
+    }
+
     /// Filter and map the resulting types with the given identifiers.
-    async fn filter(&self, q: Query<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<Vec<SqlRow>> {
-        let result_set = self.query(q).await?;
+    async fn filter(
+        &self,
+        q: Query<'_>,
+        idents: &[(TypeIdentifier, FieldArity)],
+        ctx: &Context<'_>,
+    ) -> crate::Result<Vec<SqlRow>> {
+        let result_set = self.traced_query(q, ctx).await?;
+        let mut sql_rows = Vec::new();
+
+        for row in result_set {
+            sql_rows.push(row.to_sql_row(idents)?);
+        }
+
+        Ok(sql_rows)
+    }
+
+    /// Like `filter`, for reads already rendered with their locking clauses (see `query_builder::lock_records`).
+    async fn filter_locked(
+        &self,
+        sql: String,
+        params: Vec<quaint::ast::Value<'_>>,
+        idents: &[(TypeIdentifier, FieldArity)],
+    ) -> crate::Result<Vec<SqlRow>> {
+        explain::record_raw(&sql);
+        let span = raw_sql_span(&sql);
+        let result_set = self.query_raw(&sql, &params).instrument(span.clone()).await?;
+        record_rows(&span, result_set.len());
+
         let mut sql_rows = Vec::new();
 
         for row in result_set {
@@ -40,23 +94,14 @@ pub trait QueryExt: Queryable + Send + Sync {
         params: Vec<PrismaValue>,
     ) -> std::result::Result<Value, crate::error::RawError> {
         let params: Vec<_> = params.into_iter().map(convert_lossy).collect();
-        let result_set = AssertUnwindSafe(self.query_raw(&q, &params)).catch_unwind().await??;
-
-        let columns: Vec<String> = result_set.columns().iter().map(ToString::to_string).collect();
-        let mut result = Vec::new();
-
-        for row in result_set.into_iter() {
-            let mut object = Map::new();
-
-            for (idx, p_value) in row.into_iter().enumerate() {
-                let column_name: String = columns[idx].clone();
-                object.insert(column_name, Value::from(p_value));
-            }
-
-            result.push(Value::Object(object));
-        }
-
-        Ok(Value::Array(result))
+        explain::record_raw(&q);
+        let span = raw_sql_span(&q);
+        let result_set = AssertUnwindSafe(self.query_raw(&q, &params).instrument(span.clone()))
+            .catch_unwind()
+            .await??;
+        record_rows(&span, result_set.len());
+
+        Ok(result_set_to_json(result_set))
     }
 
     /// Execute a singular SQL query in the database, returning the number of
@@ -67,14 +112,24 @@ pub trait QueryExt: Queryable + Send + Sync {
         params: Vec<PrismaValue>,
     ) -> std::result::Result<usize, crate::error::RawError> {
         let params: Vec<_> = params.into_iter().map(convert_lossy).collect();
-        let changes = AssertUnwindSafe(self.execute_raw(&q, &params)).catch_unwind().await??;
+        explain::record_raw(&q);
+        let span = raw_sql_span(&q);
+        let changes = AssertUnwindSafe(self.execute_raw(&q, &params).instrument(span.clone()))
+            .catch_unwind()
+            .await??;
+        record_rows(&span, changes as usize);
 
         Ok(changes as usize)
     }
 
     /// Select one row from the database.
-    async fn find(&self, q: Select<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<SqlRow> {
-        self.filter(q.limit(1).into(), idents)
+    async fn find(
+        &self,
+        q: Select<'_>,
+        idents: &[(TypeIdentifier, FieldArity)],
+        ctx: &Context<'_>,
+    ) -> crate::Result<SqlRow> {
+        self.filter(q.limit(1).into(), idents, ctx)
             .await?
             .into_iter()
             .next()
@@ -82,10 +137,10 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Read the first column from the first row as an integer.
-    async fn find_int(&self, q: Select<'_>) -> crate::Result<i64> {
+    async fn find_int(&self, q: Select<'_>, ctx: &Context<'_>) -> crate::Result<i64> {
         // UNWRAP: A dataset will always have at least one column, even if it contains no data.
         let id = self
-            .find(q, &[(TypeIdentifier::Int, FieldArity::Required)])
+            .find(q, &[(TypeIdentifier::Int, FieldArity::Required)], ctx)
             .await?
             .values
             .into_iter()
@@ -104,27 +159,38 @@ pub trait QueryExt: Queryable + Send + Sync {
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
+        ctx: &Context<'_>,
     ) -> crate::Result<Vec<RecordProjection>> {
         if let Some(selectors) = record_filter.selectors {
             Ok(selectors)
         } else {
-            self.filter_ids(model, record_filter.filter).await
+            self.filter_ids(model, record_filter.filter, ctx).await
         }
     }
 
     /// Read the all columns as a (primary) identifier.
-    async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<RecordProjection>> {
+    async fn filter_ids(
+        &self,
+        model: &ModelRef,
+        filter: Filter,
+        ctx: &Context<'_>,
+    ) -> crate::Result<Vec<RecordProjection>> {
         let model_id = model.primary_identifier();
         let id_cols: Vec<Column<'static>> = model_id.as_columns().collect();
 
         let select = Select::from_table(model.as_table())
             .columns(id_cols)
-            .so_that(filter.aliased_cond(None));
+            .so_that(filter.aliased_cond(None, ctx)?);
 
-        self.select_ids(select, model_id).await
+        self.select_ids(select, model_id, ctx).await
     }
 
-    async fn select_ids(&self, select: Select<'_>, model_id: ModelProjection) -> crate::Result<Vec<RecordProjection>> {
+    async fn select_ids(
+        &self,
+        select: Select<'_>,
+        model_id: ModelProjection,
+        ctx: &Context<'_>,
+    ) -> crate::Result<Vec<RecordProjection>> {
         let idents: Vec<_> = model_id
             .fields()
             .into_iter()
@@ -134,7 +200,7 @@ pub trait QueryExt: Queryable + Send + Sync {
             })
             .collect();
 
-        let mut rows = self.filter(select.into(), &idents).await?;
+        let mut rows = self.filter(select.into(), &idents, ctx).await?;
         let mut result = Vec::new();
 
         for row in rows.drain(0..) {
@@ -147,3 +213,22 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(result)
     }
 }
+
+/// Renders the rows of a result set as a JSON array of objects keyed by column name.
+pub(crate) fn result_set_to_json(result_set: ResultSet) -> Value {
+    let columns: Vec<String> = result_set.columns().iter().map(ToString::to_string).collect();
+    let mut result = Vec::new();
+
+    for row in result_set.into_iter() {
+        let mut object = Map::new();
+
+        for (idx, p_value) in row.into_iter().enumerate() {
+            let column_name: String = columns[idx].clone();
+            object.insert(column_name, Value::from(p_value));
+        }
+
+        result.push(Value::Object(object));
+    }
+
+    Value::Array(result)
+}
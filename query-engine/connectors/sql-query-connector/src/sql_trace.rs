@@ -0,0 +1,138 @@
+use quaint::{
+    ast::{Query, Value},
+    connector::SqlFamily,
+    visitor::{self, Visitor},
+};
+use tracing::{field, Span};
+
+/// Creates the `prisma:sql` span for a statement sent to the database.
+///
+/// The statement is rendered with placeholders instead of parameter values, so that no user data
+/// ends up in traces. Rendering only happens if the span is actually recorded.
+pub(crate) fn sql_span<'a, Q>(query: &Q, family: SqlFamily) -> Span
+where
+    Q: Clone + Into<Query<'a>>,
+{
+    let span = tracing::info_span!("prisma:sql", db.statement = field::Empty, db.rows = field::Empty);
+
+    if !span.is_disabled() {
+        if let Ok(statement) = sanitized_sql(query.clone(), family) {
+            span.record("db.statement", &statement.as_str());
+        }
+    }
+
+    span
+}
+
+/// Creates the `prisma:sql` span for a raw statement. Parameters are passed separately and not recorded.
+pub(crate) fn raw_sql_span(statement: &str) -> Span {
+    tracing::info_span!("prisma:sql", db.statement = statement, db.rows = field::Empty)
+}
+
+/// Records the number of rows returned or affected by the statement of a `prisma:sql` span.
+pub(crate) fn record_rows(span: &Span, rows: usize) {
+    span.record("db.rows", &rows);
+}
+
+fn sanitized_sql<'a>(query: impl Into<Query<'a>>, family: SqlFamily) -> quaint::Result<String> {
+    let (sql, _params) = build_sql(query, family)?;
+
+    Ok(sql)
+}
+
+/// Renders a statement for the given SQL family, returning the SQL with placeholders and the
+/// parameters to bind to them.
+pub(crate) fn build_sql<'a>(
+    query: impl Into<Query<'a>>,
+    family: SqlFamily,
+) -> quaint::Result<(String, Vec<Value<'a>>)> {
+    match family {
+        SqlFamily::Postgres => visitor::Postgres::build(query),
+        SqlFamily::Mysql => visitor::Mysql::build(query),
+        SqlFamily::Sqlite => visitor::Sqlite::build(query),
+        SqlFamily::Mssql => visitor::Mssql::build(query),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::ast::*;
+    use std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    /// Collects the `db.statement` values recorded on spans.
+    #[derive(Default)]
+    struct StatementCollector {
+        next_id: AtomicU64,
+        statements: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct StatementVisitor<'a>(&'a Mutex<Vec<String>>);
+
+    impl Visit for StatementVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "db.statement" {
+                self.0.lock().unwrap().push(value.to_owned());
+            }
+        }
+
+        fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+    }
+
+    impl Subscriber for StatementCollector {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            span.record(&mut StatementVisitor(&self.statements));
+            span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+
+        fn record(&self, _span: &span::Id, values: &span::Record<'_>) {
+            values.record(&mut StatementVisitor(&self.statements));
+        }
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    #[test]
+    fn sql_spans_do_not_record_bind_values() {
+        let families = [
+            SqlFamily::Postgres,
+            SqlFamily::Mysql,
+            SqlFamily::Sqlite,
+            SqlFamily::Mssql,
+        ];
+
+        for family in families.iter() {
+            let collector = StatementCollector::default();
+            let statements = Arc::clone(&collector.statements);
+
+            let query = Select::from_table("User")
+                .column("id")
+                .so_that("email".equals("secret@example.com"));
+
+            tracing::subscriber::with_default(collector, || sql_span(&query, *family));
+
+            let statements = statements.lock().unwrap();
+
+            assert_eq!(1, statements.len(), "{:?}", family);
+            assert!(statements[0].contains("email"), "{}", statements[0]);
+            assert!(!statements[0].contains("secret@example.com"), "{}", statements[0]);
+        }
+    }
+}
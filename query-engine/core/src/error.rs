@@ -1,9 +1,10 @@
 use crate::{
     InterpreterError, QueryGraphBuilderError, QueryGraphError, QueryParserError, QueryParserErrorKind,
-    RelationViolation,
+    RelationViolation, TransactionError,
 };
 use connector::error::ConnectorError;
 use prisma_models::DomainError;
+use std::time::Duration;
 use thiserror::Error;
 
 // TODO: Cleanup unused errors after refactorings.
@@ -35,6 +36,19 @@ pub enum CoreError {
 
     #[error("{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[error("{}", _0)]
+    TransactionError(TransactionError),
+
+    #[error("Request did not finish within its timeout of {} ms", _0.as_millis())]
+    QueryTimeout(Duration),
+
+    /// The error of an explained operation, with the query graph and the statements sent before it failed.
+    #[error("{}", error)]
+    Explained {
+        error: Box<CoreError>,
+        explain: serde_json::Value,
+    },
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -73,9 +87,16 @@ impl From<InterpreterError> for CoreError {
     }
 }
 
+impl From<TransactionError> for CoreError {
+    fn from(e: TransactionError) -> CoreError {
+        CoreError::TransactionError(e)
+    }
+}
+
 impl From<CoreError> for user_facing_errors::Error {
     fn from(err: CoreError) -> user_facing_errors::Error {
         match err {
+            CoreError::Explained { error, .. } => (*error).into(),
             CoreError::ConnectorError(ConnectorError {
                 user_facing_error: Some(user_facing_error),
                 ..
@@ -134,6 +155,20 @@ impl From<CoreError> for user_facing_errors::Error {
                 details,
             })
             .into(),
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::VersionMismatch {
+                model_name,
+                expected_version,
+            })
+            | CoreError::InterpreterError(InterpreterError::QueryGraphBuilderError(
+                QueryGraphBuilderError::VersionMismatch {
+                    model_name,
+                    expected_version,
+                },
+            )) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::VersionMismatch {
+                model_name,
+                expected_version,
+            })
+            .into(),
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details }).into()
             }
@@ -161,12 +196,32 @@ impl From<CoreError> for user_facing_errors::Error {
                         relation_name: relation_name.clone(),
                     })
                     .into(),
+                    InterpreterError::QueryGraphBuilderError(QueryGraphBuilderError::VersionMismatch {
+                        model_name,
+                        expected_version,
+                    }) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::VersionMismatch {
+                        model_name: model_name.clone(),
+                        expected_version: expected_version.clone(),
+                    })
+                    .into(),
                     _ => user_facing_errors::KnownError::new(user_facing_errors::query_engine::InterpretationError {
                         details: format!("{}: {}", msg, cause),
                     })
                     .into(),
                 }
             }
+            CoreError::TransactionError(err) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::InteractiveTransactionError {
+                    error: err.to_string(),
+                })
+                .into()
+            }
+            CoreError::QueryTimeout(timeout) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::RequestTimeout {
+                    timeout: timeout.as_millis().to_string(),
+                })
+                .into()
+            }
             _ => user_facing_errors::Error::from_dyn_error(&err),
         }
     }
@@ -1,8 +1,15 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use super::{
+    pipeline::QueryPipeline,
+    timeout::{execute_with_timeout, rollback_failed},
+    ExplainMode, QueryExecutor, TransactionManager,
+};
+use crate::{
+    ItxManager, Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData, TransactionOptions, TxId,
+};
 use async_trait::async_trait;
 use connector::{Connection, ConnectionLike, Connector};
 use futures::future;
+use std::time::Duration;
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
@@ -12,6 +19,9 @@ pub struct InterpretingExecutor<C> {
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Open interactive transactions.
+    itx_manager: ItxManager,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -22,14 +32,29 @@ where
         InterpretingExecutor {
             connector,
             force_transactions,
+            itx_manager: ItxManager::new(),
         }
     }
 
+    /// Returns the connection to run a non-interactive operation on.
+    /// Reads outside of transactions may go to a read replica, everything else runs on the primary.
+    async fn connection_for(&self, operation: &Operation, force_primary: bool) -> crate::Result<Box<dyn Connection>> {
+        let conn = if operation.is_read() && !self.force_transactions && !force_primary {
+            self.connector.get_read_connection().await?
+        } else {
+            self.connector.get_connection().await?
+        };
+
+        Ok(conn)
+    }
+
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
     async fn execute_single_operation(
         operation: Operation,
         conn: Box<dyn Connection>,
         force_transactions: bool,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
         query_schema: QuerySchemaRef,
     ) -> crate::Result<ResponseData> {
         // Parse, validate, and extract query graph from query document.
@@ -39,18 +64,20 @@ where
         if is_transactional {
             let tx = conn.start_transaction().await?;
             let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query_graph, interpreter, serializer).execute().await;
+            let pipeline = QueryPipeline::new(query_graph, interpreter, serializer, explain);
 
-            if result.is_ok() {
-                tx.commit().await?;
-            } else {
-                tx.rollback().await?;
+            match execute_with_timeout(conn.as_ref(), timeout, pipeline.execute()).await {
+                Ok(result) => {
+                    tx.commit().await?;
+                    Ok(result)
+                }
+                Err(err) => Err(rollback_failed(tx.as_ref(), err).await),
             }
-
-            result
         } else {
             let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
-            QueryPipeline::new(query_graph, interpreter, serializer).execute().await
+            let pipeline = QueryPipeline::new(query_graph, interpreter, serializer, explain);
+
+            execute_with_timeout(conn.as_ref(), timeout, pipeline.execute()).await
         }
     }
 }
@@ -71,13 +98,28 @@ where
     /// A failing operation does not fail the batch, instead, an error is returned alongside other responses.
     /// Note that individual operations executed in non-transactional mode can still be transactions in themselves
     /// if the query (e.g. a write op) requires it.
+    ///
+    /// If the batch is executed inside of an interactive transaction:
+    /// All operations are evaluated in sequence inside of that transaction. A failing operation fails the batch,
+    /// but committing or rolling back the transaction is left to the client.
+    ///
+    /// If the batch runs longer than `timeout`, its statement is cancelled and its transaction is rolled back.
+    /// Non-transactional batches apply the timeout to each operation.
     async fn execute_batch(
         &self,
+        tx_id: Option<TxId>,
+        force_primary: bool,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
-        if transactional {
+        if let Some(tx_id) = tx_id {
+            self.itx_manager
+                .batch_execute(&tx_id, operations, explain, timeout)
+                .await
+        } else if transactional {
             let queries = operations
                 .into_iter()
                 .map(|op| QueryGraphBuilder::new(query_schema.clone()).build(op))
@@ -85,30 +127,38 @@ where
 
             let conn = self.connector.get_connection().await?;
             let tx = conn.start_transaction().await?;
-            let mut results = Vec::with_capacity(queries.len());
 
-            for (query, info) in queries {
-                let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-                let result = QueryPipeline::new(query, interpreter, info).execute().await;
+            let execution = async {
+                let mut results = Vec::with_capacity(queries.len());
 
-                if result.is_err() {
-                    tx.rollback().await?;
+                for (query, info) in queries {
+                    let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+                    let result = QueryPipeline::new(query, interpreter, info, explain).execute().await?;
+
+                    results.push(Ok(result));
                 }
 
-                results.push(Ok(result?));
-            }
+                Ok::<_, crate::CoreError>(results)
+            };
 
-            tx.commit().await?;
-            Ok(results)
+            match execute_with_timeout(conn.as_ref(), timeout, execution).await {
+                Ok(results) => {
+                    tx.commit().await?;
+                    Ok(results)
+                }
+                Err(err) => Err(rollback_failed(tx.as_ref(), err).await),
+            }
         } else {
             let mut futures = Vec::with_capacity(operations.len());
 
             for operation in operations {
-                let conn = self.connector.get_connection().await?;
+                let conn = self.connection_for(&operation, force_primary).await?;
                 futures.push(tokio::spawn(Self::execute_single_operation(
                     operation,
                     conn,
                     self.force_transactions,
+                    explain,
+                    timeout,
                     query_schema.clone(),
                 )));
             }
@@ -124,12 +174,54 @@ where
     }
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
-        let conn = self.connector.get_connection().await?;
-        Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
+    /// Inside of an interactive transaction, the operation is routed to the connection holding that transaction.
+    /// Otherwise, reads are routed to a read replica unless `force_primary` is set.
+    /// If the operation runs longer than `timeout`, its statement is cancelled and its transaction is rolled back.
+    async fn execute(
+        &self,
+        tx_id: Option<TxId>,
+        force_primary: bool,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        if let Some(tx_id) = tx_id {
+            self.itx_manager.execute(&tx_id, operation, explain, timeout).await
+        } else {
+            let conn = self.connection_for(&operation, force_primary).await?;
+            Self::execute_single_operation(
+                operation,
+                conn,
+                self.force_transactions,
+                explain,
+                timeout,
+                query_schema.clone(),
+            )
+            .await
+        }
     }
 
     fn primary_connector(&self) -> &dyn Connector {
         &self.connector
     }
 }
+
+#[async_trait]
+impl<C> TransactionManager for InterpretingExecutor<C>
+where
+    C: Connector + Send + Sync,
+{
+    async fn start_tx(&self, query_schema: QuerySchemaRef, options: TransactionOptions) -> crate::Result<TxId> {
+        let conn = self.connector.get_connection().await?;
+        self.itx_manager.create_tx(conn, query_schema, options).await
+    }
+
+    async fn commit_tx(&self, tx_id: TxId) -> crate::Result<()> {
+        self.itx_manager.commit_tx(&tx_id).await
+    }
+
+    async fn rollback_tx(&self, tx_id: TxId) -> crate::Result<()> {
+        self.itx_manager.rollback_tx(&tx_id).await
+    }
+}
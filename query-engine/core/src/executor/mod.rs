@@ -7,21 +7,48 @@
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
 mod interpreting_executor;
 mod pipeline;
+mod timeout;
 
 pub use interpreting_executor::*;
 
-use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
+pub(crate) use pipeline::*;
+pub(crate) use timeout::*;
+
+pub use connector::ExplainMode;
+
+use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef, TransactionOptions, TxId};
 use async_trait::async_trait;
 use connector::Connector;
+use std::time::Duration;
 
 #[async_trait]
-pub trait QueryExecutor {
+pub trait QueryExecutor: TransactionManager {
     /// Executes a single operation and returns its result.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData>;
+    /// If `tx_id` is set, the operation is executed inside of that open interactive transaction.
+    /// If `force_primary` is set, reads are not routed to read replicas.
+    /// If `explain` is on, the query graph and the statements of the operation are returned alongside the data.
+    /// If `timeout` is set, the operation is cancelled once it runs for longer, rolling back its transaction.
+    async fn execute(
+        &self,
+        tx_id: Option<TxId>,
+        force_primary: bool,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData>;
 
     // Executes a batch of operations as either a fanout of individual operations (non-transactional), or in series (transactional).
+    // If `tx_id` is set, the batch is executed in series inside of that open interactive transaction.
+    // If `force_primary` is set, reads are not routed to read replicas.
+    // If `explain` is on, every operation returns its query graph and statements alongside its data.
+    // If `timeout` is set, the batch is cancelled once it runs for longer, rolling back its transaction.
     async fn execute_batch(
         &self,
+        tx_id: Option<TxId>,
+        force_primary: bool,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
@@ -29,3 +56,17 @@ pub trait QueryExecutor {
 
     fn primary_connector(&self) -> &dyn Connector;
 }
+
+/// Interactive transactions: transactions that stay open across several requests
+/// until they are explicitly committed or rolled back, or until they time out.
+#[async_trait]
+pub trait TransactionManager {
+    /// Starts a new interactive transaction and returns its id.
+    async fn start_tx(&self, query_schema: QuerySchemaRef, options: TransactionOptions) -> crate::Result<TxId>;
+
+    /// Commits the interactive transaction with the given id.
+    async fn commit_tx(&self, tx_id: TxId) -> crate::Result<()>;
+
+    /// Rolls back the interactive transaction with the given id.
+    async fn rollback_tx(&self, tx_id: TxId) -> crate::Result<()>;
+}
@@ -1,26 +1,124 @@
-use crate::{Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
+use crate::{format_json, CoreError, Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
+use connector::{explain::EXPLAIN_LOG, ExplainLog, ExplainMode};
+use serde_json::json;
+use std::time::Instant;
 
 pub struct QueryPipeline<'conn, 'tx> {
     graph: QueryGraph,
     interpreter: QueryInterpreter<'conn, 'tx>,
     serializer: IrSerializer,
+    explain: ExplainMode,
 }
 
 impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
-    pub fn new(graph: QueryGraph, interpreter: QueryInterpreter<'conn, 'tx>, serializer: IrSerializer) -> Self {
+    pub fn new(
+        graph: QueryGraph,
+        interpreter: QueryInterpreter<'conn, 'tx>,
+        serializer: IrSerializer,
+        explain: ExplainMode,
+    ) -> Self {
         Self {
             graph,
             interpreter,
             serializer,
+            explain,
         }
     }
 
+    /// Executes the query graph. Records the following metrics, labeled with the operation and the model:
+    ///
+    /// - `prisma_operations_total`: the number of executed operations, labeled with the outcome as well.
+    /// - `prisma_operation_duration_microseconds`: how long the execution took.
+    /// - `prisma_query_graph_nodes`: the number of nodes in the query graph.
+    ///
+    /// If the operation is explained, the query graph and the statements sent to the database
+    /// are returned alongside the data, or wrapped around the error if the operation failed.
     pub async fn execute(self) -> crate::Result<ResponseData> {
-        let serializer = self.serializer;
-        let expr = Expressionista::translate(self.graph)?;
-        let result = self.interpreter.interpret(expr, Env::default(), 0).await;
+        let Self {
+            graph,
+            interpreter,
+            serializer,
+            explain,
+        } = self;
+
+        let (operation, model) = operation_labels(&serializer);
+        let start = Instant::now();
+
+        metrics::value!(
+            "prisma_query_graph_nodes",
+            graph.node_count() as u64,
+            "operation" => operation.clone(),
+            "model" => model.clone()
+        );
+
+        let explained_graph = if explain.is_on() {
+            Some(format_json(&graph))
+        } else {
+            None
+        };
+        let explain_log = ExplainLog::new(explain);
+
+        let result = async {
+            let expr = Expressionista::translate(graph)?;
+            let interpretation = interpreter.interpret(expr, Env::default(), 0);
+
+            let result = if explain.is_on() {
+                EXPLAIN_LOG.scope(explain_log.clone(), interpretation).await
+            } else {
+                interpretation.await
+            };
+
+            trace!("{}", interpreter.log_output());
+            serializer.serialize(result?)
+        }
+        .await;
+
+        let outcome = if result.is_ok() { "success" } else { "error" };
+
+        metrics::counter!(
+            "prisma_operations_total",
+            1,
+            "operation" => operation.clone(),
+            "model" => model.clone(),
+            "outcome" => outcome
+        );
+
+        metrics::value!(
+            "prisma_operation_duration_microseconds",
+            start.elapsed().as_micros() as u64,
+            "operation" => operation,
+            "model" => model
+        );
+
+        let explanation = explained_graph.map(|graph| {
+            json!({
+                "queryGraph": graph,
+                "statements": explain_log.statements(),
+            })
+        });
+
+        match (result, explanation) {
+            (Ok(mut response), explanation) => {
+                response.explain = explanation;
+                Ok(response)
+            }
+            (Err(error), Some(explain)) => Err(CoreError::Explained {
+                error: Box::new(error),
+                explain,
+            }),
+            (Err(error), None) => Err(error),
+        }
+    }
+}
 
-        trace!("{}", self.interpreter.log_output());
-        serializer.serialize(result?)
+/// The operation (e.g. `findMany`) and model name of the top level field, for labeling metrics.
+/// Operations without a model, like raw queries, get an empty model label.
+fn operation_labels(serializer: &IrSerializer) -> (String, String) {
+    match &serializer.output_field.query_info {
+        Some(info) => (
+            info.tag.to_string(),
+            info.model.as_ref().map(|model| model.name.clone()).unwrap_or_default(),
+        ),
+        None => (serializer.output_field.name.clone(), String::new()),
     }
 }
@@ -0,0 +1,54 @@
+use crate::CoreError;
+use connector::{Connection, Transaction};
+use std::{future::Future, time::Duration};
+
+/// Runs `fut` on `conn`, failing with `CoreError::QueryTimeout` if it doesn't finish within `timeout`.
+///
+/// On timeout, `fut` is dropped, which stops the query pipeline at its next await point, and
+/// the session of `conn` is terminated if the connector supports it, so that the connection
+/// is discarded instead of going back to the pool. Rolling back an open transaction is left to
+/// the caller, see `rollback_failed`.
+pub(crate) async fn execute_with_timeout<T>(
+    conn: &dyn Connection,
+    timeout: Option<Duration>,
+    fut: impl Future<Output = crate::Result<T>>,
+) -> crate::Result<T> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return fut.await,
+    };
+
+    // The session id has to be known before the timeout, as the connection is busy with the timed
+    // out statement afterwards. It is looked up alongside `fut` instead of before it, so that
+    // connectors pipelining the statements of a connection don't pay an extra round trip for it.
+    let (canceller, result) = futures::join!(conn.statement_canceller(), tokio::time::timeout(timeout, fut));
+
+    match result {
+        Ok(result) => result,
+        Err(_) => {
+            match canceller {
+                Ok(Some(canceller)) => {
+                    if let Err(err) = canceller.cancel().await {
+                        warn!("Failed to cancel the statement of a timed out request: {}", err);
+                    }
+                }
+                Ok(None) => (),
+                Err(err) => warn!("Failed to look up the session of a timed out request: {}", err),
+            }
+
+            Err(CoreError::QueryTimeout(timeout))
+        }
+    }
+}
+
+/// Rolls back `tx` after it failed with `err`, and returns `err`.
+///
+/// A failing rollback is only logged: after a timeout, the session of the transaction is gone
+/// and the rollback is expected to fail, but the error of the request is still the timeout.
+pub(crate) async fn rollback_failed(tx: &dyn Transaction, err: CoreError) -> CoreError {
+    if let Err(rollback_err) = tx.rollback().await {
+        warn!("Failed to roll back a failed transaction: {}", rollback_err);
+    }
+
+    err
+}
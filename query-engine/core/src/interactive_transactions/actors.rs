@@ -0,0 +1,249 @@
+use super::{TransactionError, TransactionOptions, TxId};
+use crate::{
+    executor::{execute_with_timeout, QueryPipeline},
+    CoreError, ExplainMode, Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData,
+};
+use connector::{Connection, ConnectionLike, Transaction};
+use std::{cmp, time::Duration};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+    time::{self, Instant},
+};
+
+/// Number of requests that can be queued for a single transaction.
+const CHANNEL_SIZE: usize = 100;
+
+#[derive(Debug)]
+enum TxOpRequestMsg {
+    Single(Operation, ExplainMode, Option<Duration>),
+    Batch(Vec<Operation>, ExplainMode, Option<Duration>),
+    Commit,
+    Rollback,
+}
+
+#[derive(Debug)]
+enum TxOpResponse {
+    Single(crate::Result<ResponseData>),
+    Batch(crate::Result<Vec<crate::Result<ResponseData>>>),
+    Committed(crate::Result<()>),
+    RolledBack(crate::Result<()>),
+}
+
+struct TxOpRequest {
+    msg: TxOpRequestMsg,
+    respond_to: oneshot::Sender<TxOpResponse>,
+}
+
+/// Handle to the task driving an open interactive transaction.
+#[derive(Clone)]
+pub(super) struct ItxClient {
+    id: TxId,
+    send: mpsc::Sender<TxOpRequest>,
+}
+
+impl ItxClient {
+    pub(super) async fn execute(
+        &self,
+        operation: Operation,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
+    ) -> crate::Result<ResponseData> {
+        match self
+            .send_and_receive(TxOpRequestMsg::Single(operation, explain, timeout))
+            .await?
+        {
+            TxOpResponse::Single(result) => result,
+            other => Err(self.unexpected_response(other).into()),
+        }
+    }
+
+    pub(super) async fn batch_execute(
+        &self,
+        operations: Vec<Operation>,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
+    ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
+        match self
+            .send_and_receive(TxOpRequestMsg::Batch(operations, explain, timeout))
+            .await?
+        {
+            TxOpResponse::Batch(result) => result,
+            other => Err(self.unexpected_response(other).into()),
+        }
+    }
+
+    pub(super) async fn commit(&self) -> crate::Result<()> {
+        match self.send_and_receive(TxOpRequestMsg::Commit).await? {
+            TxOpResponse::Committed(result) => result,
+            other => Err(self.unexpected_response(other).into()),
+        }
+    }
+
+    pub(super) async fn rollback(&self) -> crate::Result<()> {
+        match self.send_and_receive(TxOpRequestMsg::Rollback).await? {
+            TxOpResponse::RolledBack(result) => result,
+            other => Err(self.unexpected_response(other).into()),
+        }
+    }
+
+    async fn send_and_receive(&self, msg: TxOpRequestMsg) -> Result<TxOpResponse, TransactionError> {
+        let (respond_to, receiver) = oneshot::channel();
+        let request = TxOpRequest { msg, respond_to };
+
+        // A failing send means the transaction task already finished, usually because it timed out.
+        self.send
+            .clone()
+            .send(request)
+            .await
+            .map_err(|_| TransactionError::NotFound)?;
+
+        receiver.await.map_err(|_| TransactionError::Closed {
+            reason: format!("Transaction {} was closed while processing the request", self.id),
+        })
+    }
+
+    fn unexpected_response(&self, response: TxOpResponse) -> TransactionError {
+        TransactionError::Unknown {
+            reason: format!("Transaction {} returned {:?}", self.id, response),
+        }
+    }
+}
+
+/// Spawns the task driving a single interactive transaction on `conn` and returns
+/// a client to it once the transaction has been opened on the database.
+///
+/// The task rolls the transaction back and finishes if:
+/// - `options.max_lifetime` has passed since the transaction was started,
+/// - no request arrived for `options.idle_timeout`,
+/// - all clients to the transaction have been dropped,
+/// - a request running in the transaction timed out.
+pub(super) async fn spawn_itx_actor(
+    id: TxId,
+    conn: Box<dyn Connection>,
+    query_schema: QuerySchemaRef,
+    options: TransactionOptions,
+) -> crate::Result<(ItxClient, JoinHandle<()>)> {
+    let (send, mut receive) = mpsc::channel::<TxOpRequest>(CHANNEL_SIZE);
+    let (open_send, open_receive) = oneshot::channel::<crate::Result<()>>();
+    let task_id = id.clone();
+
+    let handle = tokio::spawn(async move {
+        let tx = match conn.start_transaction().await {
+            Ok(tx) => {
+                let _ = open_send.send(Ok(()));
+                tx
+            }
+            Err(err) => {
+                let _ = open_send.send(Err(err.into()));
+                return;
+            }
+        };
+
+        let deadline = Instant::now() + options.max_lifetime;
+
+        loop {
+            let wait_until = cmp::min(deadline, Instant::now() + options.idle_timeout);
+
+            let request = match time::timeout_at(wait_until, receive.recv()).await {
+                Ok(Some(request)) => request,
+                Ok(None) => {
+                    trace!(
+                        "All clients of interactive transaction {} are gone, rolling back.",
+                        task_id
+                    );
+                    let _ = tx.rollback().await;
+                    break;
+                }
+                Err(_) => {
+                    debug!("Interactive transaction {} timed out, rolling back.", task_id);
+                    let _ = tx.rollback().await;
+                    break;
+                }
+            };
+
+            match request.msg {
+                TxOpRequestMsg::Single(operation, explain, timeout) => {
+                    let execution = execute_in_tx(tx.as_ref(), operation, explain, query_schema.clone());
+                    let result = execute_with_timeout(conn.as_ref(), timeout, execution).await;
+                    let timed_out = matches!(result, Err(CoreError::QueryTimeout(_)));
+                    let _ = request.respond_to.send(TxOpResponse::Single(result));
+
+                    if timed_out {
+                        debug!(
+                            "Request in interactive transaction {} timed out, rolling back.",
+                            task_id
+                        );
+                        let _ = tx.rollback().await;
+                        break;
+                    }
+                }
+                TxOpRequestMsg::Batch(operations, explain, timeout) => {
+                    let execution = batch_execute_in_tx(tx.as_ref(), operations, explain, query_schema.clone());
+                    let result = execute_with_timeout(conn.as_ref(), timeout, execution).await;
+                    let timed_out = matches!(result, Err(CoreError::QueryTimeout(_)));
+                    let _ = request.respond_to.send(TxOpResponse::Batch(result));
+
+                    if timed_out {
+                        debug!(
+                            "Request in interactive transaction {} timed out, rolling back.",
+                            task_id
+                        );
+                        let _ = tx.rollback().await;
+                        break;
+                    }
+                }
+                TxOpRequestMsg::Commit => {
+                    let result = tx.commit().await.map_err(Into::into);
+                    let _ = request.respond_to.send(TxOpResponse::Committed(result));
+                    break;
+                }
+                TxOpRequestMsg::Rollback => {
+                    let result = tx.rollback().await.map_err(Into::into);
+                    let _ = request.respond_to.send(TxOpResponse::RolledBack(result));
+                    break;
+                }
+            }
+        }
+
+        trace!("Interactive transaction {} finished.", task_id);
+    });
+
+    open_receive.await.map_err(|_| TransactionError::Closed {
+        reason: format!("Transaction {} could not be started", id),
+    })??;
+
+    Ok((ItxClient { id, send }, handle))
+}
+
+async fn execute_in_tx(
+    tx: &dyn Transaction,
+    operation: Operation,
+    explain: ExplainMode,
+    query_schema: QuerySchemaRef,
+) -> crate::Result<ResponseData> {
+    let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
+    let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx));
+
+    QueryPipeline::new(query_graph, interpreter, serializer, explain)
+        .execute()
+        .await
+}
+
+/// Operations of a batch are executed in order. The first failing operation aborts the batch,
+/// but the transaction stays open: committing or rolling back is left to the client.
+async fn batch_execute_in_tx(
+    tx: &dyn Transaction,
+    operations: Vec<Operation>,
+    explain: ExplainMode,
+    query_schema: QuerySchemaRef,
+) -> crate::Result<Vec<crate::Result<ResponseData>>> {
+    let mut results = Vec::with_capacity(operations.len());
+
+    for operation in operations {
+        let result = execute_in_tx(tx, operation, explain, query_schema.clone()).await?;
+        results.push(Ok(result));
+    }
+
+    Ok(results)
+}
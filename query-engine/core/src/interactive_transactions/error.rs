@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum TransactionError {
+    #[error("Transaction not found. Transaction ID is invalid, refers to an old closed transaction or the transaction timed out.")]
+    NotFound,
+
+    #[error("Transaction already closed: {}", reason)]
+    Closed { reason: String },
+
+    #[error("Unexpected response from the transaction: {}", reason)]
+    Unknown { reason: String },
+}
@@ -0,0 +1,93 @@
+use super::{actors::*, TransactionError, TransactionOptions, TxId};
+use crate::{ExplainMode, Operation, QuerySchemaRef, ResponseData};
+use connector::Connection;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+
+/// Keeps track of all open interactive transactions of an executor.
+#[derive(Default)]
+pub struct ItxManager {
+    clients: Arc<RwLock<HashMap<TxId, ItxClient>>>,
+}
+
+impl ItxManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a new transaction on `conn` and registers it under a fresh id.
+    /// The transaction unregisters itself once its task finishes, e.g. on timeout.
+    pub async fn create_tx(
+        &self,
+        conn: Box<dyn Connection>,
+        query_schema: QuerySchemaRef,
+        options: TransactionOptions,
+    ) -> crate::Result<TxId> {
+        let id = TxId::default();
+        let (client, handle) = spawn_itx_actor(id.clone(), conn, query_schema, options).await?;
+
+        self.clients.write().await.insert(id.clone(), client);
+
+        let clients = Arc::clone(&self.clients);
+        let finished_id = id.clone();
+
+        tokio::spawn(async move {
+            let _ = handle.await;
+            clients.write().await.remove(&finished_id);
+        });
+
+        Ok(id)
+    }
+
+    pub async fn execute(
+        &self,
+        tx_id: &TxId,
+        operation: Operation,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
+    ) -> crate::Result<ResponseData> {
+        self.client(tx_id).await?.execute(operation, explain, timeout).await
+    }
+
+    pub async fn batch_execute(
+        &self,
+        tx_id: &TxId,
+        operations: Vec<Operation>,
+        explain: ExplainMode,
+        timeout: Option<Duration>,
+    ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
+        self.client(tx_id)
+            .await?
+            .batch_execute(operations, explain, timeout)
+            .await
+    }
+
+    pub async fn commit_tx(&self, tx_id: &TxId) -> crate::Result<()> {
+        let client = self.remove_client(tx_id).await?;
+        client.commit().await
+    }
+
+    pub async fn rollback_tx(&self, tx_id: &TxId) -> crate::Result<()> {
+        let client = self.remove_client(tx_id).await?;
+        client.rollback().await
+    }
+
+    async fn client(&self, tx_id: &TxId) -> Result<ItxClient, TransactionError> {
+        self.clients
+            .read()
+            .await
+            .get(tx_id)
+            .cloned()
+            .ok_or(TransactionError::NotFound)
+    }
+
+    /// Closing requests take the client out of the registry right away,
+    /// so that no other request can be routed to a closing transaction.
+    async fn remove_client(&self, tx_id: &TxId) -> Result<ItxClient, TransactionError> {
+        self.clients
+            .write()
+            .await
+            .remove(tx_id)
+            .ok_or(TransactionError::NotFound)
+    }
+}
@@ -0,0 +1,69 @@
+//! Interactive transactions keep a connector transaction open across multiple requests.
+//!
+//! Every open transaction is driven by its own task (see `actors`) that owns the pooled
+//! connection and the transaction on it. Requests reach the task over a channel, which means
+//! that the borrow of the transaction on its connection never has to leave the task. The task
+//! rolls the transaction back on its own if it outlives its maximum lifetime or stays idle for
+//! longer than the configured idle timeout.
+mod actors;
+mod error;
+mod manager;
+
+pub use error::*;
+pub use manager::*;
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, time::Duration};
+
+/// Identifier of an open interactive transaction.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TxId(String);
+
+impl Default for TxId {
+    fn default() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+}
+
+impl<T> From<T> for TxId
+where
+    T: Into<String>,
+{
+    fn from(s: T) -> Self {
+        Self(s.into())
+    }
+}
+
+impl fmt::Display for TxId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Timeouts of a single interactive transaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransactionOptions {
+    /// Maximum time the transaction is allowed to stay open, counted from its start.
+    pub max_lifetime: Duration,
+
+    /// Maximum time the transaction is allowed to wait for the next operation.
+    pub idle_timeout: Duration,
+}
+
+impl TransactionOptions {
+    pub fn new(max_lifetime: Duration, idle_timeout: Duration) -> Self {
+        Self {
+            max_lifetime,
+            idle_timeout,
+        }
+    }
+}
+
+impl Default for TransactionOptions {
+    fn default() -> Self {
+        Self {
+            max_lifetime: Duration::from_millis(5000),
+            idle_timeout: Duration::from_millis(5000),
+        }
+    }
+}
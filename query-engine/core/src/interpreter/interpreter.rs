@@ -3,12 +3,13 @@ use super::{
     query_interpreters::{read, write},
     InterpretationResult, InterpreterError,
 };
-use crate::{Query, QueryResult};
+use crate::{Query, QueryResult, WriteQuery};
 use connector::ConnectionLike;
 use crossbeam_queue::SegQueue;
 use futures::future::{BoxFuture, FutureExt};
 use im::HashMap;
 use prisma_models::prelude::*;
+use tracing_futures::Instrument;
 
 #[derive(Debug, Clone)]
 pub enum ExpressionResult {
@@ -208,14 +209,39 @@ where
                     match query {
                         Query::Read(read) => {
                             self.log_line(level, || format!("READ {}", read));
+                            // Only the model and the kind of query are recorded: filters and arguments
+                            // contain user data, which must not end up in the exported traces.
+                            let span = info_span!(
+                                "prisma:query",
+                                kind = "read",
+                                model = %read.model().name,
+                                operation = read.operation()
+                            );
+
                             Ok(read::execute(&self.conn, read, None)
+                                .instrument(span)
                                 .await
                                 .map(ExpressionResult::Query)?)
                         }
 
                         Query::Write(write) => {
                             self.log_line(level, || format!("WRITE {}", write));
-                            Ok(write::execute(&self.conn, write).await.map(ExpressionResult::Query)?)
+                            let span = info_span!(
+                                "prisma:query",
+                                kind = "write",
+                                model = tracing::field::Empty,
+                                operation = write.operation()
+                            );
+
+                            // Raw queries are not bound to a model.
+                            if !matches!(write, WriteQuery::ExecuteRaw(_) | WriteQuery::QueryRaw(_)) {
+                                span.record("model", &write.model().name.as_str());
+                            }
+
+                            Ok(write::execute(&self.conn, write)
+                                .instrument(span)
+                                .await
+                                .map(ExpressionResult::Query)?)
                         }
                     }
                 };
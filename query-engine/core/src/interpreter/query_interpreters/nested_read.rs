@@ -1,9 +1,9 @@
 use super::inmemory_record_processor::InMemoryRecordProcessor;
-use crate::{interpreter::InterpretationResult, query_ast::*};
+use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
 use connector::{self, filter::Filter, ConnectionLike, QueryArguments, ReadOperations, ScalarCompare};
 use prisma_models::{ManyRecords, ModelProjection, Record, RecordProjection, RelationFieldRef};
 use prisma_value::PrismaValue;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub async fn m2m<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -227,3 +227,52 @@ pub async fn one2m<'a, 'b>(
 
     Ok(processor.apply(scalars))
 }
+
+/// Counts the related records of all parent records with a single grouped query per relation,
+/// instead of a query per parent record.
+pub async fn relation_counts<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: RelationCountsQuery,
+    parent_result: Option<&'a ManyRecords>,
+) -> InterpretationResult<QueryResult> {
+    let parent_records = parent_result
+        .expect("[ID retrieval] No parent results present in the query graph for counting related records.");
+
+    let parent_ids = parent_records.projections(&query.model.primary_identifier())?;
+    let mut counts: Vec<Vec<usize>> = vec![vec![0; query.counts.len()]; parent_ids.len()];
+
+    for (count_idx, count) in query.counts.iter().enumerate() {
+        let parent_links = parent_records.projections(&count.field.linking_fields())?;
+
+        // Each parent link only needs to be counted once, parents without a link have no related records.
+        let mut seen = HashSet::new();
+        let unique_links: Vec<RecordProjection> = parent_links
+            .iter()
+            .filter(|link| !link.values().any(|v| v.is_null()) && seen.insert(*link))
+            .cloned()
+            .collect();
+
+        if unique_links.is_empty() {
+            continue;
+        }
+
+        let counted: HashMap<Vec<PrismaValue>, usize> = tx
+            .count_related_records(&count.field, &unique_links, count.filter.clone())
+            .await?
+            .into_iter()
+            .map(|(link, related_count)| (link.values().collect(), related_count))
+            .collect();
+
+        for (parent_idx, link) in parent_links.iter().enumerate() {
+            if let Some(related_count) = counted.get(&link.values().collect::<Vec<_>>()) {
+                counts[parent_idx][count_idx] = *related_count;
+            }
+        }
+    }
+
+    Ok(QueryResult::RelationCounts(RelationCounts {
+        name: query.name,
+        fields: query.counts.into_iter().map(|count| count.name).collect(),
+        counts: parent_ids.into_iter().zip(counts).collect(),
+    }))
+}
@@ -16,6 +16,7 @@ pub fn execute<'a, 'b>(
             ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
             ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::RelationCountsQuery(q) => nested_read::relation_counts(tx, q, parent_result).await,
         }
     };
 
@@ -31,7 +32,9 @@ fn read_one<'conn, 'tx>(
         let model = query.model;
         let model_id = model.primary_identifier();
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
-        let scalars = tx.get_single_record(&model, &filter, &query.selected_fields).await?;
+        let scalars = tx
+            .get_single_record(&model, &filter, &query.selected_fields, query.lock)
+            .await?;
 
         match scalars {
             Some(record) => {
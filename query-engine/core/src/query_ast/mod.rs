@@ -4,6 +4,7 @@ mod write;
 pub use read::*;
 pub use write::*;
 
+use crate::QueryGraphBuilderResult;
 use connector::filter::Filter;
 use prisma_models::{ModelProjection, ModelRef};
 
@@ -37,7 +38,7 @@ impl FilteredQuery for Query {
         }
     }
 
-    fn set_filter(&mut self, filter: Filter) {
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
         match self {
             Self::Read(rq) => rq.set_filter(filter),
             Self::Write(wq) => wq.set_filter(filter),
@@ -46,7 +47,7 @@ impl FilteredQuery for Query {
 }
 
 pub trait FilteredQuery {
-    fn add_filter<T>(&mut self, filter: T)
+    fn add_filter<T>(&mut self, filter: T) -> QueryGraphBuilderResult<()>
     where
         T: Into<Filter>,
     {
@@ -70,13 +71,17 @@ pub trait FilteredQuery {
             None => Some(filter),
         };
 
-        if let Some(filter) = filter {
-            self.set_filter(filter);
+        match filter {
+            Some(filter) => self.set_filter(filter),
+            None => Ok(()),
         }
     }
 
+    /// The filter of the query, `None` if it has none or doesn't take one.
     fn get_filter(&mut self) -> Option<&mut Filter>;
-    fn set_filter(&mut self, filter: Filter);
+
+    /// Replaces the filter of the query, failing if the query doesn't take one.
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()>;
 
     fn default_filter_behaviour(inner_filters: Vec<Filter>) -> Filter {
         Filter::Or(inner_filters)
@@ -1,6 +1,7 @@
 //! Prisma read query AST
 use super::FilteredQuery;
-use connector::{filter::Filter, AggregationSelection, QueryArguments};
+use crate::{QueryGraphBuilderError, QueryGraphBuilderResult};
+use connector::{filter::Filter, AggregationSelection, QueryArguments, RowLock};
 use prisma_models::prelude::*;
 use std::fmt::Display;
 
@@ -10,6 +11,7 @@ pub enum ReadQuery {
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    RelationCountsQuery(RelationCountsQuery),
 }
 
 impl ReadQuery {
@@ -19,6 +21,7 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::RelationCountsQuery(x) => &x.name,
         }
     }
 
@@ -30,6 +33,7 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::RelatedRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::AggregateRecordsQuery(_x) => false,
+            ReadQuery::RelationCountsQuery(_x) => false,
         }
     }
 
@@ -39,6 +43,18 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.model.clone(),
             ReadQuery::RelatedRecordsQuery(x) => x.parent_field.related_field().model(),
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
+            ReadQuery::RelationCountsQuery(x) => x.model.clone(),
+        }
+    }
+
+    /// The kind of the query, without any of its arguments.
+    pub fn operation(&self) -> &'static str {
+        match self {
+            ReadQuery::RecordQuery(_) => "RecordQuery",
+            ReadQuery::ManyRecordsQuery(_) => "ManyRecordsQuery",
+            ReadQuery::RelatedRecordsQuery(_) => "RelatedRecordsQuery",
+            ReadQuery::AggregateRecordsQuery(_) => "AggregateRecordsQuery",
+            ReadQuery::RelationCountsQuery(_) => "RelationCountsQuery",
         }
     }
 }
@@ -48,15 +64,18 @@ impl FilteredQuery for ReadQuery {
         match self {
             Self::RecordQuery(q) => q.get_filter(),
             Self::ManyRecordsQuery(q) => q.get_filter(),
-            _ => unimplemented!(),
+            _ => None,
         }
     }
 
-    fn set_filter(&mut self, filter: Filter) {
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
         match self {
             Self::RecordQuery(q) => q.set_filter(filter),
             Self::ManyRecordsQuery(q) => q.set_filter(filter),
-            _ => unimplemented!(),
+            _ => Err(QueryGraphBuilderError::AssertionError(format!(
+                "{} doesn't take a filter.",
+                self.operation()
+            ))),
         }
     }
 }
@@ -88,6 +107,13 @@ impl Display for ReadQuery {
                 q.selected_fields.names().collect::<Vec<_>>()
             ),
             Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::RelationCountsQuery(q) => write!(
+                f,
+                "RelationCountsQuery(name: '{}', model: {}, relations: {:?})",
+                q.name,
+                q.model.name,
+                q.counts.iter().map(|c| c.field.name.as_str()).collect::<Vec<_>>()
+            ),
         }
     }
 }
@@ -98,6 +124,7 @@ pub struct RecordQuery {
     pub alias: Option<String>,
     pub model: ModelRef,
     pub filter: Option<Filter>,
+    pub lock: Option<RowLock>,
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
@@ -141,13 +168,35 @@ pub struct AggregateRecordsQuery {
     pub having: Option<Filter>,
 }
 
+/// Counts the related records of each parent record, for a set of to-many relations of the parent model.
+#[derive(Debug, Clone)]
+pub struct RelationCountsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+
+    /// The parent model.
+    pub model: ModelRef,
+    pub counts: Vec<RelationCount>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RelationCount {
+    /// Name of the count in the result, the alias of the selection if present.
+    pub name: String,
+    pub field: RelationFieldRef,
+
+    /// Only related records matching the filter are counted.
+    pub filter: Option<Filter>,
+}
+
 impl FilteredQuery for RecordQuery {
     fn get_filter(&mut self) -> Option<&mut Filter> {
         self.filter.as_mut()
     }
 
-    fn set_filter(&mut self, filter: Filter) {
-        self.filter = Some(filter)
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
+        self.filter = Some(filter);
+        Ok(())
     }
 }
 
@@ -156,7 +205,8 @@ impl FilteredQuery for ManyRecordsQuery {
         self.args.filter.as_mut()
     }
 
-    fn set_filter(&mut self, filter: Filter) {
-        self.args.filter = Some(filter)
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
+        self.args.filter = Some(filter);
+        Ok(())
     }
 }
@@ -1,5 +1,6 @@
 //! Write query AST
 use super::FilteredQuery;
+use crate::{QueryGraphBuilderError, QueryGraphBuilderResult};
 use connector::{filter::Filter, DatasourceFieldName, RecordFilter, WriteArgs};
 use prisma_models::prelude::*;
 use std::sync::Arc;
@@ -7,6 +8,7 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub enum WriteQuery {
     CreateRecord(CreateRecord),
+    CreateManyRecords(CreateManyRecords),
     UpdateRecord(UpdateRecord),
     DeleteRecord(DeleteRecord),
     UpdateManyRecords(UpdateManyRecords),
@@ -44,6 +46,23 @@ impl WriteQuery {
         args.insert(DatasourceFieldName(key), value)
     }
 
+    /// Replaces the records a filtered write operates on, e.g. with the identifiers found by a parent read.
+    pub fn set_record_filter(&mut self, record_filter: RecordFilter) -> QueryGraphBuilderResult<()> {
+        match self {
+            Self::UpdateRecord(x) => x.record_filter = record_filter,
+            Self::DeleteRecord(x) => x.record_filter = Some(record_filter),
+            Self::UpdateManyRecords(x) => x.record_filter = record_filter,
+            Self::DeleteManyRecords(x) => x.record_filter = record_filter,
+            _ => return Err(self.unfiltered_error()),
+        }
+
+        Ok(())
+    }
+
+    fn unfiltered_error(&self) -> QueryGraphBuilderError {
+        QueryGraphBuilderError::AssertionError(format!("{} doesn't take a filter.", self.operation()))
+    }
+
     pub fn returns(&self, projection: &ModelProjection) -> bool {
         let returns_id = &self.model().primary_identifier() == projection;
 
@@ -52,6 +71,7 @@ impl WriteQuery {
         // DeleteMany, Connect and Disconnect do not return anything.
         match self {
             Self::CreateRecord(_) => returns_id,
+            Self::CreateManyRecords(_) => false,
             Self::UpdateRecord(_) => returns_id,
             Self::DeleteRecord(_) => returns_id,
             Self::UpdateManyRecords(_) => returns_id,
@@ -66,6 +86,7 @@ impl WriteQuery {
     pub fn model(&self) -> ModelRef {
         match self {
             Self::CreateRecord(q) => Arc::clone(&q.model),
+            Self::CreateManyRecords(q) => Arc::clone(&q.model),
             Self::UpdateRecord(q) => Arc::clone(&q.model),
             Self::DeleteRecord(q) => Arc::clone(&q.model),
             Self::UpdateManyRecords(q) => Arc::clone(&q.model),
@@ -76,6 +97,22 @@ impl WriteQuery {
             Self::QueryRaw(_) => unimplemented!(),
         }
     }
+
+    /// The kind of the query, without any of its arguments.
+    pub fn operation(&self) -> &'static str {
+        match self {
+            Self::CreateRecord(_) => "CreateRecord",
+            Self::CreateManyRecords(_) => "CreateManyRecords",
+            Self::UpdateRecord(_) => "UpdateRecord",
+            Self::DeleteRecord(_) => "DeleteRecord",
+            Self::UpdateManyRecords(_) => "UpdateManyRecords",
+            Self::DeleteManyRecords(_) => "DeleteManyRecords",
+            Self::ConnectRecords(_) => "ConnectRecords",
+            Self::DisconnectRecords(_) => "DisconnectRecords",
+            Self::ExecuteRaw(_) => "ExecuteRaw",
+            Self::QueryRaw(_) => "QueryRaw",
+        }
+    }
 }
 
 impl FilteredQuery for WriteQuery {
@@ -85,17 +122,17 @@ impl FilteredQuery for WriteQuery {
             Self::DeleteManyRecords(q) => q.get_filter(),
             Self::DeleteRecord(q) => q.get_filter(),
             Self::UpdateManyRecords(q) => q.get_filter(),
-            _ => unimplemented!(),
+            _ => None,
         }
     }
 
-    fn set_filter(&mut self, filter: Filter) {
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
         match self {
             Self::UpdateRecord(q) => q.set_filter(filter),
             Self::DeleteManyRecords(q) => q.set_filter(filter),
             Self::DeleteRecord(q) => q.set_filter(filter),
             Self::UpdateManyRecords(q) => q.set_filter(filter),
-            _ => unimplemented!(),
+            _ => Err(self.unfiltered_error()),
         }
     }
 }
@@ -104,6 +141,13 @@ impl std::fmt::Display for WriteQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::CreateRecord(q) => write!(f, "CreateRecord(model: {}, args: {:?})", q.model.name, q.args,),
+            Self::CreateManyRecords(q) => write!(
+                f,
+                "CreateManyRecords(model: {}, records: {}, skip_duplicates: {})",
+                q.model.name,
+                q.args.len(),
+                q.skip_duplicates
+            ),
             Self::UpdateRecord(q) => write!(
                 f,
                 "UpdateRecord(model: {}, filter: {:?}, args: {:?})",
@@ -126,6 +170,13 @@ pub struct CreateRecord {
     pub args: WriteArgs,
 }
 
+#[derive(Debug, Clone)]
+pub struct CreateManyRecords {
+    pub model: ModelRef,
+    pub args: Vec<WriteArgs>,
+    pub skip_duplicates: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdateRecord {
     pub model: ModelRef,
@@ -177,8 +228,9 @@ impl FilteredQuery for UpdateRecord {
         Some(&mut self.record_filter.filter)
     }
 
-    fn set_filter(&mut self, filter: Filter) {
-        self.record_filter.filter = filter
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
+        self.record_filter.filter = filter;
+        Ok(())
     }
 }
 
@@ -187,8 +239,9 @@ impl FilteredQuery for UpdateManyRecords {
         Some(&mut self.record_filter.filter)
     }
 
-    fn set_filter(&mut self, filter: Filter) {
-        self.record_filter.filter = filter
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
+        self.record_filter.filter = filter;
+        Ok(())
     }
 }
 
@@ -197,8 +250,9 @@ impl FilteredQuery for DeleteManyRecords {
         Some(&mut self.record_filter.filter)
     }
 
-    fn set_filter(&mut self, filter: Filter) {
-        self.record_filter.filter = filter
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
+        self.record_filter.filter = filter;
+        Ok(())
     }
 }
 
@@ -207,12 +261,12 @@ impl FilteredQuery for DeleteRecord {
         self.record_filter.as_mut().map(|f| &mut f.filter)
     }
 
-    fn set_filter(&mut self, filter: Filter) {
+    fn set_filter(&mut self, filter: Filter) -> QueryGraphBuilderResult<()> {
         match self.record_filter {
             Some(ref mut rf) => rf.filter = filter,
             None => self.record_filter = Some(filter.into()),
         }
 
-        //.filter = Some(filter)
+        Ok(())
     }
 }
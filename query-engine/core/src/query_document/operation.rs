@@ -7,6 +7,10 @@ pub enum Operation {
 }
 
 impl Operation {
+    pub fn is_read(&self) -> bool {
+        matches!(self, Self::Read(_))
+    }
+
     pub fn is_find_one(&self) -> bool {
         match self {
             Self::Read(selection) => selection.is_find_one(),
@@ -1,4 +1,5 @@
 use super::*;
+use serde_json::json;
 use std::fmt::{self, Display};
 
 pub fn format(graph: &QueryGraph) -> String {
@@ -11,6 +12,47 @@ pub fn format(graph: &QueryGraph) -> String {
     )
 }
 
+/// Renders the graph as JSON for explained operations: the nodes with their content, the edges with
+/// the dependency they express between two nodes (including `Then` and `Else` flow edges), and the
+/// nodes returning the result.
+pub fn format_json(graph: &QueryGraph) -> serde_json::Value {
+    let nodes: Vec<_> = graph
+        .graph
+        .node_indices()
+        .map(|node_ix| {
+            let node = NodeRef { node_ix };
+            let (kind, content) = match graph.node_content(&node) {
+                Some(Node::Query(q)) => ("query", q.to_string()),
+                Some(Node::Flow(flow)) => ("flow", flow.to_string()),
+                Some(Node::Computation(c)) => ("computation", c.to_string()),
+                Some(Node::Empty) | None => ("empty", String::new()),
+            };
+
+            json!({ "id": node.id(), "kind": kind, "content": content })
+        })
+        .collect();
+
+    let edges: Vec<_> = graph
+        .graph
+        .edge_indices()
+        .map(|edge_ix| {
+            let edge = EdgeRef { edge_ix };
+            let dependency = graph.edge_content(&edge).map(ToString::to_string);
+
+            json!({
+                "id": edge.id(),
+                "from": graph.edge_source(&edge).id(),
+                "to": graph.edge_target(&edge).id(),
+                "dependency": dependency,
+            })
+        })
+        .collect();
+
+    let result_nodes: Vec<_> = graph.result_nodes().iter().map(NodeRef::id).collect();
+
+    json!({ "nodes": nodes, "edges": edges, "resultNodes": result_nodes })
+}
+
 fn stringify_nodes(graph: &QueryGraph, nodes: Vec<NodeRef>, seen_nodes: &mut Vec<NodeRef>) -> Vec<String> {
     let mut rendered_nodes = vec![];
 
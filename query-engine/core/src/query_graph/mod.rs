@@ -305,6 +305,11 @@ impl QueryGraph {
         Ok(edge)
     }
 
+    /// Returns the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
     /// Mark the query graph to need a transaction.
     pub fn flag_transactional(&mut self) {
         self.needs_transaction = true;
@@ -758,7 +763,7 @@ impl QueryGraph {
                     primary_model_id,
                     Box::new(|mut reload_node, parent_projections| {
                         if let Node::Query(Query::Read(ReadQuery::ManyRecordsQuery(ref mut mr))) = reload_node {
-                            mr.set_filter(parent_projections.filter());
+                            mr.set_filter(parent_projections.filter())?;
                         }
 
                         Ok(reload_node)
@@ -39,6 +39,12 @@ pub enum QueryGraphBuilderError {
 
     RecordNotFound(String),
 
+    /// The version field of the record to update didn't hold the expected version.
+    VersionMismatch {
+        model_name: String,
+        expected_version: String,
+    },
+
     QueryGraphError(QueryGraphError),
 }
 
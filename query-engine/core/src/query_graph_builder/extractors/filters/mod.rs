@@ -2,12 +2,12 @@ mod filter_grouping;
 mod relation;
 mod scalar;
 
-use super::utils;
+use super::{utils, without_deleted};
 use crate::{
     query_document::{ParsedInputMap, ParsedInputValue},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::Filter, QueryMode, RelationCompare, ScalarCompare};
+use connector::{filter::Filter, JsonFilterPath, QueryMode, RelationCompare, ScalarCompare};
 use filter_grouping::*;
 use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
 use std::{convert::TryInto, str::FromStr};
@@ -153,6 +153,19 @@ fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> Qu
                 None => QueryMode::Default,
             };
 
+            let json_path = filter_map.remove("path").map(parse_json_path).transpose()?;
+
+            if json_path.is_none()
+                && filter_map
+                    .keys()
+                    .any(|k| k.starts_with("string_") || k.starts_with("array_"))
+            {
+                return Err(QueryGraphBuilderError::InputError(format!(
+                    "Json string and array filters on field {} require a `path`.",
+                    field.name
+                )));
+            }
+
             let mut filters: Vec<Filter> = filter_map
                 .into_iter()
                 .map(|(k, v)| scalar::parse(&k, field, v, false))
@@ -162,6 +175,11 @@ fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> Qu
                 .collect();
 
             filters.iter_mut().for_each(|f| f.set_mode(mode.clone()));
+
+            if let Some(path) = json_path {
+                filters.iter_mut().for_each(|f| f.set_json_path(path.clone()));
+            }
+
             Ok(filters)
         }
         x => Err(QueryGraphBuilderError::InputError(format!(
@@ -189,7 +207,10 @@ fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -
                 .map(|(k, v)| relation::parse(&k, field, v))
                 .collect::<QueryGraphBuilderResult<Vec<_>>>()
                 .or_else(|_| {
-                    extract_filter(filter_map, &field.related_model()).map(|filter| vec![field.to_one_related(filter)])
+                    let related_model = field.related_model();
+
+                    extract_filter(filter_map, &related_model)
+                        .map(|filter| vec![field.to_one_related(without_deleted(filter, &related_model))])
                 })
         }
 
@@ -200,6 +221,29 @@ fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -
     }
 }
 
+fn parse_json_path(input: ParsedInputValue) -> QueryGraphBuilderResult<JsonFilterPath> {
+    let value: PrismaValue = input.try_into()?;
+
+    match value {
+        PrismaValue::String(path) => Ok(JsonFilterPath::String(path)),
+        PrismaValue::List(keys) => keys
+            .into_iter()
+            .map(|key| match key {
+                PrismaValue::String(key) => Ok(key),
+                x => Err(QueryGraphBuilderError::InputError(format!(
+                    "Invalid Json path key: {:?}",
+                    x
+                ))),
+            })
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()
+            .map(JsonFilterPath::Array),
+        x => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid Json path: {:?}",
+            x
+        ))),
+    }
+}
+
 fn parse_query_mode(input: ParsedInputValue) -> QueryGraphBuilderResult<QueryMode> {
     let value: PrismaValue = input.try_into()?;
     let s = match value {
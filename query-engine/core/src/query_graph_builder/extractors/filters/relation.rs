@@ -1,22 +1,43 @@
 use super::extract_filter;
-use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
+use crate::{
+    not_deleted_filter, without_deleted, ParsedInputMap, ParsedInputValue, QueryGraphBuilderError,
+    QueryGraphBuilderResult,
+};
 use connector::{Filter, RelationCompare};
 use prisma_models::RelationFieldRef;
 use std::convert::TryInto;
 
 pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
     let value: Option<ParsedInputMap> = input.try_into()?;
+    let related_model = field.related_model();
 
+    // Soft deleted related records are treated as if they were gone: they can't match `some`, `none`
+    // and `is`, and don't have to match `every`.
     match (filter_key, value) {
         // Relation list filters
-        ("some", Some(value)) => Ok(field.at_least_one_related(extract_filter(value, &field.related_model())?)),
-        ("none", Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
-        ("every", Some(value)) => Ok(field.every_related(extract_filter(value, &field.related_model())?)),
+        ("some", Some(value)) => {
+            Ok(field.at_least_one_related(without_deleted(extract_filter(value, &related_model)?, &related_model)))
+        }
+        ("none", Some(value)) => {
+            Ok(field.no_related(without_deleted(extract_filter(value, &related_model)?, &related_model)))
+        }
+        ("every", Some(value)) => {
+            let filter = extract_filter(value, &related_model)?;
+
+            Ok(field.every_related(match not_deleted_filter(&related_model) {
+                Some(not_deleted) => Filter::or(vec![filter, Filter::not(vec![not_deleted])]),
+                None => filter,
+            }))
+        }
 
         // One-relation filters
-        ("is", Some(value)) => Ok(field.to_one_related(extract_filter(value, &field.related_model())?)),
+        ("is", Some(value)) => {
+            Ok(field.to_one_related(without_deleted(extract_filter(value, &related_model)?, &related_model)))
+        }
         ("is", None) => Ok(field.one_relation_is_null()),
-        ("isNot", Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
+        ("isNot", Some(value)) => {
+            Ok(field.no_related(without_deleted(extract_filter(value, &related_model)?, &related_model)))
+        }
         ("isNot", None) => Ok(Filter::not(vec![field.one_relation_is_null()])),
 
         _ => Err(QueryGraphBuilderError::InputError(format!(
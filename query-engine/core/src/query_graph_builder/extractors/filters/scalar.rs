@@ -1,5 +1,5 @@
 use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
-use connector::{Filter, ScalarCompare};
+use connector::{Filter, JsonCondition, JsonTargetType, ScalarCompare, ScalarCondition};
 use prisma_models::{PrismaValue, ScalarFieldRef};
 use std::convert::TryInto;
 
@@ -71,6 +71,59 @@ pub fn parse(
         "startsWith" => vec![field.starts_with(as_prisma_value(input)?)],
         "endsWith" => vec![field.ends_with(as_prisma_value(input)?)],
 
+        "search" if reverse => vec![field.not_search(as_prisma_value(input)?)],
+        "search" => vec![field.search(as_prisma_value(input)?)],
+
+        "string_contains" if reverse => vec![json_target(
+            field.not_contains(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        "string_starts_with" if reverse => vec![json_target(
+            field.not_starts_with(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        "string_ends_with" if reverse => vec![json_target(
+            field.not_ends_with(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        "array_contains" if reverse => vec![json_target(
+            field.not_contains(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+        "array_starts_with" if reverse => vec![json_target(
+            field.not_starts_with(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+        "array_ends_with" if reverse => vec![json_target(
+            field.not_ends_with(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+
+        "string_contains" => vec![json_target(
+            field.contains(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        "string_starts_with" => vec![json_target(
+            field.starts_with(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        "string_ends_with" => vec![json_target(
+            field.ends_with(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        "array_contains" => vec![json_target(
+            field.contains(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+        "array_starts_with" => vec![json_target(
+            field.starts_with(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+        "array_ends_with" => vec![json_target(
+            field.ends_with(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+
         "lt" if reverse => vec![field.greater_than_or_equals(as_prisma_value(input)?)],
         "gt" if reverse => vec![field.less_than_or_equals(as_prisma_value(input)?)],
         "lte" if reverse => vec![field.greater_than(as_prisma_value(input)?)],
@@ -103,6 +156,22 @@ fn as_prisma_value(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaVal
     Ok(input.try_into()?)
 }
 
+/// Turns the string filter into a Json filter on the given target type.
+fn json_target(filter: Filter, target_type: JsonTargetType) -> Filter {
+    match filter {
+        Filter::Scalar(mut sf) => {
+            sf.condition = ScalarCondition::JsonCompare(JsonCondition {
+                condition: Box::new(sf.condition),
+                path: None,
+                target_type: Some(target_type),
+            });
+
+            Filter::Scalar(sf)
+        }
+        filter => filter,
+    }
+}
+
 fn aggregation_filter<F>(
     field: &ScalarFieldRef,
     input: ParsedInputValue,
@@ -3,9 +3,10 @@ use crate::{
     query_document::{ParsedArgument, ParsedInputMap},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::QueryArguments;
+use connector::{LockStrength, LockWait, QueryArguments, RowLock};
 use prisma_models::{
-    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder,
+    Field, IndexType, ModelProjection, ModelRef, NullsOrder, OrderBy, PrismaValue, RecordProjection, RelationFieldRef,
+    ScalarFieldRef, SortAggregation, SortOrder,
 };
 use std::convert::{identity, TryInto};
 
@@ -13,7 +14,24 @@ use std::convert::{identity, TryInto};
 /// e.g. that the query schema guarantees that required fields are present.
 /// Errors occur if conversions fail.
 pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> QueryGraphBuilderResult<QueryArguments> {
-    let query_args = arguments.into_iter().fold(
+    let include_deleted = extract_include_deleted(&arguments)?;
+    build_query_args(arguments, model, include_deleted)
+}
+
+/// Same as `extract_query_args`, but keeps soft deleted records in the result.
+pub fn extract_query_args_including_deleted(
+    arguments: Vec<ParsedArgument>,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<QueryArguments> {
+    build_query_args(arguments, model, true)
+}
+
+fn build_query_args(
+    arguments: Vec<ParsedArgument>,
+    model: &ModelRef,
+    include_deleted: bool,
+) -> QueryGraphBuilderResult<QueryArguments> {
+    let mut query_args = arguments.into_iter().fold(
         Ok(QueryArguments::new(model.clone())),
         |result: QueryGraphBuilderResult<QueryArguments>, arg| {
             if let Ok(res) = result {
@@ -43,6 +61,11 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         ..res
                     }),
 
+                    "lock" => Ok(QueryArguments {
+                        lock: Some(extract_lock(arg.value)?),
+                        ..res
+                    }),
+
                     "where" => {
                         let val: Option<ParsedInputMap> = arg.value.try_into()?;
                         match val {
@@ -62,36 +85,24 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
         },
     )?;
 
+    query_args.filter = exclude_deleted(query_args.filter.take(), model, include_deleted);
+
     Ok(finalize_arguments(query_args, model))
 }
 
-/// Extracts order by conditions in order of appearance, as defined in
+/// Extracts order by conditions in order of appearance, as defined in the `orderBy` argument.
 fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<OrderBy>> {
     match value {
         ParsedInputValue::List(list) => list
             .into_iter()
             .map(|list_value| {
                 let object: ParsedInputMap = list_value.try_into()?;
-
-                match object.into_iter().next() {
-                    None => Ok(None),
-                    Some((field_name, sort_order)) => {
-                        let field = model.fields().find_from_scalar(&field_name)?;
-                        let value: PrismaValue = sort_order.try_into()?;
-                        let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                            "asc" => SortOrder::Ascending,
-                            "desc" => SortOrder::Descending,
-                            _ => unreachable!(),
-                        };
-
-                        Ok(Some(OrderBy::new(field, sort_order)))
-                    }
-                }
+                process_order_object(model, object, vec![])
             })
             .collect::<QueryGraphBuilderResult<Vec<_>>>()
             .map(|results| results.into_iter().filter_map(identity).collect()),
 
-        ParsedInputValue::Map(map) => Ok(match process_order_object(model, map)? {
+        ParsedInputValue::Map(map) => Ok(match process_order_object(model, map, vec![])? {
             Some(order) => vec![order],
             None => vec![],
         }),
@@ -100,22 +111,162 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
     }
 }
 
-fn process_order_object(model: &ModelRef, object: ParsedInputMap) -> QueryGraphBuilderResult<Option<OrderBy>> {
-    // let object: ParsedInputMap = list_value.try_into()?;
-
+/// Processes a single order object. Objects on to-one relations are nested order objects of the related model,
+/// objects on to-many relations select an aggregation of the related records (e.g. `{ posts: { _count: asc } }`).
+fn process_order_object(
+    model: &ModelRef,
+    object: ParsedInputMap,
+    mut path: Vec<RelationFieldRef>,
+) -> QueryGraphBuilderResult<Option<OrderBy>> {
     match object.into_iter().next() {
         None => Ok(None),
-        Some((field_name, sort_order)) => {
-            let field = model.fields().find_from_scalar(&field_name)?;
-            let value: PrismaValue = sort_order.try_into()?;
-            let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                "asc" => SortOrder::Ascending,
-                "desc" => SortOrder::Descending,
-                _ => unreachable!(),
+        Some((field_name, value)) if field_name == "_relevance" => {
+            let object: ParsedInputMap = value.try_into()?;
+            extract_order_by_relevance(model, object).map(Some)
+        }
+        Some((field_name, value)) => match model.fields().find_from_all(&field_name)? {
+            Field::Scalar(sf) => {
+                let (sort_order, nulls_order) = extract_scalar_sort_order(value)?;
+                Ok(Some(
+                    OrderBy::with_path(sf.clone(), path, sort_order).with_nulls_order(nulls_order),
+                ))
+            }
+
+            Field::Relation(rf) if rf.is_list => {
+                let object: ParsedInputMap = value.try_into()?;
+                path.push(rf.clone());
+
+                match object.into_iter().next() {
+                    None => Ok(None),
+                    Some((aggregation, value)) => {
+                        let sort_aggregation = match aggregation.as_str() {
+                            "_count" => SortAggregation::Count,
+                            _ => unreachable!(),
+                        };
+
+                        Ok(Some(OrderBy::relation_aggregation(
+                            path,
+                            sort_aggregation,
+                            extract_sort_order(value)?,
+                        )))
+                    }
+                }
+            }
+
+            Field::Relation(rf) => {
+                let object: ParsedInputMap = value.try_into()?;
+                path.push(rf.clone());
+
+                process_order_object(&rf.related_model(), object, path)
+            }
+        },
+    }
+}
+
+/// Relevance orderings rank records by the relevance of one or more String fields for a full-text search query
+/// (`{ _relevance: { fields: [title, content], search: "database", sort: desc } }`).
+fn extract_order_by_relevance(model: &ModelRef, mut object: ParsedInputMap) -> QueryGraphBuilderResult<OrderBy> {
+    let fields: Vec<ScalarFieldRef> = match object.remove("fields").unwrap() {
+        ParsedInputValue::List(list) => list
+            .into_iter()
+            .map(|element| {
+                let field: ScalarFieldRef = element.try_into()?;
+                Ok(field)
+            })
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()?,
+        value => vec![value.try_into()?],
+    };
+
+    let has_fulltext_index = model
+        .indexes()
+        .iter()
+        .filter(|index| index.typ == IndexType::Fulltext)
+        .map(|index| index.fields())
+        .any(|index_fields| index_fields.len() == fields.len() && fields.iter().all(|f| index_fields.contains(f)));
+
+    if !has_fulltext_index {
+        let field_names: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "Ordering by relevance on model {} requires a fulltext index on exactly the fields [{}].",
+            model.name,
+            field_names.join(", ")
+        )));
+    }
+
+    let search: PrismaValue = object.remove("search").unwrap().try_into()?;
+    let sort_order = extract_sort_order(object.remove("sort").unwrap())?;
+
+    Ok(OrderBy::relevance(fields, search.into_string().unwrap(), sort_order))
+}
+
+/// Scalar fields are either ordered by a plain sort order (`asc`), or, for nullable fields,
+/// by an object that also places null values (`{ sort: asc, nulls: last }`).
+fn extract_scalar_sort_order(value: ParsedInputValue) -> QueryGraphBuilderResult<(SortOrder, Option<NullsOrder>)> {
+    match value {
+        ParsedInputValue::Map(mut map) => {
+            let sort_order = extract_sort_order(map.remove("sort").unwrap())?;
+            let nulls_order = match map.remove("nulls") {
+                Some(value) => {
+                    let value: PrismaValue = value.try_into()?;
+
+                    match value.into_string().unwrap().to_lowercase().as_str() {
+                        "first" => Some(NullsOrder::First),
+                        "last" => Some(NullsOrder::Last),
+                        _ => unreachable!(),
+                    }
+                }
+                None => None,
             };
 
-            Ok(Some(OrderBy::new(field, sort_order)))
+            Ok((sort_order, nulls_order))
+        }
+        value => Ok((extract_sort_order(value)?, None)),
+    }
+}
+
+fn extract_sort_order(value: ParsedInputValue) -> QueryGraphBuilderResult<SortOrder> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match value.into_string().unwrap().to_lowercase().as_str() {
+        "asc" => SortOrder::Ascending,
+        "desc" => SortOrder::Descending,
+        _ => unreachable!(),
+    })
+}
+
+/// Extracts the row lock of a read, e.g. `{ mode: forUpdate, skipLocked: true }`.
+pub fn extract_lock(value: ParsedInputValue) -> QueryGraphBuilderResult<RowLock> {
+    let mut map: ParsedInputMap = value.try_into()?;
+    let mode: PrismaValue = map.remove("mode").unwrap().try_into()?;
+
+    let strength = match mode.into_string().unwrap().as_str() {
+        "forUpdate" => LockStrength::ForUpdate,
+        "forShare" => LockStrength::ForShare,
+        _ => unreachable!(),
+    };
+
+    let wait = match (extract_flag(&mut map, "skipLocked")?, extract_flag(&mut map, "noWait")?) {
+        (true, true) => {
+            return Err(QueryGraphBuilderError::InputError(
+                "A row lock can either skip locked rows or not wait for them, but not both.".to_owned(),
+            ))
+        }
+        (true, false) => LockWait::SkipLocked,
+        (false, true) => LockWait::NoWait,
+        (false, false) => LockWait::Wait,
+    };
+
+    Ok(RowLock { strength, wait })
+}
+
+fn extract_flag(map: &mut ParsedInputMap, name: &str) -> QueryGraphBuilderResult<bool> {
+    match map.remove(name) {
+        Some(value) => {
+            let value: PrismaValue = value.try_into()?;
+            Ok(value == PrismaValue::Boolean(true))
         }
+        None => Ok(false),
     }
 }
 
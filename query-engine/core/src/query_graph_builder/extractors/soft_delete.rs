@@ -0,0 +1,38 @@
+use crate::{query_document::ParsedArgument, QueryGraphBuilderResult};
+use connector::{Filter, ScalarCompare};
+use prisma_models::{ModelRef, PrismaValue};
+use std::convert::TryInto;
+
+/// Returns the filter excluding soft deleted records, if `model` is soft deleted.
+pub fn not_deleted_filter(model: &ModelRef) -> Option<Filter> {
+    model.soft_delete_field().map(|field| field.equals(PrismaValue::Null))
+}
+
+/// Restricts `filter` to records that are not soft deleted, unless the read includes deleted records.
+pub fn exclude_deleted(filter: Option<Filter>, model: &ModelRef, include_deleted: bool) -> Option<Filter> {
+    match (filter, not_deleted_filter(model)) {
+        (filter, _) if include_deleted => filter,
+        (Some(filter), Some(not_deleted)) => Some(Filter::and(vec![filter, not_deleted])),
+        (None, Some(not_deleted)) => Some(not_deleted),
+        (filter, None) => filter,
+    }
+}
+
+/// Restricts `filter` to records that are not soft deleted, if `model` is soft deleted.
+pub fn without_deleted(filter: Filter, model: &ModelRef) -> Filter {
+    match not_deleted_filter(model) {
+        Some(not_deleted) => Filter::and(vec![filter, not_deleted]),
+        None => filter,
+    }
+}
+
+/// Extracts the `includeDeleted` flag of a read, which is only present on soft deleted models.
+pub fn extract_include_deleted(arguments: &[ParsedArgument]) -> QueryGraphBuilderResult<bool> {
+    match arguments.iter().find(|arg| arg.name == "includeDeleted") {
+        Some(arg) => {
+            let value: PrismaValue = arg.value.clone().try_into()?;
+            Ok(value == PrismaValue::Boolean(true))
+        }
+        None => Ok(false),
+    }
+}
@@ -72,8 +72,19 @@ fn verify_orderings(orderings: &[OrderBy], group_by: &[ScalarFieldRef]) -> Query
     let mut missing_fields = vec![];
 
     for ordering in orderings {
-        if !group_by.contains(&ordering.field) {
-            missing_fields.push(ordering.field.name.clone());
+        match ordering.scalar_field() {
+            Some(field) if group_by.contains(field) => (),
+            Some(field) => missing_fields.push(field.name.clone()),
+            None if ordering.relevance.is_some() => {
+                return Err(QueryGraphBuilderError::InputError(
+                    "Ordering by relevance is not supported for groupBy queries.".to_owned(),
+                ))
+            }
+            None => {
+                return Err(QueryGraphBuilderError::InputError(
+                    "Ordering by relations is not supported for groupBy queries.".to_owned(),
+                ))
+            }
         }
     }
 
@@ -4,7 +4,22 @@ use prisma_models::ModelRef;
 use std::convert::TryInto;
 
 /// Builds a read query from a parsed incoming read query field.
-pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+pub fn find_one(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let include_deleted = extractors::extract_include_deleted(&field.arguments)?;
+    build_find_one(field, model, include_deleted)
+}
+
+/// Builds the read returning the record of an update. Updates by unique filter also match soft
+/// deleted records, e.g. to restore them, so the read doesn't leave them out.
+pub fn find_one_including_deleted(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    build_find_one(field, model, true)
+}
+
+fn build_find_one(
+    mut field: ParsedField,
+    model: ModelRef,
+    include_deleted: bool,
+) -> QueryGraphBuilderResult<ReadQuery> {
     let filter = match field.arguments.lookup("where") {
         Some(where_arg) => {
             let arg: ParsedInputMap = where_arg.value.try_into()?;
@@ -13,6 +28,13 @@ pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         None => None,
     };
 
+    let filter = extractors::exclude_deleted(filter, &model, include_deleted);
+
+    let lock = match field.arguments.lookup("lock") {
+        Some(lock_arg) => Some(extractors::extract_lock(lock_arg.value)?),
+        None => None,
+    };
+
     let name = field.name;
     let alias = field.alias;
     let model = model;
@@ -27,6 +49,7 @@ pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         alias,
         model,
         filter,
+        lock,
         selected_fields,
         nested,
         selection_order,
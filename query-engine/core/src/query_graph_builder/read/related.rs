@@ -1,5 +1,5 @@
 use super::*;
-use crate::{query_document::ParsedField, ReadQuery, RelatedRecordsQuery};
+use crate::{query_document::ParsedField, ReadQuery, RelatedRecordsQuery, RelationCount, RelationCountsQuery};
 use prisma_models::{ModelRef, RelationFieldRef};
 
 pub fn find_related(
@@ -7,7 +7,14 @@ pub fn find_related(
     parent: RelationFieldRef,
     model: ModelRef,
 ) -> QueryGraphBuilderResult<ReadQuery> {
-    let args = extractors::extract_query_args(field.arguments, &model)?;
+    // A required to-one relation always points to a record, deleted or not. Leaving soft deleted
+    // records out would return null for a field that can't be null.
+    let args = if parent.is_required && !parent.is_list {
+        extractors::extract_query_args_including_deleted(field.arguments, &model)?
+    } else {
+        extractors::extract_query_args(field.arguments, &model)?
+    };
+
     let name = field.name;
     let alias = field.alias;
     let sub_selections = field.nested_fields.unwrap().fields;
@@ -30,3 +37,34 @@ pub fn find_related(
         parent_projections: None,
     }))
 }
+
+/// Builds the query for the `_count` selection of `model`, counting the related records of each
+/// selected relation, optionally filtered by the `where` argument of the selection.
+pub fn count_related(field: ParsedField, model: &ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let counts = field
+        .nested_fields
+        .unwrap()
+        .fields
+        .into_iter()
+        .map(|pair| {
+            let relation_field = model
+                .fields()
+                .find_from_relation_fields(&pair.parsed_field.name)
+                .unwrap();
+            let args = extractors::extract_query_args(pair.parsed_field.arguments, &relation_field.related_model())?;
+
+            Ok(RelationCount {
+                name: pair.parsed_field.alias.unwrap_or(pair.parsed_field.name),
+                field: relation_field,
+                filter: args.filter,
+            })
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    Ok(ReadQuery::RelationCountsQuery(RelationCountsQuery {
+        name: field.name,
+        alias: field.alias,
+        model: model.clone(),
+        counts,
+    }))
+}
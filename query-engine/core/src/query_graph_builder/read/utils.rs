@@ -38,6 +38,10 @@ pub fn collect_selected_fields(from: &[FieldPair], model: &ModelRef) -> ModelPro
 pub fn collect_nested_queries(from: Vec<FieldPair>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
         .filter_map(|pair| {
+            if pair.parsed_field.name == "_count" {
+                return Some(related::count_related(pair.parsed_field, model));
+            }
+
             let model_field = model.fields().find_from_all(&pair.parsed_field.name).unwrap();
             match model_field {
                 Field::Scalar(_) => None,
@@ -71,12 +75,10 @@ pub fn merge_relation_selections(
 
     let nested: Vec<_> = nested_queries
         .into_iter()
-        .map(|nested_query| {
-            if let ReadQuery::RelatedRecordsQuery(ref rq) = nested_query {
-                rq.parent_field.linking_fields()
-            } else {
-                unreachable!()
-            }
+        .flat_map(|nested_query| match nested_query {
+            ReadQuery::RelatedRecordsQuery(ref rq) => vec![rq.parent_field.linking_fields()],
+            ReadQuery::RelationCountsQuery(ref cq) => cq.counts.iter().map(|c| c.field.linking_fields()).collect(),
+            _ => unreachable!(),
         })
         .collect();
 
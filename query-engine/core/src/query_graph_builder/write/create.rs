@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     query_ast::*,
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, ParsedField, ParsedInputMap,
+    ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue,
 };
 use connector::IdFilter;
 use prisma_models::ModelRef;
@@ -39,7 +39,7 @@ pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
                 }?;
 
                 if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = read_node {
-                    rq.add_filter(parent_id.filter());
+                    rq.add_filter(parent_id.filter())?;
                 };
 
                 Ok(read_node)
@@ -50,6 +50,46 @@ pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     Ok(())
 }
 
+/// Creates a create many records query and adds it to the query graph.
+/// Nested writes are not part of the input type, so every record maps to exactly one row.
+pub fn create_many_records(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+) -> QueryGraphBuilderResult<()> {
+    graph.flag_transactional();
+
+    let data_list: Vec<ParsedInputValue> = match field.arguments.lookup("data") {
+        Some(data) => data.value.try_into()?,
+        None => vec![],
+    };
+
+    let skip_duplicates: bool = match field.arguments.lookup("skipDuplicates") {
+        Some(arg) => arg.value.try_into()?,
+        None => false,
+    };
+
+    let args = data_list
+        .into_iter()
+        .map(|data_value| {
+            let data_map: ParsedInputMap = data_value.try_into()?;
+            let mut args = WriteArgsParser::from(&model, data_map)?.args;
+
+            args.add_datetimes(Arc::clone(&model));
+            Ok(args)
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    let query = CreateManyRecords {
+        model,
+        args,
+        skip_duplicates,
+    };
+
+    graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
+    Ok(())
+}
+
 pub fn create_record_node(
     graph: &mut QueryGraph,
     model: ModelRef,
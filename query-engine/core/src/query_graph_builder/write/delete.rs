@@ -17,16 +17,25 @@ pub fn delete_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 
     // Prefetch read query for the delete
     let mut read_query = read::find_one(field, Arc::clone(&model))?;
-    read_query.add_filter(filter.clone());
+    read_query.add_filter(filter.clone())?;
 
     let read_node = graph.create_node(Query::Read(read_query));
-    let delete_query = Query::Write(WriteQuery::DeleteRecord(DeleteRecord {
-        model: Arc::clone(&model),
-        record_filter: Some(filter.into()),
-    }));
 
-    let delete_node = graph.create_node(delete_query);
-    utils::insert_deletion_checks(graph, &model, &read_node, &delete_node)?;
+    // Soft deleted records stay in place, so relations to them don't need to be checked.
+    let delete_node = if model.soft_delete_field().is_some() {
+        let delete_query = utils::delete_many_records_query(&model, filter.into());
+        graph.create_node(Query::Write(delete_query))
+    } else {
+        let delete_query = Query::Write(WriteQuery::DeleteRecord(DeleteRecord {
+            model: Arc::clone(&model),
+            record_filter: Some(filter.into()),
+        }));
+
+        let delete_node = graph.create_node(delete_query);
+        utils::insert_deletion_checks(graph, &model, &read_node, &delete_node)?;
+
+        delete_node
+    };
 
     graph.create_edge(
         &read_node,
@@ -62,6 +71,13 @@ pub fn delete_many_records(
         None => Filter::empty(),
     };
 
+    if model.soft_delete_field().is_some() {
+        let delete_query = utils::delete_many_records_query(&model, without_deleted(filter, &model).into());
+        graph.create_node(Query::Write(delete_query));
+
+        return Ok(());
+    }
+
     let model_id = model.primary_identifier();
     let read_query = utils::read_ids_infallible(model.clone(), model_id, filter.clone());
     let record_filter = filter.into();
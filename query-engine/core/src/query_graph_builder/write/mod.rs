@@ -13,7 +13,7 @@ pub mod utils;
 use super::*;
 
 // Expose top level write operation builder functions.
-pub use create::create_record;
+pub use create::{create_many_records, create_record};
 pub use delete::{delete_many_records, delete_record};
 pub use raw::{execute_raw, query_raw};
 pub use update::{update_many_records, update_record};
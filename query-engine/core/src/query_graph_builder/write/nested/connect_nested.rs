@@ -400,7 +400,7 @@ fn handle_one_to_one(
                  }?;
 
                  if let Node::Query(Query::Write(ref mut wq)) = update_children_node {
-                    wq.add_filter(child_id.filter());
+                    wq.add_filter(child_id.filter())?;
                  }
 
                  Ok(update_children_node)
@@ -458,7 +458,7 @@ fn handle_one_to_one(
                 }?;
 
                 if let Node::Query(Query::Write(ref mut wq)) = update_parent_node {
-                    wq.add_filter(parent_id.filter());
+                    wq.add_filter(parent_id.filter())?;
                 }
 
                 Ok(update_parent_node)
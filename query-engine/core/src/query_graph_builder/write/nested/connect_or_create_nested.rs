@@ -625,7 +625,7 @@ fn one_to_one_inlined_parent(
                 }?;
 
                 if let Node::Query(ref mut q) = update_parent_node {
-                    q.add_filter(parent_id.filter());
+                    q.add_filter(parent_id.filter())?;
                 }
 
                 Ok(update_parent_node)
@@ -785,7 +785,7 @@ fn one_to_one_inlined_child(
                 }?;
 
                 if let Node::Query(ref mut q) = update_child_node {
-                    q.add_filter(child_id.filter());
+                    q.add_filter(child_id.filter())?;
                 }
 
                 Ok(update_child_node)
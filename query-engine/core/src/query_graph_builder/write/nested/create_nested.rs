@@ -369,7 +369,7 @@ fn handle_one_to_one(
                 }?;
 
                 if let Node::Query(Query::Write(ref mut wq)) = update_node {
-                    wq.add_filter(parent_id.filter());
+                    wq.add_filter(parent_id.filter())?;
                 }
 
                 Ok(update_node)
@@ -40,16 +40,19 @@ pub fn nested_delete(
 
         let filter_len = filters.len();
         let or_filter = Filter::Or(filters);
-        let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
-            model: Arc::clone(&child_model),
-            record_filter: or_filter.clone().into(),
-        });
+        let delete_many = utils::delete_many_records_query(child_model, or_filter.clone().into());
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
-        let find_child_records_node =
-            utils::insert_find_children_by_parent_node(graph, parent_node, parent_relation_field, or_filter)?;
+        let find_child_records_node = utils::insert_find_children_by_parent_node(
+            graph,
+            parent_node,
+            parent_relation_field,
+            without_deleted(or_filter, child_model),
+        )?;
 
-        utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_many_node)?;
+        if child_model.soft_delete_field().is_none() {
+            utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_many_node)?;
+        }
 
         let relation_name = parent_relation_field.relation().name.clone();
         let parent_name = parent_relation_field.model().name.clone();
@@ -69,8 +72,8 @@ pub fn nested_delete(
                         });
                     }
 
-                    if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) = delete_many_node {
-                        dmr.record_filter = child_ids.into();
+                    if let Node::Query(Query::Write(ref mut wq)) = delete_many_node {
+                        wq.set_record_filter(child_ids.into())?;
                     }
 
                     Ok(delete_many_node)
@@ -82,15 +85,26 @@ pub fn nested_delete(
         let should_delete = if let PrismaValue::Boolean(b) = val { b } else { false };
 
         if should_delete {
-            let find_child_records_node =
-                utils::insert_find_children_by_parent_node(graph, parent_node, parent_relation_field, Filter::empty())?;
-
-            let delete_record_node = graph.create_node(Query::Write(WriteQuery::DeleteRecord(DeleteRecord {
-                model: Arc::clone(&child_model),
-                record_filter: None,
-            })));
-
-            utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_record_node)?;
+            let find_child_records_node = utils::insert_find_children_by_parent_node(
+                graph,
+                parent_node,
+                parent_relation_field,
+                without_deleted(Filter::empty(), child_model),
+            )?;
+
+            let delete_record_node = if child_model.soft_delete_field().is_some() {
+                let delete_query = utils::delete_many_records_query(child_model, RecordFilter::empty());
+                graph.create_node(Query::Write(delete_query))
+            } else {
+                let delete_record_node = graph.create_node(Query::Write(WriteQuery::DeleteRecord(DeleteRecord {
+                    model: Arc::clone(&child_model),
+                    record_filter: None,
+                })));
+
+                utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_record_node)?;
+
+                delete_record_node
+            };
 
             graph.create_edge(
                  &find_child_records_node,
@@ -101,8 +115,8 @@ pub fn nested_delete(
                          None => Err(QueryGraphBuilderError::AssertionError("[Query Graph] Expected a valid parent ID to be present for a nested delete on a one-to-many relation.".to_string())),
                      }?;
 
-                     if let Node::Query(Query::Write(WriteQuery::DeleteRecord(ref mut dq))) = delete_record_node {
-                         dq.record_filter = Some(child_id.into());
+                     if let Node::Query(Query::Write(ref mut wq)) = delete_record_node {
+                         wq.set_record_filter(child_id.into())?;
                      }
 
                      Ok(delete_record_node)
@@ -127,16 +141,19 @@ pub fn nested_delete_many(
         let as_map: ParsedInputMap = value.try_into()?;
         let filter = extract_filter(as_map, child_model)?;
 
-        let find_child_records_node =
-            utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter.clone())?;
-
-        let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
-            model: Arc::clone(&child_model),
-            record_filter: RecordFilter::empty(),
-        });
+        let find_child_records_node = utils::insert_find_children_by_parent_node(
+            graph,
+            parent,
+            parent_relation_field,
+            without_deleted(filter, child_model),
+        )?;
 
+        let delete_many = utils::delete_many_records_query(child_model, RecordFilter::empty());
         let delete_many_node = graph.create_node(Query::Write(delete_many));
-        utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_many_node)?;
+
+        if child_model.soft_delete_field().is_none() {
+            utils::insert_deletion_checks(graph, child_model, &find_child_records_node, &delete_many_node)?;
+        }
 
         graph.create_edge(
             &find_child_records_node,
@@ -144,8 +161,8 @@ pub fn nested_delete_many(
             QueryGraphDependency::ParentProjection(
                 child_model_identifier.clone(),
                 Box::new(move |mut delete_many_node, child_ids| {
-                    if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) = delete_many_node {
-                        dmr.record_filter = child_ids.into();
+                    if let Node::Query(Query::Write(ref mut wq)) = delete_many_node {
+                        wq.set_record_filter(child_ids.into())?;
                     }
 
                     Ok(delete_many_node)
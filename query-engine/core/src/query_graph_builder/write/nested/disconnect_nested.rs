@@ -237,7 +237,7 @@ fn handle_one_to_x(
 
                 // Handle filter & arg injection
                 if let Node::Query(Query::Write(ref mut wq @ WriteQuery::UpdateManyRecords(_))) = update_node {
-                    wq.set_filter(links.filter());
+                    wq.set_filter(links.filter())?;
                     wq.inject_projection_into_args(null_record_id);
                 };
 
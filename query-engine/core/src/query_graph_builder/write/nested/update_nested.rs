@@ -4,7 +4,7 @@ use crate::{
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
     ParsedInputValue,
 };
-use connector::{Filter, RecordFilter};
+use connector::{Filter, IdFilter, RecordFilter};
 use prisma_models::{ModelRef, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
 use write_args_parser::*;
@@ -52,8 +52,9 @@ pub fn nested_update(
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter)?;
 
-        let update_node =
-            update::update_record_node(graph, Filter::empty(), Arc::clone(child_model), data.try_into()?)?;
+        let data_map: ParsedInputMap = data.try_into()?;
+        let expected_version = update::expected_version(child_model, &data_map);
+        let update_node = update::update_record_node(graph, Filter::empty(), Arc::clone(child_model), data_map)?;
 
         let child_model_identifier = parent_relation_field.related_model().primary_identifier();
 
@@ -70,14 +71,18 @@ pub fn nested_update(
                         )),
                     }?;
 
-                    if let Node::Query(Query::Write(WriteQuery::UpdateRecord(ref mut ur))) = update_node {
-                        ur.record_filter = child_id.into();
+                    if let Node::Query(Query::Write(ref mut wq)) = update_node {
+                        wq.add_filter(child_id.filter())?;
                     }
 
                     Ok(update_node)
                 }),
             ),
         )?;
+
+        if let Some(expected_version) = expected_version {
+            update::insert_version_check(graph, &update_node, child_model, expected_version)?;
+        }
     }
 
     Ok(())
@@ -98,8 +103,12 @@ pub fn nested_update_many(
         let where_map: ParsedInputMap = where_arg.try_into()?;
         let child_model_identifier = parent_relation_field.related_model().primary_identifier();
 
-        let filter = extract_filter(where_map, child_model)?;
-        let update_args = WriteArgsParser::from(&child_model, data_map)?;
+        let mut filter = extract_filter(where_map, child_model)?;
+        let mut update_args = WriteArgsParser::from(&child_model, data_map)?;
+
+        if let Some(expected) = update_args.args.update_version(Arc::clone(&child_model)) {
+            filter = update::version_filter(&child_model, filter, expected);
+        }
 
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter)?;
@@ -114,12 +114,13 @@ pub fn nested_upsert(
 
         let if_node = graph.create_node(Flow::default_if());
         let create_node = create::create_record_node(graph, Arc::clone(&child_model), create_input.try_into()?)?;
-        let update_node = update::update_record_node(
-            graph,
-            Filter::empty(),
-            Arc::clone(&child_model),
-            update_input.try_into()?,
-        )?;
+        let update_map: ParsedInputMap = update_input.try_into()?;
+        let expected_version = update::expected_version(&child_model, &update_map);
+        let update_node = update::update_record_node(graph, Filter::empty(), Arc::clone(&child_model), update_map)?;
+
+        if let Some(expected_version) = expected_version {
+            update::insert_version_check(graph, &update_node, &child_model, expected_version)?;
+        }
 
         graph.create_edge(
             &read_children_node,
@@ -146,7 +147,7 @@ pub fn nested_upsert(
                         None => Err(QueryGraphBuilderError::AssertionError("[Query Graph] Expected a valid parent ID to be present for a nested update in a nested upsert.".to_string())),
                     }?;
 
-                    wq.add_filter(child_id.filter());
+                    wq.add_filter(child_id.filter())?;
                 }
 
                 Ok(update_node)
@@ -176,7 +177,7 @@ pub fn nested_upsert(
                     }?;
 
                     if let Node::Query(Query::Write(ref mut wq)) = update_node {
-                        wq.add_filter(parent_id.filter());
+                        wq.add_filter(parent_id.filter())?;
                     }
 
                     Ok(update_node)
@@ -3,10 +3,10 @@ use crate::query_graph_builder::write::write_args_parser::*;
 use crate::{
     query_ast::*,
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, ParsedField, ParsedInputMap,
+    ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue,
 };
-use connector::{Filter, IdFilter};
-use prisma_models::ModelRef;
+use connector::{Filter, IdFilter, ScalarCompare};
+use prisma_models::{ModelRef, PrismaValue};
 use std::{convert::TryInto, sync::Arc};
 
 /// Creates an update record query and adds it to the query graph, together with it's nested queries and companion read query.
@@ -18,11 +18,37 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     // "data"
     let data_argument = field.arguments.lookup("data").unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
+    let expected_version = expected_version(&model, &data_map);
+
+    let update_node = update_record_node(graph, filter.clone(), Arc::clone(&model), data_map)?;
+
+    // With an expected version, an update that doesn't find its record can either mean that the
+    // record doesn't exist or that its version moved on. A prefetch of the record tells them apart.
+    if expected_version.is_some() {
+        let read_ids = utils::read_ids_infallible(Arc::clone(&model), model.primary_identifier(), filter);
+        let read_ids_node = graph.create_node(read_ids);
+
+        graph.create_edge(
+            &read_ids_node,
+            &update_node,
+            QueryGraphDependency::ParentProjection(
+                model.primary_identifier(),
+                Box::new(|update_node, parent_ids| {
+                    if !parent_ids.is_empty() {
+                        Ok(update_node)
+                    } else {
+                        Err(QueryGraphBuilderError::RecordNotFound(
+                            "Record to update not found.".to_string(),
+                        ))
+                    }
+                }),
+            ),
+        )?;
+    }
 
-    let update_node = update_record_node(graph, filter, Arc::clone(&model), data_map)?;
-
-    let read_query = read::find_one(field, model.clone())?;
+    let read_query = read::find_one_including_deleted(field, model.clone())?;
     let read_node = graph.create_node(Query::Read(read_query));
+    let model_name = model.name.clone();
 
     graph.add_result_node(&read_node);
     graph.create_edge(
@@ -31,15 +57,19 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
         QueryGraphDependency::ParentProjection(
             model.primary_identifier(),
             Box::new(move |mut read_node, mut parent_ids| {
-                let parent_id = match parent_ids.pop() {
-                    Some(pid) => Ok(pid),
-                    None => Err(QueryGraphBuilderError::RecordNotFound(
+                let parent_id = match (parent_ids.pop(), expected_version) {
+                    (Some(pid), _) => Ok(pid),
+                    (None, Some(expected_version)) => Err(QueryGraphBuilderError::VersionMismatch {
+                        model_name,
+                        expected_version: expected_version.to_string(),
+                    }),
+                    (None, None) => Err(QueryGraphBuilderError::RecordNotFound(
                         "Record to update not found.".to_string(),
                     )),
                 }?;
 
                 if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = read_node {
-                    rq.add_filter(parent_id.filter());
+                    rq.add_filter(parent_id.filter())?;
                 };
 
                 Ok(read_node)
@@ -68,6 +98,11 @@ pub fn update_many_records(
     let update_args = WriteArgsParser::from(&model, data_map)?;
 
     let mut args = update_args.args;
+    let filter = match args.update_version(Arc::clone(&model)) {
+        Some(expected) => version_filter(&model, filter, expected),
+        None => filter,
+    };
+
     args.update_datetimes(Arc::clone(&model));
 
     let record_filter = filter.into();
@@ -96,9 +131,14 @@ where
     let update_args = WriteArgsParser::from(&model, data_map)?;
     let mut args = update_args.args;
 
+    let filter = filter.into();
+    let filter = match args.update_version(Arc::clone(&model)) {
+        Some(expected) => version_filter(&model, filter, expected),
+        None => filter,
+    };
+
     args.update_datetimes(Arc::clone(&model));
 
-    let filter = filter.into();
     let record_filter = filter.into();
     let ur = UpdateRecord {
         model,
@@ -113,3 +153,54 @@ where
 
     Ok(node)
 }
+
+/// Returns the version an update expects the record to have, if the model is versioned and
+/// the update data holds one.
+pub fn expected_version(model: &ModelRef, data_map: &ParsedInputMap) -> Option<PrismaValue> {
+    let field = model.fields().version().clone()?;
+
+    match data_map.get(&field.name) {
+        Some(ParsedInputValue::Single(pv)) if *pv != PrismaValue::Null => Some(pv.clone()),
+        _ => None,
+    }
+}
+
+/// Fails the query with a version mismatch if `update_node` didn't update any record. Nested
+/// updates only run on records that are known to exist, so no update means a stale version.
+pub fn insert_version_check(
+    graph: &mut QueryGraph,
+    update_node: &NodeRef,
+    model: &ModelRef,
+    expected_version: PrismaValue,
+) -> QueryGraphBuilderResult<()> {
+    let check_node = graph.create_node(Node::Empty);
+    let model_name = model.name.clone();
+
+    graph.create_edge(
+        update_node,
+        &check_node,
+        QueryGraphDependency::ParentProjection(
+            model.primary_identifier(),
+            Box::new(move |check_node, updated_ids| {
+                if updated_ids.is_empty() {
+                    return Err(QueryGraphBuilderError::VersionMismatch {
+                        model_name,
+                        expected_version: expected_version.to_string(),
+                    });
+                }
+
+                Ok(check_node)
+            }),
+        ),
+    )?;
+
+    Ok(())
+}
+
+/// Restricts `filter` to records holding the expected version.
+pub fn version_filter(model: &ModelRef, filter: Filter, expected: PrismaValue) -> Filter {
+    match model.fields().version() {
+        Some(field) => Filter::and(vec![filter, field.equals(expected)]),
+        None => filter,
+    }
+}
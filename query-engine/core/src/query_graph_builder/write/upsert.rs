@@ -23,9 +23,12 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     let read_parent_records_node = graph.create_node(read_parent_records);
 
     let create_node = create::create_record_node(graph, Arc::clone(&model), create_argument.value.try_into()?)?;
-    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_argument.value.try_into()?)?;
+    let update_map: ParsedInputMap = update_argument.value.try_into()?;
+    let expected_version = update::expected_version(&model, &update_map);
+    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_map)?;
+    let model_name = model.name.clone();
 
-    let read_query = read::find_one(field, Arc::clone(&model))?;
+    let read_query = read::find_one_including_deleted(field, Arc::clone(&model))?;
     let read_node_create = graph.create_node(Query::Read(read_query.clone()));
     let read_node_update = graph.create_node(Query::Read(read_query));
 
@@ -58,15 +61,19 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
         QueryGraphDependency::ParentProjection(
             model_id.clone(),
             Box::new(move |mut read_node_update, mut parent_ids| {
-                let parent_id = match parent_ids.pop() {
-                    Some(pid) => Ok(pid),
-                    None => Err(QueryGraphBuilderError::AssertionError(
+                let parent_id = match (parent_ids.pop(), expected_version) {
+                    (Some(pid), _) => Ok(pid),
+                    (None, Some(expected_version)) => Err(QueryGraphBuilderError::VersionMismatch {
+                        model_name,
+                        expected_version: expected_version.to_string(),
+                    }),
+                    (None, None) => Err(QueryGraphBuilderError::AssertionError(
                         "Expected a valid parent ID to be present for create follow-up for upsert query.".to_string(),
                     )),
                 }?;
 
                 if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = read_node_update {
-                    rq.add_filter(parent_id.filter());
+                    rq.add_filter(parent_id.filter())?;
                 };
 
                 Ok(read_node_update)
@@ -88,7 +95,7 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
                 }?;
 
                 if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = read_node_create {
-                    rq.add_filter(parent_id.filter());
+                    rq.add_filter(parent_id.filter())?;
                 };
 
                 Ok(read_node_create)
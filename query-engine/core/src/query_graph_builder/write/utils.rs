@@ -3,9 +3,10 @@ use crate::{
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
     ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{Filter, WriteArgs};
+use chrono::Utc;
+use connector::{Filter, RecordFilter, WriteArgs};
 use itertools::Itertools;
-use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
+use prisma_models::{ModelProjection, ModelRef, PrismaValue, RelationFieldRef};
 use std::sync::Arc;
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -158,6 +159,28 @@ where
     graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(ur)))
 }
 
+/// Creates the query deleting the records of `model` matching `record_filter`. Soft deleted models keep
+/// their records, the query marks them as deleted by setting the soft delete field to the current time.
+pub fn delete_many_records_query(model: &ModelRef, record_filter: RecordFilter) -> WriteQuery {
+    match model.soft_delete_field() {
+        Some(field) => {
+            let mut args = WriteArgs::new();
+            args.insert(&field, PrismaValue::DateTime(Utc::now().into()));
+            args.update_datetimes(Arc::clone(model));
+
+            WriteQuery::UpdateManyRecords(UpdateManyRecords {
+                model: Arc::clone(model),
+                record_filter,
+                args,
+            })
+        }
+        None => WriteQuery::DeleteManyRecords(DeleteManyRecords {
+            model: Arc::clone(model),
+            record_filter,
+        }),
+    }
+}
+
 /// Inserts checks and disconnects for existing models for a 1:1 relation.
 /// Expects that the parent node returns a valid ID for the model the `parent_relation_field` is located on.
 ///
@@ -261,8 +284,10 @@ pub fn insert_existing_1to1_related_model_checks(
     Ok(read_existing_children)
 }
 
-/// Inserts checks into the graph that check all required, non-list relations pointing to
-/// the given `model`. Those checks fail at runtime (edges to the `Empty` node) if one or more
+/// Inserts checks into the graph that check all non-list relations pointing to the given `model`
+/// that restrict deletions, i.e. required relations without an explicit `onDelete` and relations
+/// with `onDelete: Restrict` or `onDelete: NoAction`. Relations that cascade or set null on delete
+/// are left to the database. Those checks fail at runtime (edges to the `Empty` node) if one or more
 /// records are found. Checks are inserted between `parent_node` and `child_node`.
 ///
 /// This function is usually part of a delete (`deleteOne` or `deleteMany`).
@@ -314,7 +339,7 @@ pub fn insert_deletion_checks(
     if !relation_fields.is_empty() {
         let noop_node = graph.create_node(Node::Empty);
 
-        // We know that the relation can't be a list and must be required on the related model for `model` (see fields_requiring_model).
+        // We know that the relation can't be a list and must restrict deletions of `model` records (see fields_requiring_model).
         // For all requiring models (RM), we use the field on `model` to query for existing RM records and error out if at least one exists.
         for rf in relation_fields {
             let relation_field = rf.related_field();
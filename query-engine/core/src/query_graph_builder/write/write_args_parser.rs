@@ -21,13 +21,28 @@ impl WriteArgsParser {
 
                 match field {
                     Field::Scalar(sf) if sf.is_list => {
-                        let set_value: PrismaValue = match v {
-                            ParsedInputValue::List(_) => v.try_into()?,
-                            ParsedInputValue::Map(mut map) => map.remove("set").unwrap().try_into()?,
+                        let expr: WriteExpression = match v {
+                            ParsedInputValue::List(_) => WriteExpression::Value(v.try_into()?),
+                            ParsedInputValue::Map(map) => {
+                                let (operation, value) = map.into_iter().next().unwrap();
+
+                                match operation.as_str() {
+                                    "set" => WriteExpression::Value(value.try_into()?),
+                                    "push" => {
+                                        let pushed = match value {
+                                            ParsedInputValue::Single(pv) => PrismaValue::List(vec![pv]),
+                                            value => value.try_into()?,
+                                        };
+
+                                        WriteExpression::Push(pushed)
+                                    }
+                                    _ => unreachable!("Invalid scalar list update operation"),
+                                }
+                            }
                             _ => unreachable!(),
                         };
 
-                        args.args.insert(sf, set_value)
+                        args.args.insert(sf, expr)
                     }
 
                     Field::Scalar(sf) => {
@@ -39,6 +54,10 @@ impl WriteArgsParser {
 
                                 match operation.as_str() {
                                     "set" => WriteExpression::Value(value),
+                                    "unset" if value == PrismaValue::Boolean(true) => WriteExpression::Unset,
+
+                                    // `unset: false` leaves the field untouched.
+                                    "unset" => return Ok(args),
                                     "increment" => WriteExpression::Add(value),
                                     "decrement" => WriteExpression::Substract(value),
                                     "multiply" => WriteExpression::Multiply(value),
@@ -2,6 +2,7 @@ use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
     CoreError, DatabaseEnumType, EnumType, OutputFieldRef, QueryResult, RecordAggregations, RecordSelection,
+    RelationCounts,
 };
 use bigdecimal::ToPrimitive;
 use connector::AggregationResult;
@@ -41,6 +42,7 @@ pub fn serialize_internal(
     match result {
         QueryResult::RecordSelection(rs) => serialize_record_selection(rs, field, &field.field_type, is_list),
         QueryResult::RecordAggregations(ras) => serialize_aggregations(field, ras),
+        QueryResult::RelationCounts(counts) => Ok(serialize_relation_counts(counts)),
 
         QueryResult::Count(c) => {
             // Todo needs a real implementation or needs to move to RecordAggregation
@@ -175,6 +177,28 @@ fn find_nested_aggregate_output_field(
     nested_object_type.find_field(nested_field_name).unwrap()
 }
 
+/// Serializes the relation counts of each parent record into an object of count name to count.
+fn serialize_relation_counts(relation_counts: RelationCounts) -> CheckedItemsWithParents {
+    let RelationCounts { fields, counts, .. } = relation_counts;
+
+    counts
+        .into_iter()
+        .map(|(parent_id, counts)| {
+            let map: Map = fields
+                .iter()
+                .cloned()
+                .zip(
+                    counts
+                        .into_iter()
+                        .map(|count| Item::Value(PrismaValue::Int(count as i64))),
+                )
+                .collect();
+
+            (Some(parent_id), Item::Ref(ItemRef::new(Item::Map(map))))
+        })
+        .collect()
+}
+
 fn coerce_non_numeric(value: PrismaValue, output: &OutputType) -> PrismaValue {
     match (value, output.borrow()) {
         (PrismaValue::Int(x), OutputType::Scalar(ScalarType::String)) if x == 0 => PrismaValue::Null,
@@ -370,13 +394,16 @@ fn process_nested_results(
     // Unwraps are safe due to query validation.
     for nested_result in nested {
         // todo Workaround, tb changed with flat reads.
-        if let QueryResult::RecordSelection(ref rs) = nested_result {
-            let name = rs.name.clone();
-            let field = enclosing_type.find_field(&name).unwrap();
-            let result = serialize_internal(nested_result, &field, false)?;
+        let name = match nested_result {
+            QueryResult::RecordSelection(ref rs) => rs.name.clone(),
+            QueryResult::RelationCounts(ref counts) => counts.name.clone(),
+            _ => continue,
+        };
 
-            nested_mapping.insert(name, result);
-        }
+        let field = enclosing_type.find_field(&name).unwrap();
+        let result = serialize_internal(nested_result, &field, false)?;
+
+        nested_mapping.insert(name, result);
     }
 
     Ok(nested_mapping)
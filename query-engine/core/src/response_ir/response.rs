@@ -7,10 +7,17 @@ pub struct ResponseData {
 
     /// The actual response data.
     pub data: Item,
+
+    /// The query graph and the statements of the operation, if it was explained.
+    pub explain: Option<serde_json::Value>,
 }
 
 impl ResponseData {
     pub fn new(key: String, data: Item) -> Self {
-        Self { key, data }
+        Self {
+            key,
+            data,
+            explain: None,
+        }
     }
 }
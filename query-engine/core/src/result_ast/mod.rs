@@ -8,6 +8,7 @@ pub enum QueryResult {
     RecordSelection(RecordSelection),
     Json(serde_json::Value),
     RecordAggregations(RecordAggregations),
+    RelationCounts(RelationCounts),
     Unit,
 }
 
@@ -42,3 +43,15 @@ pub struct RecordAggregations {
     /// Actual aggregation results.
     pub results: Vec<AggregationRow>,
 }
+
+#[derive(Debug, Clone)]
+pub struct RelationCounts {
+    /// Name of the query.
+    pub name: String,
+
+    /// Ordered list of the names of the selected counts.
+    pub fields: Vec<String>,
+
+    /// The counts of each parent record, in the order of `fields`, keyed by the ID of the parent.
+    pub counts: Vec<(RecordProjection, Vec<usize>)>,
+}
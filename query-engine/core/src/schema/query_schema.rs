@@ -93,6 +93,7 @@ pub enum QueryTag {
     FindFirst,
     FindMany,
     CreateOne,
+    CreateMany,
     UpdateOne,
     UpdateMany,
     DeleteOne,
@@ -112,6 +113,7 @@ impl fmt::Display for QueryTag {
             Self::FindFirst => "findFirst",
             Self::FindMany => "findMany",
             Self::CreateOne => "createOne",
+            Self::CreateMany => "createMany",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
             Self::DeleteOne => "deleteOne",
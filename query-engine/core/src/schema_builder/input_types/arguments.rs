@@ -1,4 +1,5 @@
 use super::*;
+use datamodel_connector::ConnectorCapability;
 
 /// Builds "where" argument.
 pub(crate) fn where_argument(ctx: &mut BuilderContext, model: &ModelRef) -> InputField {
@@ -32,6 +33,19 @@ pub(crate) fn create_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Op
     }
 }
 
+/// Builds "data" and "skipDuplicates" arguments intended for the create many field.
+/// "skipDuplicates" is only available if the connector can ignore conflicting inserts.
+pub(crate) fn create_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let create_many_type = InputType::object(create_objects::create_many_object_type(ctx, model));
+    let mut args = vec![input_field("data", InputType::list(create_many_type), None)];
+
+    if ctx.capabilities.contains(ConnectorCapability::CreateSkipDuplicates) {
+        args.push(input_field("skipDuplicates", InputType::boolean(), None).optional());
+    }
+
+    args
+}
+
 /// Builds "where" (unique) argument intended for the delete field.
 pub(crate) fn delete_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Vec<InputField>> {
     where_unique_argument(ctx, model).map(|arg| vec![arg])
@@ -92,6 +106,18 @@ pub(crate) fn many_records_field_arguments(ctx: &mut BuilderContext, field: &Mod
     }
 }
 
+/// Builds "lock" argument, locking the rows a read returns until the end of the transaction.
+pub(crate) fn lock_argument(ctx: &mut BuilderContext) -> InputField {
+    input_field("lock", InputType::object(row_lock_object_type(ctx)), None).optional()
+}
+
+/// Builds "includeDeleted" argument for reads of soft deleted models, which exclude deleted records by default.
+pub(crate) fn include_deleted_argument(model: &ModelRef) -> Option<InputField> {
+    model
+        .soft_delete_field()
+        .map(|_| input_field("includeDeleted", InputType::boolean(), None).optional())
+}
+
 /// Builds "many records where" arguments solely based on the given model.
 pub(crate) fn many_records_arguments(
     ctx: &mut BuilderContext,
@@ -119,6 +145,7 @@ pub(crate) fn many_records_arguments(
         );
     }
 
+    args.extend(include_deleted_argument(model));
     args
 }
 
@@ -137,7 +164,7 @@ pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef) -> I
 pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
     let field_enum_type = InputType::Enum(model_field_enum(model));
 
-    vec![
+    let mut args = vec![
         where_argument(ctx, &model),
         order_by_argument(ctx, &model),
         input_field(
@@ -153,5 +180,8 @@ pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) ->
         .optional(),
         input_field("take", InputType::int(), None).optional(),
         input_field("skip", InputType::int(), None).optional(),
-    ]
+    ];
+
+    args.extend(include_deleted_argument(model));
+    args
 }
@@ -131,6 +131,7 @@ fn full_scalar_filter_type(
             .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
             .chain(alphanumeric_filters(mapped_scalar_type.clone()))
             .chain(string_filters(mapped_scalar_type.clone()))
+            .chain(search_filter(ctx, typ))
             .chain(query_mode_field(ctx, nested))
             .collect(),
 
@@ -143,7 +144,11 @@ fn full_scalar_filter_type(
             .chain(alphanumeric_filters(mapped_scalar_type.clone()))
             .collect(),
 
-        TypeIdentifier::Boolean | TypeIdentifier::Json | TypeIdentifier::Xml | TypeIdentifier::Bytes => {
+        TypeIdentifier::Json => equality_filters(mapped_scalar_type.clone(), nullable)
+            .chain(json_filters(ctx, mapped_scalar_type.clone()))
+            .collect(),
+
+        TypeIdentifier::Boolean | TypeIdentifier::Xml | TypeIdentifier::Bytes => {
             equality_filters(mapped_scalar_type.clone(), nullable).collect()
         }
 
@@ -231,6 +236,47 @@ fn string_filters(mapped_type: InputType) -> impl Iterator<Item = InputField> {
     .into_iter()
 }
 
+fn search_filter(ctx: &BuilderContext, typ: &TypeIdentifier) -> impl Iterator<Item = InputField> {
+    let fields = if typ == &TypeIdentifier::String && ctx.capabilities.contains(ConnectorCapability::FullTextSearch) {
+        vec![input_field("search", InputType::string(), None).optional()]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
+/// Filters on values inside of Json documents. The format of the `path` depends on the connector:
+/// A JSON path string on MySQL (`"$.pets[0].name"`), a list of keys and indexes on Postgres (`["pets", "0", "name"]`).
+fn json_filters(ctx: &BuilderContext, mapped_type: InputType) -> impl Iterator<Item = InputField> {
+    let path_type = if ctx.capabilities.contains(ConnectorCapability::JsonFilteringJsonPath) {
+        Some(InputType::string())
+    } else if ctx.capabilities.contains(ConnectorCapability::JsonFilteringArrayPath) {
+        Some(InputType::list(InputType::string()))
+    } else {
+        None
+    };
+
+    let fields = match path_type {
+        Some(path_type) => vec![
+            input_field("path", path_type, None).optional(),
+            input_field("string_contains", InputType::string(), None).optional(),
+            input_field("string_starts_with", InputType::string(), None).optional(),
+            input_field("string_ends_with", InputType::string(), None).optional(),
+            input_field("array_contains", mapped_type.clone(), None).optional(),
+            input_field("array_starts_with", mapped_type.clone(), None).optional(),
+            input_field("array_ends_with", mapped_type.clone(), None).optional(),
+            input_field("lt", mapped_type.clone(), None).optional(),
+            input_field("lte", mapped_type.clone(), None).optional(),
+            input_field("gt", mapped_type.clone(), None).optional(),
+            input_field("gte", mapped_type, None).optional(),
+        ],
+        None => vec![],
+    };
+
+    fields.into_iter()
+}
+
 fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item = InputField> {
     // Limit query mode field to the topmost filter level.
     // Only build mode field for connectors with insensitive filter support.
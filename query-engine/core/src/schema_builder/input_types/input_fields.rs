@@ -126,6 +126,7 @@ pub(crate) fn nested_update_input_field(ctx: &mut BuilderContext, field: &Relati
 
 /// Builds scalar input fields using the mapper and the given, prefiltered, scalar fields.
 /// The mapper is responsible for mapping the fields to input types.
+/// Scalar list fields can be `set`, and additionally be `push`ed to if `with_list_operations` is set.
 pub(crate) fn scalar_input_fields<T, F>(
     ctx: &mut BuilderContext,
     model_name: String,
@@ -133,6 +134,7 @@ pub(crate) fn scalar_input_fields<T, F>(
     prefiltered_fields: Vec<ScalarFieldRef>,
     non_list_field_mapper: F,
     with_defaults: bool,
+    with_list_operations: bool,
 ) -> Vec<InputField>
 where
     T: Into<String>,
@@ -162,8 +164,20 @@ where
             let input_object = match ctx.get_input_type(&set_object_ident) {
                 Some(t) => t,
                 None => {
-                    let set_fields = vec![input_field("set", list_input_type.clone(), None)];
-                    let input_object = Arc::new(input_object_type(set_object_ident.clone(), set_fields));
+                    let input_object = if with_list_operations {
+                        let element_input_type = map_scalar_input_type(ctx, &f.type_identifier, false);
+                        let fields = vec![
+                            input_field("set", list_input_type.clone(), None).optional(),
+                            input_field("push", vec![element_input_type, list_input_type.clone()], None).optional(),
+                        ];
+
+                        let mut input_object = input_object_type(set_object_ident.clone(), fields);
+                        input_object.require_exactly_one_field();
+                        Arc::new(input_object)
+                    } else {
+                        let set_fields = vec![input_field("set", list_input_type.clone(), None)];
+                        Arc::new(input_object_type(set_object_ident.clone(), set_fields))
+                    };
 
                     ctx.cache_input_type(set_object_ident, input_object.clone());
                     Arc::downgrade(&input_object)
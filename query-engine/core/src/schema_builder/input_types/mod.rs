@@ -6,12 +6,14 @@ mod objects;
 
 use super::*;
 use crate::schema::*;
+use datamodel_connector::ConnectorCapability;
 use objects::*;
 use prisma_models::{RelationFieldRef, ScalarFieldRef};
 
 /// Builds "<Model>OrderByInput" object types.
+/// To-one relations can be ordered by the fields of the related model,
+/// to-many relations by an aggregation of the related records.
 pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
-    let enum_type = Arc::new(string_enum_type("SortOrder", vec!["asc".to_owned(), "desc".to_owned()]));
     let ident = Identifier::new(format!("{}OrderByInput", model.name), PRISMA_NAMESPACE);
 
     return_cached_input!(ctx, &ident);
@@ -22,17 +24,158 @@ pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -
     let input_object = Arc::new(input_object);
     ctx.cache_input_type(ident, input_object.clone());
 
-    let fields = model
+    let supports_nulls_order = ctx.capabilities.contains(ConnectorCapability::OrderByNullsFirstLast);
+    let mut fields: Vec<InputField> = model
         .fields()
         .scalar()
         .iter()
-        .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
+        .map(|sf| {
+            let mut types = vec![InputType::Enum(sort_order_enum())];
+
+            if supports_nulls_order && !sf.is_required && !sf.is_list {
+                types.push(InputType::object(sort_order_object_type(ctx)));
+            }
+
+            input_field(sf.name.clone(), types, None).optional()
+        })
         .collect();
 
+    let relation_fields = model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| !rf.related_model().is_embedded)
+        .map(|rf| {
+            let related_model = rf.related_model();
+            let related_object_type = if rf.is_list {
+                order_by_relation_aggregate_object_type(ctx, &related_model)
+            } else {
+                order_by_object_type(ctx, &related_model)
+            };
+
+            input_field(rf.name.clone(), InputType::object(related_object_type), None).optional()
+        });
+
+    fields.extend(relation_fields);
+
+    if ctx.capabilities.contains(ConnectorCapability::FullTextIndex) {
+        if let Some(relevance_object_type) = order_by_relevance_object_type(ctx, model) {
+            fields.push(input_field("_relevance", InputType::object(relevance_object_type), None).optional());
+        }
+    }
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model>OrderByRelationAggregateInput" object types, used to order by aggregations of to-many relations.
+fn order_by_relation_aggregate_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}OrderByRelationAggregateInput", model.name), PRISMA_NAMESPACE);
+
+    return_cached_input!(ctx, &ident);
+
+    let mut input_object = init_input_object_type(ident.clone());
+    input_object.allow_at_most_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = vec![input_field("_count", InputType::Enum(sort_order_enum()), None).optional()];
+
     input_object.set_fields(fields);
     Arc::downgrade(&input_object)
 }
 
+/// Builds "<Model>OrderByRelevanceInput" object types, used to order by the relevance of a full-text search
+/// on the String fields of the model. Models without String fields can't be ordered by relevance.
+fn order_by_relevance_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> Option<InputObjectTypeWeakRef> {
+    let values: Vec<(String, ScalarFieldRef)> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|sf| sf.type_identifier == TypeIdentifier::String && !sf.is_list)
+        .map(|sf| (sf.name.clone(), sf))
+        .collect();
+
+    if values.is_empty() {
+        return None;
+    }
+
+    let ident = Identifier::new(format!("{}OrderByRelevanceInput", model.name), PRISMA_NAMESPACE);
+
+    if let Some(cached) = ctx.get_input_type(&ident) {
+        return Some(cached);
+    }
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let field_enum = InputType::Enum(Arc::new(EnumType::FieldRef(FieldRefEnumType {
+        name: format!("{}OrderByRelevanceFieldEnum", capitalize(&model.name)),
+        values,
+    })));
+
+    let fields = vec![
+        input_field("fields", vec![InputType::list(field_enum.clone()), field_enum], None),
+        input_field("sort", InputType::Enum(sort_order_enum()), None),
+        input_field("search", InputType::string(), None),
+    ];
+
+    input_object.set_fields(fields);
+    Some(Arc::downgrade(&input_object))
+}
+
+/// Builds the "SortOrderInput" object type, which allows to define the placement of null values on nullable fields.
+fn sort_order_object_type(ctx: &mut BuilderContext) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new("SortOrderInput".to_owned(), PRISMA_NAMESPACE);
+
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let nulls_order_enum = Arc::new(string_enum_type(
+        "NullsOrder",
+        vec!["first".to_owned(), "last".to_owned()],
+    ));
+
+    let fields = vec![
+        input_field("sort", InputType::Enum(sort_order_enum()), None),
+        input_field("nulls", InputType::Enum(nulls_order_enum), None).optional(),
+    ];
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds the "RowLockInput" object type, e.g. `{ mode: forUpdate, skipLocked: true }`.
+fn row_lock_object_type(ctx: &mut BuilderContext) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new("RowLockInput".to_owned(), PRISMA_NAMESPACE);
+
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let lock_mode_enum = Arc::new(string_enum_type(
+        "RowLockMode",
+        vec!["forUpdate".to_owned(), "forShare".to_owned()],
+    ));
+
+    let fields = vec![
+        input_field("mode", InputType::Enum(lock_mode_enum), None),
+        input_field("skipLocked", InputType::boolean(), None).optional(),
+        input_field("noWait", InputType::boolean(), None).optional(),
+    ];
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+fn sort_order_enum() -> EnumTypeRef {
+    Arc::new(string_enum_type("SortOrder", vec!["asc".to_owned(), "desc".to_owned()]))
+}
+
 fn map_scalar_input_type_for_field(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputType {
     map_scalar_input_type(ctx, &field.type_identifier, field.is_list)
 }
@@ -66,6 +66,7 @@ fn checked_create_input_type(
                 .nullable_if(!f.is_required)
         },
         true,
+        false,
     );
 
     // Compute input fields for relational fields.
@@ -102,7 +103,8 @@ fn relation_input_fields_for_checked_create(
             let field_is_opposite_relation_field =
                 parent_field.filter(|pf| pf.related_field().name == rf.name).is_some();
 
-            if field_is_opposite_relation_field {
+            // Views are read-only, so no records of them can be created or connected through a relation.
+            if field_is_opposite_relation_field || related_model.is_view {
                 None
             } else {
                 let input_object = match ctx.get_input_type(&ident) {
@@ -139,6 +141,38 @@ fn relation_input_fields_for_checked_create(
         .collect()
 }
 
+/// Builds the create many input type (<x>CreateManyInput).
+/// `createMany` inserts rows into a single table, so the input only contains scalar fields.
+/// Relations can only be written through their relation scalars, which makes the input "unchecked".
+pub(crate) fn create_many_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}CreateManyInput", model.name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let scalar_fields: Vec<ScalarFieldRef> = model.fields().scalar();
+
+    let fields = input_fields::scalar_input_fields(
+        ctx,
+        model.name.clone(),
+        "CreateMany",
+        scalar_fields,
+        |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| {
+            let typ = map_scalar_input_type_for_field(ctx, &f);
+
+            input_field(f.name.clone(), typ, default)
+                .optional_if(!f.is_required || f.default_value.is_some() || f.is_created_at() || f.is_updated_at())
+                .nullable_if(!f.is_required)
+        },
+        true,
+        false,
+    );
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
 fn field_should_be_kept_for_checked_create_input_type(field: &ScalarFieldRef) -> bool {
     !field.is_auto_generated_int_id
 }
@@ -201,6 +235,7 @@ fn unchecked_create_input_type(
                 .nullable_if(!f.is_required)
         },
         true,
+        false,
     );
 
     // Compute input fields for relational fields.
@@ -239,7 +274,8 @@ fn relation_input_fields_for_unchecked_create(
 
             // Filter out all inlined relations on `child_model`.
             // -> Only relations that point to other models are allowed in the unchecked input.
-            if field_is_opposite_relation_field || !child_field.is_inlined_on_enclosing_model() {
+            // Views are read-only, so no records of them can be created or connected through a relation.
+            if field_is_opposite_relation_field || !child_field.is_inlined_on_enclosing_model() || child_model.is_view {
                 None
             } else {
                 let input_object = match ctx.get_input_type(&ident) {
@@ -94,6 +94,7 @@ pub(super) fn scalar_input_fields_for_checked_update(ctx: &mut BuilderContext, m
             .collect(),
         |ctx, f: ScalarFieldRef, default| non_list_scalar_update_field_mapper(ctx, &f, default),
         false,
+        true,
     )
 }
 
@@ -127,6 +128,7 @@ pub(super) fn scalar_input_fields_for_unchecked_update(
         scalar_fields,
         |ctx, f: ScalarFieldRef, default| non_list_scalar_update_field_mapper(ctx, &f, default),
         false,
+        true,
     )
 }
 
@@ -135,6 +137,12 @@ fn non_list_scalar_update_field_mapper(
     field: &ScalarFieldRef,
     default: Option<DefaultValue>,
 ) -> InputField {
+    // The version is incremented by the engine, the input only holds the version the update expects.
+    if field.is_version() {
+        let typ = map_scalar_input_type_for_field(ctx, field);
+        return input_field(field.name.clone(), typ, default).optional();
+    }
+
     let base_update_type = match &field.type_identifier {
         TypeIdentifier::Float => InputType::object(operations_object_type(ctx, "Float", field, true)),
         TypeIdentifier::Decimal => InputType::object(operations_object_type(ctx, "Decimal", field, true)),
@@ -193,6 +201,10 @@ fn operations_object_type(
         fields.push(input_field("divide", typ, None).optional());
     }
 
+    if !field.is_required {
+        fields.push(input_field("unset", InputType::boolean(), None).optional());
+    }
+
     obj.set_fields(fields);
 
     Arc::downgrade(&obj)
@@ -228,7 +240,8 @@ fn relation_input_fields_for_checked_update_one(
             let field_is_opposite_relation_field =
                 parent_field.filter(|pf| pf.related_field().name == rf.name).is_some();
 
-            if field_is_opposite_relation_field {
+            // Views are read-only, so no records of them can be written through a relation.
+            if field_is_opposite_relation_field || related_model.is_view {
                 None
             } else {
                 let input_object = match ctx.get_input_type(&ident) {
@@ -289,7 +302,11 @@ fn relation_input_fields_for_unchecked_update_one(
 
             // Filter out all inlined relations on `related_model`.
             // -> Only relations that point to other models are allowed in the unchecked input.
-            if field_is_opposite_relation_field || !related_field.is_inlined_on_enclosing_model() {
+            // Views are read-only, so no records of them can be written through a relation.
+            if field_is_opposite_relation_field
+                || !related_field.is_inlined_on_enclosing_model()
+                || related_model.is_view
+            {
                 None
             } else {
                 let input_object = match ctx.get_input_type(&ident) {
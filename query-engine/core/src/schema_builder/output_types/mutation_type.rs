@@ -7,8 +7,10 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
     let non_embedded_models = ctx.internal_data_model.non_embedded_models();
     let mut fields: Vec<OutputField> = non_embedded_models
         .into_iter()
+        // Views are read-only.
+        .filter(|model| !model.is_view)
         .map(|model| {
-            let mut vec = vec![create_item_field(ctx, &model)];
+            let mut vec = vec![create_item_field(ctx, &model), create_many_field(ctx, &model)];
 
             append_opt(&mut vec, delete_item_field(ctx, &model));
             append_opt(&mut vec, update_item_field(ctx, &model));
@@ -131,6 +133,25 @@ fn create_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField
     )
 }
 
+/// Builds a create many mutation field (e.g. createManyUsers) for given model.
+fn create_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    let arguments = arguments::create_many_arguments(ctx, model);
+    let field_name = ctx.pluralize_internal(
+        format!("createMany{}", pluralize(&model.name)),
+        format!("createMany{}", model.name),
+    );
+
+    field(
+        field_name,
+        arguments,
+        OutputType::object(output_objects::batch_payload_object_type(ctx)),
+        Some(QueryInfo {
+            model: Some(Arc::clone(&model)),
+            tag: QueryTag::CreateMany,
+        }),
+    )
+}
+
 /// Builds a delete mutation field (e.g. deleteUser) for given model.
 fn delete_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
     arguments::delete_arguments(ctx, model).map(|args| {
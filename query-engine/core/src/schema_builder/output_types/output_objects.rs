@@ -33,12 +33,60 @@ pub(crate) fn initialize_model_object_type_cache(ctx: &mut BuilderContext) {
 /// Computes model output type fields.
 /// Important: This requires that the cache has already been initialized.
 fn compute_model_object_type_fields(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<OutputField> {
-    model
+    let mut fields: Vec<OutputField> = model
         .fields()
         .all
         .iter()
         .map(|f| output_objects::map_field(ctx, f))
-        .collect()
+        .collect();
+
+    if let Some(count_field) = relation_count_field(ctx, model) {
+        fields.push(count_field);
+    }
+
+    fields
+}
+
+/// Builds the `_count` field, counting the related records of every to-many relation of the model.
+/// Models without to-many relations don't get the field.
+fn relation_count_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
+    let relation_fields: Vec<_> = model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| rf.is_list && !rf.related_model().is_embedded)
+        .collect();
+
+    if relation_fields.is_empty() {
+        None
+    } else {
+        let object_type = OutputType::object(relation_count_object_type(ctx, model, &relation_fields));
+        Some(field("_count", vec![], object_type, None).optional())
+    }
+}
+
+/// Maps the object type of the `_count` field: one count per to-many relation,
+/// optionally only counting the related records matching `where`.
+fn relation_count_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    relation_fields: &[RelationFieldRef],
+) -> ObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}CountOutputType", capitalize(&model.name)), PRISMA_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let fields: Vec<OutputField> = relation_fields
+        .iter()
+        .map(|rf| {
+            let args = vec![arguments::where_argument(ctx, &rf.related_model())];
+            field(rf.name.clone(), args, OutputType::int(), None)
+        })
+        .collect();
+
+    let object = Arc::new(object_type(ident.clone(), fields, None));
+    ctx.cache_output_type(ident, object.clone());
+
+    Arc::downgrade(&object)
 }
 
 /// Returns an output object type for the given model.
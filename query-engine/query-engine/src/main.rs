@@ -3,6 +3,7 @@ extern crate tracing;
 
 use cli::CliCommand;
 use error::PrismaError;
+use opentelemetry_otlp::Uninstall;
 use opt::PrismaOpt;
 use request_handlers::PrismaResponse;
 use std::{error::Error, process};
@@ -21,6 +22,7 @@ mod exec_loader;
 mod opt;
 mod request_handlers;
 mod server;
+mod telemetry;
 
 #[cfg(test)]
 mod tests;
@@ -45,7 +47,7 @@ async fn main() -> Result<(), AnyError> {
     async fn main() -> Result<(), PrismaError> {
         let opts = PrismaOpt::from_args();
 
-        init_logger(opts.log_format());
+        let _telemetry = init_logger(opts.log_format(), opts.open_telemetry_endpoint.as_deref())?;
         feature_flags::initialize(opts.raw_feature_flags.as_slice())?;
 
         match CliCommand::from_opt(&opts)? {
@@ -60,16 +62,30 @@ async fn main() -> Result<(), AnyError> {
     }
 }
 
-fn init_logger(log_format: LogFormat) {
+/// Sets up logging, and exporting spans over OTLP if an OpenTelemetry endpoint is configured.
+/// Spans are exported until the returned guard is dropped.
+fn init_logger(log_format: LogFormat, open_telemetry_endpoint: Option<&str>) -> PrismaResult<Option<Uninstall>> {
     // Enable `tide` logs to be captured.
     let filter = EnvFilter::from_default_env().add_directive("tide=info".parse().unwrap());
 
+    let (telemetry_layer, uninstall) = match open_telemetry_endpoint {
+        Some(endpoint) => {
+            let (tracer, uninstall) = telemetry::install_exporter(endpoint)?;
+            (
+                Some(tracing_opentelemetry::layer().with_tracer(tracer)),
+                Some(uninstall),
+            )
+        }
+        None => (None, None),
+    };
+
     match log_format {
         LogFormat::Text => {
             let subscriber = FmtSubscriber::builder()
                 .with_max_level(tracing::Level::TRACE)
                 .finish()
-                .with(TimingLayer::new());
+                .with(TimingLayer::new())
+                .with(telemetry_layer);
 
             subscriber::set_global_default(subscriber).expect("Could not initialize logger");
         }
@@ -78,10 +94,13 @@ fn init_logger(log_format: LogFormat) {
                 .json()
                 .with_env_filter(filter)
                 .finish()
-                .with(TimingLayer::new());
+                .with(TimingLayer::new())
+                .with(telemetry_layer);
             subscriber::set_global_default(subscriber).expect("Could not initialize logger");
         }
     }
+
+    Ok(uninstall)
 }
 
 fn set_panic_hook(log_format: LogFormat) {
@@ -1,8 +1,9 @@
 use crate::{error::PrismaError, PrismaResult};
 use datamodel::diagnostics::ValidatedConfiguration;
 use datamodel::Datamodel;
+use query_core::TransactionOptions;
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::{ffi::OsStr, fs::File, io::Read, time::Duration};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -92,6 +93,26 @@ pub struct PrismaOpt {
     #[structopt(long = "log-format", env = "RUST_LOG_FORMAT")]
     pub log_format: Option<String>,
 
+    /// The endpoint of an OpenTelemetry collector to export spans to over OTLP, e.g. `http://localhost:4317`.
+    /// Spans are not exported if unset.
+    #[structopt(long, env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    pub open_telemetry_endpoint: Option<String>,
+
+    /// Maximum lifetime of an interactive transaction in milliseconds.
+    /// Transactions still open after this time are rolled back.
+    #[structopt(long, env, default_value = "5000")]
+    pub transaction_timeout: u64,
+
+    /// Maximum time in milliseconds an interactive transaction may wait for its next request.
+    /// Idle transactions are rolled back after this time.
+    #[structopt(long, env, default_value = "5000")]
+    pub transaction_idle_timeout: u64,
+
+    /// Default timeout of a request in milliseconds, overridable per request with the `x-request-timeout` header.
+    /// Requests running for longer are cancelled and their transactions rolled back. Requests don't time out if unset.
+    #[structopt(long, env)]
+    pub request_timeout: Option<u64>,
+
     #[structopt(subcommand)]
     pub subcommand: Option<Subcommand>,
 
@@ -161,6 +182,19 @@ impl PrismaOpt {
         }
     }
 
+    /// The upper bounds for the timeouts of interactive transactions.
+    pub(crate) fn transaction_options(&self) -> TransactionOptions {
+        TransactionOptions::new(
+            Duration::from_millis(self.transaction_timeout),
+            Duration::from_millis(self.transaction_idle_timeout),
+        )
+    }
+
+    /// The default timeout of a request.
+    pub(crate) fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout.map(Duration::from_millis)
+    }
+
     /// The unix path to listen on.
     pub(crate) fn unix_path(&self) -> Option<&String> {
         self.unix_path.as_ref()
@@ -3,9 +3,11 @@ use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
 use futures::FutureExt;
 use graphql_parser as gql;
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{
+    BatchDocument, CompactedDocument, ExplainMode, Item, Operation, QueryDocument, QueryValue, ResponseData, TxId,
+};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
+use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc, time::Duration};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", untagged)]
@@ -20,6 +22,9 @@ pub struct SingleQuery {
     query: String,
     operation_name: Option<String>,
     variables: HashMap<String, String>,
+    /// `statements` or `plans` to get the query graph and the statements of the request in `extensions.explain`.
+    #[serde(default)]
+    explain: ExplainMode,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -27,6 +32,9 @@ pub struct SingleQuery {
 pub struct MultiQuery {
     batch: Vec<SingleQuery>,
     transaction: bool,
+    /// Explains every operation of the batch. The `explain` option of the queries in the batch is ignored.
+    #[serde(default)]
+    explain: ExplainMode,
 }
 
 impl From<String> for SingleQuery {
@@ -35,6 +43,7 @@ impl From<String> for SingleQuery {
             query,
             operation_name: None,
             variables: HashMap::new(),
+            explain: ExplainMode::Off,
         }
     }
 }
@@ -45,7 +54,22 @@ impl From<&str> for SingleQuery {
     }
 }
 
+impl SingleQuery {
+    #[cfg(test)]
+    pub(crate) fn explained(mut self, explain: ExplainMode) -> Self {
+        self.explain = explain;
+        self
+    }
+}
+
 impl GraphQlBody {
+    fn explain(&self) -> ExplainMode {
+        match self {
+            GraphQlBody::Single(body) => body.explain,
+            GraphQlBody::Multi(bodies) => bodies.explain,
+        }
+    }
+
     /// Convert a `GraphQlBody` into a `QueryDocument`.
     pub(crate) fn into_doc(self) -> PrismaResult<QueryDocument> {
         match self {
@@ -75,25 +99,58 @@ impl GraphQlBody {
 }
 
 /// Handle a Graphql request.
-pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
+/// If `tx_id` is set, the request is executed inside of that open interactive transaction.
+/// If `force_primary` is set, reads of the request are not routed to read replicas.
+/// If the request asks to be explained, every response carries the query graph and the statements of its
+/// operation in `extensions.explain`, errors included.
+/// If `timeout` is set, the request is cancelled once it runs for longer, rolling back its transaction.
+pub(crate) async fn handle(
+    body: GraphQlBody,
+    tx_id: Option<TxId>,
+    force_primary: bool,
+    timeout: Option<Duration>,
+    cx: Arc<PrismaContext>,
+) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
+    let explain = body.explain();
+
     match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
+        Ok(QueryDocument::Single(query)) => {
+            handle_single_query(query, tx_id, force_primary, explain, timeout, cx.clone()).await
+        }
         Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
+            BatchDocument::Multi(batch, transactional) => {
+                handle_batch(batch, transactional, tx_id, force_primary, explain, timeout, &cx).await
+            }
+            BatchDocument::Compact(compacted) => {
+                handle_compacted(compacted, tx_id, force_primary, explain, timeout, &cx).await
+            }
         },
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_single_query(
+    query: Operation,
+    tx_id: Option<TxId>,
+    force_primary: bool,
+    explain: ExplainMode,
+    timeout: Option<Duration>,
+    ctx: Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
-        .catch_unwind()
-        .await
+    let gql_response = match AssertUnwindSafe(handle_graphql_query(
+        query,
+        tx_id,
+        force_primary,
+        explain,
+        timeout,
+        &*ctx,
+    ))
+    .catch_unwind()
+    .await
     {
         Ok(Ok(responses)) => responses.into(),
         Ok(Err(err)) => err.into(),
@@ -107,13 +164,26 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
     PrismaResponse::Single(gql_response)
 }
 
-async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(
+    queries: Vec<Operation>,
+    transactional: bool,
+    tx_id: Option<TxId>,
+    force_primary: bool,
+    explain: ExplainMode,
+    timeout: Option<Duration>,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    match AssertUnwindSafe(
-        ctx.executor
-            .execute_batch(queries, transactional, ctx.query_schema().clone()),
-    )
+    match AssertUnwindSafe(ctx.executor.execute_batch(
+        tx_id,
+        force_primary,
+        explain,
+        timeout,
+        queries,
+        transactional,
+        ctx.query_schema().clone(),
+    ))
     .catch_unwind()
     .await
     {
@@ -139,7 +209,14 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    tx_id: Option<TxId>,
+    force_primary: bool,
+    explain: ExplainMode,
+    timeout: Option<Duration>,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
@@ -148,12 +225,20 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
-        .catch_unwind()
-        .await
+    match AssertUnwindSafe(handle_graphql_query(
+        document.operation,
+        tx_id,
+        force_primary,
+        explain,
+        timeout,
+        ctx,
+    ))
+    .catch_unwind()
+    .await
     {
         Ok(Ok(response_data)) => {
             let mut gql_response: GQLResponse = response_data.into();
+            let explained = gql_response.extension("explain").cloned();
 
             // We find the response data and make a hash from the given unique keys.
             let data = gql_response
@@ -169,6 +254,11 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
                     let vals: Vec<QueryValue> = args.into_iter().map(|(_, v)| v).collect();
                     let mut responses = GQLResponse::with_capacity(1);
 
+                    // All queries of the batch were answered by the same operation.
+                    if let Some(explained) = &explained {
+                        responses.insert_extension("explain", explained.clone());
+                    }
+
                     // Copying here is mandatory due to some of the queries
                     // might be repeated with the same arguments in the original
                     // batch. We need to give the same answer for both of them.
@@ -206,6 +296,23 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    tx_id: Option<TxId>,
+    force_primary: bool,
+    explain: ExplainMode,
+    timeout: Option<Duration>,
+    ctx: &PrismaContext,
+) -> PrismaResult<ResponseData> {
+    Ok(ctx
+        .executor
+        .execute(
+            tx_id,
+            force_primary,
+            explain,
+            timeout,
+            query_doc,
+            Arc::clone(ctx.query_schema()),
+        )
+        .await?)
 }
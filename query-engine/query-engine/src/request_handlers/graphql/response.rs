@@ -14,6 +14,10 @@ pub struct GQLResponse {
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
     errors: Vec<GQLError>,
+
+    /// Additional information about the execution, like the `explain` output.
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    extensions: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, serde::Serialize, PartialEq)]
@@ -41,13 +45,26 @@ impl GQLResponse {
     pub fn take_data(&mut self, key: impl AsRef<str>) -> Option<Item> {
         self.data.remove(key.as_ref())
     }
+
+    pub fn insert_extension(&mut self, key: impl Into<String>, value: serde_json::Value) {
+        self.extensions.insert(key.into(), value);
+    }
+
+    pub fn extension(&self, key: impl AsRef<str>) -> Option<&serde_json::Value> {
+        self.extensions.get(key.as_ref())
+    }
 }
 
 impl From<PrismaError> for GQLResponse {
     fn from(err: PrismaError) -> Self {
-        let mut responses = Self::default();
-        responses.insert_error(err);
-        responses
+        match err {
+            PrismaError::CoreError(core_error) => core_error.into(),
+            err => {
+                let mut responses = Self::default();
+                responses.insert_error(err);
+                responses
+            }
+        }
     }
 }
 
@@ -99,6 +116,11 @@ impl From<ResponseData> for GQLResponse {
         let mut gql_response = GQLResponse::with_capacity(1);
 
         gql_response.insert_data(response.key, response.data);
+
+        if let Some(explain) = response.explain {
+            gql_response.insert_extension("explain", explain);
+        }
+
         gql_response
     }
 }
@@ -107,7 +129,14 @@ impl From<CoreError> for GQLResponse {
     fn from(err: CoreError) -> GQLResponse {
         let mut gql_response = GQLResponse::default();
 
-        gql_response.insert_error(err);
+        match err {
+            CoreError::Explained { error, explain } => {
+                gql_response.insert_error(*error);
+                gql_response.insert_extension("explain", explain);
+            }
+            err => gql_response.insert_error(err),
+        }
+
         gql_response
     }
 }
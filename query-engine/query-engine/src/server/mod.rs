@@ -3,34 +3,58 @@
 use crate::context::PrismaContext;
 use crate::dmmf;
 use crate::opt::PrismaOpt;
-use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
-use crate::PrismaResult;
+use crate::request_handlers::graphql::{self, GQLResponse, GraphQLSchemaRenderer, GraphQlBody};
+use crate::{telemetry, PrismaResult};
 use elapsed_middleware::ElapsedMiddleware;
 
-use query_core::schema::QuerySchemaRenderer;
+use query_core::{schema::QuerySchemaRenderer, TransactionOptions, TxId};
+use serde::Deserialize;
 use serde_json::json;
 use tide::http::{mime, StatusCode};
 use tide::{prelude::*, Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
+use tracing_futures::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use std::sync::Arc;
+use std::{cmp, sync::Arc, time::Duration};
 
 mod elapsed_middleware;
+pub(crate) mod prometheus;
+
+/// Header routing a GraphQL request into an open interactive transaction.
+static TRANSACTION_ID_HEADER: &str = "x-transaction-id";
+
+/// Header forcing the reads of a GraphQL request onto the primary database instead of a read replica.
+static FORCE_PRIMARY_HEADER: &str = "x-force-primary";
+
+/// Header setting the timeout of a GraphQL request in milliseconds, overriding the server's default.
+/// Requests running for longer are cancelled on the database and their transactions rolled back.
+static REQUEST_TIMEOUT_HEADER: &str = "x-request-timeout";
 
 //// Shared application state.
 pub(crate) struct State {
     cx: Arc<PrismaContext>,
     enable_playground: bool,
     enable_debug_mode: bool,
+    transaction_options: TransactionOptions,
+    request_timeout: Option<Duration>,
 }
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    fn new(
+        cx: PrismaContext,
+        enable_playground: bool,
+        enable_debug_mode: bool,
+        transaction_options: TransactionOptions,
+        request_timeout: Option<Duration>,
+    ) -> Self {
         Self {
             cx: Arc::new(cx),
             enable_playground,
             enable_debug_mode,
+            transaction_options,
+            request_timeout,
         }
     }
 }
@@ -41,10 +65,21 @@ impl Clone for State {
             cx: self.cx.clone(),
             enable_playground: self.enable_playground,
             enable_debug_mode: self.enable_debug_mode,
+            transaction_options: self.transaction_options,
+            request_timeout: self.request_timeout,
         }
     }
 }
 
+/// Optional timeouts requested by the client when starting an interactive transaction, in milliseconds.
+/// Requested values are capped at the timeouts the server was configured with.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionStartBody {
+    timeout: Option<u64>,
+    idle_timeout: Option<u64>,
+}
+
 /// Create a new server and listen.
 pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     let config = opts
@@ -59,7 +94,15 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
         .build()
         .await?;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    prometheus::install();
+
+    let mut app = tide::with_state(State::new(
+        cx,
+        opts.enable_playground,
+        opts.enable_debug_mode,
+        opts.transaction_options(),
+        opts.request_timeout(),
+    ));
     app.with(ElapsedMiddleware::new());
 
     if opts.enable_playground {
@@ -71,8 +114,13 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
+    app.at("/metrics").get(metrics_handler);
     app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
 
+    app.at("/transaction/start").post(transaction_start_handler);
+    app.at("/transaction/:id/commit").post(transaction_commit_handler);
+    app.at("/transaction/:id/rollback").post(transaction_rollback_handler);
+
     // Start the Tide server and log the server details.
     // NOTE: The `info!` statement is essential for the correct working of the client.
     let mut listener = match opts.unix_path() {
@@ -94,14 +142,100 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
+    let tx_id = req.header(TRANSACTION_ID_HEADER).map(|id| TxId::from(id.as_str()));
+    let force_primary = req
+        .header(FORCE_PRIMARY_HEADER)
+        .map(|value| value.as_str() == "true")
+        .unwrap_or(false);
+    let timeout = match req.header(REQUEST_TIMEOUT_HEADER) {
+        Some(value) => match value.as_str().parse() {
+            Ok(ms) => Some(Duration::from_millis(ms)),
+            Err(_) => {
+                let message = format!(
+                    "Invalid `{}` header: expected a timeout in milliseconds, got `{}`.",
+                    REQUEST_TIMEOUT_HEADER,
+                    value.as_str()
+                );
+
+                return Ok(Response::builder(StatusCode::BadRequest).body(message).build());
+            }
+        },
+        None => req.state().request_timeout,
+    };
+    let span = info_span!("prisma:request", tx_id = ?tx_id);
+    span.set_parent(telemetry::parent_context(&req));
+
     let body: GraphQlBody = req.body_json().await?;
     let cx = req.state().cx.clone();
-    let result = graphql::handle(body, cx).await;
+    let result = graphql::handle(body, tx_id, force_primary, timeout, cx)
+        .instrument(span)
+        .await;
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
 }
 
+/// Starts an interactive transaction and returns its id. GraphQL requests carrying
+/// the id in the `x-transaction-id` header are executed inside of the transaction.
+async fn transaction_start_handler(mut req: Request<State>) -> tide::Result {
+    let bytes = req.body_bytes().await?;
+    let body: TransactionStartBody = if bytes.is_empty() {
+        TransactionStartBody::default()
+    } else {
+        serde_json::from_slice(&bytes)?
+    };
+
+    let max = req.state().transaction_options;
+    let options = TransactionOptions::new(
+        body.timeout
+            .map(|ms| cmp::min(Duration::from_millis(ms), max.max_lifetime))
+            .unwrap_or(max.max_lifetime),
+        body.idle_timeout
+            .map(|ms| cmp::min(Duration::from_millis(ms), max.idle_timeout))
+            .unwrap_or(max.idle_timeout),
+    );
+
+    let cx = req.state().cx.clone();
+
+    match cx.executor.start_tx(cx.query_schema().clone(), options).await {
+        Ok(tx_id) => Ok(Response::builder(StatusCode::Ok)
+            .body(Body::from_json(&json!({ "id": tx_id }))?)
+            .build()),
+        Err(err) => transaction_error_response(err),
+    }
+}
+
+/// Commits the interactive transaction with the given id.
+async fn transaction_commit_handler(req: Request<State>) -> tide::Result {
+    let tx_id = TxId::from(req.param("id")?);
+
+    match req.state().cx.executor.commit_tx(tx_id).await {
+        Ok(()) => Ok(Response::builder(StatusCode::Ok)
+            .body(Body::from_json(&json!({}))?)
+            .build()),
+        Err(err) => transaction_error_response(err),
+    }
+}
+
+/// Rolls back the interactive transaction with the given id.
+async fn transaction_rollback_handler(req: Request<State>) -> tide::Result {
+    let tx_id = TxId::from(req.param("id")?);
+
+    match req.state().cx.executor.rollback_tx(tx_id).await {
+        Ok(()) => Ok(Response::builder(StatusCode::Ok)
+            .body(Body::from_json(&json!({}))?)
+            .build()),
+        Err(err) => transaction_error_response(err),
+    }
+}
+
+fn transaction_error_response(err: query_core::CoreError) -> tide::Result {
+    let response = GQLResponse::from(err);
+    let mut res = Response::new(StatusCode::BadRequest);
+    res.set_body(Body::from_json(&response)?);
+    Ok(res)
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -135,6 +269,17 @@ async fn dmmf_handler(req: Request<State>) -> tide::Result {
     Ok(res)
 }
 
+/// Renders the recorded metrics in the Prometheus text exposition format.
+async fn metrics_handler(_req: Request<State>) -> tide::Result {
+    #[cfg(feature = "sql")]
+    sql_connector::record_pool_connections().await;
+
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(prometheus::RECORDER.render());
+    res.set_content_type(mime::PLAIN);
+    Ok(res)
+}
+
 /// Simple status endpoint
 async fn server_info_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
     Ok(json!({
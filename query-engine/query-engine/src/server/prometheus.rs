@@ -0,0 +1,187 @@
+//! A `metrics` recorder keeping everything recorded by the query engine in memory,
+//! to be scraped from the `/metrics` endpoint in the Prometheus text exposition format.
+
+use metrics::{Key, Recorder};
+use once_cell::sync::Lazy;
+use std::{collections::BTreeMap, fmt::Write, sync::Mutex};
+
+/// The recorder installed by `install`.
+pub(crate) static RECORDER: Lazy<PrometheusRecorder> = Lazy::new(PrometheusRecorder::default);
+
+/// Upper bounds of the histogram buckets. Histograms hold durations in microseconds as well as
+/// plain counts, so the bounds grow exponentially to cover both.
+const BUCKETS: &[u64] = &[
+    1, 4, 16, 64, 256, 1_024, 4_096, 16_384, 65_536, 262_144, 1_048_576, 4_194_304, 16_777_216,
+];
+
+/// Installs `RECORDER` as the global recorder of the `metrics` crate.
+pub(crate) fn install() {
+    if metrics::set_recorder(&*RECORDER).is_err() {
+        warn!("A metrics recorder was already installed, the /metrics endpoint will stay empty.");
+    }
+}
+
+/// Sorted label pairs of a metric.
+type Labels = Vec<(String, String)>;
+
+#[derive(Debug, Default)]
+struct Histogram {
+    /// Non-cumulative counts per bucket of `BUCKETS`.
+    buckets: Vec<u64>,
+    sum: u64,
+    count: u64,
+}
+
+impl Histogram {
+    fn record(&mut self, value: u64) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; BUCKETS.len()];
+        }
+
+        if let Some(idx) = BUCKETS.iter().position(|bound| value <= *bound) {
+            self.buckets[idx] += 1;
+        }
+
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    counters: BTreeMap<String, BTreeMap<Labels, u64>>,
+    gauges: BTreeMap<String, BTreeMap<Labels, i64>>,
+    histograms: BTreeMap<String, BTreeMap<Labels, Histogram>>,
+}
+
+/// Keeps counters, gauges and histograms in memory.
+#[derive(Debug, Default)]
+pub(crate) struct PrometheusRecorder {
+    registry: Mutex<Registry>,
+}
+
+impl PrometheusRecorder {
+    /// Renders all recorded metrics in the Prometheus text exposition format.
+    pub(crate) fn render(&self) -> String {
+        let registry = self.registry.lock().unwrap();
+        let mut out = String::new();
+
+        for (name, series) in registry.counters.iter() {
+            writeln!(out, "# TYPE {} counter", name).unwrap();
+
+            for (labels, value) in series {
+                writeln!(out, "{}{} {}", name, render_labels(labels, None), value).unwrap();
+            }
+        }
+
+        for (name, series) in registry.gauges.iter() {
+            writeln!(out, "# TYPE {} gauge", name).unwrap();
+
+            for (labels, value) in series {
+                writeln!(out, "{}{} {}", name, render_labels(labels, None), value).unwrap();
+            }
+        }
+
+        for (name, series) in registry.histograms.iter() {
+            writeln!(out, "# TYPE {} histogram", name).unwrap();
+
+            for (labels, histogram) in series {
+                let mut cumulative = 0;
+
+                for (bound, count) in BUCKETS.iter().zip(histogram.buckets.iter()) {
+                    cumulative += count;
+                    let labels = render_labels(labels, Some(&bound.to_string()));
+                    writeln!(out, "{}_bucket{} {}", name, labels, cumulative).unwrap();
+                }
+
+                let inf_labels = render_labels(labels, Some("+Inf"));
+                writeln!(out, "{}_bucket{} {}", name, inf_labels, histogram.count).unwrap();
+
+                let labels = render_labels(labels, None);
+                writeln!(out, "{}_sum{} {}", name, labels, histogram.sum).unwrap();
+                writeln!(out, "{}_count{} {}", name, labels, histogram.count).unwrap();
+            }
+        }
+
+        out
+    }
+}
+
+impl Recorder for PrometheusRecorder {
+    fn increment_counter(&self, key: Key, value: u64) {
+        let (name, labels) = split_key(key);
+        let mut registry = self.registry.lock().unwrap();
+
+        *registry.counters.entry(name).or_default().entry(labels).or_default() += value;
+    }
+
+    fn update_gauge(&self, key: Key, value: i64) {
+        let (name, labels) = split_key(key);
+        let mut registry = self.registry.lock().unwrap();
+
+        registry.gauges.entry(name).or_default().insert(labels, value);
+    }
+
+    fn record_histogram(&self, key: Key, value: u64) {
+        let (name, labels) = split_key(key);
+        let mut registry = self.registry.lock().unwrap();
+
+        registry
+            .histograms
+            .entry(name)
+            .or_default()
+            .entry(labels)
+            .or_default()
+            .record(value);
+    }
+}
+
+/// Splits a key into a valid Prometheus metric name and its sorted labels.
+/// Metrics recorded by dependencies can use names like `pool.check_out`, which become `pool_check_out`.
+fn split_key(key: Key) -> (String, Labels) {
+    let (name, labels) = key.into_parts();
+
+    let name = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == ':' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    let mut labels: Labels = labels
+        .into_iter()
+        .map(|label| {
+            let (key, value) = label.into_parts();
+            (key.into_owned(), value.into_owned())
+        })
+        .collect();
+
+    labels.sort();
+
+    (name, labels)
+}
+
+fn render_labels(labels: &[(String, String)], le: Option<&str>) -> String {
+    let mut rendered: Vec<String> = labels
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\"", key, escape_label_value(value)))
+        .collect();
+
+    if let Some(le) = le {
+        rendered.push(format!("le=\"{}\"", le));
+    }
+
+    if rendered.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", rendered.join(","))
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
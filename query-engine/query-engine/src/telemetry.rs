@@ -0,0 +1,45 @@
+//! OpenTelemetry integration: spans are exported to a collector over OTLP, and traces started by
+//! clients are continued from the W3C `traceparent` header of incoming requests.
+
+use crate::{error::PrismaError, PrismaResult};
+use opentelemetry::{
+    global,
+    propagation::Extractor,
+    sdk::{propagation::TraceContextPropagator, trace::Tracer},
+    Context,
+};
+use opentelemetry_otlp::Uninstall;
+use tide::Request;
+
+/// Installs the OTLP exporter sending spans to the collector at `endpoint`, and the W3C trace
+/// context propagator. Spans are exported until the returned `Uninstall` guard is dropped.
+pub(crate) fn install_exporter(endpoint: &str) -> PrismaResult<(Tracer, Uninstall)> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    opentelemetry_otlp::new_pipeline()
+        .with_endpoint(endpoint)
+        .install()
+        .map_err(|err| {
+            PrismaError::ConfigurationError(format!(
+                "Could not initialize the OpenTelemetry exporter for `{}`: {}",
+                endpoint, err
+            ))
+        })
+}
+
+/// The trace context sent by the client with a request, if any.
+pub(crate) fn parent_context<State>(req: &Request<State>) -> Context {
+    global::get_text_map_propagator(|propagator| propagator.extract(&RequestHeaders(req)))
+}
+
+struct RequestHeaders<'a, State>(&'a Request<State>);
+
+impl<'a, State> Extractor for RequestHeaders<'a, State> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.header(key).map(|values| values.as_str())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.header_names().map(|name| name.as_str()).collect()
+    }
+}
@@ -0,0 +1,52 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static PRODUCT: &str = indoc! {r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check(name: "positivePrice", expression: "price > 0")
+    }
+"#};
+
+// MySQL before 8.0.16 parses check constraints, but silently ignores them.
+#[test_each_connector(tags("postgres", "mysql_8", "mariadb", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn check_constraint_violations_are_reported(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&PRODUCT).await?;
+
+    let query = r#"mutation { createOneProduct(data: { id: 1, price: 10 }) { id price } }"#;
+
+    assert_eq!(
+        json!({ "data": { "createOneProduct": { "id": 1, "price": 10 } } }),
+        query_engine.request(query).await
+    );
+
+    for query in &[
+        r#"mutation { createOneProduct(data: { id: 2, price: 0 }) { id } }"#,
+        r#"mutation { updateOneProduct(where: { id: 1 }, data: { price: -1 }) { id } }"#,
+    ] {
+        let result = query_engine.request(*query).await;
+
+        assert_eq!(
+            Some("P2027"),
+            result["errors"][0]["user_facing_error"]["error_code"].as_str()
+        );
+
+        assert_eq!(
+            Some("Check constraint violation on the constraint: `positivePrice`"),
+            result["errors"][0]["user_facing_error"]["message"].as_str()
+        );
+    }
+
+    let query = r#"query { findManyProduct { id price } }"#;
+
+    assert_eq!(
+        json!({ "data": { "findManyProduct": [{ "id": 1, "price": 10 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int     @id
+        title String
+        done  Boolean @default(false)
+    }
+"};
+
+static COUNT_TODOS: &str = indoc! {r#"
+    query {
+        aggregateTodo { count { _all } }
+    }
+"#};
+
+#[test_each_connector]
+async fn create_many_returns_the_number_of_created_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        mutation {
+            createManyTodo(data: [
+                { id: 1, title: "title1" },
+                { id: 2, title: "title2", done: true },
+                { id: 3, title: "title3" }
+            ]) { count }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "createManyTodo": { "count": 3 } } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(orderBy: { id: asc }) { id done }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [
+            { "id": 1, "done": false },
+            { "id": 2, "done": true },
+            { "id": 3, "done": false }
+        ] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("mssql"))]
+async fn create_many_can_skip_duplicates(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "title1" }) { id } }"#)
+        .await;
+
+    let query = indoc! {r#"
+        mutation {
+            createManyTodo(data: [
+                { id: 1, title: "duplicate" },
+                { id: 2, title: "title2" }
+            ], skipDuplicates: true) { count }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "createManyTodo": { "count": 1 } } }),
+        query_engine.request(query).await
+    );
+
+    assert_eq!(
+        json!({ "data": { "aggregateTodo": { "count": { "_all": 2 } } } }),
+        query_engine.request(COUNT_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_many_splits_large_inputs_into_multiple_statements(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    // Enough rows to exceed the bind parameter limit of every supported database.
+    let records: Vec<String> = (1..=25_000)
+        .map(|id| format!(r#"{{ id: {}, title: "title{}" }}"#, id, id))
+        .collect();
+
+    let query = format!(
+        "mutation {{ createManyTodo(data: [{}]) {{ count }} }}",
+        records.join(", ")
+    );
+
+    assert_eq!(
+        json!({ "data": { "createManyTodo": { "count": 25000 } } }),
+        query_engine.request(query).await
+    );
+
+    assert_eq!(
+        json!({ "data": { "aggregateTodo": { "count": { "_all": 25000 } } } }),
+        query_engine.request(COUNT_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_many_splits_inputs_exceeding_the_row_limit_of_a_statement(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    // Few enough bind parameters for a single statement, but more rows than SQL Server
+    // allows in one `INSERT ... VALUES`.
+    let records: Vec<String> = (1..=1040).map(|id| format!(r#"{{ id: {}, title: "" }}"#, id)).collect();
+
+    let query = format!(
+        "mutation {{ createManyTodo(data: [{}]) {{ count }} }}",
+        records.join(", ")
+    );
+
+    assert_eq!(
+        json!({ "data": { "createManyTodo": { "count": 1040 } } }),
+        query_engine.request(query).await
+    );
+
+    assert_eq!(
+        json!({ "data": { "aggregateTodo": { "count": { "_all": 1040 } } } }),
+        query_engine.request(COUNT_TODOS).await
+    );
+
+    Ok(())
+}
@@ -5,7 +5,7 @@ use crate::{
 };
 use datamodel_connector::ConnectorCapabilities;
 use prisma_models::DatamodelConverter;
-use query_core::{schema_builder, BuildMode, QuerySchema};
+use query_core::{schema_builder, BuildMode, InputType, IntoArc, QuerySchema};
 use serial_test::serial;
 use std::sync::Arc;
 
@@ -73,6 +73,56 @@ fn must_fail_if_the_schema_is_invalid() {
     assert!(test_dmmf_cli_command(schema).is_err());
 }
 
+#[test]
+#[serial]
+fn views_must_not_have_mutations() {
+    let dm = r#"
+        model Blog {
+            blogId String @id
+        }
+
+        view BlogSummary {
+            blogId String @id
+        }
+    "#;
+
+    let (query_schema, _) = get_query_schema(dm);
+
+    assert!(query_schema.find_query_field("findManyBlogSummary").is_some());
+    assert!(query_schema.find_mutation_field("createOneBlog").is_some());
+    assert!(query_schema.find_mutation_field("createOneBlogSummary").is_none());
+    assert!(query_schema.find_mutation_field("deleteManyBlogSummary").is_none());
+}
+
+#[test]
+#[serial]
+fn views_must_not_be_writable_through_relations() {
+    let dm = r#"
+        model Blog {
+            blogId  String       @id
+            summary BlogSummary?
+        }
+
+        view BlogSummary {
+            blogId String @id
+            blog   Blog   @relation(fields: [blogId], references: [blogId])
+        }
+    "#;
+
+    let (query_schema, _) = get_query_schema(dm);
+
+    for mutation in &["createOneBlog", "updateOneBlog"] {
+        let field = query_schema.find_mutation_field(*mutation).unwrap();
+        let data = field.arguments.iter().find(|argument| argument.name == "data").unwrap();
+
+        for field_type in &data.field_types {
+            if let InputType::Object(input_object) = field_type {
+                assert!(input_object.into_arc().find_field("summary").is_none());
+            }
+        }
+    }
+}
+
 fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
     feature_flags::initialize(&[String::from("all")]).unwrap();
 
@@ -90,6 +140,8 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         raw_feature_flags: vec![],
         unix_path: None,
         subcommand: Some(Subcommand::Cli(CliOpt::Dmmf)),
+        transaction_timeout: 5000,
+        transaction_idle_timeout: 5000,
     };
 
     let cli_cmd = CliCommand::from_opt(&prisma_opt)?.unwrap();
@@ -0,0 +1,130 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::ExplainMode;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+static FIND_TODOS: &str = indoc! {r#"
+    query {
+        findManyTodo { id title }
+    }
+"#};
+
+#[test_each_connector]
+async fn explained_reads_return_the_graph_and_statements(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "title1" }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request_explained(ExplainMode::Statements, FIND_TODOS)
+        .await;
+    let explain = &response["extensions"]["explain"];
+
+    assert_eq!(
+        json!({ "findManyTodo": [{ "id": 1, "title": "title1" }] }),
+        response["data"]
+    );
+
+    let nodes = explain["queryGraph"]["nodes"].as_array().unwrap();
+    assert_eq!(1, nodes.len());
+    assert_eq!("query", nodes[0]["kind"]);
+
+    let statements = explain["statements"].as_array().unwrap();
+    assert_eq!(1, statements.len());
+    assert!(statements[0]["sql"].as_str().unwrap().starts_with("SELECT"));
+    assert!(statements[0].get("plan").is_none());
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("mssql"))]
+async fn explained_reads_can_include_the_database_plan(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let response = query_engine.request_explained(ExplainMode::Plans, FIND_TODOS).await;
+    let statements = response["extensions"]["explain"]["statements"].as_array().unwrap();
+
+    assert!(!statements[0]["plan"].as_array().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn explained_writes_return_the_dependencies_of_the_graph(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let response = query_engine
+        .request_explained(
+            ExplainMode::Statements,
+            r#"mutation { createOneTodo(data: { id: 1, title: "title1" }) { id } }"#,
+        )
+        .await;
+
+    let graph = &response["extensions"]["explain"]["queryGraph"];
+    let edges = graph["edges"].as_array().unwrap();
+
+    assert_eq!(2, graph["nodes"].as_array().unwrap().len());
+    assert_eq!(1, edges.len());
+    assert!(edges[0]["dependency"].as_str().unwrap().starts_with("ParentProjection"));
+
+    let statements = response["extensions"]["explain"]["statements"].as_array().unwrap();
+    assert!(statements[0]["sql"].as_str().unwrap().starts_with("INSERT"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn requests_are_not_explained_by_default(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let response = query_engine.request(FIND_TODOS).await;
+
+    assert!(response.get("extensions").is_none());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn failed_operations_return_what_was_explained_before_the_error(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let create_todo = r#"mutation { createOneTodo(data: { id: 1, title: "title1" }) { id } }"#;
+
+    query_engine.request(create_todo).await;
+
+    let response = query_engine
+        .request_explained(ExplainMode::Statements, create_todo)
+        .await;
+
+    assert_eq!("P2002", response["errors"][0]["user_facing_error"]["error_code"]);
+
+    let statements = response["extensions"]["explain"]["statements"].as_array().unwrap();
+    assert!(statements[0]["sql"].as_str().unwrap().starts_with("INSERT"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn batches_are_explained_with_the_explain_option(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let body = json!({
+        "batch": [{ "query": FIND_TODOS, "variables": {} }],
+        "transaction": false,
+        "explain": "statements",
+    });
+
+    let response = query_engine.request_body(serde_json::from_value(body)?).await;
+    let statements = response[0]["extensions"]["explain"]["statements"].as_array().unwrap();
+
+    assert!(statements[0]["sql"].as_str().unwrap().starts_with("SELECT"));
+
+    Ok(())
+}
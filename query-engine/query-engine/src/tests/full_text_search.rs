@@ -0,0 +1,151 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static POSTGRES_POST: &str = indoc! {"
+    model Post {
+        id    Int    @id
+        title String
+
+        @@fulltext([title])
+    }
+"};
+
+static FULLTEXT_POST: &str = indoc! {"
+    model Post {
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title])
+        @@fulltext([title, content])
+    }
+"};
+
+static CREATE_POSTS: &str = indoc! {r#"
+    mutation {
+        createManyPost(data: [
+            { id: 1, title: "Cats and dogs", content: "A story about pets" },
+            { id: 2, title: "Databases explained", content: "Indexes, cats and queries" },
+            { id: 3, title: "Cooking for beginners", content: "Recipes without any cats" }
+        ]) { count }
+    }
+"#};
+
+#[test_each_connector(tags("postgres"))]
+async fn search_filter_works_on_postgres(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTGRES_POST).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createManyPost(data: [
+                { id: 1, title: "Cats and dogs" },
+                { id: 2, title: "Databases explained" },
+                { id: 3, title: "A cat on the database" }
+            ]) { count }
+        }
+    "#};
+
+    query_engine.request(mutation).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(where: { title: { search: "cat database" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 3 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(where: { title: { not: { search: "cat" } } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn search_filter_works_on_mysql(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&FULLTEXT_POST).await?;
+    query_engine.request(CREATE_POSTS).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(where: { title: { search: "databases" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(where: { title: { not: { search: "databases" } } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 1 }, { "id": 3 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "postgres"))]
+async fn ordering_by_relevance_works(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&FULLTEXT_POST).await?;
+    query_engine.request(CREATE_POSTS).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(orderBy: [{ _relevance: { fields: [title, content], search: "cats", sort: desc } }, { id: asc }]) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+    let ids: Vec<_> = result["data"]["findManyPost"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|post| post["id"].as_i64().unwrap())
+        .collect();
+
+    // Every post mentions cats, the one mentioning them in the title is the most relevant.
+    assert_eq!(Some(&1), ids.first());
+    assert_eq!(3, ids.len());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "postgres"))]
+async fn ordering_by_relevance_requires_a_fulltext_index(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&FULLTEXT_POST).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(orderBy: { _relevance: { fields: [content], search: "cats", sort: desc } }) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+
+    assert_eq!(
+        Some("P2019"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    Ok(())
+}
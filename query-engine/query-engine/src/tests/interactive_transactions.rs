@@ -0,0 +1,89 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::TransactionOptions;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+static CREATE_TODO: &str = indoc! {r#"
+    mutation {
+        createOneTodo(data: { id: 1, title: "title1" }) { id }
+    }
+"#};
+
+static FIND_TODOS: &str = indoc! {r#"
+    query {
+        findManyTodo { id title }
+    }
+"#};
+
+#[test_each_connector]
+async fn committed_transactions_persist_their_writes(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_tx(TransactionOptions::default()).await?;
+
+    query_engine.request_in_tx(&tx_id, CREATE_TODO).await;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1, "title": "title1" }] } }),
+        query_engine.request_in_tx(&tx_id, FIND_TODOS).await
+    );
+
+    query_engine.commit_tx(tx_id).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1, "title": "title1" }] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolled_back_transactions_discard_their_writes(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_tx(TransactionOptions::default()).await?;
+
+    query_engine.request_in_tx(&tx_id, CREATE_TODO).await;
+    query_engine.rollback_tx(tx_id).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn idle_transactions_are_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let options = TransactionOptions::new(Duration::from_secs(5), Duration::from_millis(100));
+    let tx_id = query_engine.start_tx(options).await?;
+
+    query_engine.request_in_tx(&tx_id, CREATE_TODO).await;
+    tokio::time::delay_for(Duration::from_millis(500)).await;
+
+    let response = query_engine.request_in_tx(&tx_id, FIND_TODOS).await;
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2023");
+
+    let error = query_engine.commit_tx(tx_id).await.unwrap_err();
+    assert_eq!(
+        user_facing_errors::Error::from(error).as_known().unwrap().error_code,
+        "P2023"
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use super::test_api::*;
+use indoc::indoc;
+use quaint::connector::SqlFamily;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static PROFILE: &str = indoc! {"
+    model Profile {
+        id   Int  @id
+        meta Json
+    }
+"};
+
+static CREATE_PROFILES: &str = indoc! {r#"
+    mutation {
+        createManyProfile(data: [
+            { id: 1, meta: "{\"name\": \"Bob\", \"age\": 31, \"height\": 1.82, \"pets\": [\"cat\", \"dog\"]}" },
+            { id: 2, meta: "{\"name\": \"Alice\", \"age\": 24, \"height\": 1.65, \"pets\": [\"dog\"]}" },
+            { id: 3, meta: "{\"name\": \"Bobby\", \"age\": 18, \"height\": 1.7, \"pets\": []}" }
+        ]) { count }
+    }
+"#};
+
+#[test_each_connector(tags("postgres"))]
+async fn json_filters_with_array_path(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&PROFILE).await?;
+    query_engine.request(CREATE_PROFILES).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: ["name"], string_starts_with: "Bob" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 1 }, { "id": 3 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: ["pets"], array_contains: "\"dog\"" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 1 }, { "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: ["age"], gt: "20" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 1 }, { "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: ["height"], lt: "1.75" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 2 }, { "id": 3 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "sqlite"))]
+async fn json_filters_with_string_path(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&PROFILE).await?;
+    query_engine.request(CREATE_PROFILES).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: "$.name", string_contains: "ice" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: "$.pets", array_starts_with: "\"cat\"" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 1 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: "$.pets", array_contains: "\"dog\"" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 1 }, { "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: "$.age", lt: "25" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 2 }, { "id": 3 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyProfile(where: { meta: { path: "$.height", gt: "1.68" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyProfile": [{ "id": 1 }, { "id": 3 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "postgres", "sqlite"))]
+async fn json_paths_must_match_the_database(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&PROFILE).await?;
+    query_engine.request(CREATE_PROFILES).await;
+
+    // Each database gets the path format of the other one.
+    let path = match api.connection_info().sql_family() {
+        SqlFamily::Postgres => r#""$.name""#,
+        _ => r#"["name"]"#,
+    };
+
+    let query = format!(
+        r#"query {{ findManyProfile(where: {{ meta: {{ path: {}, equals: "\"Bob\"" }} }}) {{ id }} }}"#,
+        path
+    );
+
+    let result = query_engine.request(query).await;
+
+    assert_eq!(
+        Some("P2019"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    Ok(())
+}
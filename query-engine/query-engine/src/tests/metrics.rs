@@ -0,0 +1,70 @@
+use crate::server::prometheus::PrometheusRecorder;
+use indoc::indoc;
+use metrics::{Key, Label, Recorder};
+
+fn key(name: &'static str, labels: &[(&'static str, &'static str)]) -> Key {
+    let labels: Vec<Label> = labels.iter().map(|(key, value)| Label::new(*key, *value)).collect();
+    Key::from_name_and_labels(name, labels)
+}
+
+#[test]
+fn counters_and_gauges_are_rendered_in_the_prometheus_format() {
+    let recorder = PrometheusRecorder::default();
+
+    recorder.increment_counter(key("prisma_transactions_total", &[("outcome", "commit")]), 1);
+    recorder.increment_counter(key("prisma_transactions_total", &[("outcome", "commit")]), 2);
+    recorder.increment_counter(key("prisma_transactions_total", &[("outcome", "rollback")]), 1);
+    recorder.update_gauge(
+        key("prisma_pool_connections", &[("state", "active"), ("pool", "primary")]),
+        3,
+    );
+    recorder.update_gauge(
+        key("prisma_pool_connections", &[("state", "active"), ("pool", "primary")]),
+        2,
+    );
+    recorder.update_gauge(key("pool.idle", &[]), 5);
+
+    let expected = indoc! {r#"
+        # TYPE prisma_transactions_total counter
+        prisma_transactions_total{outcome="commit"} 3
+        prisma_transactions_total{outcome="rollback"} 1
+        # TYPE pool_idle gauge
+        pool_idle 5
+        # TYPE prisma_pool_connections gauge
+        prisma_pool_connections{pool="primary",state="active"} 2
+    "#};
+
+    assert_eq!(expected, recorder.render());
+}
+
+#[test]
+fn histograms_are_rendered_with_cumulative_buckets() {
+    let recorder = PrometheusRecorder::default();
+    let histogram_key = || {
+        key(
+            "prisma_query_graph_nodes",
+            &[("model", "Todo"), ("operation", "findMany")],
+        )
+    };
+
+    recorder.record_histogram(histogram_key(), 1);
+    recorder.record_histogram(histogram_key(), 3);
+    recorder.record_histogram(histogram_key(), 100_000_000);
+
+    let rendered = recorder.render();
+    let labels = r#"model="Todo",operation="findMany""#;
+
+    assert!(rendered.starts_with("# TYPE prisma_query_graph_nodes histogram\n"));
+    assert!(rendered.contains(&format!("prisma_query_graph_nodes_bucket{{{},le=\"1\"}} 1\n", labels)));
+    assert!(rendered.contains(&format!("prisma_query_graph_nodes_bucket{{{},le=\"4\"}} 2\n", labels)));
+    assert!(rendered.contains(&format!(
+        "prisma_query_graph_nodes_bucket{{{},le=\"16777216\"}} 2\n",
+        labels
+    )));
+    assert!(rendered.contains(&format!(
+        "prisma_query_graph_nodes_bucket{{{},le=\"+Inf\"}} 3\n",
+        labels
+    )));
+    assert!(rendered.contains(&format!("prisma_query_graph_nodes_sum{{{}}} 100000004\n", labels)));
+    assert!(rendered.contains(&format!("prisma_query_graph_nodes_count{{{}}} 3\n", labels)));
+}
@@ -1,4 +1,21 @@
+mod check_constraints;
+mod create_many;
 mod dmmf;
 mod errors;
 mod execute_raw;
+mod explain;
+mod full_text_search;
+mod interactive_transactions;
+mod json_filters;
+mod metrics;
+mod optimistic_concurrency;
+mod order_by_nulls;
+mod order_by_relations;
+mod read_replicas;
+mod referential_actions;
+mod relation_counts;
+mod request_timeout;
+mod row_locks;
+mod soft_delete;
 mod test_api;
+mod update_operations;
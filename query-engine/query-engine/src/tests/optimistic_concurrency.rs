@@ -0,0 +1,209 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static DOCUMENT: &str = indoc! {"
+    model Document {
+        id      Int    @id
+        title   String
+        version Int    @default(0) @version
+    }
+"};
+
+static CREATE_DOCUMENTS: &str = indoc! {r#"
+    mutation {
+        createManyDocument(data: [{ id: 1, title: "a" }, { id: 2, title: "b" }]) { count }
+    }
+"#};
+
+#[test_each_connector]
+async fn updates_increment_the_version(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENT).await?;
+    query_engine.request(CREATE_DOCUMENTS).await;
+
+    let query = r#"mutation { updateOneDocument(where: { id: 1 }, data: { title: "b" }) { title version } }"#;
+
+    assert_eq!(
+        json!({ "data": { "updateOneDocument": { "title": "b", "version": 1 } } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        mutation {
+            updateOneDocument(where: { id: 1 }, data: { title: "c", version: 1 }) { title version }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOneDocument": { "title": "c", "version": 2 } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn updates_with_a_stale_version_fail(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENT).await?;
+    query_engine.request(CREATE_DOCUMENTS).await;
+
+    let query = indoc! {r#"
+        mutation {
+            updateOneDocument(where: { id: 1 }, data: { title: "b", version: 3 }) { title version }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+
+    assert_eq!(
+        Some("P2025"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    let query = indoc! {r#"
+        mutation {
+            updateOneDocument(where: { id: 3 }, data: { title: "b", version: 0 }) { title version }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+
+    assert_eq!(
+        Some("P2016"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    assert_eq!(
+        json!({ "data": { "findUniqueDocument": { "title": "a", "version": 0 } } }),
+        query_engine
+            .request(r#"query { findUniqueDocument(where: { id: 1 }) { title version } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn update_many_only_updates_records_with_the_expected_version(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&DOCUMENT).await?;
+    query_engine.request(CREATE_DOCUMENTS).await;
+
+    query_engine
+        .request(r#"mutation { updateOneDocument(where: { id: 2 }, data: { title: "c" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "updateManyDocument": { "count": 1 } } }),
+        query_engine
+            .request(r#"mutation { updateManyDocument(data: { title: "d", version: 0 }) { count } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyDocument": [{ "title": "d", "version": 1 }, { "title": "c", "version": 1 }] } }),
+        query_engine
+            .request(r#"query { findManyDocument(orderBy: { id: asc }) { title version } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+static FOLDER: &str = indoc! {"
+    model Folder {
+        id        Int        @id
+        documents Document[]
+    }
+
+    model Document {
+        id       Int    @id
+        title    String
+        version  Int    @default(0) @version
+        folderId Int
+        folder   Folder @relation(fields: [folderId], references: [id])
+    }
+"};
+
+static CREATE_FOLDER: &str = indoc! {r#"
+    mutation {
+        createOneFolder(data: { id: 1, documents: { create: [{ id: 1, title: "a" }] } }) { id }
+    }
+"#};
+
+#[test_each_connector]
+async fn nested_updates_with_a_stale_version_fail(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&FOLDER).await?;
+    query_engine.request(CREATE_FOLDER).await;
+
+    let query = indoc! {r#"
+        mutation {
+            updateOneFolder(
+                where: { id: 1 }
+                data: { documents: { update: { where: { id: 1 }, data: { title: "b", version: 3 } } } }
+            ) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+
+    assert_eq!(
+        Some("P2025"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    let query = indoc! {r#"
+        mutation {
+            updateOneFolder(
+                where: { id: 1 }
+                data: { documents: { update: { where: { id: 1 }, data: { title: "b", version: 0 } } } }
+            ) { documents { title version } }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOneFolder": { "documents": [{ "title": "b", "version": 1 }] } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn nested_upserts_with_a_stale_version_fail(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&FOLDER).await?;
+    query_engine.request(CREATE_FOLDER).await;
+
+    let query = indoc! {r#"
+        mutation {
+            updateOneFolder(
+                where: { id: 1 }
+                data: {
+                    documents: {
+                        upsert: {
+                            where: { id: 1 }
+                            create: { id: 1, title: "c" }
+                            update: { title: "b", version: 3 }
+                        }
+                    }
+                }
+            ) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+
+    assert_eq!(
+        Some("P2025"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    assert_eq!(
+        json!({ "data": { "findUniqueDocument": { "title": "a", "version": 0 } } }),
+        query_engine
+            .request(r#"query { findUniqueDocument(where: { id: 1 }) { title version } }"#)
+            .await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id       Int     @id
+        priority Int?
+    }
+"};
+
+static CREATE_TODOS: &str = indoc! {r#"
+    mutation {
+        createManyTodo(data: [
+            { id: 1, priority: 2 },
+            { id: 2 },
+            { id: 3, priority: 1 },
+            { id: 4 }
+        ]) { count }
+    }
+"#};
+
+#[test_each_connector]
+async fn nulls_can_be_ordered_first(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    query_engine.request(CREATE_TODOS).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(orderBy: [{ priority: { sort: asc, nulls: first } }, { id: asc }]) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 2 }, { "id": 4 }, { "id": 3 }, { "id": 1 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn nulls_can_be_ordered_last(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    query_engine.request(CREATE_TODOS).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(orderBy: [{ priority: { sort: desc, nulls: last } }, { id: asc }]) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1 }, { "id": 3 }, { "id": 2 }, { "id": 4 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cursors_work_with_explicitly_ordered_nulls(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    query_engine.request(CREATE_TODOS).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(
+                orderBy: [{ priority: { sort: asc, nulls: last } }, { id: asc }],
+                cursor: { id: 1 },
+                take: 2
+            ) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1 }, { "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(
+                orderBy: [{ priority: { sort: asc, nulls: first } }, { id: asc }],
+                cursor: { id: 4 },
+                take: -3
+            ) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 2 }, { "id": 4 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id       Int    @id
+        title    String
+        authorId Int
+        author   User   @relation(fields: [authorId], references: [id])
+    }
+"};
+
+static CREATE_DATA: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: {
+            id: 1,
+            name: "Bob",
+            posts: { create: [{ id: 1, title: "a" }] }
+        }) { id }
+    }
+"#};
+
+static CREATE_MORE_DATA: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: {
+            id: 2,
+            name: "Alice",
+            posts: { create: [{ id: 2, title: "b" }, { id: 3, title: "c" }] }
+        }) { id }
+    }
+"#};
+
+static CREATE_USER_WITHOUT_POSTS: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: { id: 3, name: "Carol" }) { id }
+    }
+"#};
+
+#[test_each_connector]
+async fn order_by_to_one_relation_field(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    query_engine.request(CREATE_DATA).await;
+    query_engine.request(CREATE_MORE_DATA).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(orderBy: [{ author: { name: asc } }, { id: desc }]) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 3 }, { "id": 2 }, { "id": 1 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn order_by_to_many_relation_count(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    query_engine.request(CREATE_DATA).await;
+    query_engine.request(CREATE_MORE_DATA).await;
+    query_engine.request(CREATE_USER_WITHOUT_POSTS).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(orderBy: { posts: { _count: desc } }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 2 }, { "id": 1 }, { "id": 3 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cursor_pagination_on_relation_ordering(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    query_engine.request(CREATE_DATA).await;
+    query_engine.request(CREATE_MORE_DATA).await;
+    query_engine.request(CREATE_USER_WITHOUT_POSTS).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(orderBy: [{ posts: { _count: asc } }, { id: asc }], cursor: { id: 1 }, take: 2) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 1 }, { "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(orderBy: [{ author: { name: asc } }, { id: asc }], cursor: { id: 3 }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 3 }, { "id": 1 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
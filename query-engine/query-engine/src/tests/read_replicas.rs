@@ -0,0 +1,69 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::TransactionOptions;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+static CREATE_TODO: &str = indoc! {r#"
+    mutation {
+        createOneTodo(data: { id: 1, title: "title1" }) { id }
+    }
+"#};
+
+static FIND_TODOS: &str = indoc! {r#"
+    query {
+        findManyTodo { id title }
+    }
+"#};
+
+#[test_each_connector(ignore("sqlite"))]
+async fn reads_outside_of_transactions_are_served_by_the_replica(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_replica(&TODO).await?;
+
+    assert_eq!(
+        json!({ "data": { "createOneTodo": { "id": 1 } } }),
+        query_engine.request(CREATE_TODO).await
+    );
+
+    // The replica is a separate database that never sees the writes, so it has no todos.
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1, "title": "title1" }] } }),
+        query_engine.request_on_primary(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn reads_in_transactions_are_served_by_the_primary(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_replica(&TODO).await?;
+    let tx_id = query_engine.start_tx(TransactionOptions::default()).await?;
+
+    query_engine.request_in_tx(&tx_id, CREATE_TODO).await;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1, "title": "title1" }] } }),
+        query_engine.request_in_tx(&tx_id, FIND_TODOS).await
+    );
+
+    query_engine.commit_tx(tx_id).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
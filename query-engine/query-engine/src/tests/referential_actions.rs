@@ -0,0 +1,107 @@
+use super::test_api::*;
+use indoc::{formatdoc, indoc};
+use serde_json::json;
+use test_macros::test_each_connector;
+
+fn schema(on_delete: &str) -> String {
+    formatdoc! {"
+        model User {{
+            id    Int    @id
+            posts Post[]
+        }}
+
+        model Post {{
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id], onDelete: {})
+        }}
+    ", on_delete}
+}
+
+static CREATE_USER_WITH_POST: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: { id: 1, posts: { create: { id: 1 } } }) { id }
+    }
+"#};
+
+static DELETE_USER: &str = indoc! {r#"
+    mutation {
+        deleteOneUser(where: { id: 1 }) { id }
+    }
+"#};
+
+#[test_each_connector(tags("postgres"))]
+async fn on_delete_cascade_deletes_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&schema("Cascade")).await?;
+    query_engine.request(CREATE_USER_WITH_POST).await;
+
+    assert_eq!(
+        json!({ "data": { "deleteOneUser": { "id": 1 } } }),
+        query_engine.request(DELETE_USER).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyPost { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn on_delete_restrict_prevents_deleting_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&schema("Restrict")).await?;
+    query_engine.request(CREATE_USER_WITH_POST).await;
+
+    let result = query_engine.request(DELETE_USER).await;
+
+    assert_eq!(
+        Some("P2014"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn on_delete_set_null_disconnects_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id], onDelete: SetNull)
+        }
+    "};
+
+    let query_engine = api.create_engine(dm).await?;
+    query_engine.request(CREATE_USER_WITH_POST).await;
+
+    assert_eq!(
+        json!({ "data": { "deleteOneUser": { "id": 1 } } }),
+        query_engine.request(DELETE_USER).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyPost { id userId }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 1, "userId": null }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id         Int        @id
+        title      String
+        published  Boolean
+        authorId   Int
+        author     User       @relation(fields: [authorId], references: [id])
+        categories Category[]
+    }
+
+    model Category {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+"};
+
+static CREATE_DATA: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: {
+            id: 1,
+            name: "Bob",
+            posts: {
+                create: [
+                    { id: 1, title: "a", published: true, categories: { create: [{ id: 1, name: "x" }, { id: 2, name: "y" }] } },
+                    { id: 2, title: "b", published: false }
+                ]
+            }
+        }) { id }
+    }
+"#};
+
+static CREATE_MORE_DATA: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: {
+            id: 2,
+            name: "Alice",
+            posts: { create: [{ id: 3, title: "c", published: true, categories: { connect: { id: 1 } } }] }
+        }) { id }
+    }
+"#};
+
+static CREATE_USER_WITHOUT_POSTS: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: { id: 3, name: "Carol" }) { id }
+    }
+"#};
+
+#[test_each_connector]
+async fn count_one_to_many_relations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    query_engine.request(CREATE_DATA).await;
+    query_engine.request(CREATE_MORE_DATA).await;
+    query_engine.request(CREATE_USER_WITHOUT_POSTS).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(orderBy: { id: asc }) { id _count { posts } }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyUser": [
+                    { "id": 1, "_count": { "posts": 2 } },
+                    { "id": 2, "_count": { "posts": 1 } },
+                    { "id": 3, "_count": { "posts": 0 } }
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn count_relations_with_filters(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    query_engine.request(CREATE_DATA).await;
+    query_engine.request(CREATE_MORE_DATA).await;
+
+    let query = indoc! {r#"
+        query {
+            findUniqueUser(where: { id: 1 }) {
+                _count {
+                    posts
+                    published: posts(where: { published: { equals: true } })
+                }
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findUniqueUser": { "_count": { "posts": 2, "published": 1 } } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn count_many_to_many_relations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    query_engine.request(CREATE_DATA).await;
+    query_engine.request(CREATE_MORE_DATA).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyCategory(orderBy: { id: asc }) {
+                id
+                _count { posts }
+                posts(orderBy: { id: asc }) { id _count { categories(where: { name: { equals: "y" } }) } }
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyCategory": [
+                    {
+                        "id": 1,
+                        "_count": { "posts": 2 },
+                        "posts": [
+                            { "id": 1, "_count": { "categories": 1 } },
+                            { "id": 3, "_count": { "categories": 0 } }
+                        ]
+                    },
+                    {
+                        "id": 2,
+                        "_count": { "posts": 1 },
+                        "posts": [{ "id": 1, "_count": { "categories": 1 } }]
+                    }
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use super::test_api::*;
+use indoc::indoc;
+use quaint::connector::SqlFamily;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static ITEM: &str = indoc! {"
+    model Item {
+        id    Int @id
+        stock Int
+    }
+"};
+
+fn sleep_query(api: &TestApi, seconds: u32) -> String {
+    let sleep = match api.connection_info().sql_family() {
+        SqlFamily::Postgres => format!("SELECT pg_sleep({})", seconds),
+        SqlFamily::Mssql => format!("WAITFOR DELAY '00:00:{:02}'", seconds),
+        _ => format!("SELECT SLEEP({})", seconds),
+    };
+
+    format!(r#"mutation {{ queryRaw(query: "{}", parameters: "[]") }}"#, sleep)
+}
+
+#[test_each_connector(tags("postgres", "mysql", "mssql_2017", "mssql_2019"))]
+async fn requests_running_longer_than_their_timeout_are_cancelled(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+
+    let response = query_engine
+        .request_with_timeout(Duration::from_millis(200), sleep_query(api, 5))
+        .await;
+
+    assert_eq!("P2026", response["errors"][0]["user_facing_error"]["error_code"]);
+    assert_eq!("200", response["errors"][0]["user_facing_error"]["meta"]["timeout"]);
+
+    // The cancelled statement doesn't hold up the following requests.
+    let response = query_engine
+        .request_with_timeout(
+            Duration::from_secs(2),
+            r#"mutation { createOneItem(data: { id: 1, stock: 10 }) { id } }"#,
+        )
+        .await;
+
+    assert_eq!(json!({ "data": { "createOneItem": { "id": 1 } } }), response);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql", "mssql_2017", "mssql_2019"))]
+async fn timed_out_transactional_batches_are_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+
+    let response = query_engine
+        .request_batch_with_timeout(
+            Duration::from_millis(200),
+            vec![
+                r#"mutation { createOneItem(data: { id: 1, stock: 10 }) { id } }"#.to_owned(),
+                sleep_query(api, 5),
+            ],
+        )
+        .await;
+
+    assert_eq!("P2026", response["errors"][0]["user_facing_error"]["error_code"]);
+
+    assert_eq!(
+        json!({ "data": { "findManyItem": [] } }),
+        query_engine.request("query { findManyItem { id } }").await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::TransactionOptions;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static ITEM: &str = indoc! {"
+    model Item {
+        id    Int @id
+        stock Int
+    }
+"};
+
+static CREATE_ITEMS: &str = indoc! {r#"
+    mutation {
+        createManyItem(data: [{ id: 1, stock: 10 }, { id: 2, stock: 20 }]) { count }
+    }
+"#};
+
+#[test_each_connector(ignore("sqlite"))]
+async fn locking_reads_return_records_inside_transactions(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    query_engine.request(CREATE_ITEMS).await;
+
+    let tx_id = query_engine.start_tx(TransactionOptions::default()).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyItem(where: { stock: { gt: 5 } }, orderBy: { id: asc }, lock: { mode: forUpdate }) { id stock }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyItem": [{ "id": 1, "stock": 10 }, { "id": 2, "stock": 20 }] } }),
+        query_engine.request_in_tx(&tx_id, query).await
+    );
+
+    let query = r#"query { findUniqueItem(where: { id: 2 }, lock: { mode: forUpdate }) { stock } }"#;
+
+    assert_eq!(
+        json!({ "data": { "findUniqueItem": { "stock": 20 } } }),
+        query_engine.request_in_tx(&tx_id, query).await
+    );
+
+    query_engine.commit_tx(tx_id).await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mssql_2017", "mssql_2019"))]
+async fn skip_locked_leaves_out_rows_locked_by_other_transactions(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    query_engine.request(CREATE_ITEMS).await;
+
+    let first_tx = query_engine.start_tx(TransactionOptions::default()).await?;
+    let second_tx = query_engine.start_tx(TransactionOptions::default()).await?;
+
+    query_engine
+        .request_in_tx(
+            &first_tx,
+            r#"query { findUniqueItem(where: { id: 1 }, lock: { mode: forUpdate }) { id } }"#,
+        )
+        .await;
+
+    let query = indoc! {r#"
+        query {
+            findFirstItem(orderBy: { id: asc }, lock: { mode: forUpdate, skipLocked: true }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findFirstItem": { "id": 2 } } }),
+        query_engine.request_in_tx(&second_tx, query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyItem(where: { id: 1 }, lock: { mode: forShare, noWait: true }) { id }
+        }
+    "#};
+
+    // The row is locked by the first transaction, so the read fails instead of waiting.
+    let result = query_engine.request_in_tx(&second_tx, query).await;
+    assert!(result["errors"][0].is_object());
+
+    query_engine.rollback_tx(second_tx).await?;
+    query_engine.rollback_tx(first_tx).await?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn locking_reads_require_a_transaction(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    query_engine.request(CREATE_ITEMS).await;
+
+    let result = query_engine
+        .request(r#"query { findManyItem(lock: { mode: forUpdate }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        Some("P2024"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn locking_reads_are_not_supported_on_sqlite(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    let tx_id = query_engine.start_tx(TransactionOptions::default()).await?;
+
+    let result = query_engine
+        .request_in_tx(&tx_id, r#"query { findManyItem(lock: { mode: forShare }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        Some("P2024"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    query_engine.rollback_tx(tx_id).await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn skip_locked_and_no_wait_are_exclusive(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    let tx_id = query_engine.start_tx(TransactionOptions::default()).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyItem(lock: { mode: forUpdate, skipLocked: true, noWait: true }) { id }
+        }
+    "#};
+
+    let result = query_engine.request_in_tx(&tx_id, query).await;
+
+    assert_eq!(
+        Some("P2019"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    query_engine.rollback_tx(tx_id).await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_5_6", "mariadb"))]
+async fn shared_locks_fall_back_to_lock_in_share_mode_before_mysql_8(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    query_engine.request(CREATE_ITEMS).await;
+
+    let tx_id = query_engine.start_tx(TransactionOptions::default()).await?;
+
+    let query = r#"query { findManyItem(orderBy: { id: asc }, lock: { mode: forShare }) { id } }"#;
+
+    assert_eq!(
+        json!({ "data": { "findManyItem": [{ "id": 1 }, { "id": 2 }] } }),
+        query_engine.request_in_tx(&tx_id, query).await
+    );
+
+    let query = r#"query { findManyItem(lock: { mode: forUpdate, skipLocked: true }) { id } }"#;
+    let result = query_engine.request_in_tx(&tx_id, query).await;
+
+    assert_eq!(
+        Some("P2024"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    query_engine.rollback_tx(tx_id).await?;
+
+    Ok(())
+}
@@ -0,0 +1,260 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id        Int       @id
+        title     String
+        authorId  Int
+        author    User      @relation(fields: [authorId], references: [id])
+        deletedAt DateTime?
+
+        @@softDelete(field: deletedAt)
+    }
+"};
+
+static CREATE_BLOG: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: {
+            id: 1,
+            posts: { create: [{ id: 1, title: "a" }, { id: 2, title: "b" }, { id: 3, title: "c" }] }
+        }) { id }
+    }
+"#};
+
+#[test_each_connector]
+async fn deleted_records_are_kept_but_left_out_of_reads(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    query_engine.request(CREATE_BLOG).await;
+
+    assert_eq!(
+        json!({ "data": { "deleteOnePost": { "id": 1 } } }),
+        query_engine
+            .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 2 }, { "id": 3 }] } }),
+        query_engine
+            .request(r#"query { findManyPost(orderBy: { id: asc }) { id } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findUniquePost": null } }),
+        query_engine
+            .request(r#"query { findUniquePost(where: { id: 1 }) { id } }"#)
+            .await
+    );
+
+    let result = query_engine
+        .request(r#"query { findUniquePost(where: { id: 1 }, includeDeleted: true) { id deletedAt } }"#)
+        .await;
+
+    assert_eq!(json!(1), result["data"]["findUniquePost"]["id"]);
+    assert!(result["data"]["findUniquePost"]["deletedAt"].is_string());
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 1 }, { "id": 2 }, { "id": 3 }] } }),
+        query_engine
+            .request(r#"query { findManyPost(orderBy: { id: asc }, includeDeleted: true) { id } }"#)
+            .await
+    );
+
+    // Deleting a deleted record fails like deleting a record that doesn't exist.
+    let result = query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert!(result["errors"][0].is_object());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn delete_many_marks_records_as_deleted(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    query_engine.request(CREATE_BLOG).await;
+
+    assert_eq!(
+        json!({ "data": { "deleteManyPost": { "count": 2 } } }),
+        query_engine
+            .request(r#"mutation { deleteManyPost(where: { id: { lt: 3 } }) { count } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "deleteManyPost": { "count": 1 } } }),
+        query_engine.request(r#"mutation { deleteManyPost { count } }"#).await
+    );
+
+    assert_eq!(
+        json!({ "data": { "aggregatePost": { "_count": { "_all": 0 } } } }),
+        query_engine
+            .request(r#"query { aggregatePost { _count { _all } } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "aggregatePost": { "_count": { "_all": 3 } } } }),
+        query_engine
+            .request(r#"query { aggregatePost(includeDeleted: true) { _count { _all } } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn nested_deletes_mark_related_records_as_deleted(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    query_engine.request(CREATE_BLOG).await;
+
+    let query = indoc! {r#"
+        mutation {
+            updateOneUser(where: { id: 1 }, data: { posts: { delete: [{ id: 1 }], deleteMany: [{ title: "b" }] } }) {
+                posts { id }
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOneUser": { "posts": [{ "id": 3 }] } } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findUniqueUser(where: { id: 1 }) {
+                posts(orderBy: { id: asc }, includeDeleted: true) { id }
+                _count { posts }
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findUniqueUser": { "posts": [{ "id": 1 }, { "id": 2 }, { "id": 3 }], "_count": { "posts": 1 } } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn relation_filters_ignore_deleted_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    query_engine.request(CREATE_BLOG).await;
+    query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [] } }),
+        query_engine
+            .request(r#"query { findManyUser(where: { posts: { some: { title: "a" } } }) { id } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 1 }] } }),
+        query_engine
+            .request(r#"query { findManyUser(where: { posts: { none: { title: "a" } } }) { id } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 1 }] } }),
+        query_engine
+            .request(r#"query { findManyUser(where: { posts: { every: { title: { not: "a" } } } }) { id } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+static AUTHORS: &str = indoc! {"
+    model User {
+        id        Int       @id
+        posts     Post[]
+        deletedAt DateTime?
+
+        @@softDelete(field: deletedAt)
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+"};
+
+#[test_each_connector]
+async fn required_to_one_relations_keep_deleted_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&AUTHORS).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }] } }) { id } }"#)
+        .await;
+
+    query_engine
+        .request(r#"mutation { deleteOneUser(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 1, "author": { "id": 1 } }] } }),
+        query_engine
+            .request(r#"query { findManyPost { id author { id } } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn updates_and_upserts_match_deleted_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    query_engine.request(CREATE_BLOG).await;
+    query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    // Updating a deleted record keeps it deleted unless the update restores it.
+    let result = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "x" }) { title deletedAt } }"#)
+        .await;
+
+    assert_eq!(json!("x"), result["data"]["updateOnePost"]["title"]);
+    assert!(result["data"]["updateOnePost"]["deletedAt"].is_string());
+
+    let query = indoc! {r#"
+        mutation {
+            upsertOnePost(
+                where: { id: 1 }
+                create: { id: 1, title: "y", authorId: 1 }
+                update: { title: "z", deletedAt: null }
+            ) { title deletedAt }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "upsertOnePost": { "title": "z", "deletedAt": null } } }),
+        query_engine.request(query).await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findUniquePost": { "id": 1 } } }),
+        query_engine
+            .request(r#"query { findUniquePost(where: { id: 1 }) { id } }"#)
+            .await
+    );
+
+    Ok(())
+}
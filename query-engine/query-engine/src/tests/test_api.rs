@@ -13,8 +13,9 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use query_core::{ExplainMode, TransactionOptions, TxId};
 use sql_migration_connector::SqlMigrationConnector;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use test_setup::*;
 
 pub struct QueryEngine {
@@ -28,9 +29,72 @@ impl QueryEngine {
     }
 
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_tx(None, false, None, body).await
+    }
+
+    pub async fn request_in_tx(&self, tx_id: &TxId, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_tx(Some(tx_id.clone()), false, None, body).await
+    }
+
+    pub async fn request_on_primary(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_tx(None, true, None, body).await
+    }
+
+    pub async fn request_explained(&self, explain: ExplainMode, body: impl Into<SingleQuery>) -> serde_json::Value {
+        let body: SingleQuery = body.into();
+
+        self.request_with_tx(None, false, None, body.explained(explain)).await
+    }
+
+    pub async fn request_with_timeout(&self, timeout: Duration, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_tx(None, false, Some(timeout), body).await
+    }
+
+    /// Sends the request body as it would come in over HTTP.
+    pub async fn request_body(&self, body: GraphQlBody) -> serde_json::Value {
+        let response = graphql::handle(body, None, false, None, self.context.clone()).await;
+
+        serde_json::to_value(response).unwrap()
+    }
+
+    /// Executes the queries as a transactional batch.
+    pub async fn request_batch_with_timeout(&self, timeout: Duration, queries: Vec<String>) -> serde_json::Value {
+        let batch: Vec<_> = queries
+            .into_iter()
+            .map(|query| serde_json::json!({ "query": query, "variables": {} }))
+            .collect();
+
+        let body: GraphQlBody =
+            serde_json::from_value(serde_json::json!({ "batch": batch, "transaction": true })).unwrap();
+        let cx = self.context.clone();
+        let response = graphql::handle(body, None, false, Some(timeout), cx).await;
+
+        serde_json::to_value(response).unwrap()
+    }
+
+    pub async fn start_tx(&self, options: TransactionOptions) -> query_core::Result<TxId> {
+        let schema = self.context.query_schema().clone();
+        self.context.executor.start_tx(schema, options).await
+    }
+
+    pub async fn commit_tx(&self, tx_id: TxId) -> query_core::Result<()> {
+        self.context.executor.commit_tx(tx_id).await
+    }
+
+    pub async fn rollback_tx(&self, tx_id: TxId) -> query_core::Result<()> {
+        self.context.executor.rollback_tx(tx_id).await
+    }
+
+    async fn request_with_tx(
+        &self,
+        tx_id: Option<TxId>,
+        force_primary: bool,
+        timeout: Option<Duration>,
+        body: impl Into<SingleQuery>,
+    ) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
-        match graphql::handle(body, cx).await {
+        match graphql::handle(body, tx_id, force_primary, timeout, cx).await {
             PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
             _ => unreachable!(),
         }
@@ -41,13 +105,63 @@ pub struct TestApi {
     connection_info: ConnectionInfo,
     migration_api: MigrationApi<SqlMigrationConnector>,
     config: String,
+    /// The URL of a separate database on the same server, used as read replica.
+    replica_url: Option<String>,
 }
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with_config(&self.config, datamodel).await
+    }
+
+    /// Like `create_engine`, but the datasource gets a read replica: a separate database with the same schema, that
+    /// never sees the writes made through the engine. Reads served by the replica are therefore easy to tell apart.
+    pub async fn create_engine_with_replica(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        let replica_url = self
+            .replica_url
+            .as_deref()
+            .expect("Read replicas are not supported on this connector.");
+
+        let url_line = self
+            .config
+            .lines()
+            .find(|line| line.trim_start().starts_with("url"))
+            .unwrap();
+
+        let indentation = &url_line[..url_line.len() - url_line.trim_start().len()];
+
+        let replica_config = self
+            .config
+            .replacen(url_line, &format!("{}url = \"{}\"", indentation, replica_url), 1);
+
+        let replica_connector = match &self.connection_info {
+            ConnectionInfo::Postgres(_) => postgres_migration_connector(replica_url).await,
+            ConnectionInfo::Mysql(_) => mysql_migration_connector(replica_url).await,
+            ConnectionInfo::Mssql(_) => mssql_migration_connector(replica_url).await,
+            ConnectionInfo::Sqlite { .. } | ConnectionInfo::InMemorySqlite { .. } => unreachable!(),
+        };
+
+        MigrationApi::new(replica_connector)
+            .schema_push(&SchemaPushInput {
+                schema: format!("{}\n\n{}", replica_config, datamodel),
+                force: true,
+                assume_empty: true,
+            })
+            .await?;
+
+        let config = self.config.replacen(
+            url_line,
+            &format!("{}\n{}replicaUrls = [\"{}\"]", url_line, indentation, replica_url),
+            1,
+        );
+
+        self.create_engine_with_config(&config, datamodel).await
+    }
+
+    async fn create_engine_with_config(&self, config: &str, datamodel: &str) -> anyhow::Result<QueryEngine> {
         feature_flags::initialize(&[String::from("all")]).unwrap();
 
-        let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
+        let datamodel_string = format!("{}\n\n{}", config, datamodel);
         let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
         let config = datamodel::parse_configuration(&datamodel_string).unwrap();
 
@@ -97,6 +211,7 @@ pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(mysql_8_url(&replica_db_name(db_name))),
     }
 }
 
@@ -113,6 +228,7 @@ pub async fn mysql_5_6_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(mysql_5_6_url(&replica_db_name(db_name))),
     }
 }
 
@@ -129,6 +245,7 @@ pub async fn mysql_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(mysql_url(&replica_db_name(db_name))),
     }
 }
 
@@ -145,6 +262,7 @@ pub async fn mysql_mariadb_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(mariadb_url(&replica_db_name(db_name))),
     }
 }
 
@@ -161,6 +279,7 @@ pub async fn postgres9_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(postgres_9_url(&replica_db_name(db_name))),
     }
 }
 
@@ -177,6 +296,7 @@ pub async fn postgres_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(postgres_10_url(&replica_db_name(db_name))),
     }
 }
 
@@ -193,6 +313,7 @@ pub async fn postgres11_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(postgres_11_url(&replica_db_name(db_name))),
     }
 }
 
@@ -209,6 +330,7 @@ pub async fn postgres12_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(postgres_12_url(&replica_db_name(db_name))),
     }
 }
 
@@ -225,6 +347,7 @@ pub async fn postgres13_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(postgres_13_url(&replica_db_name(db_name))),
     }
 }
 
@@ -241,6 +364,7 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: None,
     }
 }
 
@@ -257,6 +381,7 @@ pub async fn mssql_2017_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(mssql_2017_url(&replica_db_name(db_name))),
     }
 }
 
@@ -273,9 +398,14 @@ pub async fn mssql_2019_test_api(args: TestAPIArgs) -> TestApi {
         connection_info,
         migration_api,
         config,
+        replica_url: Some(mssql_2019_url(&replica_db_name(db_name))),
     }
 }
 
+fn replica_db_name(db_name: &str) -> String {
+    format!("r_{}", db_name)
+}
+
 pub(super) async fn mysql_migration_connector(url_str: &str) -> SqlMigrationConnector {
     create_mysql_database(&url_str.parse().unwrap()).await.unwrap();
     SqlMigrationConnector::new(url_str, BitFlags::all()).await.unwrap()
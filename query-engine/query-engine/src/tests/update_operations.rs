@@ -0,0 +1,106 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+#[test_each_connector(tags("postgres"))]
+async fn push_appends_to_scalar_lists(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {"
+        model Post {
+            id   Int      @id
+            tags String[]
+        }
+    "};
+
+    let query_engine = api.create_engine(dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, tags: { set: ["a"] } }) { id } }"#)
+        .await;
+
+    let query = indoc! {r#"
+        mutation {
+            updateOnePost(where: { id: 1 }, data: { tags: { push: "b" } }) { tags }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOnePost": { "tags": ["a", "b"] } } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        mutation {
+            updateOnePost(where: { id: 1 }, data: { tags: { push: ["c", "d"] } }) { tags }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOnePost": { "tags": ["a", "b", "c", "d"] } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn push_appends_to_scalar_lists_in_update_many(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {"
+        model Post {
+            id   Int   @id
+            nums Int[]
+        }
+    "};
+
+    let query_engine = api.create_engine(dm).await?;
+
+    query_engine
+        .request(r#"mutation { createManyPost(data: [{ id: 1, nums: [1] }, { id: 2 }]) { count } }"#)
+        .await;
+
+    query_engine
+        .request(r#"mutation { updateManyPost(data: { nums: { push: 2 } }) { count } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "nums": [1, 2] }, { "nums": [2] }] } }),
+        query_engine
+            .request(r#"query { findManyPost(orderBy: { id: asc }) { nums } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn unset_removes_optional_values(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {"
+        model User {
+            id       Int     @id
+            nickname String?
+            age      Int?
+        }
+    "};
+
+    let query_engine = api.create_engine(dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, nickname: "Bob", age: 31 }) { id } }"#)
+        .await;
+
+    let query = indoc! {r#"
+        mutation {
+            updateOneUser(where: { id: 1 }, data: { nickname: { unset: true }, age: { unset: false } }) {
+                nickname
+                age
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOneUser": { "nickname": null, "age": 31 } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}